@@ -1,6 +1,35 @@
+use std::borrow::Cow;
+#[cfg(feature = "fs")]
+use std::collections::BTreeMap;
+#[cfg(feature = "fs")]
+use std::ffi::{OsStr, OsString};
+#[cfg(feature = "fs")]
+use std::fs::File;
+#[cfg(feature = "fs")]
+use std::io::Write;
 use std::path::{Path, PathBuf};
-
-use crate::{Append, Xdg, XdgDir, XdgError, XdgSysDirs};
+#[cfg(feature = "fs")]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+#[cfg(feature = "fs")]
+use std::time::SystemTime;
+use std::{env, fmt, iter};
+#[cfg(feature = "fs")]
+use std::{fs, io};
+
+use crate::{
+    write_summary_line, Append, AppendLen, DesktopEnvironment, RelativePathPolicy, Scope,
+    SessionClass, SessionType, Shell, SudoPolicy, ThumbnailSize, Xdg, XdgDiagnostics, XdgDir,
+    XdgDirKind, XdgError, XdgPaths, XdgSysDirs,
+};
+use crate::search_cache::SearchCache;
+#[cfg(feature = "fs")]
+use crate::{
+    existing_dir, AutostartEntry, EntryType, FileLock, InstallOptions, PruneOptions, RecentFile,
+    RemoveOptions, SaveOptions, SearchOrder, SymlinkPolicy, TrashEntry, XdgAppDirs,
+};
+#[cfg(all(feature = "fs", feature = "serde"))]
+use crate::ConfigFormat;
 
 /// _An implementation of the [XDG Base Directory Specification](<https://specifications.freedesktop.org/basedir-spec/basedir-spec-latest.html>)_
 /// with extent to application-specific subdirectories.
@@ -102,328 +131,1002 @@ use crate::{Append, Xdg, XdgDir, XdgError, XdgSysDirs};
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XdgApp {
     /// The [`Xdg`] instance.
     xdg: Xdg,
-    /// The application name.
-    name: &'static str,
+    /// The application name, shared among clones.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_name"))]
+    name: Arc<str>,
+    /// The optional application profile.
+    #[cfg_attr(feature = "serde", serde(default, deserialize_with = "deserialize_profile"))]
+    profile: Option<Arc<str>>,
+    /// Previous names of the application, searched after the current one.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "no_aliases", deserialize_with = "deserialize_aliases")
+    )]
+    aliases: Arc<[Arc<str>]>,
+    /// Whether app-specific override environment variables are honored.
+    #[cfg_attr(feature = "serde", serde(default))]
+    env_overrides: bool,
+    /// Prefix of the app-specific override environment variables, derived
+    /// from the application name if not set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    env_prefix: Option<Arc<str>>,
+    /// Cache of the paths where files were last found, if enabled through
+    /// [`XdgApp::with_search_cache`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    search_cache: Option<SearchCache>,
+}
+
+/// Returns an empty list of application aliases.
+#[inline]
+fn no_aliases() -> Arc<[Arc<str>]> {
+    Arc::from(Vec::new())
+}
+
+/// Characters not allowed in app names, profiles and aliases.
+const INVALID_NAME_CHARS: &[char] = if cfg!(windows) { &['/', '\\', '\0'] } else { &['/', '\0'] };
+
+/// Ensures the app `name` is a single, non-empty path component, so that app
+/// subdirectories can't escape their XDG base directory.
+fn validate_name(name: &str) -> Result<(), XdgError> {
+    if name.is_empty() || name == "." || name == ".." || name.contains(INVALID_NAME_CHARS) {
+        return Err(XdgError::InvalidAppName { name: name.to_owned() });
+    }
+
+    Ok(())
+}
+
+/// Deserializes a valid app name.
+#[cfg(feature = "serde")]
+fn deserialize_name<'de, D>(deserializer: D) -> Result<Arc<str>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let name = <Arc<str> as serde::Deserialize>::deserialize(deserializer)?;
+    validate_name(&name).map_err(serde::de::Error::custom)?;
+    Ok(name)
+}
+
+/// Deserializes an optional, valid app profile.
+#[cfg(feature = "serde")]
+fn deserialize_profile<'de, D>(deserializer: D) -> Result<Option<Arc<str>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let profile = <Option<Arc<str>> as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(profile) = &profile {
+        validate_name(profile).map_err(serde::de::Error::custom)?;
+    }
+
+    Ok(profile)
+}
+
+/// Deserializes a list of valid app aliases.
+#[cfg(feature = "serde")]
+fn deserialize_aliases<'de, D>(deserializer: D) -> Result<Arc<[Arc<str>]>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let aliases = <Arc<[Arc<str>]> as serde::Deserialize>::deserialize(deserializer)?;
+    for alias in aliases.iter() {
+        validate_name(alias).map_err(serde::de::Error::custom)?;
+    }
+
+    Ok(aliases)
 }
 
 impl XdgApp {
     /// Constructs a new [`XdgApp`] instance from the given `home` directory.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the app `name` is not a valid single
+    /// path component (see [`XdgApp::new`]).
     #[inline]
-    pub fn with_home<P>(home: P, name: &'static str) -> XdgApp
+    pub fn with_home<P, N>(home: P, name: N) -> Result<XdgApp, XdgError>
     where
         P: Into<PathBuf>,
+        N: Into<Arc<str>>,
     {
-        XdgApp { xdg: Xdg::with_home(home), name }
+        XdgApp::from_xdg(Xdg::with_home(home), name)
     }
 
     /// Constructs a new [`XdgApp`] instance, given the app `name`.
     ///
+    /// The app `name` is stored as an [`Arc<str>`], so that it can be computed
+    /// at runtime (e.g. a [`String`]) and cheaply shared among clones.
+    ///
     /// # Errors
     ///
-    /// This function returns an error if neither `HOME` or `USER` environment
+    /// This function returns an error if the app `name` is not a valid single
+    /// path component, i.e. it is empty, `.` or `..`, or contains path
+    /// separators (`/`, and `\\` on Windows) or NUL bytes, or if neither `HOME` or `USER` environment
     /// variable is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// assert_eq!(
+    ///     XdgError::InvalidAppName { name: String::from("../../etc") },
+    ///     XdgApp::new("../../etc").unwrap_err(),
+    /// );
+    /// ```
     #[inline]
-    pub fn new(name: &'static str) -> Result<XdgApp, XdgError> {
-        Ok(XdgApp { xdg: Xdg::new()?, name })
+    pub fn new<N>(name: N) -> Result<XdgApp, XdgError>
+    where
+        N: Into<Arc<str>>,
+    {
+        let name = name.into();
+        validate_name(&name)?;
+        Ok(XdgApp::from_xdg_unchecked(Xdg::new()?, name))
     }
 
-    /// Constructs a new [`XdgApp`] upgrading an existing [`Xdg`].
+    /// Constructs a new [`XdgApp`] instance, given the app `name`, resolving
+    /// the home directory according to the given [`SudoPolicy`].
+    ///
+    /// See [`Xdg::new_with_sudo_policy`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the app `name` is not a valid single
+    /// path component, or if neither `HOME` or `USER` environment variable is
+    /// set, and the home directory of the invoking user is not resolved.
     #[inline]
-    #[must_use]
-    pub fn from_xdg(xdg: Xdg, name: &'static str) -> XdgApp {
-        XdgApp { xdg, name }
+    pub fn new_with_sudo_policy<N>(name: N, policy: SudoPolicy) -> Result<XdgApp, XdgError>
+    where
+        N: Into<Arc<str>>,
+    {
+        let name = name.into();
+        validate_name(&name)?;
+        Ok(XdgApp::from_xdg_unchecked(Xdg::new_with_sudo_policy(policy)?, name))
     }
 
-    /// Downgrades the [`XdgApp`] to the contained [`Xdg`].
+    /// Constructs a new [`XdgApp`] instance in strict specification-compliance
+    /// mode.
+    ///
+    /// See [`Xdg::new_strict`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the app `name` is not a valid single
+    /// path component, or if the `HOME` environment variable is not set.
+    #[inline]
+    pub fn new_strict<N>(name: N) -> Result<XdgApp, XdgError>
+    where
+        N: Into<Arc<str>>,
+    {
+        let name = name.into();
+        validate_name(&name)?;
+        Ok(XdgApp::from_xdg_unchecked(Xdg::new_strict()?, name))
+    }
+
+    /// Constructs a new [`XdgApp`] instance for the user named `user`, given
+    /// the app `name`.
+    ///
+    /// See [`Xdg::for_user`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the app `name` is not a valid single
+    /// path component, or if the home directory of the user is not found in
+    /// the user database.
+    #[inline]
+    #[cfg(all(unix, feature = "passwd"))]
+    pub fn for_user<N>(user: &str, name: N) -> Result<XdgApp, XdgError>
+    where
+        N: Into<Arc<str>>,
+    {
+        let name = name.into();
+        validate_name(&name)?;
+        Ok(XdgApp::from_xdg_unchecked(Xdg::for_user(user)?, name))
+    }
+
+    /// Returns the home directory of the user invoking `sudo`, if any.
+    ///
+    /// See [`Xdg::sudo_user_home`] for further details.
     #[inline]
     #[must_use]
-    pub fn into_xdg(self) -> Xdg {
-        self.into()
+    pub fn sudo_user_home() -> Option<PathBuf> {
+        Xdg::sudo_user_home()
     }
 
-    /// Returns the **home** directory of the user owning the process.
+    /// Constructs a new [`XdgApp`] upgrading an existing [`Xdg`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the app `name` is not a valid single
+    /// path component (see [`XdgApp::new`]).
+    #[inline]
+    pub fn from_xdg<N>(xdg: Xdg, name: N) -> Result<XdgApp, XdgError>
+    where
+        N: Into<Arc<str>>,
+    {
+        let name = name.into();
+        validate_name(&name)?;
+        Ok(XdgApp::from_xdg_unchecked(xdg, name))
+    }
+
+    /// Constructs a new [`XdgApp`] upgrading an existing [`Xdg`], given an
+    /// already validated app `name`.
+    #[inline]
+    fn from_xdg_unchecked(xdg: Xdg, name: Arc<str>) -> XdgApp {
+        XdgApp {
+            xdg,
+            name,
+            profile: None,
+            aliases: no_aliases(),
+            env_overrides: false,
+            env_prefix: None,
+            search_cache: None,
+        }
+    }
+
+    /// Returns the application name.
+    #[inline]
     #[must_use]
-    pub fn home(&self) -> &Path {
-        self.xdg.home()
+    pub fn name(&self) -> &str {
+        &self.name
     }
 
-    /// Returns the _user-specific_ XDG **cache** directory specified by the
-    /// `XDG_CACHE_HOME` environment variable. Falls back to `$HOME/.cache`
-    /// if `XDG_CACHE_HOME` is not set or is set to an empty value.
+    /// Sets the application `profile`, so that every application subdirectory
+    /// resolves to `<xdg_dir>/<app_name>/<profile>`.
+    ///
+    /// Profiles allow running multiple isolated configurations of the same
+    /// application side by side.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// This method returns an error if the `profile` is not a valid single
+    /// path component (see [`XdgApp::new`]).
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
+    /// # use std::path::Path;
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let cache_dir = xdg.cache()?;
+    /// std::env::set_var("XDG_CONFIG_HOME", "/home/user/.config");
+    ///
+    /// let xdg = XdgApp::new("app_name")?.with_profile("dev")?;
+    /// assert_eq!(Path::new("/home/user/.config/app_name/dev"), xdg.app_config()?);
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn cache(&self) -> Result<PathBuf, XdgError> {
-        self.xdg.cache()
+    pub fn with_profile<P>(mut self, profile: P) -> Result<XdgApp, XdgError>
+    where
+        P: Into<Arc<str>>,
+    {
+        let profile = profile.into();
+        validate_name(&profile)?;
+        self.profile = Some(profile);
+        Ok(self)
     }
 
-    /// Returns the _user-specific_ XDG **configuration** directory specified by
-    /// the `XDG_CONFIG_HOME` environment variable. Falls back to
-    /// `$HOME/.config` if `XDG_CONFIG_HOME` is not set or is set
-    /// to an empty value.
+    /// Sets the previous names of the application, so that every
+    /// `search_app_*` method also looks for files under them, in order.
+    ///
+    /// This eases renaming an application, since the files in the app
+    /// subdirectories named after the previous names are still found. Every
+    /// _user-specific_ app subdirectory is searched before any _system-wide_
+    /// one, so that the user's files under a previous name take precedence
+    /// over the system defaults under the current name.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// This method returns an error if any of the `aliases` is not a valid
+    /// app name (see [`XdgApp::new`]).
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let config_dir = xdg.config()?;
+    /// let xdg = XdgApp::new("new_name")?.with_aliases(["old_name"])?;
+    /// // Looks for `$XDG_CONFIG_HOME/new_name/config.toml`, then for
+    /// // `$XDG_CONFIG_HOME/old_name/config.toml`.
+    /// # #[cfg(feature = "fs")]
+    /// match xdg.search_app_config_file("config.toml")? {
+    ///     Some(app_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
     /// # Ok(())
     /// # }
     /// ```
+    pub fn with_aliases<I, A>(mut self, aliases: I) -> Result<XdgApp, XdgError>
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<Arc<str>>,
+    {
+        self.aliases = aliases
+            .into_iter()
+            .map(|alias| {
+                let alias = alias.into();
+                validate_name(&alias)?;
+                Ok(alias)
+            })
+            .collect::<Result<_, XdgError>>()?;
+        Ok(self)
+    }
+
+    /// Returns the previous names of the application.
     #[inline]
-    pub fn config(&self) -> Result<PathBuf, XdgError> {
-        self.xdg.config()
+    #[must_use]
+    pub fn aliases(&self) -> &[Arc<str>] {
+        &self.aliases
     }
 
-    /// Returns the _user-specific_ XDG **data** directory specified by the
-    /// `XDG_DATA_HOME` environment variable. Falls back to
-    /// `$HOME/.local/share` if `XDG_DATA_HOME` is not set or is set to an
-    /// empty value.
+    /// Returns an iterator over the current application followed, in order,
+    /// by one [`XdgApp`] for each alias.
+    #[cfg(feature = "fs")]
+    fn aliased(&self) -> impl Iterator<Item = Cow<'_, XdgApp>> {
+        let aliases = self.aliases.iter().map(|alias| {
+            Cow::Owned(XdgApp {
+                name: Arc::clone(alias),
+                aliases: no_aliases(),
+                env_overrides: false,
+                ..self.clone()
+            })
+        });
+
+        iter::once(Cow::Borrowed(self)).chain(aliases)
+    }
+
+    /// Returns the _user-specific_ XDG app subdirectories of the current
+    /// application and its aliases, in order.
+    #[cfg(feature = "fs")]
+    fn get_aliased_app_dir_paths(&self, dir: XdgDir) -> Result<Vec<PathBuf>, XdgError> {
+        self.aliased().map(|app| app.get_app_dir_path(dir)).collect()
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG app subdirectories
+    /// of the current application and its aliases, in order.
+    #[cfg(feature = "fs")]
+    fn get_aliased_app_sys_dir_paths(&self, dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
+        let dir_paths = self.xdg.resolve_sys_dir_paths(dirs)?;
+        let mut paths = Vec::new();
+        for app in self.aliased() {
+            paths.extend(dir_paths.iter().map(|path| app.append_app(path.clone())));
+        }
+
+        Ok(paths)
+    }
+
+    /// Returns the XDG app subdirectories searched for a file inside the `dir`
+    /// XDG directories, in the [`SearchOrder`] they are consulted.
+    /// Subdirectories failing to resolve are left out.
+    #[cfg(feature = "fs")]
+    fn searched_app_dirs(&self, dir: XdgDir) -> Vec<PathBuf> {
+        let usr_dirs = self.get_aliased_app_dir_paths(dir).unwrap_or_default();
+        let sys_dirs = match dir.to_sys() {
+            Some(sys_dirs) => self.get_aliased_app_sys_dir_paths(sys_dirs).unwrap_or_default(),
+            None => Vec::new(),
+        };
+
+        let dirs = match self.xdg.search_order() {
+            SearchOrder::UserFirst => [usr_dirs, sys_dirs],
+            SearchOrder::SystemFirst => [sys_dirs, usr_dirs],
+        };
+        dirs.into_iter().flatten().collect()
+    }
+
+    /// Sets whether the app-specific override environment variables
+    /// `<APP>_CACHE_DIR`, `<APP>_CONFIG_DIR`, `<APP>_DATA_DIR` and
+    /// `<APP>_STATE_DIR` are honored. Defaults to `false`.
     ///
-    /// # Errors
+    /// When enabled, each of these variables, if set to an absolute path,
+    /// replaces the corresponding _user-specific_ app subdirectory (i.e.
+    /// `<xdg_dir>/<app_name>`), taking precedence over the XDG environment
+    /// variables; the profile, if any, is still appended. Relative paths are
+    /// ignored.
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_DATA_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_DATA_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// The `<APP>` prefix is derived from the application name, uppercased
+    /// and with any non-alphanumeric character replaced by `_` (e.g.
+    /// `my-app` results in `MY_APP`), unless set through
+    /// [`XdgApp::with_env_prefix`].
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
+    /// # use std::path::Path;
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let data_dir = xdg.data()?;
+    /// std::env::set_var("XDG_CONFIG_HOME", "/home/user/.config");
+    /// std::env::set_var("MY_APP_CONFIG_DIR", "/etc/my-app");
+    ///
+    /// let xdg = XdgApp::new("my-app")?;
+    /// assert_eq!(Path::new("/home/user/.config/my-app"), xdg.app_config()?);
+    ///
+    /// let xdg = xdg.with_env_overrides(true);
+    /// assert_eq!(Path::new("/etc/my-app"), xdg.app_config()?);
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn data(&self) -> Result<PathBuf, XdgError> {
-        self.xdg.data()
+    #[must_use]
+    pub fn with_env_overrides(mut self, env_overrides: bool) -> XdgApp {
+        self.env_overrides = env_overrides;
+        self
     }
 
-    /// Returns the _user-specific_ XDG **state** directory specified by the
-    /// `XDG_STATE_HOME` environment variable. Falls back to
-    /// `$HOME/.local/state` if `XDG_STATE_HOME` is not set or is set to an
-    /// empty value.
+    /// Sets the `prefix` of the app-specific override environment variables,
+    /// i.e. `<prefix>_CONFIG_DIR` and the like, honoring them.
     ///
-    /// # Errors
-    ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// See [`XdgApp::with_env_overrides`] for further details.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
+    /// # use std::path::Path;
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let state_dir = xdg.state()?;
+    /// std::env::set_var("MA_DATA_DIR", "/srv/my-app");
+    ///
+    /// let xdg = XdgApp::new("my-app")?.with_env_prefix("MA");
+    /// assert_eq!(Path::new("/srv/my-app"), xdg.app_data()?);
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn state(&self) -> Result<PathBuf, XdgError> {
-        self.xdg.state()
+    #[must_use]
+    pub fn with_env_prefix<P>(mut self, prefix: P) -> XdgApp
+    where
+        P: Into<Arc<str>>,
+    {
+        self.env_overrides = true;
+        self.env_prefix = Some(prefix.into());
+        self
     }
 
-    /// Returns the _user-specific_ XDG **binary** directory specified by the
-    /// `XDG_BIN_HOME` environment variable. Falls back to
-    /// `$HOME/.local/bin` if `XDG_BIN_HOME` is not set or is set to an
-    /// empty value.
+    /// Returns the name of the app-specific override environment variable
+    /// for the `dir` app subdirectory, if any.
+    fn env_override_key(&self, dir: XdgDir) -> Option<String> {
+        let suffix = match dir {
+            XdgDir::Cache => "CACHE_DIR",
+            XdgDir::Config => "CONFIG_DIR",
+            XdgDir::Data => "DATA_DIR",
+            XdgDir::State => "STATE_DIR",
+            XdgDir::Bin => return None,
+        };
+
+        let prefix = match self.env_prefix.as_deref() {
+            Some(prefix) => Cow::Borrowed(prefix),
+            None => self
+                .name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+                .collect(),
+        };
+
+        Some(format!("{prefix}_{suffix}"))
+    }
+
+    /// Returns the directory set to the app-specific override environment
+    /// variable for the `dir` app subdirectory, if enabled and set to an
+    /// absolute path.
+    fn get_env_override(&self, dir: XdgDir) -> Option<PathBuf> {
+        if !self.env_overrides {
+            return None;
+        }
+
+        let path = PathBuf::from(env::var_os(self.env_override_key(dir)?)?);
+        path.is_absolute().then_some(path)
+    }
+
+    /// Sets the [`RelativePathPolicy`] governing XDG environment variables set
+    /// to relative paths. Defaults to [`RelativePathPolicy::Error`].
     ///
-    /// # Errors
+    /// See [`Xdg::with_relative_path_policy`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn with_relative_path_policy(mut self, policy: RelativePathPolicy) -> XdgApp {
+        self.xdg = self.xdg.with_relative_path_policy(policy);
+        self
+    }
+
+    /// Sets whether XDG environment variables set to non-unicode values are
+    /// accepted. Defaults to `false`.
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_BIN_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_BIN_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// See [`Xdg::with_non_unicode`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn with_non_unicode(mut self, non_unicode: bool) -> XdgApp {
+        self.xdg = self.xdg.with_non_unicode(non_unicode);
+        self
+    }
+
+    /// Sets whether strict specification-compliance mode is enabled. Defaults
+    /// to `false`.
     ///
-    /// # Examples
+    /// See [`Xdg::with_strict`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn with_strict(mut self, strict: bool) -> XdgApp {
+        self.xdg = self.xdg.with_strict(strict);
+        self
+    }
+
+    /// Sets whether paths returned by the `search_*` methods are
+    /// canonicalized. Defaults to `false`.
     ///
-    /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let bin_dir = xdg.bin()?;
-    /// # Ok(())
-    /// # }
-    /// ```
+    /// See [`Xdg::with_canonicalize`] for further details.
     #[inline]
-    pub fn bin(&self) -> Result<PathBuf, XdgError> {
-        self.xdg.bin()
+    #[must_use]
+    #[cfg(feature = "fs")]
+    pub fn with_canonicalize(mut self, canonicalize: bool) -> XdgApp {
+        self.xdg = self.xdg.with_canonicalize(canonicalize);
+        self
     }
 
-    /// Returns the XDG **runtime** directory specified by the `XDG_RUNTIME_DIR`
-    /// environment variable.
+    /// Sets the [`SymlinkPolicy`] governing symbolic links found by the
+    /// `search_*` methods. Defaults to [`SymlinkPolicy::Follow`].
     ///
-    /// # Note
+    /// See [`Xdg::with_symlink_policy`] for further details.
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "fs")]
+    pub fn with_symlink_policy(mut self, policy: SymlinkPolicy) -> XdgApp {
+        self.xdg = self.xdg.with_symlink_policy(policy);
+        self
+    }
+
+    /// Sets the [`SearchOrder`] of the `search_*` methods returning the first
+    /// match. Defaults to [`SearchOrder::UserFirst`].
     ///
-    /// This method returns:
-    /// - `Some` if the `XDG_RUNTIME_DIR` environment variable is set;
-    /// - `None` if the `XDG_RUNTIME_DIR` environment variable is not set or is
-    ///   set to an empty value.
+    /// See [`Xdg::with_search_order`] for further details.
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "fs")]
+    pub fn with_search_order(mut self, order: SearchOrder) -> XdgApp {
+        self.xdg = self.xdg.with_search_order(order);
+        self
+    }
+
+    /// Sets the [`Scope`] selecting the fallback directories used when the
+    /// _user-specific_ XDG environment variables are not set. Defaults to
+    /// [`Scope::User`].
     ///
-    /// # Errors
+    /// See [`Xdg::with_scope`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn with_scope(mut self, scope: Scope) -> XdgApp {
+        self.xdg = self.xdg.with_scope(scope);
+        self
+    }
+
+    /// Sets whether the XDG base directories are cached. Defaults to `false`.
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// See [`Xdg::with_caching`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn with_caching(mut self, caching: bool) -> XdgApp {
+        self.xdg = self.xdg.with_caching(caching);
+        self
+    }
+
+    /// Clears the cached XDG base directories, if caching is enabled, and
+    /// the paths cached through [`XdgApp::with_search_cache`].
+    ///
+    /// See [`Xdg::refresh`] for further details.
+    #[inline]
+    pub fn refresh(&mut self) {
+        self.xdg.refresh();
+        if let Some(search_cache) = &self.search_cache {
+            search_cache.clear();
+        }
+    }
+
+    /// Sets whether the paths where files are found by the `search_app_*`
+    /// methods are cached. Defaults to `false`.
+    ///
+    /// When enabled, the path where a file was last found is probed first on
+    /// the next search for the same file, skipping the walk through the
+    /// precedence-ordered XDG app subdirectories as long as the file is still
+    /// there. This is useful for hot files, e.g. the main configuration file
+    /// read by multiple subsystems.
+    ///
+    /// # Note
+    ///
+    /// A file created in a higher-precedence subdirectory after being cached
+    /// is only picked up once the cache is cleared through
+    /// [`XdgApp::refresh`]. The cached paths are neither cloned, compared,
+    /// hashed nor serialized.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.runtime()? {
-    ///     Some(runtime_dir) => { /* ... */ },
+    /// # #[cfg(feature = "fs")] {
+    /// let xdg = XdgApp::new("app_name")?.with_search_cache(true);
+    /// match xdg.search_app_config_file("config")? {
+    ///     Some(app_config_file) => { /* ... */ },
     ///     None => { /* ... */ },
     /// }
+    /// # }
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn runtime(&self) -> Result<Option<PathBuf>, XdgError> {
-        self.xdg.runtime()
+    #[must_use]
+    pub fn with_search_cache(mut self, search_cache: bool) -> XdgApp {
+        self.search_cache = search_cache.then(SearchCache::default);
+        self
     }
 
-    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
-    /// directories specified by the `XDG_CONFIG_DIRS` environment variable,
-    /// Falls back to `/etc/xdg` if `XDG_CONFIG_DIRS` is not set or is set
-    /// to an empty value.
-    ///
-    /// # Note
-    ///
-    /// Used to search for config files in addition to the `XDG_CONFIG_HOME`
-    /// user-specific base directory.
+    /// Returns whether the paths where files are found are cached.
+    #[inline]
+    #[must_use]
+    pub fn search_cache(&self) -> bool {
+        self.search_cache.is_some()
+    }
+
+    /// Sets whether `$VAR` and `${VAR}` references inside XDG environment
+    /// variables are expanded. Defaults to `false`.
     ///
-    /// The order denotes the importance: the first directory the most
-    /// important, the last directory the least important.
+    /// See [`Xdg::with_expand_vars`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn with_expand_vars(mut self, expand_vars: bool) -> XdgApp {
+        self.xdg = self.xdg.with_expand_vars(expand_vars);
+        self
+    }
+
+    /// Returns the application **profile**, if any.
+    #[inline]
+    #[must_use]
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Returns the number of bytes required to append the application
+    /// subdirectory to a path.
+    #[inline]
+    fn app_len(&self) -> usize {
+        Path::new(&*self.name).append_len() + self.profile.as_deref().map_or(0, |p| Path::new(p).append_len())
+    }
+
+    /// Returns the path of the _user-specific_ XDG app subdirectory, reserving
+    /// room for `additional` bytes to be appended afterwards.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
-    ///   path(s) in the colon separated value represents a relative path;
-    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    fn get_app_dir_path_with_capacity(
+        &self,
+        dir: XdgDir,
+        additional: usize,
+    ) -> Result<PathBuf, XdgError> {
+        if let Some(mut path) = self.get_env_override(dir) {
+            path.reserve(additional);
+            return Ok(match self.profile.as_deref() {
+                Some(profile) => path.append(profile),
+                None => path,
+            });
+        }
+
+        self.xdg
+            .get_dir_path_with_capacity(dir, self.app_len() + additional)
+            .map(|path| self.append_app(path))
+    }
+
+    /// Appends the application subdirectory (`<app_name>` or
+    /// `<app_name>/<profile>`) to `path`.
+    #[inline]
+    fn append_app(&self, path: PathBuf) -> PathBuf {
+        let path = path.append(&*self.name);
+        match self.profile.as_deref() {
+            Some(profile) => path.append(profile),
+            None => path,
+        }
+    }
+
+    /// Runs `f` on the Tokio blocking thread pool, passing it a clone of this
+    /// instance, and returns a future resolving to its output.
+    ///
+    /// See [`Xdg::unblock`] for further details.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime;
+    /// the returned future propagates any panic raised by `f`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{XdgApp, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let config_dirs = XdgApp::sys_config()?;
-    /// # Ok(())
-    /// # }
-    /// ````
-    #[inline]
-    pub fn sys_config() -> Result<Vec<PathBuf>, XdgError> {
-        Xdg::sys_config()
+    /// async fn read_config(xdg: &XdgApp) -> Result<String, XdgError> {
+    ///     xdg.unblock(|xdg| xdg.read_app_config_file_to_string("config.toml")).await
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn unblock<F, T>(&self, f: F) -> crate::Unblock<T>
+    where
+        F: FnOnce(&XdgApp) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let xdg = self.clone();
+        crate::unblock::unblock(move || f(&xdg))
     }
 
-    /// Returns the system-wide, preference-ordered, XDG **data** directories
-    /// specified by the `XDG_DATA_DIRS` environment variable. Falls back to
-    /// `/usr/local/share:/usr/share` if `XDG_DATA_DIRS` is not set or is
-    /// set to an empty value.
+    /// Asynchronous counterpart of [`XdgApp::search_app_cache_file`], running
+    /// the search on the Tokio blocking thread pool.
     ///
-    /// # Note
+    /// # Errors
     ///
-    /// Used to search for data files in addition to the `XDG_DATA_HOME`
-    /// user-specific base directory.
+    /// This method returns the same errors as
+    /// [`XdgApp::search_app_cache_file`].
     ///
-    /// The order denotes the importance: the first directory the most
-    /// important, the last directory the least important.
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn search_app_cache_file_async<P>(
+        &self,
+        file: P,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = file.as_ref().to_path_buf();
+        self.unblock(move |xdg| xdg.search_app_cache_file(file)).await
+    }
+
+    /// Asynchronous counterpart of [`XdgApp::search_app_config_file`], running
+    /// the search on the Tokio blocking thread pool.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
-    ///   path(s) in the colon separated value represents a relative path;
-    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// This method returns the same errors as
+    /// [`XdgApp::search_app_config_file`].
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// ```rust
-    /// # use microxdg::{XdgApp, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let data_dirs = XdgApp::sys_data()?;
-    /// # Ok(())
-    /// # }
-    /// ````
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn search_app_config_file_async<P>(
+        &self,
+        file: P,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = file.as_ref().to_path_buf();
+        self.unblock(move |xdg| xdg.search_app_config_file(file)).await
+    }
+
+    /// Asynchronous counterpart of [`XdgApp::search_app_data_file`], running
+    /// the search on the Tokio blocking thread pool.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as
+    /// [`XdgApp::search_app_data_file`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn search_app_data_file_async<P>(
+        &self,
+        file: P,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = file.as_ref().to_path_buf();
+        self.unblock(move |xdg| xdg.search_app_data_file(file)).await
+    }
+
+    /// Asynchronous counterpart of [`XdgApp::search_app_state_file`], running
+    /// the search on the Tokio blocking thread pool.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as
+    /// [`XdgApp::search_app_state_file`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn search_app_state_file_async<P>(
+        &self,
+        file: P,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = file.as_ref().to_path_buf();
+        self.unblock(move |xdg| xdg.search_app_state_file(file)).await
+    }
+
+    /// Asynchronous counterpart of [`XdgApp::read_app_config_file`], running
+    /// the search on the Tokio blocking thread pool and reading the file
+    /// through `tokio::fs`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`XdgApp::read_app_config_file`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn read_app_config_file_async<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_config_file_async(&file).await?;
+        let path = Xdg::require_found(file, found, || self.searched_app_dirs(XdgDir::Config))?;
+        tokio::fs::read(&path).await.map_err(|source| XdgError::Io { path, source })
+    }
+
+    /// Asynchronous counterpart of [`XdgApp::read_app_config_file_to_string`],
+    /// running the search on the Tokio blocking thread pool and reading the
+    /// file through `tokio::fs`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as
+    /// [`XdgApp::read_app_config_file_to_string`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn read_app_config_file_to_string_async<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_config_file_async(&file).await?;
+        let path = Xdg::require_found(file, found, || self.searched_app_dirs(XdgDir::Config))?;
+        tokio::fs::read_to_string(&path).await.map_err(|source| XdgError::Io { path, source })
+    }
+
+    /// Asynchronous counterpart of [`XdgApp::read_app_data_file`], running
+    /// the search on the Tokio blocking thread pool and reading the file
+    /// through `tokio::fs`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`XdgApp::read_app_data_file`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn read_app_data_file_async<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_data_file_async(&file).await?;
+        let path = Xdg::require_found(file, found, || self.searched_app_dirs(XdgDir::Data))?;
+        tokio::fs::read(&path).await.map_err(|source| XdgError::Io { path, source })
+    }
+
+    /// Asynchronous counterpart of [`XdgApp::read_app_data_file_to_string`],
+    /// running the search on the Tokio blocking thread pool and reading the
+    /// file through `tokio::fs`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as
+    /// [`XdgApp::read_app_data_file_to_string`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn read_app_data_file_to_string_async<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_data_file_async(&file).await?;
+        let path = Xdg::require_found(file, found, || self.searched_app_dirs(XdgDir::Data))?;
+        tokio::fs::read_to_string(&path).await.map_err(|source| XdgError::Io { path, source })
+    }
+
+    /// Asynchronous counterpart of [`XdgApp::save_app_state`], writing the
+    /// file on the Tokio blocking thread pool.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`XdgApp::save_app_state`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn save_app_state_async<P, C>(
+        &self,
+        file: P,
+        contents: C,
+        options: SaveOptions,
+    ) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+        C: Into<Vec<u8>>,
+    {
+        let file = file.as_ref().to_path_buf();
+        let contents = contents.into();
+        self.unblock(move |xdg| xdg.save_app_state(file, contents, options)).await
+    }
+
+    /// Downgrades the [`XdgApp`] to the contained [`Xdg`].
     #[inline]
-    pub fn sys_data() -> Result<Vec<PathBuf>, XdgError> {
-        Xdg::sys_data()
+    #[must_use]
+    pub fn into_xdg(self) -> Xdg {
+        self.into()
     }
 
-    /// Returns the path to the application subdirectory of an XDG base
-    /// directory by the associated XDG environment variable or a fallback
-    /// in the case the environment variable is not set or is set to an
-    /// empty value.
+    /// Returns the **home** directory of the user owning the process.
+    #[must_use]
+    pub fn home(&self) -> &Path {
+        self.xdg.home()
+    }
+
+    /// Returns an [`XdgPaths`] snapshot of every XDG base directory, resolved
+    /// at once.
+    ///
+    /// See [`Xdg::paths`] for further details.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
+    /// This method returns an error if any of the XDG environment variables is
+    /// set, but its value represents a relative path or invalid unicode.
     #[inline]
-    fn get_app_dir_path(&self, dir: XdgDir) -> Result<PathBuf, XdgError> {
-        self.xdg.get_dir_path(dir).map(|path| path.append(self.name))
+    pub fn paths(&self) -> Result<XdgPaths, XdgError> {
+        self.xdg.paths()
     }
 
-    /// Returns the _user-specific_ XDG **cache** subdirectory for the current
-    /// application.
+    /// Returns an iterator over every _user-specific_ XDG base directory,
+    /// labeled by its [`XdgDirKind`] and resolved lazily.
     ///
-    /// # Note
+    /// See [`Xdg::dirs`] for further details.
+    #[inline]
+    pub fn dirs(&self) -> impl Iterator<Item = (XdgDirKind, Result<PathBuf, XdgError>)> + '_ {
+        self.xdg.dirs()
+    }
+
+    /// Returns the shell lines exporting every resolved XDG base directory
+    /// into the environment, in the syntax of the given [`Shell`].
     ///
-    /// This method uses the XDG cache directory specified by the
-    /// `XDG_CACHE_HOME`, if available. Falls back to
-    /// `$HOME/.cache/<app_name>` if `XDG_CACHE_HOME` is not set or is set to an
-    /// empty value.
+    /// See [`Xdg::to_env_exports`] for further details.
     ///
-    /// See [`XdgApp::cache`] for further deatils.
+    /// # Errors
+    ///
+    /// This method returns an error if any of the XDG environment variables is
+    /// set, but its value represents a relative path or invalid unicode, or if
+    /// any of the resolved directories is not valid unicode.
+    #[inline]
+    pub fn to_env_exports(&self, shell: Shell) -> Result<String, XdgError> {
+        self.xdg.to_env_exports(shell)
+    }
+
+    /// Returns an [`XdgDiagnostics`] report of every environment variable
+    /// relevant to the XDG base directories.
+    ///
+    /// See [`Xdg::diagnostics`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn diagnostics(&self) -> XdgDiagnostics {
+        self.xdg.diagnostics()
+    }
+
+    /// Returns the _user-specific_ XDG **cache** directory specified by the
+    /// `XDG_CACHE_HOME` environment variable. Falls back to `$HOME/.cache`
+    /// if `XDG_CACHE_HOME` is not set or is set to an empty value.
     ///
     /// # Errors
     ///
@@ -439,27 +1142,20 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let app_cache_dir = xdg.app_cache()?;
+    /// let cache_dir = xdg.cache()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn app_cache(&self) -> Result<PathBuf, XdgError> {
-        self.get_app_dir_path(XdgDir::Cache)
+    pub fn cache(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.cache()
     }
 
-    /// Returns the _user-specific_ XDG **configuration** subdirectory for the
-    /// current application.
-    ///
-    /// # Note
-    ///
-    /// This method uses the XDG configuration directory specified by the
-    /// `XDG_CONFIG_HOME` if available. Falls back to
-    /// `$HOME/.config/<app_name>` if `XDG_CONFIG_HOME` is not set or is set
+    /// Returns the _user-specific_ XDG **configuration** directory specified by
+    /// the `XDG_CONFIG_HOME` environment variable. Falls back to
+    /// `$HOME/.config` if `XDG_CONFIG_HOME` is not set or is set
     /// to an empty value.
     ///
-    /// See [`XdgApp::config`] for further deatils.
-    ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
@@ -474,26 +1170,19 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let app_config_dir = xdg.app_config()?;
+    /// let config_dir = xdg.config()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn app_config(&self) -> Result<PathBuf, XdgError> {
-        self.get_app_dir_path(XdgDir::Config)
+    pub fn config(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.config()
     }
 
-    /// Returns the _user-specific_ XDG **data** subdirectory for the current
-    /// application.
-    ///
-    /// # Note
-    ///
-    /// This method uses the XDG data directory specified by the
-    /// `XDG_DATA_HOME`, if available. Falls back to
-    /// `$HOME/.local/share/<app_name>` if `XDG_DATA_HOME` is not set or is set
-    /// to an empty value.
-    ///
-    /// See [`XdgApp::data`] for further deatils.
+    /// Returns the _user-specific_ XDG **data** directory specified by the
+    /// `XDG_DATA_HOME` environment variable. Falls back to
+    /// `$HOME/.local/share` if `XDG_DATA_HOME` is not set or is set to an
+    /// empty value.
     ///
     /// # Errors
     ///
@@ -509,26 +1198,19 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let app_data_dir = xdg.app_data()?;
+    /// let data_dir = xdg.data()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn app_data(&self) -> Result<PathBuf, XdgError> {
-        self.get_app_dir_path(XdgDir::Data)
+    pub fn data(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.data()
     }
 
-    /// Returns the _user-specific_ XDG **state** subdirectory for the current
-    /// application.
-    ///
-    /// # Note
-    ///
-    /// This method uses the XDG state directory specified by the
-    /// `XDG_STATE_HOME`, if available. Falls back to
-    /// `$HOME/.local/state/<name>` if `XDG_STATE_HOME` is not set or is set to
-    /// an empty value.
-    ///
-    /// See [`XdgApp::state`] for further deatils.
+    /// Returns the _user-specific_ XDG **state** directory specified by the
+    /// `XDG_STATE_HOME` environment variable. Falls back to
+    /// `$HOME/.local/state` if `XDG_STATE_HOME` is not set or is set to an
+    /// empty value.
     ///
     /// # Errors
     ///
@@ -544,183 +1226,195 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let app_state_dir = xdg.app_state()?;
+    /// let state_dir = xdg.state()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn app_state(&self) -> Result<PathBuf, XdgError> {
-        self.get_app_dir_path(XdgDir::State)
-    }
-
-    /// Returns the _system-wide_, preference-ordered, paths set to a system XDG
-    /// environment variable or a fallback in the case the environment
-    /// variable is not set or is set to an empty value.
-    ///
-    /// # Errors
-    ///
-    /// This method returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
-    fn get_app_sys_dir_paths(&self, dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
-        let env_var_key = dirs.env_var();
-        match Xdg::get_env_var(env_var_key)? {
-            Some(env_var_val) => Xdg::iter_sys_dir_paths(env_var_key, &env_var_val)
-                .map(|result| result.map(|path| path.append(self.name)))
-                .collect(),
-            None => Ok(dirs.fallback().map(|path| path.append(self.name)).collect()),
-        }
+    pub fn state(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.state()
     }
 
-    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
-    /// subdirectories for the current application.
-    ///
-    /// # Note
-    ///
-    /// This method uses the preference-ordered configuration directories
-    /// specified by the `XDG_CONFIG_DIRS` environment variable. Falls back
-    /// to `/etc/xdg/<app_name>` if `XDG_CONFIG_DIRS` is not set or is set
-    /// to an empty value.
-    ///
-    /// See [`XdgApp::sys_config`] for further details.
+    /// Returns the _user-specific_ XDG **binary** directory specified by the
+    /// `XDG_BIN_HOME` environment variable. Falls back to
+    /// `$HOME/.local/bin` if `XDG_BIN_HOME` is not set or is set to an
+    /// empty value.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// - the `XDG_BIN_HOME` environment is set, but its value represents
+    ///   invalid unicode.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use microxdg::{XdgApp, XdgError};
+    /// # use microxdg::{Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let app_sys_config_dirs = xdg.app_sys_config()?;
+    /// let xdg = Xdg::new()?;
+    /// let bin_dir = xdg.bin()?;
     /// # Ok(())
     /// # }
-    /// ````
+    /// ```
     #[inline]
-    pub fn app_sys_config(&self) -> Result<Vec<PathBuf>, XdgError> {
-        self.get_app_sys_dir_paths(XdgSysDirs::Config)
+    pub fn bin(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.bin()
     }
 
-    /// Returns the _system-wide_, preference-ordered, XDG **data**
-    /// subdirectories for the current application.
-    ///
-    /// # Note
+    /// Returns the _user-specific_ XDG `dir` directory.
     ///
-    /// This method uses the preference-ordered data directories specified by
-    /// the `XDG_DATA_DIRS` environment variable. Falls back to
-    /// `/usr/local/share/<app_name>:/usr/share/<app_name>` if
-    /// `XDG_DATA_DIRS` is not set or is set to an empty value.
-    ///
-    /// See [`XdgApp::sys_data`] for further details.
+    /// See [`Xdg::dir`] for further details.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    /// - the XDG environment variable of `dir` is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_DATA_DIRS` environment variable is set to invalid unicode.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # use microxdg::{XdgApp, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let app_data_sys_dirs = xdg.app_sys_data()?;
-    /// # Ok(())
-    /// # }
-    /// ````
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents invalid unicode.
     #[inline]
-    pub fn app_sys_data(&self) -> Result<Vec<PathBuf>, XdgError> {
-        self.get_app_sys_dir_paths(XdgSysDirs::Data)
+    pub fn dir(&self, dir: XdgDir) -> Result<PathBuf, XdgError> {
+        self.xdg.dir(dir)
     }
 
-    /// Returns the _user-specific_ XDG **cache** file as
-    /// `$XDG_CACHE_HOME/<file>`. Falls back to `$HOME/.cache/<file>` if
-    /// `XDG_CACHE_HOME` is not set or is set to an empty value.
+    /// Returns the XDG **runtime** directory specified by the `XDG_RUNTIME_DIR`
+    /// environment variable.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// This method returns:
+    /// - `Some` if the `XDG_RUNTIME_DIR` environment variable is set;
+    /// - `None` if the `XDG_RUNTIME_DIR` environment variable is not set or is
+    ///   set to an empty value.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
     ///   represents invalid unicode.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let cache_file = xdg.cache_file("file")?;
+    /// match xdg.runtime()? {
+    ///     Some(runtime_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn cache_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.cache_file(file)
+    pub fn runtime(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.xdg.runtime()
     }
 
-    /// Returns the _user-specific_ XDG **config** file as
-    /// `$XDG_CONFIG_HOME/<file>`. Falls back to `$HOME/.config/<file>` if
-    /// `XDG_CONFIG_HOME` is not set or is set to an empty value.
+    /// Returns the root directory of the thumbnail cache as
+    /// `$XDG_CACHE_HOME/thumbnails`.
     ///
-    /// # Note
+    /// See [`Xdg::thumbnails`] for further details.
+    #[inline]
+    pub fn thumbnails(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.thumbnails()
+    }
+
+    /// Returns the thumbnail cache directory holding thumbnails of the given
+    /// `size`, as `$XDG_CACHE_HOME/thumbnails/<size>`.
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// See [`Xdg::thumbnail_dir`] for further details.
+    #[inline]
+    pub fn thumbnail_dir(&self, size: ThumbnailSize) -> Result<PathBuf, XdgError> {
+        self.xdg.thumbnail_dir(size)
+    }
+
+    /// Returns the thumbnail cache directory recording failed thumbnail
+    /// creations, as `$XDG_CACHE_HOME/thumbnails/fail`.
     ///
-    /// # Errors
+    /// See [`Xdg::thumbnail_fail_dir`] for further details.
+    #[inline]
+    pub fn thumbnail_fail_dir(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.thumbnail_fail_dir()
+    }
+
+    /// Returns the path of the thumbnail of the given `size` for the file
+    /// identified by `uri`, as `$XDG_CACHE_HOME/thumbnails/<size>/<md5>.png`.
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// See [`Xdg::thumbnail_path`] for further details.
+    #[inline]
+    pub fn thumbnail_path(&self, uri: &str, size: ThumbnailSize) -> Result<PathBuf, XdgError> {
+        self.xdg.thumbnail_path(uri, size)
+    }
+
+    /// Returns the _home trash_ directory, as `$XDG_DATA_HOME/Trash`.
     ///
-    /// # Exapmles
+    /// See [`Xdg::trash`] for further details.
+    #[inline]
+    pub fn trash(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.trash()
+    }
+
+    /// Moves the file or directory at `path` into the _home trash_.
     ///
-    /// ```rust
-    /// # use microxdg::{XdgApp, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let config_file = xdg.config_file("file")?;
-    /// # Ok(())
-    /// # }
-    /// ```
+    /// See [`Xdg::trash_put`] for further details.
     #[inline]
-    pub fn config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    #[cfg(feature = "fs")]
+    pub fn trash_put<P>(&self, path: P) -> Result<TrashEntry, XdgError>
     where
         P: AsRef<Path>,
     {
-        self.xdg.config_file(file)
+        self.xdg.trash_put(path)
     }
 
-    /// Returns the _user-specific_ XDG **data** file as
-    /// `$XDG_DATA_HOME/<file>`. Falls back to `$HOME/.local/share/<file>`
-    /// if `XDG_DATA_HOME` is not set or is set to an empty value.
+    /// Returns the entries of the _home trash_, sorted by name.
+    ///
+    /// See [`Xdg::trash_list`] for further details.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn trash_list(&self) -> Result<Vec<TrashEntry>, XdgError> {
+        self.xdg.trash_list()
+    }
+
+    /// Moves the trashed file of `entry` back to its original path.
+    ///
+    /// See [`Xdg::trash_restore`] for further details.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn trash_restore(&self, entry: &TrashEntry) -> Result<PathBuf, XdgError> {
+        self.xdg.trash_restore(entry)
+    }
+
+    /// Returns the path of the desktop's recently used files list, as
+    /// `$XDG_DATA_HOME/recently-used.xbel`.
+    ///
+    /// See [`Xdg::recently_used`] for further details.
+    #[inline]
+    pub fn recently_used(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.recently_used()
+    }
+
+    /// Returns the entries of the desktop's recently used files list.
+    ///
+    /// See [`Xdg::recent_files`] for further details.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn recent_files(&self) -> Result<Vec<RecentFile>, XdgError> {
+        self.xdg.recent_files()
+    }
+
+    /// Adds the file identified by `uri` (e.g. `file:///home/user/notes.txt`)
+    /// to the desktop's recently used files list, registering the application
+    /// as the one that used it, and returns whether it was added.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// The list is created if missing. Files already in the list are left
+    /// untouched, in which case this method returns `false`.
     ///
     /// # Errors
     ///
@@ -728,98 +1422,430 @@ impl XdgApp {
     /// - the `XDG_DATA_HOME` environment variable is set, but its value
     ///   represents a relative path;
     /// - the `XDG_DATA_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    ///   represents invalid unicode;
+    /// - the list could not be read, is not a valid XBEL file or could not be
+    ///   written ([`XdgError::Io`]).
     ///
-    /// # Exapmles
+    /// # Examples
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let data_file = xdg.data_file("file")?;
+    /// xdg.add_recent_file("file:///home/user/notes.txt", "text/plain")?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.data_file(file)
+    #[cfg(feature = "fs")]
+    pub fn add_recent_file(&self, uri: &str, mime_type: &str) -> Result<bool, XdgError> {
+        crate::recent::add(&self.recently_used()?, uri, mime_type, &self.name)
     }
 
-    /// Returns the _user-specific_ XDG **state** file as
-    /// `$XDG_STATE_HOME/<file>`. Falls back to `$HOME/.local/state/<file>`
-    /// if `XDG_STATE_HOME` is not set or is set to an empty value.
+    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
+    /// directories specified by the `XDG_CONFIG_DIRS` environment variable,
+    /// Falls back to `/etc/xdg` if `XDG_CONFIG_DIRS` is not set or is set
+    /// to an empty value.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// Used to search for config files in addition to the `XDG_CONFIG_HOME`
+    /// user-specific base directory.
+    ///
+    /// The order denotes the importance: the first directory the most
+    /// important, the last directory the least important. Empty entries
+    /// (e.g. `/usr/share::/usr/local/share`) and duplicate directories are
+    /// skipped, retaining the first occurrence of each directory only.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
     ///   represents invalid unicode.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let state_file = xdg.state_file("file")?;
+    /// let config_dirs = XdgApp::sys_config()?;
     /// # Ok(())
     /// # }
-    /// ```
+    /// ````
     #[inline]
-    pub fn state_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.state_file(file)
+    pub fn sys_config() -> Result<Vec<PathBuf>, XdgError> {
+        Xdg::sys_config()
     }
 
-    /// Returns the _user-specific_ XDG **bin** file as
-    /// `$XDG_BIN_HOME/<file>`. Falls back to `$HOME/.local/bin/<file>`
-    /// if `XDG_BIN_HOME` is not set or is set to an empty value.
+    /// Returns the system-wide, preference-ordered, XDG **data** directories
+    /// specified by the `XDG_DATA_DIRS` environment variable. Falls back to
+    /// `/usr/local/share:/usr/share` if `XDG_DATA_DIRS` is not set or is
+    /// set to an empty value.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// Used to search for data files in addition to the `XDG_DATA_HOME`
+    /// user-specific base directory.
+    ///
+    /// The order denotes the importance: the first directory the most
+    /// important, the last directory the least important. Empty entries
+    /// (e.g. `/usr/share::/usr/local/share`) and duplicate directories are
+    /// skipped, retaining the first occurrence of each directory only.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_BIN_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_BIN_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = XdgApp::new("app_name")?;
-    /// let bin_file = xdg.bin_file("file")?;
+    /// let data_dirs = XdgApp::sys_data()?;
     /// # Ok(())
     /// # }
-    /// ```
+    /// ````
     #[inline]
-    pub fn bin_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
+    pub fn sys_data() -> Result<Vec<PathBuf>, XdgError> {
+        Xdg::sys_data()
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG `dirs` directories.
+    ///
+    /// See [`Xdg::sys_dirs`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable of `dirs` is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path and
+    ///   the policy is [`RelativePathPolicy::Error`];
+    /// - the XDG environment variable of `dirs` is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    pub fn sys_dirs(&self, dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.sys_dirs(dirs)
+    }
+
+    /// Returns the candidate paths of the _system-wide_ XDG **configuration**
+    /// file as `<dir>/<file>`.
+    ///
+    /// See [`Xdg::sys_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    pub fn sys_config_file<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
         P: AsRef<Path>,
     {
-        self.xdg.bin_file(file)
+        self.xdg.sys_config_file(file)
     }
 
-    /// Returns the _user-specific_ XDG file path as
-    /// `<xdg_dir>/<app_name>/<file>`.
+    /// Returns the candidate paths of the _system-wide_ XDG **data**
+    /// file as `<dir>/<file>`.
+    ///
+    /// See [`Xdg::sys_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    pub fn sys_data_file<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.sys_data_file(file)
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
+    /// directories specified by the `XDG_CONFIG_DIRS` environment variable,
+    /// ignoring invalid entries.
+    ///
+    /// See [`Xdg::sys_config_lossy`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn sys_config_lossy() -> Vec<PathBuf> {
+        Xdg::sys_config_lossy()
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **data** directories
+    /// specified by the `XDG_DATA_DIRS` environment variable, ignoring invalid
+    /// entries.
+    ///
+    /// See [`Xdg::sys_data_lossy`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn sys_data_lossy() -> Vec<PathBuf> {
+        Xdg::sys_data_lossy()
+    }
+
+    /// Returns the _user-specific_ XDG **configuration** directory followed by
+    /// the _system-wide_, preference-ordered, XDG **configuration**
+    /// directories.
+    ///
+    /// See [`Xdg::config_dirs_all`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    pub fn config_dirs_all(&self) -> Result<impl Iterator<Item = PathBuf>, XdgError> {
+        self.xdg.config_dirs_all()
+    }
+
+    /// Returns the _user-specific_ XDG **data** directory followed by the
+    /// _system-wide_, preference-ordered, XDG **data** directories.
+    ///
+    /// See [`Xdg::data_dirs_all`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    pub fn data_dirs_all(&self) -> Result<impl Iterator<Item = PathBuf>, XdgError> {
+        self.xdg.data_dirs_all()
+    }
+
+    /// Returns the _user-specific_ autostart directory, as
+    /// `$XDG_CONFIG_HOME/autostart`.
+    ///
+    /// See [`Xdg::autostart`] for further details.
+    #[inline]
+    pub fn autostart(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.autostart()
+    }
+
+    /// Returns the _system-wide_, preference-ordered, autostart directories.
+    ///
+    /// See [`Xdg::sys_autostart`] for further details.
+    #[inline]
+    pub fn sys_autostart(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.sys_autostart()
+    }
+
+    /// Returns the _user-specific_ menu directory, as
+    /// `$XDG_CONFIG_HOME/menus`.
+    ///
+    /// See [`Xdg::menus`] for further details.
+    #[inline]
+    pub fn menus(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.menus()
+    }
+
+    /// Returns the _system-wide_, preference-ordered, menu directories.
+    ///
+    /// See [`Xdg::sys_menus`] for further details.
+    #[inline]
+    pub fn sys_menus(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.sys_menus()
+    }
+
+    /// Returns the _user-specific_ desktop entry directory, as
+    /// `$XDG_DATA_HOME/applications`.
+    ///
+    /// See [`Xdg::applications`] for further details.
+    #[inline]
+    pub fn applications(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.applications()
+    }
+
+    /// Returns every desktop entry directory, in precedence order.
+    ///
+    /// See [`Xdg::applications_dirs`] for further details.
+    #[inline]
+    pub fn applications_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.applications_dirs()
+    }
+
+    /// Searches for the desktop entry identified by `desktop_file_id` inside
+    /// the desktop entry directories, returning the first match.
+    ///
+    /// See [`Xdg::find_desktop_entry`] for further details.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn find_desktop_entry(&self, desktop_file_id: &str) -> Result<Option<PathBuf>, XdgError> {
+        self.xdg.find_desktop_entry(desktop_file_id)
+    }
+
+    /// Searches for the desktop entry identified by `desktop_file_id` inside
+    /// the desktop entry directories and parses the first match.
+    ///
+    /// See [`Xdg::read_desktop_entry`] for further details.
+    #[inline]
+    #[cfg(feature = "desktop-entry")]
+    pub fn read_desktop_entry(
+        &self,
+        desktop_file_id: &str,
+    ) -> Result<Option<crate::DesktopEntry>, XdgError> {
+        self.xdg.read_desktop_entry(desktop_file_id)
+    }
+
+    /// Returns the current desktop environments, as set to the colon
+    /// separated `XDG_CURRENT_DESKTOP` environment variable, in order.
+    ///
+    /// See [`Xdg::current_desktop`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn current_desktop() -> Vec<DesktopEnvironment> {
+        Xdg::current_desktop()
+    }
+
+    /// Returns the type of the current login session, if any.
+    ///
+    /// See [`Xdg::session_type`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn session_type() -> Option<SessionType> {
+        Xdg::session_type()
+    }
+
+    /// Returns the class of the current login session, if any.
+    ///
+    /// See [`Xdg::session_class`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn session_class() -> Option<SessionClass> {
+        Xdg::session_class()
+    }
+
+    /// Returns the identifier of the current login session, if any.
+    ///
+    /// See [`Xdg::session_id`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn session_id() -> Option<String> {
+        Xdg::session_id()
+    }
+
+    /// Returns the seat of the current login session, if any.
+    ///
+    /// See [`Xdg::seat`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn seat() -> Option<String> {
+        Xdg::seat()
+    }
+
+    /// Returns the virtual terminal number of the current login session, if
+    /// any.
+    ///
+    /// See [`Xdg::vtnr`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn vtnr() -> Option<u32> {
+        Xdg::vtnr()
+    }
+
+    /// Detects the application sandbox (Flatpak or Snap) the current process
+    /// runs in, if any.
+    ///
+    /// See [`Xdg::sandbox`] for further details.
+    #[inline]
+    #[must_use]
+    pub fn sandbox(&self) -> Option<crate::Sandbox> {
+        self.xdg.sandbox()
+    }
+
+    /// Returns the icon search path, in precedence order.
+    ///
+    /// See [`Xdg::icon_dirs`] for further details.
+    #[inline]
+    pub fn icon_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.icon_dirs()
+    }
+
+    /// Returns the shared MIME-info database directories, in precedence
+    /// order.
+    ///
+    /// See [`Xdg::mime_dirs`] for further details.
+    #[inline]
+    pub fn mime_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.mime_dirs()
+    }
+
+    /// Returns the `mimeapps.list` files holding the MIME type/application
+    /// associations, in lookup order.
+    ///
+    /// See [`Xdg::mimeapps_lists`] for further details.
+    #[inline]
+    pub fn mimeapps_lists(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.mimeapps_lists()
+    }
+
+    /// Returns the desktop file ID of the default application for
+    /// `mime_type`.
+    ///
+    /// See [`Xdg::default_app_for`] for further details.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn default_app_for(&self, mime_type: &str) -> Result<Option<String>, XdgError> {
+        self.xdg.default_app_for(mime_type)
+    }
+
+    /// Loads the file name to MIME type mapping out of the `globs2` files
+    /// inside the shared MIME-info database directories.
+    ///
+    /// See [`Xdg::mime_globs`] for further details.
+    #[inline]
+    #[cfg(feature = "mime-glob")]
+    pub fn mime_globs(&self) -> Result<crate::MimeGlobs, XdgError> {
+        self.xdg.mime_globs()
+    }
+
+    /// Returns the D-Bus **session** bus service directories, in precedence
+    /// order.
+    ///
+    /// See [`Xdg::dbus_services_dirs`] for further details.
+    #[inline]
+    pub fn dbus_services_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.dbus_services_dirs()
+    }
+
+    /// Returns the D-Bus **system** bus service directories, in precedence
+    /// order.
+    ///
+    /// See [`Xdg::dbus_system_services_dirs`] for further details.
+    #[inline]
+    pub fn dbus_system_services_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.dbus_system_services_dirs()
+    }
+
+    /// Returns the path to the application subdirectory of an XDG base
+    /// directory by the associated XDG environment variable or a fallback
+    /// in the case the environment variable is not set or is set to an
+    /// empty value.
     ///
     /// # Errors
     ///
@@ -829,22 +1855,21 @@ impl XdgApp {
     /// - the XDG environment variable is set, but its value represents invalid
     ///   unicode.
     #[inline]
-    fn get_app_file_path<P>(&self, dir: XdgDir, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.get_dir_path(dir).map(|path| path.append(self.name).append(file))
+    fn get_app_dir_path(&self, dir: XdgDir) -> Result<PathBuf, XdgError> {
+        self.get_app_dir_path_with_capacity(dir, 0)
     }
 
-    /// Returns the _user-specific_ XDG **cache** application file as
-    /// `$XDG_CACHE_HOME/<app_name>/<file>`. Falls back to
-    /// `$HOME/.cache/<app_name>/<file>` if `XDG_CACHE_HOME` is not set or
-    /// is set to an empty value.
+    /// Returns the _user-specific_ XDG **cache** subdirectory for the current
+    /// application.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// This method uses the XDG cache directory specified by the
+    /// `XDG_CACHE_HOME`, if available. Falls back to
+    /// `$HOME/.cache/<app_name>` if `XDG_CACHE_HOME` is not set or is set to an
+    /// empty value.
+    ///
+    /// See [`XdgApp::cache`] for further deatils.
     ///
     /// # Errors
     ///
@@ -860,27 +1885,26 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let app_cache_file = xdg.app_cache_file("file")?;
+    /// let app_cache_dir = xdg.app_cache()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn app_cache_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.get_app_file_path(XdgDir::Cache, file)
+    pub fn app_cache(&self) -> Result<PathBuf, XdgError> {
+        self.get_app_dir_path(XdgDir::Cache)
     }
 
-    /// Returns the _user-specific_ XDG **config** application file as
-    /// `$XDG_CONFIG_HOME/<app_name>/<file>`. Falls back to
-    /// `$HOME/.config/<app_name>/<file>` if `XDG_CONFIG_HOME` is not set or
-    /// is set to an empty value.
+    /// Returns the _user-specific_ XDG **configuration** subdirectory for the
+    /// current application.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// This method uses the XDG configuration directory specified by the
+    /// `XDG_CONFIG_HOME` if available. Falls back to
+    /// `$HOME/.config/<app_name>` if `XDG_CONFIG_HOME` is not set or is set
+    /// to an empty value.
+    ///
+    /// See [`XdgApp::config`] for further deatils.
     ///
     /// # Errors
     ///
@@ -896,27 +1920,26 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let app_config_file = xdg.app_config_file("file")?;
+    /// let app_config_dir = xdg.app_config()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn app_config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.get_app_file_path(XdgDir::Config, file)
+    pub fn app_config(&self) -> Result<PathBuf, XdgError> {
+        self.get_app_dir_path(XdgDir::Config)
     }
 
-    /// Returns the _user-specific_ XDG **data** application file as
-    /// `$XDG_DATA_HOME/<app_name>/<file>`. Falls back to
-    /// `$HOME/.local/share/<app_name>/<file>` if `XDG_DATA_HOME` is not set
-    /// or is set to an empty value.
+    /// Returns the _user-specific_ XDG **data** subdirectory for the current
+    /// application.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// This method uses the XDG data directory specified by the
+    /// `XDG_DATA_HOME`, if available. Falls back to
+    /// `$HOME/.local/share/<app_name>` if `XDG_DATA_HOME` is not set or is set
+    /// to an empty value.
+    ///
+    /// See [`XdgApp::data`] for further deatils.
     ///
     /// # Errors
     ///
@@ -932,27 +1955,26 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let app_data_file = xdg.app_data_file("file")?;
+    /// let app_data_dir = xdg.app_data()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn app_data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.get_app_file_path(XdgDir::Data, file)
+    pub fn app_data(&self) -> Result<PathBuf, XdgError> {
+        self.get_app_dir_path(XdgDir::Data)
     }
 
-    /// Returns the _user-specific_ XDG **state** application file as
-    /// `$XDG_STATE_HOME/<app_name>/<file>`. Falls back to
-    /// `$HOME/.local/state/<app_name>/<file>` if `XDG_STATE_HOME` is not
-    /// set or is set to an empty value.
+    /// Returns the _user-specific_ XDG **state** subdirectory for the current
+    /// application.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// This method uses the XDG state directory specified by the
+    /// `XDG_STATE_HOME`, if available. Falls back to
+    /// `$HOME/.local/state/<name>` if `XDG_STATE_HOME` is not set or is set to
+    /// an empty value.
+    ///
+    /// See [`XdgApp::state`] for further deatils.
     ///
     /// # Errors
     ///
@@ -968,88 +1990,148 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// let app_state_file = xdg.app_state_file("file")?;
+    /// let app_state_dir = xdg.app_state()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn app_state_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.get_app_file_path(XdgDir::State, file)
+    pub fn app_state(&self) -> Result<PathBuf, XdgError> {
+        self.get_app_dir_path(XdgDir::State)
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **cache** directory
-    /// specified by the `XDG_CACHE_HOME` environment variable. The search
-    /// falls back to `$HOME/.cache` if `XDG_CACHE_HOME` is not set or is
-    /// set to an empty value.
+    /// Returns the host-specific subdirectory of the _user-specific_ XDG
+    /// **state** subdirectory for the current application, i.e.
+    /// `<app_state>/<hostname>`.
+    ///
+    /// This keeps apart the state of machines sharing the home directory (e.g.
+    /// mounted via NFS), such as window layouts.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// The host name is the one set in the kernel on Unix (i.e. returned by
+    /// `gethostname`), or the `COMPUTERNAME` environment variable on Windows.
+    /// The non-exported `HOSTNAME` shell variable is never read.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the host name is not available or is not a valid single path
+    ///   component ([`XdgError::InvalidHostName`]).
     ///
     /// # Examples
     ///
     /// ```rust
+    /// # use std::path::Path;
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
+    /// std::env::set_var("XDG_STATE_HOME", "/home/user/.local/state");
+    ///
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_cache_file("file")? {
-    ///     Some(cache_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
+    /// let host_dir = xdg.app_state_host_dir()?;
+    /// assert_eq!(Some(Path::new("/home/user/.local/state/app_name")), host_dir.parent());
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "fs")]
+    pub fn app_state_host_dir(&self) -> Result<PathBuf, XdgError> {
+        let path = self.app_state()?;
+        Ok(path.append(hostname()?))
+    }
+
+    /// Returns the _user-specific_ XDG **cache** subdirectory for the
+    /// current application, only if it exists and is a directory.
+    ///
+    /// See [`Xdg::cache_checked`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
     #[inline]
-    pub fn search_cache_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.search_cache_file(file)
+    #[cfg(feature = "fs")]
+    pub fn app_cache_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.app_cache().map(existing_dir)
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **configuration**
-    /// directory specified by the`XDG_CONFIG_HOME` environment variable. If
-    /// `XDG_CONFIG_HOME` is not set or is set to an empty value, the search
-    /// falls back to `$HOME/.config`.
+    /// Returns the _user-specific_ XDG **configuration** subdirectory for the
+    /// current application, only if it exists and is a directory.
     ///
-    /// If `file` is not found inside the _user-specific_ XDG directory, a
-    /// lookup is performed on the _system-wide_, preference ordered
-    /// directories specified by the `XDG_CONFIG_DIRS`. If `XDG_CONFIG_DIRS`
-    /// is not set or is set to an empty value, the search falls back to
-    /// `/etc/xdg`.
+    /// See [`Xdg::config_checked`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn app_config_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.app_config().map(existing_dir)
+    }
+
+    /// Returns the _user-specific_ XDG **data** subdirectory for the
+    /// current application, only if it exists and is a directory.
+    ///
+    /// See [`Xdg::data_checked`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn app_data_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.app_data().map(existing_dir)
+    }
+
+    /// Returns the _user-specific_ XDG **state** subdirectory for the
+    /// current application, only if it exists and is a directory.
+    ///
+    /// See [`Xdg::state_checked`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn app_state_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.app_state().map(existing_dir)
+    }
+
+    /// Returns an iterator over the entries of the _user-specific_ XDG
+    /// **configuration** subdirectory for the current application.
+    ///
+    /// See [`XdgApp::app_config`] for further details.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// If the subdirectory does not exist, the returned iterator is empty.
+    /// Entries which could not be read are silently skipped.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
     /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_CONFIG_HOME` environment variable is set to invalid unicode;
-    /// - `file` was **not** found inside the _user-specific_ XDG config
-    ///   directory and:
-    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but one (or
-    ///       more) path(s) in the colon separated value represents a relative
-    ///       path;
-    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but its value
-    ///       represents invalid unicode.
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the subdirectory exists, but could not be read.
     ///
     /// # Examples
     ///
@@ -1057,50 +2139,36 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_config_file("file")? {
-    ///     Some(config_file) => { /* ... */ },
-    ///     None => { /* ... */ },
+    /// for entry in xdg.read_app_config_dir()? {
+    ///     println!("{}", entry.path().display());
     /// }
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn search_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.search_config_file(file)
+    #[cfg(feature = "fs")]
+    pub fn read_app_config_dir(&self) -> Result<impl Iterator<Item = fs::DirEntry>, XdgError> {
+        read_dir_entries(self.app_config()?)
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **data** directory
-    /// specified by the `XDG_DATA_HOME` environment variable. If
-    /// `XDG_DATA_HOME` is not set or is set to an empty value, the search
-    /// falls back to `$HOME/.local/share`.
+    /// Returns an iterator over the entries of the _user-specific_ XDG
+    /// **data** subdirectory for the current application.
     ///
-    /// If `file` is not found inside the _user-specific_ XDG directory, a
-    /// lookup is performed on the _system-wide_, preference ordered
-    /// directories specified by the `XDG_DATA_DIRS`. If `XDG_DATA_DIRS` is
-    /// not set or is set to an empty value, the search falls back to
-    /// `/usr/local/share:/usr/share`.
+    /// See [`XdgApp::app_data`] for further details.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// If the subdirectory does not exist, the returned iterator is empty.
+    /// Entries which could not be read are silently skipped.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
     /// - the `XDG_DATA_HOME` environment variable is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_DATA_HOME` environment variable is set to invalid unicode;
-    /// - `file` was **not** found inside the _user-specific_ XDG data directory
-    ///   and:
-    ///     - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
-    ///       path(s) in the colon separated value represents a relative path;
-    ///     - the `XDG_DATA_DIRS` environment variable is set, but its value
-    ///       represents invalid unicode.
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the subdirectory exists, but could not be read.
     ///
     /// # Examples
     ///
@@ -1108,38 +2176,35 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_data_file("file")? {
-    ///     Some(data_file) => { /* ... */ },
-    ///     None => { /* ... */ },
+    /// for entry in xdg.read_app_data_dir()? {
+    ///     println!("{}", entry.path().display());
     /// }
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn search_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.search_data_file(file)
+    #[cfg(feature = "fs")]
+    pub fn read_app_data_dir(&self) -> Result<impl Iterator<Item = fs::DirEntry>, XdgError> {
+        read_dir_entries(self.app_data()?)
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **state** directory
-    /// specified by the `XDG_STATE_HOME` environment variable. The search
-    /// falls back to `$HOME/.local/state` if `XDG_STATE_HOME` is not set or
-    /// is set to an empty value.
+    /// Returns the _user-specific_ XDG **runtime** subdirectory for the
+    /// current application as `$XDG_RUNTIME_DIR/<app_name>`.
     ///
     /// # Note
     ///
     /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// - `Some` if the `XDG_RUNTIME_DIR` environment variable is set;
+    /// - `None` if the `XDG_RUNTIME_DIR` environment variable is not set or is
+    ///   set to an empty value.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_STATE_HOME` environment variable is set to invalid unicode.
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
+    ///   represents invalid unicode.
     ///
     /// # Examples
     ///
@@ -1147,38 +2212,29 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_state_file("file")? {
-    ///     Some(state_file) => { /* ... */ },
+    /// match xdg.app_runtime()? {
+    ///     Some(app_runtime_dir) => { /* ... */ },
     ///     None => { /* ... */ },
     /// }
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn search_state_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.search_state_file(file)
+    pub fn app_runtime(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.xdg.runtime().map(|path| path.map(|path| self.append_app(path)))
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **bin** directory
-    /// specified by the `XDG_BIN_HOME` environment variable. The search
-    /// falls back to `$HOME/.local/bin` if `XDG_BIN_HOME` is not set or
-    /// is set to an empty value.
-    ///
-    /// # Note
-    ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// Returns the thumbnail cache subdirectory recording the failed thumbnail
+    /// creations of the current application, as
+    /// `$XDG_CACHE_HOME/thumbnails/fail/<app_name>`.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_BIN_HOME` environment variable is set to invalid unicode.
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
     ///
     /// # Examples
     ///
@@ -1186,145 +2242,212 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_bin_file("file")? {
-    ///     Some(state_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
+    /// let app_thumbnail_fail_dir = xdg.app_thumbnail_fail_dir()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn search_bin_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.search_bin_file(file)
+    pub fn app_thumbnail_fail_dir(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.thumbnail_fail_dir().map(|path| self.append_app(path))
     }
 
-    /// Searches for `file` inside a _user-specific_ XDG app subdirectory.
+    /// Returns the path of the application's own autostart desktop entry, as
+    /// `$XDG_CONFIG_HOME/autostart/<app_name>.desktop`.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if the file is found inside the specified XDG app subdirectory;
-    /// - `None` if the file is **not** found inside the specified XDG app
-    ///   directory.
+    /// The profile, if any, is not taken into account, since the desktop
+    /// environment autostarts the application as a whole. This method does
+    /// not guarantee the desktop entry exists.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let autostart_entry = xdg.autostart_entry()?;
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
-    fn search_app_usr_file<P>(&self, dir: XdgDir, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.xdg.get_dir_path(dir).map(|mut path| {
-            path.push(self.name);
-            path.push(file);
-            path.is_file().then_some(path)
-        })
+    pub fn autostart_entry(&self) -> Result<PathBuf, XdgError> {
+        self.xdg.autostart().map(|path| path.append(format!("{}.desktop", self.name)))
     }
 
-    /// Searches for `file` inside a _system-wide_, preference-ordered, set of
-    /// XDG app subdirectories.
+    /// Installs the application's own autostart desktop entry, serializing
+    /// `entry` into `$XDG_CONFIG_HOME/autostart/<app_name>.desktop`, and
+    /// returns its path.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if the file is found inside one of the preference-ordered set
-    ///   of XDG system subdirectories for the current application;
-    /// - `None` if the file is **not** found inside any of the
-    ///   preference-ordered set of XDG system subdirectory for the current
-    ///   application.
+    /// An already installed desktop entry is overwritten. The autostart
+    /// directory is created if missing.
     ///
     /// # Errors
     ///
-    /// This funciton returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
-    #[inline]
-    fn search_app_sys_file<P>(&self, dirs: XdgSysDirs, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        let env_var_key = dirs.env_var();
-        match Xdg::get_env_var(env_var_key)? {
-            Some(env_var_val) => Xdg::iter_sys_dir_paths(env_var_key, &env_var_val)
-                .map(|result| result.map(|path| path.append(self.name).append(&file)))
-                .find(|path| path.as_ref().is_ok_and(|path| path.is_file()))
-                .transpose(),
-            None => Ok(dirs
-                .fallback()
-                .map(|path| path.append(self.name).append(&file))
-                .find(|path| path.is_file())),
-        }
-    }
-
-    /// Searches for `file` inside XDG app subdirectories in the following
-    /// order:
-    /// - _user-specific_ XDG subdirectory for the current application;
-    /// - _system-wide_, preference-ordered, set of XDG subdirectories for the
-    ///   current application.
-    ///
-    /// # Note
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the desktop entry could not be written ([`XdgError::Io`]).
     ///
-    /// This method returns:
-    /// - `Some` if the file is found inside one of the XDG subdirectories for
-    ///   the current application;
-    /// - `None` if the file is **not** found inside one of the XDG
-    ///   subdirectories for the current.
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use microxdg::{AutostartEntry, XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// xdg.install_autostart(&AutostartEntry {
+    ///     name: String::from("App"),
+    ///     exec: String::from("/usr/bin/app --minimized"),
+    ///     ..Default::default()
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn install_autostart(&self, entry: &AutostartEntry) -> Result<PathBuf, XdgError> {
+        let path = self.autostart_entry()?;
+        let mut file = Xdg::create_file(path.clone())?;
+        file.write_all(entry.to_desktop_entry().as_bytes())
+            .map_err(|source| XdgError::Io { path: path.clone(), source })?;
+
+        Ok(path)
+    }
+
+    /// Removes the application's own autostart desktop entry, returning
+    /// whether it was installed.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDir`]) is set, but
-    ///   its value represents a relative path;
-    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDir`]) is set, but
-    ///   its value represents invalid unicode.
-    #[inline]
-    fn search_app_file<P>(&self, dir: XdgDir, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        if let Some(path) = self.search_app_usr_file(dir, &file)? {
-            return Ok(Some(path));
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the desktop entry could not be removed ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// if xdg.remove_autostart()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn remove_autostart(&self) -> Result<bool, XdgError> {
+        let path = self.autostart_entry()?;
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(source) => Err(XdgError::Io { path, source }),
         }
+    }
 
-        if let Some(sys_dirs) = dir.to_sys() {
-            if let Some(path) = self.search_app_sys_file(sys_dirs, &file)? {
-                return Ok(Some(path));
-            }
+    /// Installs a D-Bus **session** bus service activation file for the
+    /// application, as `$XDG_DATA_HOME/dbus-1/services/<bus_name>.service`,
+    /// and returns its path.
+    ///
+    /// The service file maps the well-known `bus_name` (e.g.
+    /// `org.example.App`) to the `exec` command line, which the session bus
+    /// runs whenever a client requests the name while not owned.
+    ///
+    /// # Note
+    ///
+    /// An already installed service file is overwritten. The services
+    /// directory is created if missing.
+    ///
+    /// The `bus_name` must be a valid D-Bus well-known bus name, i.e. at least
+    /// two non-empty elements separated by `.`, made of ASCII letters, digits,
+    /// `_` and `-`, not starting with a digit, and at most 255 bytes long.
+    /// The `exec` command line must not contain line breaks.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `bus_name` or the `exec` command line is not valid, or the
+    ///   service file could not be written ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// xdg.install_dbus_service("org.example.App", "/usr/bin/app --gapplication-service")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn install_dbus_service(&self, bus_name: &str, exec: &str) -> Result<PathBuf, XdgError> {
+        let path = self.xdg.data_file(format!("dbus-1/services/{bus_name}.service"))?;
+        let invalid = if !is_dbus_bus_name(bus_name) {
+            Some("invalid D-Bus well-known bus name")
+        } else if exec.contains(['\n', '\r']) {
+            Some("line break in the service command line")
+        } else {
+            None
+        };
+        if let Some(message) = invalid {
+            let source = io::Error::new(io::ErrorKind::InvalidInput, message);
+            return Err(XdgError::Io { path, source });
         }
 
-        Ok(None)
+        let mut file = Xdg::create_file(path.clone())?;
+        file.write_all(format!("[D-BUS Service]\nName={bus_name}\nExec={exec}\n").as_bytes())
+            .map_err(|source| XdgError::Io { path: path.clone(), source })?;
+
+        Ok(path)
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **cache** app
-    /// subdirectory specified by `$XDG_CACHE_HOME/<app_name>`. The search
-    /// falls back to `$HOME/.cache/<app_name>` if `XDG_CACHE_HOME` is not
-    /// set or is set to an empty value.
+    /// Returns the path of the `socket` file inside the _user-specific_ XDG
+    /// **runtime** app subdirectory, as `$XDG_RUNTIME_DIR/<app_name>/<socket>`,
+    /// creating the app subdirectory if missing.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG subdirectories for the
-    ///   current application;
-    /// - `None` if `file` is **not** found inside any of the XDG subdirectories
-    ///   for the current application.
+    /// The app subdirectory is created with permissions set to `0700` on
+    /// Unix, as required by the specification. The socket itself is **not**
+    /// created, leaving it up to the caller to bind it. The `socket` name must
+    /// be a single path component, i.e. neither an absolute path nor one
+    /// escaping the app subdirectory.
+    ///
+    /// Socket paths are bounded by the size of `sun_path`: paths longer than
+    /// 107 bytes on Linux (103 bytes on other platforms) are rejected upfront,
+    /// rather than failing later when binding the socket.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    /// - the `socket` name is not a single path component
+    ///   ([`XdgError::InvalidAppName`]);
+    /// - the `XDG_RUNTIME_DIR` environment variable is not set or is set to
+    ///   an empty value ([`XdgError::RuntimeDirNotSet`]);
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
     ///   represents a relative path;
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the socket path is too long ([`XdgError::SocketPathTooLong`]);
+    /// - the app subdirectory could not be created ([`XdgError::Io`]).
     ///
     /// # Examples
     ///
@@ -1332,918 +2455,8123 @@ impl XdgApp {
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_app_cache_file("file")? {
-    ///     Some(app_cache_file) => { /* ... */ },
-    ///     None => { /* ... */ },
+    /// match xdg.app_runtime_socket("control.sock") {
+    ///     Ok(socket) => { /* Bind a listener to `socket`... */ },
+    ///     Err(XdgError::RuntimeDirNotSet) => { /* ... */ },
+    ///     Err(err) => return Err(err),
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub fn search_app_cache_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    #[cfg(feature = "fs")]
+    pub fn app_runtime_socket<P>(&self, socket: P) -> Result<PathBuf, XdgError>
     where
         P: AsRef<Path>,
     {
-        self.search_app_file(XdgDir::Cache, file)
+        // Maximum length of the `sun_path` field, excluding the NUL terminator.
+        const MAX_SOCKET_PATH_LEN: usize =
+            if cfg!(any(target_os = "linux", target_os = "android")) { 107 } else { 103 };
+
+        // The socket must be a single path component, never escaping the app
+        // subdirectory.
+        let socket = socket.as_ref();
+        validate_name(&socket.to_string_lossy())?;
+
+        let app_runtime = self.app_runtime()?.ok_or(XdgError::RuntimeDirNotSet)?;
+        let path = app_runtime.join(socket);
+        if path.as_os_str().len() > MAX_SOCKET_PATH_LEN {
+            return Err(XdgError::SocketPathTooLong { path, max_len: MAX_SOCKET_PATH_LEN });
+        }
+        Xdg::create_dir_all(&app_runtime)?;
+
+        Ok(path)
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **config** app
-    /// subdirectory specified by `$XDG_CONFIG_HOME/<app_name>`. The search
-    /// falls back to `$HOME/.config/<app_name>` if `XDG_CONFIG_HOME` is not
-    /// set or is set to an empty value.
+    /// Creates a uniquely named temporary file, starting with `prefix`, inside
+    /// the _user-specific_ XDG **cache** app subdirectory, returning its path
+    /// along with the file opened in write-only mode.
+    ///
+    /// Being on the same filesystem as the rest of the application cache,
+    /// the temporary file can be atomically renamed into its final
+    /// destination, e.g. once a download completes.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG subdirectories for the
-    ///   current application;
-    /// - `None` if `file` is **not** found inside any of the XDG subdirectories
-    ///   for the current application.
+    /// Missing parent directories are created with permissions set to `0700`,
+    /// while the file is created with permissions set to `0600` on Unix. The
+    /// temporary file is **not** removed automatically.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
     ///   represents a relative path;
     /// - the `XDG_CACHE_HOME` environment variable is set, but its value
     ///   represents invalid unicode;
-    /// - `file` was **not** found inside the _user-specific_ XDG config
-    ///   directory and:
-    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but one (or
-    ///       more) path(s) in the colon separated value represents a relative
-    ///       path;
-    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but its value
-    ///       represents invalid unicode.
+    /// - the file or one of its parent directories could not be created
+    ///   ([`XdgError::Io`]).
     ///
     /// # Examples
     ///
-    /// ```rust
+    /// ```rust,no_run
+    /// # use std::io::Write;
     /// # use microxdg::{XdgApp, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_app_config_file("file")? {
-    ///     Some(app_config_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
+    /// let (partial, mut file) = xdg.app_cache_tempfile("download-")?;
+    /// file.write_all(b"...")?;
+    /// std::fs::rename(partial, xdg.app_cache_file("archive.tar.gz")?)?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn search_app_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.search_app_file(XdgDir::Config, file)
+    #[cfg(feature = "fs")]
+    pub fn app_cache_tempfile(&self, prefix: &str) -> Result<(PathBuf, File), XdgError> {
+        let dir = self.app_cache()?;
+        Xdg::create_dir_all(&dir)?;
+
+        create_temp_file(&dir, OsStr::new(prefix))
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **data** app
-    /// subdirectory specified by `$XDG_DATA_HOME/<app_name>`. The search
-    /// falls back to `$HOME/.data/<app_name>` if `XDG_DATA_HOME` is not set
-    /// or is set to an empty value.
+    /// Creates a uniquely named temporary directory, starting with `prefix`,
+    /// inside the _user-specific_ XDG **cache** app subdirectory, returning
+    /// its path.
+    ///
+    /// See [`XdgApp::app_cache_tempfile`] for further details.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG subdirectories for the
-    ///   current application;
-    /// - `None` if `file` is **not** found inside any of the XDG subdirectories
-    ///   for the current application.
+    /// The temporary directory is created with permissions set to `0700` on
+    /// Unix, and is **not** removed automatically.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
     ///   represents a relative path;
     /// - the `XDG_CACHE_HOME` environment variable is set, but its value
     ///   represents invalid unicode;
-    /// - `file` was **not** found inside the _user-specific_ XDG data directory
-    ///   and:
-    ///     - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
-    ///       path(s) in the colon separated value represents a relative path;
-    ///     - the `XDG_DATA_DIRS` environment variable is set, but its value
-    ///       represents invalid unicode.
+    /// - the directory or one of its parent directories could not be created
+    ///   ([`XdgError::Io`]).
     ///
     /// # Examples
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use microxdg::{XdgApp, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_app_data_file("file")? {
-    ///     Some(app_data_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
+    /// let unpacked = xdg.app_cache_tempdir("unpack-")?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn search_app_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.search_app_file(XdgDir::Data, file)
+    #[cfg(feature = "fs")]
+    pub fn app_cache_tempdir(&self, prefix: &str) -> Result<PathBuf, XdgError> {
+        let dir = self.app_cache()?;
+        Xdg::create_dir_all(&dir)?;
+
+        let (path, ()) = create_unique(&dir, OsStr::new(prefix), |path| {
+            let mut dir_builder = fs::DirBuilder::new();
+            #[cfg(unix)]
+            std::os::unix::fs::DirBuilderExt::mode(&mut dir_builder, 0o700);
+
+            dir_builder.create(path)
+        })?;
+
+        Ok(path)
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **state** app
-    /// subdirectory specified by `$XDG_STATE_HOME/<app_name>`. The search
-    /// falls back to `$HOME/.state/<app_name>` if `XDG_STATE_HOME` is not
-    /// set or is set to an empty value.
+    /// Creates the _user-specific_ XDG **cache**, **configuration**, **data**
+    /// and **state** subdirectories for the current application, along with
+    /// the **runtime** one if the `XDG_RUNTIME_DIR` environment variable is
+    /// set, returning their paths.
+    ///
+    /// Subdirectories that already exist are left untouched, making this
+    /// method suitable for first-run initialization.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG subdirectories for the
-    ///   current application;
-    /// - `None` if `file` is **not** found inside any of the XDG subdirectories
-    ///   for the current application.
+    /// Missing directories are created with permissions set to `0700` on
+    /// Unix, as required by the specification.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// - one of the XDG environment variables is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - one of the subdirectories could not be created ([`XdgError::Io`]).
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{XdgApp, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let tmp_dir = tempfile::tempdir()?;
+    /// # std::env::set_var("HOME", tmp_dir.path());
     /// let xdg = XdgApp::new("app_name")?;
-    /// match xdg.search_app_state_file("file")? {
-    ///     Some(app_state_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
+    /// let app_dirs = xdg.ensure_app_dirs()?;
+    /// assert!(app_dirs.config.is_dir());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn search_app_state_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.search_app_file(XdgDir::State, file)
-    }
-}
+    #[cfg(feature = "fs")]
+    pub fn ensure_app_dirs(&self) -> Result<XdgAppDirs, XdgError> {
+        let app_dirs = XdgAppDirs {
+            cache: self.app_cache()?,
+            config: self.app_config()?,
+            data: self.app_data()?,
+            state: self.app_state()?,
+            runtime: self.app_runtime()?,
+        };
+
+        for path in app_dirs.iter() {
+            Xdg::create_dir_all(path)?;
+        }
 
-impl From<XdgApp> for Xdg {
-    #[inline]
-    fn from(xdg_app: XdgApp) -> Self {
-        xdg_app.xdg
+        Ok(app_dirs)
     }
-}
 
-#[cfg(test)]
-mod test {
-    use std::error::Error;
-    use std::ffi::OsStr;
-    use std::os::unix::prelude::OsStrExt;
-    use std::{env, fs};
+    /// Removes the _user-specific_ XDG app subdirectories selected by
+    /// `options`, along with their contents, returning the paths of the
+    /// removed directories.
+    ///
+    /// Subdirectories that do not exist are skipped. When
+    /// [`RemoveOptions::dry_run`] is set, the returned paths are the ones that
+    /// _would_ be removed, and the filesystem is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - one of the XDG environment variables is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - one of the subdirectories could not be removed ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{RemoveOptions, XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let options = RemoveOptions::new().config(true).data(true).dry_run(true);
+    /// for app_dir in xdg.remove_app_dirs(options)? {
+    ///     println!("would remove {}", app_dir.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn remove_app_dirs(&self, options: RemoveOptions) -> Result<Vec<PathBuf>, XdgError> {
+        let selected = [
+            (options.cache, XdgDir::Cache),
+            (options.config, XdgDir::Config),
+            (options.data, XdgDir::Data),
+            (options.state, XdgDir::State),
+        ];
+
+        let mut removed = Vec::new();
+        for (_, dir) in selected.into_iter().filter(|(selected, _)| *selected) {
+            let path = self.get_app_dir_path(dir)?;
+            if !path.is_dir() {
+                continue;
+            }
 
-    use super::*;
+            if !options.dry_run {
+                if let Err(source) = fs::remove_dir_all(&path) {
+                    return Err(XdgError::Io { path, source });
+                }
+            }
 
-    const INVALID_UNICODE_BYTES: [u8; 4] = [0xF0, 0x90, 0x80, 0x67];
+            removed.push(path);
+        }
 
-    #[inline]
-    fn remove_xdg_vars() {
-        env::remove_var("USER");
-        env::remove_var("HOME");
+        Ok(removed)
+    }
 
-        // User XDG environment variables
-        env::remove_var("XDG_CACHE_HOME");
-        env::remove_var("XDG_CONFIG_HOME");
-        env::remove_var("XDG_DATA_HOME");
-        env::remove_var("XDG_STATE_HOME");
-        env::remove_var("XDG_BIN_HOME");
-        env::remove_var("XDG_RUNTIME_DIR");
+    /// Prunes the _user-specific_ XDG **cache** app subdirectory according to
+    /// `options`, returning the paths of the removed files.
+    ///
+    /// Files last modified longer than [`PruneOptions::max_age`] ago are
+    /// removed first; then, the least recently modified files are evicted
+    /// until the total size of the cache fits within
+    /// [`PruneOptions::max_total_size`].
+    ///
+    /// # Note
+    ///
+    /// Only regular files are removed, while directories are left in place.
+    /// Unreadable directories are silently skipped and symbolic links are
+    /// **not** followed.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - one of the files could not be removed ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use microxdg::{PruneOptions, XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let options = PruneOptions::new()
+    ///     .max_age(Duration::from_secs(30 * 24 * 60 * 60))
+    ///     .max_total_size(512 * 1024 * 1024);
+    /// let removed = xdg.prune_app_cache(options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn prune_app_cache(&self, options: PruneOptions) -> Result<Vec<PathBuf>, XdgError> {
+        let mut files = Vec::new();
+        collect_files_recursive(&self.app_cache()?, &mut files);
+        files.sort_unstable_by(|(path, modified, _), (other_path, other_modified, _)| {
+            modified.cmp(other_modified).then_with(|| path.cmp(other_path))
+        });
+
+        let now = SystemTime::now();
+        let mut total_size: u64 = files.iter().map(|(_, _, size)| size).sum();
+        let mut removed = Vec::new();
+        for (path, modified, size) in files {
+            let expired = options.max_age.is_some_and(|max_age| {
+                now.duration_since(modified).is_ok_and(|age| age > max_age)
+            });
+            let oversized = options.max_total_size.is_some_and(|max_size| total_size > max_size);
+
+            // Files are sorted from the oldest to the newest one: once a file
+            // is neither expired nor needed to fit the size budget, neither
+            // are the following ones.
+            if !expired && !oversized {
+                break;
+            }
 
-        // User XDG environment variables
-        env::remove_var("XDG_CONFIG_DIRS");
-        env::remove_var("XDG_DATA_DIRS");
-    }
+            match fs::remove_file(&path) {
+                Ok(()) => removed.push(path),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {},
+                Err(source) => return Err(XdgError::Io { path, source }),
+            }
 
-    #[test]
-    #[rustfmt::skip]
-    fn new_xdg_app() -> Result<(), XdgError> {
-        remove_xdg_vars();
+            total_size -= size;
+        }
 
-        env::set_var("USER", "user2");
-        env::set_var("HOME", "/home/user1");
+        Ok(removed)
+    }
 
-        assert_eq!(
-            Path::new("/home/user1"),
+    /// Installs the `src` file into the _user-specific_ XDG **data** app
+    /// subdirectory as `$XDG_DATA_HOME/<app_name>/<rel_dest>`, returning
+    /// whether the file was copied or skipped according to `options`.
+    ///
+    /// # Note
+    ///
+    /// Missing parent directories are created with permissions set to `0700`,
+    /// while the permissions of `src` are preserved on the installed file. An
+    /// already installed file is overwritten unless
+    /// [`InstallOptions::skip_newer`] is set and the installed file is not
+    /// older than `src`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `src` could not be read, or the file or one of its parent
+    ///   directories could not be created ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use microxdg::{InstallOptions, XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let options = InstallOptions::new().skip_newer(true);
+    /// xdg.install_app_data_file("/usr/share/app_name/dark.toml", "themes/dark.toml", options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn install_app_data_file<S, P>(
+        &self,
+        src: S,
+        rel_dest: P,
+        options: InstallOptions,
+    ) -> Result<bool, XdgError>
+    where
+        S: AsRef<Path>,
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+        let dest = self.app_data_file(rel_dest)?;
+        if options.skip_newer {
+            let modified = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified());
+            let src_modified = modified(src)
+                .map_err(|source| XdgError::Io { path: src.to_path_buf(), source })?;
+            if modified(&dest).is_ok_and(|dest_modified| dest_modified >= src_modified) {
+                return Ok(false);
+            }
+        }
+
+        if let Some(parent) = dest.parent() {
+            Xdg::create_dir_all(parent)?;
+        }
+        fs::copy(src, &dest).map_err(|source| XdgError::Io { path: dest, source })?;
+
+        Ok(true)
+    }
+
+    /// Returns the _system-wide_, preference-ordered, paths set to a system XDG
+    /// environment variable or a fallback in the case the environment
+    /// variable is not set or is set to an empty value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    fn get_app_sys_dir_paths(&self, dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
+        let dir_paths = self.xdg.resolve_sys_dir_paths(dirs)?;
+        Ok(dir_paths.into_iter().map(|path| self.append_app(path)).collect())
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
+    /// subdirectories for the current application.
+    ///
+    /// # Note
+    ///
+    /// This method uses the preference-ordered configuration directories
+    /// specified by the `XDG_CONFIG_DIRS` environment variable. Falls back
+    /// to `/etc/xdg/<app_name>` if `XDG_CONFIG_DIRS` is not set or is set
+    /// to an empty value.
+    ///
+    /// See [`XdgApp::sys_config`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let app_sys_config_dirs = xdg.app_sys_config()?;
+    /// # Ok(())
+    /// # }
+    /// ````
+    #[inline]
+    pub fn app_sys_config(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.get_app_sys_dir_paths(XdgSysDirs::Config)
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **data**
+    /// subdirectories for the current application.
+    ///
+    /// # Note
+    ///
+    /// This method uses the preference-ordered data directories specified by
+    /// the `XDG_DATA_DIRS` environment variable. Falls back to
+    /// `/usr/local/share/<app_name>:/usr/share/<app_name>` if
+    /// `XDG_DATA_DIRS` is not set or is set to an empty value.
+    ///
+    /// See [`XdgApp::sys_data`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set to invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let app_data_sys_dirs = xdg.app_sys_data()?;
+    /// # Ok(())
+    /// # }
+    /// ````
+    #[inline]
+    pub fn app_sys_data(&self) -> Result<Vec<PathBuf>, XdgError> {
+        self.get_app_sys_dir_paths(XdgSysDirs::Data)
+    }
+
+    /// Returns the candidate paths of the _system-wide_ XDG **configuration** app
+    /// file as `<dir>/<app_name>/<file>` for each of the preference-ordered
+    /// `XDG_CONFIG_DIRS` directories.
+    ///
+    /// See [`Xdg::sys_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for path in xdg.app_sys_config_file("file")? {
+    ///     println!("looked up `{}`", path.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_sys_config_file<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let dir_paths = self.get_app_sys_dir_paths(XdgSysDirs::Config)?;
+        Ok(dir_paths.into_iter().map(|path| path.append(&file)).collect())
+    }
+
+    /// Returns the candidate paths of the _system-wide_ XDG **data** app
+    /// file as `<dir>/<app_name>/<file>` for each of the preference-ordered
+    /// `XDG_DATA_DIRS` directories.
+    ///
+    /// See [`Xdg::sys_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for path in xdg.app_sys_data_file("file")? {
+    ///     println!("looked up `{}`", path.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_sys_data_file<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let dir_paths = self.get_app_sys_dir_paths(XdgSysDirs::Data)?;
+        Ok(dir_paths.into_iter().map(|path| path.append(&file)).collect())
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
+    /// subdirectories for the current application, ignoring invalid entries
+    /// of the `XDG_CONFIG_DIRS` environment variable.
+    ///
+    /// See [`Xdg::sys_config_lossy`] for further details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let app_sys_config_dirs = xdg.app_sys_config_lossy();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn app_sys_config_lossy(&self) -> Vec<PathBuf> {
+        Xdg::sys_config_lossy().into_iter().map(|path| self.append_app(path)).collect()
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **data**
+    /// subdirectories for the current application, ignoring invalid entries
+    /// of the `XDG_DATA_DIRS` environment variable.
+    ///
+    /// See [`Xdg::sys_data_lossy`] for further details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let app_sys_data_dirs = xdg.app_sys_data_lossy();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn app_sys_data_lossy(&self) -> Vec<PathBuf> {
+        Xdg::sys_data_lossy().into_iter().map(|path| self.append_app(path)).collect()
+    }
+
+    /// Returns the _user-specific_ XDG **configuration** subdirectory for the
+    /// current application followed by the _system-wide_,
+    /// preference-ordered, XDG **configuration** subdirectories for the
+    /// current application.
+    ///
+    /// See [`XdgApp::app_config`] and [`XdgApp::app_sys_config`] for further
+    /// details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for app_config_dir in xdg.app_config_dirs_all()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_config_dirs_all(&self) -> Result<impl Iterator<Item = PathBuf>, XdgError> {
+        Ok(iter::once(self.app_config()?).chain(self.app_sys_config()?))
+    }
+
+    /// Returns the _user-specific_ XDG **data** subdirectory for the current
+    /// application followed by the _system-wide_, preference-ordered, XDG
+    /// **data** subdirectories for the current application.
+    ///
+    /// See [`XdgApp::app_data`] and [`XdgApp::app_sys_data`] for further
+    /// details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for app_data_dir in xdg.app_data_dirs_all()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_data_dirs_all(&self) -> Result<impl Iterator<Item = PathBuf>, XdgError> {
+        Ok(iter::once(self.app_data()?).chain(self.app_sys_data()?))
+    }
+
+    /// Returns the _user-specific_ XDG **cache** file as
+    /// `$XDG_CACHE_HOME/<file>`. Falls back to `$HOME/.cache/<file>` if
+    /// `XDG_CACHE_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let cache_file = xdg.cache_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn cache_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.cache_file(file)
+    }
+
+    /// Returns the _user-specific_ XDG **config** file as
+    /// `$XDG_CONFIG_HOME/<file>`. Falls back to `$HOME/.config/<file>` if
+    /// `XDG_CONFIG_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let config_file = xdg.config_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.config_file(file)
+    }
+
+    /// Returns the _user-specific_ XDG **data** file as
+    /// `$XDG_DATA_HOME/<file>`. Falls back to `$HOME/.local/share/<file>`
+    /// if `XDG_DATA_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let data_file = xdg.data_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.data_file(file)
+    }
+
+    /// Returns the _user-specific_ XDG **state** file as
+    /// `$XDG_STATE_HOME/<file>`. Falls back to `$HOME/.local/state/<file>`
+    /// if `XDG_STATE_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let state_file = xdg.state_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn state_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.state_file(file)
+    }
+
+    /// Returns the _user-specific_ XDG **bin** file as
+    /// `$XDG_BIN_HOME/<file>`. Falls back to `$HOME/.local/bin/<file>`
+    /// if `XDG_BIN_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let bin_file = xdg.bin_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn bin_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.bin_file(file)
+    }
+
+    /// Returns the _user-specific_ XDG file as `<dir>/<file>`.
+    ///
+    /// See [`Xdg::file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents a relative path;
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    pub fn file<P>(&self, dir: XdgDir, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.file(dir, file)
+    }
+
+    /// Returns the _user-specific_ XDG file path as
+    /// `<xdg_dir>/<app_name>/<file>`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    fn get_app_file_path<P>(&self, dir: XdgDir, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_app_dir_path_with_capacity(dir, file.as_ref().append_len())
+            .map(|path| path.append(file))
+    }
+
+    /// Returns the path made of `components` joined under the `dir` app
+    /// subdirectory.
+    #[inline]
+    fn get_app_subpath<I, P>(&self, dir: XdgDir, components: I) -> Result<PathBuf, XdgError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let mut path = self.get_app_dir_path(dir)?;
+        path.extend(components);
+        Ok(path)
+    }
+
+    /// Returns the _user-specific_ XDG **cache** application file as
+    /// `$XDG_CACHE_HOME/<app_name>/<file>`. Falls back to
+    /// `$HOME/.cache/<app_name>/<file>` if `XDG_CACHE_HOME` is not set or
+    /// is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let app_cache_file = xdg.app_cache_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_cache_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_app_file_path(XdgDir::Cache, file)
+    }
+
+    /// Returns the _user-specific_ XDG **config** application file as
+    /// `$XDG_CONFIG_HOME/<app_name>/<file>`. Falls back to
+    /// `$HOME/.config/<app_name>/<file>` if `XDG_CONFIG_HOME` is not set or
+    /// is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let app_config_file = xdg.app_config_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_app_file_path(XdgDir::Config, file)
+    }
+
+    /// Returns the _user-specific_ XDG **data** application file as
+    /// `$XDG_DATA_HOME/<app_name>/<file>`. Falls back to
+    /// `$HOME/.local/share/<app_name>/<file>` if `XDG_DATA_HOME` is not set
+    /// or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let app_data_file = xdg.app_data_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_app_file_path(XdgDir::Data, file)
+    }
+
+    /// Returns the _user-specific_ XDG **state** application file as
+    /// `$XDG_STATE_HOME/<app_name>/<file>`. Falls back to
+    /// `$HOME/.local/state/<app_name>/<file>` if `XDG_STATE_HOME` is not
+    /// set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let app_state_file = xdg.app_state_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_state_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_app_file_path(XdgDir::State, file)
+    }
+
+    /// Returns the path made of `components` joined, in order, under the
+    /// _user-specific_ XDG **cache** application subdirectory, i.e.
+    /// `$XDG_CACHE_HOME/<app_name>/<component>/...`.
+    ///
+    /// See [`XdgApp::app_cache_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// # std::env::remove_var("XDG_CACHE_HOME");
+    /// let xdg = XdgApp::with_home("/home/user", "app_name")?;
+    /// assert_eq!(
+    ///     PathBuf::from("/home/user/.cache/app_name/thumbnails/large/image.png"),
+    ///     xdg.app_cache_path(["thumbnails", "large", "image.png"])?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_cache_path<I, P>(&self, components: I) -> Result<PathBuf, XdgError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.get_app_subpath(XdgDir::Cache, components)
+    }
+
+    /// Returns the path made of `components` joined, in order, under the
+    /// _user-specific_ XDG **configuration** application subdirectory, i.e.
+    /// `$XDG_CONFIG_HOME/<app_name>/<component>/...`.
+    ///
+    /// See [`XdgApp::app_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// # std::env::remove_var("XDG_CONFIG_HOME");
+    /// let xdg = XdgApp::with_home("/home/user", "app_name")?;
+    /// assert_eq!(
+    ///     PathBuf::from("/home/user/.config/app_name/themes/dark/colors.toml"),
+    ///     xdg.app_config_path(["themes", "dark", "colors.toml"])?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_config_path<I, P>(&self, components: I) -> Result<PathBuf, XdgError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.get_app_subpath(XdgDir::Config, components)
+    }
+
+    /// Returns the path made of `components` joined, in order, under the
+    /// _user-specific_ XDG **data** application subdirectory, i.e.
+    /// `$XDG_DATA_HOME/<app_name>/<component>/...`.
+    ///
+    /// See [`XdgApp::app_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// # std::env::remove_var("XDG_DATA_HOME");
+    /// let xdg = XdgApp::with_home("/home/user", "app_name")?;
+    /// assert_eq!(
+    ///     PathBuf::from("/home/user/.local/share/app_name/plugins/example/plugin.so"),
+    ///     xdg.app_data_path(["plugins", "example", "plugin.so"])?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_data_path<I, P>(&self, components: I) -> Result<PathBuf, XdgError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.get_app_subpath(XdgDir::Data, components)
+    }
+
+    /// Returns the path made of `components` joined, in order, under the
+    /// _user-specific_ XDG **state** application subdirectory, i.e.
+    /// `$XDG_STATE_HOME/<app_name>/<component>/...`.
+    ///
+    /// See [`XdgApp::app_state_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// # std::env::remove_var("XDG_STATE_HOME");
+    /// let xdg = XdgApp::with_home("/home/user", "app_name")?;
+    /// assert_eq!(
+    ///     PathBuf::from("/home/user/.local/state/app_name/sessions/last/history"),
+    ///     xdg.app_state_path(["sessions", "last", "history"])?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn app_state_path<I, P>(&self, components: I) -> Result<PathBuf, XdgError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.get_app_subpath(XdgDir::State, components)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **cache** directory
+    /// specified by the `XDG_CACHE_HOME` environment variable. The search
+    /// falls back to `$HOME/.cache` if `XDG_CACHE_HOME` is not set or is
+    /// set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_cache_file("file")? {
+    ///     Some(cache_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_cache_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_cache_file(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory specified by the`XDG_CONFIG_HOME` environment variable. If
+    /// `XDG_CONFIG_HOME` is not set or is set to an empty value, the search
+    /// falls back to `$HOME/.config`.
+    ///
+    /// If `file` is not found inside the _user-specific_ XDG directory, a
+    /// lookup is performed on the _system-wide_, preference ordered
+    /// directories specified by the `XDG_CONFIG_DIRS`. If `XDG_CONFIG_DIRS`
+    /// is not set or is set to an empty value, the search falls back to
+    /// `/etc/xdg`.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set to invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG config
+    ///   directory and:
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but one (or
+    ///       more) path(s) in the colon separated value represents a relative
+    ///       path;
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_config_file("file")? {
+    ///     Some(config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_config_file(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// specified by the `XDG_DATA_HOME` environment variable. If
+    /// `XDG_DATA_HOME` is not set or is set to an empty value, the search
+    /// falls back to `$HOME/.local/share`.
+    ///
+    /// If `file` is not found inside the _user-specific_ XDG directory, a
+    /// lookup is performed on the _system-wide_, preference ordered
+    /// directories specified by the `XDG_DATA_DIRS`. If `XDG_DATA_DIRS` is
+    /// not set or is set to an empty value, the search falls back to
+    /// `/usr/local/share:/usr/share`.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set to invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG data directory
+    ///   and:
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///       path(s) in the colon separated value represents a relative path;
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_data_file("file")? {
+    ///     Some(data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_data_file(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and the _system-wide_ XDG **configuration** directories,
+    /// returning the first regular file whose metadata satisfies `predicate`.
+    ///
+    /// See [`Xdg::search_config_file_with`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_file_with<P, F>(
+        &self,
+        file: P,
+        predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        self.xdg.search_config_file_with(file, predicate)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// and the _system-wide_ XDG **data** directories, returning the first
+    /// regular file whose metadata satisfies `predicate`.
+    ///
+    /// See [`Xdg::search_data_file_with`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_file_with<P, F>(
+        &self,
+        file: P,
+        predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        self.xdg.search_data_file_with(file, predicate)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and the _system-wide_ XDG **configuration** directories, failing if
+    /// `file` is **not** found.
+    ///
+    /// See [`Xdg::require_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn require_config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.require_config_file(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data**
+    /// directory and the _system-wide_ XDG **data** directories, failing if
+    /// `file` is **not** found.
+    ///
+    /// See [`Xdg::require_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn require_data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.require_data_file(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory only.
+    ///
+    /// See [`Xdg::search_usr_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_usr_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_usr_config_file(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// only.
+    ///
+    /// See [`Xdg::search_usr_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_usr_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_usr_data_file(file)
+    }
+
+    /// Searches for `file` inside the _system-wide_ XDG **configuration**
+    /// directories.
+    ///
+    /// See [`Xdg::search_sys_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_sys_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_sys_config_file(file)
+    }
+
+    /// Searches for `file` inside the _system-wide_ XDG **data** directories.
+    ///
+    /// See [`Xdg::search_sys_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_sys_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_sys_data_file(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **state** directory
+    /// specified by the `XDG_STATE_HOME` environment variable. The search
+    /// falls back to `$HOME/.local/state` if `XDG_STATE_HOME` is not set or
+    /// is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set to invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_state_file("file")? {
+    ///     Some(state_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_state_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_state_file(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **bin** directory
+    /// specified by the `XDG_BIN_HOME` environment variable. The search
+    /// falls back to `$HOME/.local/bin` if `XDG_BIN_HOME` is not set or
+    /// is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment variable is set to invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_bin_file("file")? {
+    ///     Some(state_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_bin_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_bin_file(file)
+    }
+
+    /// Searches for the `executable` regular file inside the _user-specific_
+    /// XDG **binary** directory, skipping files lacking the execute
+    /// permission bits.
+    ///
+    /// See [`Xdg::find_executable`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn find_executable<P>(&self, executable: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.find_executable(executable)
+    }
+
+    /// Searches for the `executable` regular file inside the _user-specific_
+    /// XDG **binary** directory and then inside the directories listed by the
+    /// `PATH` environment variable.
+    ///
+    /// See [`Xdg::find_executable_in_path`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn find_executable_in_path<P>(&self, executable: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.find_executable_in_path(executable)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the XDG
+    /// **cache** directories.
+    ///
+    /// See [`Xdg::search_cache_entry`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_cache_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_cache_entry(entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the XDG
+    /// **configuration** directories.
+    ///
+    /// See [`Xdg::search_config_entry`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_config_entry(entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the XDG
+    /// **data** directories.
+    ///
+    /// See [`Xdg::search_data_entry`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_data_entry(entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the XDG
+    /// **state** directories.
+    ///
+    /// See [`Xdg::search_state_entry`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_state_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_state_entry(entry, entry_type)
+    }
+
+    /// Searches for the `dir` subdirectory inside the XDG **cache**
+    /// directories.
+    ///
+    /// See [`Xdg::search_cache_dir`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_cache_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_cache_dir(dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the XDG **configuration**
+    /// directories.
+    ///
+    /// See [`Xdg::search_config_dir`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_config_dir(dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the XDG **data** directories.
+    ///
+    /// See [`Xdg::search_data_dir`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_data_dir(dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the XDG **state**
+    /// directories.
+    ///
+    /// See [`Xdg::search_state_dir`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_state_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_state_dir(dir)
+    }
+
+    /// Searches for `file` inside a _user-specific_ XDG app subdirectory.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the file is found inside the specified XDG app subdirectory;
+    /// - `None` if the file is **not** found inside the specified XDG app
+    ///   directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_app_usr_file<P>(
+        &self,
+        dir: XdgDir,
+        file: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = self.get_app_dir_path_with_capacity(dir, file.as_ref().append_len())?;
+        let path = path.append(file);
+        Ok(self.xdg.probe_entry(&path, entry_type)?.then_some(path))
+    }
+
+    /// Searches for `file` inside a _system-wide_, preference-ordered, set of
+    /// XDG app subdirectories.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the file is found inside one of the preference-ordered set
+    ///   of XDG system subdirectories for the current application;
+    /// - `None` if the file is **not** found inside any of the
+    ///   preference-ordered set of XDG system subdirectory for the current
+    ///   application.
+    ///
+    /// # Errors
+    ///
+    /// This funciton returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_app_sys_file<P>(
+        &self,
+        dirs: XdgSysDirs,
+        file: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.probe_first(
+            self.xdg
+                .read_sys_dir_paths_lossy(dirs)?
+                .into_iter()
+                .map(|path| self.append_app(path).append(&file)),
+            entry_type,
+        )
+    }
+
+    /// Searches for `file` inside XDG app subdirectories in the following
+    /// order:
+    /// - _user-specific_ XDG subdirectory for the current application;
+    /// - _system-wide_, preference-ordered, set of XDG subdirectories for the
+    ///   current application.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the file is found inside one of the XDG subdirectories for
+    ///   the current application;
+    /// - `None` if the file is **not** found inside one of the XDG
+    ///   subdirectories for the current.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDir`]) is set, but
+    ///   its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDir`]) is set, but
+    ///   its value represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_app_file<P>(
+        &self,
+        dir: XdgDir,
+        file: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = file.as_ref();
+        let search_cache = self.search_cache.as_ref();
+        if let Some(path) = search_cache.and_then(|cache| cache.get(dir, entry_type, file)) {
+            if self.xdg.probe_entry(&path, entry_type)? {
+                return self.xdg.found(path).map(Some);
+            }
+        }
+
+        let search_usr = || -> Result<Option<PathBuf>, XdgError> {
+            for app in self.aliased() {
+                if let Some(path) = app.search_app_usr_file(dir, file, entry_type)? {
+                    return Ok(Some(path));
+                }
+            }
+
+            Ok(None)
+        };
+        let search_sys = || -> Result<Option<PathBuf>, XdgError> {
+            if let Some(sys_dirs) = dir.to_sys() {
+                for app in self.aliased() {
+                    if let Some(path) = app.search_app_sys_file(sys_dirs, file, entry_type)? {
+                        return Ok(Some(path));
+                    }
+                }
+            }
+
+            Ok(None)
+        };
+
+        let path = match self.xdg.search_order() {
+            SearchOrder::UserFirst => match search_usr()? {
+                Some(path) => Some(path),
+                None => search_sys()?,
+            },
+            SearchOrder::SystemFirst => match search_sys()? {
+                Some(path) => Some(path),
+                None => search_usr()?,
+            },
+        };
+
+        if let Some(search_cache) = search_cache {
+            search_cache.set(dir, entry_type, file, path.as_deref());
+        }
+
+        match path {
+            Some(path) => self.xdg.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for `file` inside XDG app subdirectories, in the same order as
+    /// [`XdgApp::search_app_file`], returning the first regular file whose
+    /// metadata satisfies `predicate`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_app_file_with<P, F>(
+        &self,
+        dir: XdgDir,
+        file: P,
+        predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        let usr_paths = self.get_aliased_app_dir_paths(dir)?;
+        let sys_paths = match dir.to_sys() {
+            Some(sys_dirs) => self.get_aliased_app_sys_dir_paths(sys_dirs)?,
+            None => Vec::new(),
+        };
+
+        let paths = match self.xdg.search_order() {
+            SearchOrder::UserFirst => [usr_paths, sys_paths],
+            SearchOrder::SystemFirst => [sys_paths, usr_paths],
+        };
+        let paths = paths.into_iter().flatten().map(|path| path.append(&file));
+
+        match self.xdg.probe_first_with(paths, predicate)? {
+            Some(path) => self.xdg.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **cache** app
+    /// subdirectory specified by `$XDG_CACHE_HOME/<app_name>`. The search
+    /// falls back to `$HOME/.cache/<app_name>` if `XDG_CACHE_HOME` is not
+    /// set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG subdirectories for the
+    ///   current application;
+    /// - `None` if `file` is **not** found inside any of the XDG subdirectories
+    ///   for the current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_cache_file("file")? {
+    ///     Some(app_cache_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_cache_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file(XdgDir::Cache, file, EntryType::File)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **config** app
+    /// subdirectory specified by `$XDG_CONFIG_HOME/<app_name>`. The search
+    /// falls back to `$HOME/.config/<app_name>` if `XDG_CONFIG_HOME` is not
+    /// set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG subdirectories for the
+    ///   current application;
+    /// - `None` if `file` is **not** found inside any of the XDG subdirectories
+    ///   for the current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG config
+    ///   directory and:
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but one (or
+    ///       more) path(s) in the colon separated value represents a relative
+    ///       path;
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_config_file("file")? {
+    ///     Some(app_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file(XdgDir::Config, file, EntryType::File)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** app
+    /// subdirectory specified by `$XDG_DATA_HOME/<app_name>`. The search
+    /// falls back to `$HOME/.data/<app_name>` if `XDG_DATA_HOME` is not set
+    /// or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG subdirectories for the
+    ///   current application;
+    /// - `None` if `file` is **not** found inside any of the XDG subdirectories
+    ///   for the current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG data directory
+    ///   and:
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///       path(s) in the colon separated value represents a relative path;
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_data_file("file")? {
+    ///     Some(app_data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file(XdgDir::Data, file, EntryType::File)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration** app
+    /// subdirectory and the _system-wide_ XDG **configuration** app
+    /// subdirectories, like [`XdgApp::search_app_config_file`] does, returning
+    /// the first regular file whose metadata satisfies `predicate`.
+    ///
+    /// The predicate receives the path and the metadata of each candidate
+    /// during the walk, allowing to filter on size, modification time or
+    /// permissions without reading the metadata again afterwards.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - a candidate is a dangling symbolic link and the [`SymlinkPolicy`] is
+    ///   [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_config_file_with("file", |_, metadata| metadata.len() > 0)? {
+    ///     Some(app_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_file_with<P, F>(
+        &self,
+        file: P,
+        predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        self.search_app_file_with(XdgDir::Config, file, predicate)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** app
+    /// subdirectory and the _system-wide_ XDG **data** app subdirectories,
+    /// like [`XdgApp::search_app_data_file`] does, returning the first regular
+    /// file whose metadata satisfies `predicate`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - a candidate is a dangling symbolic link and the [`SymlinkPolicy`] is
+    ///   [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_data_file_with("file", |_, metadata| metadata.len() > 0)? {
+    ///     Some(app_data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_data_file_with<P, F>(
+        &self,
+        file: P,
+        predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        self.search_app_file_with(XdgDir::Data, file, predicate)
+    }
+
+    /// Searches for each of the given `files` inside the XDG app
+    /// subdirectories of the paired [`XdgDir`], like
+    /// [`XdgApp::search_app_config_file`] does, mapping each file to where it
+    /// was found, or to `None` if missing.
+    ///
+    /// This is useful to report every missing file at once, e.g. at startup.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) of one of
+    ///   the searched directories is set, but its value represents a relative
+    ///   path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) of one of
+    ///   the searched directories is set, but its value represents invalid
+    ///   unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgDir, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let files = xdg.check_files([
+    ///     ("config.toml", XdgDir::Config),
+    ///     ("keymap", XdgDir::Config),
+    ///     ("db.sqlite", XdgDir::Data),
+    /// ])?;
+    /// let missing: Vec<_> = files.iter().filter(|(_, found)| found.is_none()).collect();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn check_files<I, P>(
+        &self,
+        files: I,
+    ) -> Result<BTreeMap<PathBuf, Option<PathBuf>>, XdgError>
+    where
+        I: IntoIterator<Item = (P, XdgDir)>,
+        P: AsRef<Path>,
+    {
+        let mut found = BTreeMap::new();
+        for (file, dir) in files {
+            let path = self.search_app_file(dir, &file, EntryType::File)?;
+            found.insert(file.as_ref().to_path_buf(), path);
+        }
+
+        Ok(found)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration** app
+    /// subdirectory and the _system-wide_ XDG **configuration** app subdirectories,
+    /// like [`XdgApp::search_app_config_file`] does, failing if `file` is
+    /// **not** found.
+    ///
+    /// Unlike the returned [`XdgError::FileNotFound`] error, the `None`
+    /// returned by [`XdgApp::search_app_config_file`] can't tell users which
+    /// subdirectories were searched.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.require_app_config_file("file") {
+    ///     Ok(app_config_file) => { /* ... */ },
+    ///     Err(err @ XdgError::FileNotFound { .. }) => eprintln!("{err}"),
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn require_app_config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_config_file(&file)?;
+        Xdg::require_found(file, found, || self.searched_app_dirs(XdgDir::Config))
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** app
+    /// subdirectory and the _system-wide_ XDG **data** app subdirectories,
+    /// like [`XdgApp::search_app_data_file`] does, failing if `file` is
+    /// **not** found.
+    ///
+    /// Unlike the returned [`XdgError::FileNotFound`] error, the `None`
+    /// returned by [`XdgApp::search_app_data_file`] can't tell users which
+    /// subdirectories were searched.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.require_app_data_file("file") {
+    ///     Ok(app_data_file) => { /* ... */ },
+    ///     Err(err @ XdgError::FileNotFound { .. }) => eprintln!("{err}"),
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn require_app_data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_data_file(&file)?;
+        Xdg::require_found(file, found, || self.searched_app_dirs(XdgDir::Data))
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration** app
+    /// subdirectory only, never consulting the _system-wide_ ones.
+    ///
+    /// See [`XdgApp::search_app_config_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_usr_config_file("file")? {
+    ///     Some(app_usr_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_usr_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        for app in self.aliased() {
+            let path = app.search_app_usr_file(XdgDir::Config, &file, EntryType::File)?;
+            if let Some(path) = path {
+                return self.xdg.found(path).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** app
+    /// subdirectory only, never consulting the _system-wide_ ones.
+    ///
+    /// See [`XdgApp::search_app_data_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_usr_data_file("file")? {
+    ///     Some(app_usr_data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_usr_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        for app in self.aliased() {
+            let path = app.search_app_usr_file(XdgDir::Data, &file, EntryType::File)?;
+            if let Some(path) = path {
+                return self.xdg.found(path).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Searches for `file` inside the _system-wide_, preference-ordered, XDG
+    /// **configuration** app subdirectories, skipping the _user-specific_ one.
+    ///
+    /// See [`XdgApp::search_app_config_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_sys_config_file("file")? {
+    ///     Some(app_sys_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_sys_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        for app in self.aliased() {
+            let path = app.search_app_sys_file(XdgSysDirs::Config, &file, EntryType::File)?;
+            if let Some(path) = path {
+                return self.xdg.found(path).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Searches for `file` inside the _system-wide_, preference-ordered, XDG
+    /// **data** app subdirectories, skipping the _user-specific_ one.
+    ///
+    /// See [`XdgApp::search_app_data_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_sys_data_file("file")? {
+    ///     Some(app_sys_data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_sys_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        for app in self.aliased() {
+            let path = app.search_app_sys_file(XdgSysDirs::Data, &file, EntryType::File)?;
+            if let Some(path) = path {
+                return self.xdg.found(path).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **state** app
+    /// subdirectory specified by `$XDG_STATE_HOME/<app_name>`. The search
+    /// falls back to `$HOME/.state/<app_name>` if `XDG_STATE_HOME` is not
+    /// set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG subdirectories for the
+    ///   current application;
+    /// - `None` if `file` is **not** found inside any of the XDG subdirectories
+    ///   for the current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_state_file("file")? {
+    ///     Some(app_state_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_state_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file(XdgDir::State, file, EntryType::File)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the XDG
+    /// **cache** app subdirectories, like [`XdgApp::search_app_cache_file`]
+    /// does for regular files.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{EntryType, XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_cache_entry("entry", EntryType::Any)? {
+    ///     Some(app_cache_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_cache_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file(XdgDir::Cache, entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the XDG
+    /// **configuration** app subdirectories, like
+    /// [`XdgApp::search_app_config_file`] does for regular files.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{EntryType, XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_config_entry("entry", EntryType::Any)? {
+    ///     Some(app_config_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file(XdgDir::Config, entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the XDG
+    /// **data** app subdirectories, like [`XdgApp::search_app_data_file`] does
+    /// for regular files.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{EntryType, XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_data_entry("entry", EntryType::Any)? {
+    ///     Some(app_data_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_data_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file(XdgDir::Data, entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the XDG
+    /// **state** app subdirectories, like [`XdgApp::search_app_state_file`]
+    /// does for regular files.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{EntryType, XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_state_entry("entry", EntryType::Any)? {
+    ///     Some(app_state_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_state_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file(XdgDir::State, entry, entry_type)
+    }
+
+    /// Searches for the `dir` subdirectory inside the XDG **cache** app
+    /// subdirectories, returning the first existing one in order of precedence.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_cache_dir("cache")? {
+    ///     Some(cache_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_cache_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_cache_entry(dir, EntryType::Dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the XDG **configuration** app
+    /// subdirectories, returning the first existing one in order of precedence.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_config_dir("themes")? {
+    ///     Some(themes_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_config_entry(dir, EntryType::Dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the XDG **data** app
+    /// subdirectories, returning the first existing one in order of precedence.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_data_dir("plugins")? {
+    ///     Some(plugins_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_data_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_data_entry(dir, EntryType::Dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the XDG **state** app
+    /// subdirectories, returning the first existing one in order of precedence.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_state_dir("sessions")? {
+    ///     Some(sessions_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_app_state_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_state_entry(dir, EntryType::Dir)
+    }
+
+    /// Recursively searches for `file` inside XDG app subdirectories in the
+    /// following order, descending at most `max_depth` levels into each of
+    /// them:
+    /// - _user-specific_ XDG subdirectory for the current application;
+    /// - _system-wide_, preference-ordered, set of XDG subdirectories for the
+    ///   current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents invalid unicode.
+    #[cfg(feature = "fs")]
+    fn search_app_file_recursive<P>(
+        &self,
+        dir: XdgDir,
+        file: P,
+        max_depth: usize,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = file.as_ref();
+        for dir_path in self.get_aliased_app_dir_paths(dir)? {
+            if let Some(path) = search_file_recursive(&self.xdg, dir_path, file, max_depth)? {
+                return self.xdg.found(path).map(Some);
+            }
+        }
+
+        if let Some(sys_dirs) = dir.to_sys() {
+            for dir_path in self.get_aliased_app_sys_dir_paths(sys_dirs)? {
+                if let Some(path) = search_file_recursive(&self.xdg, dir_path, file, max_depth)? {
+                    return self.xdg.found(path).map(Some);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Recursively searches for `file` inside the XDG **cache** app
+    /// subdirectory and its subdirectories, descending at most `max_depth`
+    /// levels. A `max_depth` of `0` is equivalent to
+    /// [`XdgApp::search_app_cache_file`].
+    ///
+    /// # Note
+    ///
+    /// The search is breadth-first: shallower matches take precedence over
+    /// deeper ones and subdirectories at the same level are visited in
+    /// lexicographic order. Unreadable directories are silently skipped and
+    /// symbolic links to directories are **not** followed.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[cfg(feature = "fs")]
+    pub fn search_app_cache_file_recursive<P>(
+        &self,
+        file: P,
+        max_depth: usize,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file_recursive(XdgDir::Cache, file, max_depth)
+    }
+
+    /// Recursively searches for `file` inside the XDG **config** app
+    /// subdirectories and their subdirectories, descending at most
+    /// `max_depth` levels. A `max_depth` of `0` is equivalent to
+    /// [`XdgApp::search_app_config_file`].
+    ///
+    /// # Note
+    ///
+    /// The _user-specific_ app subdirectory tree is entirely searched before
+    /// the _system-wide_ ones. Each search is breadth-first: shallower matches
+    /// take precedence over deeper ones and subdirectories at the same level
+    /// are visited in lexicographic order. Unreadable directories are
+    /// silently skipped and symbolic links to directories are **not**
+    /// followed.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG config
+    ///   directory and:
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but one (or
+    ///       more) path(s) in the colon separated value represents a relative
+    ///       path;
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// // Looks into `conf.d/` and `conf.d/include/` as well.
+    /// match xdg.search_app_config_file_recursive("file", 2)? {
+    ///     Some(app_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_file_recursive<P>(
+        &self,
+        file: P,
+        max_depth: usize,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file_recursive(XdgDir::Config, file, max_depth)
+    }
+
+    /// Recursively searches for `file` inside the XDG **data** app
+    /// subdirectories and their subdirectories, descending at most
+    /// `max_depth` levels. A `max_depth` of `0` is equivalent to
+    /// [`XdgApp::search_app_data_file`].
+    ///
+    /// See [`XdgApp::search_app_config_file_recursive`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG data directory
+    ///   and:
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///       path(s) in the colon separated value represents a relative path;
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    #[cfg(feature = "fs")]
+    pub fn search_app_data_file_recursive<P>(
+        &self,
+        file: P,
+        max_depth: usize,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file_recursive(XdgDir::Data, file, max_depth)
+    }
+
+    /// Recursively searches for `file` inside the XDG **state** app
+    /// subdirectory and its subdirectories, descending at most `max_depth`
+    /// levels. A `max_depth` of `0` is equivalent to
+    /// [`XdgApp::search_app_state_file`].
+    ///
+    /// See [`XdgApp::search_app_cache_file_recursive`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[cfg(feature = "fs")]
+    pub fn search_app_state_file_recursive<P>(
+        &self,
+        file: P,
+        max_depth: usize,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_file_recursive(XdgDir::State, file, max_depth)
+    }
+
+    /// Searches for `<stem>.<ext>` inside XDG app subdirectories, trying each
+    /// of the given extensions in order for every subdirectory, in the
+    /// following order:
+    /// - _user-specific_ XDG subdirectory for the current application;
+    /// - _system-wide_, preference-ordered, set of XDG subdirectories for the
+    ///   current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents invalid unicode.
+    #[cfg(feature = "fs")]
+    fn search_app_file_with_ext<S>(
+        &self,
+        dir: XdgDir,
+        stem: S,
+        exts: &[&str],
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        S: AsRef<OsStr>,
+    {
+        let stem = stem.as_ref();
+        let with_ext = |dir_paths: Vec<PathBuf>| {
+            dir_paths.into_iter().flat_map(move |dir_path| {
+                exts.iter().map(move |ext| {
+                    let mut file = OsString::from(stem);
+                    file.push(".");
+                    file.push(ext);
+
+                    dir_path.join(file)
+                })
+            })
+        };
+
+        let dir_paths = self.get_aliased_app_dir_paths(dir)?;
+        if let Some(path) = self.xdg.probe_first(with_ext(dir_paths), EntryType::File)? {
+            return self.xdg.found(path).map(Some);
+        }
+
+        if let Some(sys_dirs) = dir.to_sys() {
+            let sys_dir_paths = self.get_aliased_app_sys_dir_paths(sys_dirs)?;
+            if let Some(path) = self.xdg.probe_first(with_ext(sys_dir_paths), EntryType::File)? {
+                return self.xdg.found(path).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Searches for a configuration file named `<stem>.<ext>` inside the XDG
+    /// **config** app subdirectories, trying each of the given extensions in
+    /// order. Useful when the same configuration file may be provided in
+    /// several formats.
+    ///
+    /// # Note
+    ///
+    /// The precedence of the XDG subdirectories prevails over the order of
+    /// the extensions: `$XDG_CONFIG_HOME/<app_name>/<stem>.<ext>` is returned
+    /// for any of the extensions, before looking into the _system-wide_
+    /// subdirectories.
+    ///
+    /// See [`XdgApp::search_app_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the file was **not** found inside the _user-specific_ XDG config
+    ///   directory and:
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but one (or
+    ///       more) path(s) in the colon separated value represents a relative
+    ///       path;
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_config_file_with_ext("config", &["toml", "yml"])? {
+    ///     Some(app_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_file_with_ext<S>(
+        &self,
+        stem: S,
+        exts: &[&str],
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        S: AsRef<OsStr>,
+    {
+        self.search_app_file_with_ext(XdgDir::Config, stem, exts)
+    }
+
+    /// Searches for a data file named `<stem>.<ext>` inside the XDG **data**
+    /// app subdirectories, trying each of the given extensions in order.
+    ///
+    /// See [`XdgApp::search_app_config_file_with_ext`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the file was **not** found inside the _user-specific_ XDG data
+    ///   directory and:
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///       path(s) in the colon separated value represents a relative path;
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    #[cfg(feature = "fs")]
+    pub fn search_app_data_file_with_ext<S>(
+        &self,
+        stem: S,
+        exts: &[&str],
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        S: AsRef<OsStr>,
+    {
+        self.search_app_file_with_ext(XdgDir::Data, stem, exts)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and every _system-wide_ XDG **configuration** directory,
+    /// returning all the matches in precedence order.
+    ///
+    /// See [`Xdg::search_config_files`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_files<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_config_files(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration** directory
+    /// and the _system-wide_ XDG **configuration** directories,
+    /// returning at most the first `limit` matches in precedence order.
+    ///
+    /// See [`Xdg::search_config_files_bounded`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_files_bounded<P>(
+        &self,
+        file: P,
+        limit: usize,
+    ) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_config_files_bounded(file, limit)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and every _system-wide_ XDG **configuration** directory,
+    /// returning the most recently modified match.
+    ///
+    /// See [`Xdg::search_config_file_newest`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the modification time of one of the matches can't be read.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_file_newest<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_config_file_newest(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// and every _system-wide_ XDG **data** directory, returning all the
+    /// matches in precedence order.
+    ///
+    /// See [`Xdg::search_data_files`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_files<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_data_files(file)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// and the _system-wide_ XDG **data** directories,
+    /// returning at most the first `limit` matches in precedence order.
+    ///
+    /// See [`Xdg::search_data_files_bounded`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_files_bounded<P>(
+        &self,
+        file: P,
+        limit: usize,
+    ) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.search_data_files_bounded(file, limit)
+    }
+
+    /// Searches for regular files matching `pattern` inside the
+    /// _user-specific_ XDG **data** directory and every _system-wide_ XDG
+    /// **data** directory, returning all the matches in precedence order.
+    ///
+    /// See [`Xdg::search_data_glob`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "glob")]
+    pub fn search_data_glob(&self, pattern: &str) -> Result<Vec<PathBuf>, XdgError> {
+        self.xdg.search_data_glob(pattern)
+    }
+
+    /// Searches for regular files matching `pattern` inside the
+    /// _user-specific_ XDG **data** app subdirectory and every _system-wide_
+    /// XDG **data** app subdirectory, returning all the matches in precedence
+    /// order: `$XDG_DATA_HOME/<app_name>` first, followed by each
+    /// `$XDG_DATA_DIRS/<app_name>`.
+    ///
+    /// See [`Xdg::search_data_glob`] for the supported wildcards.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for plugin in xdg.search_app_data_glob("plugins/*.so")? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "glob")]
+    pub fn search_app_data_glob(&self, pattern: &str) -> Result<Vec<PathBuf>, XdgError> {
+        let mut paths = Vec::new();
+        for path in self.get_aliased_app_dir_paths(XdgDir::Data)? {
+            paths.extend(crate::glob::glob(path, pattern));
+        }
+        for sys_path in self.get_aliased_app_sys_dir_paths(XdgSysDirs::Data)? {
+            paths.extend(crate::glob::glob(sys_path, pattern));
+        }
+
+        paths.into_iter().map(|path| self.xdg.found(path)).collect()
+    }
+
+    /// Searches for `file` inside every XDG app subdirectory, collecting up to
+    /// `limit` matches in the following order:
+    /// - _user-specific_ XDG subdirectory for the current application;
+    /// - _system-wide_, preference-ordered, set of XDG subdirectories for the
+    ///   current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_app_files<P>(
+        &self,
+        dir: XdgDir,
+        file: P,
+        limit: usize,
+    ) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut paths = Vec::new();
+        for app in self.aliased() {
+            if paths.len() >= limit {
+                break;
+            }
+
+            paths.extend(app.search_app_usr_file(dir, &file, EntryType::File)?);
+        }
+
+        if let Some(sys_dirs) = dir.to_sys().filter(|_| paths.len() < limit) {
+            let sys_paths = self.get_aliased_app_sys_dir_paths(sys_dirs)?.into_iter();
+            let limit = limit - paths.len();
+            paths.extend(self.xdg.probe_all(sys_paths.map(|path| path.append(&file)), limit)?);
+        }
+
+        paths.into_iter().map(|path| self.xdg.found(path)).collect()
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration** app
+    /// subdirectory and every _system-wide_ XDG **configuration** app
+    /// subdirectory, returning all the matches in precedence order:
+    /// `$XDG_CONFIG_HOME/<app_name>` first, followed by each
+    /// `$XDG_CONFIG_DIRS/<app_name>`.
+    ///
+    /// This is useful to layer configuration files, e.g. defaults provided in
+    /// `/etc/xdg/<app_name>` overridden by the user's configuration in
+    /// `~/.config/<app_name>`.
+    ///
+    /// # Note
+    ///
+    /// This method returns an empty [`Vec`] if `file` is **not** found inside
+    /// any of the XDG subdirectories for the current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// // Apply the least important layer first.
+    /// for app_config_file in xdg.search_app_config_files("file")?.iter().rev() {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_files<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_files(XdgDir::Config, file, usize::MAX)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration** app
+    /// subdirectory and the _system-wide_ XDG **configuration** app
+    /// subdirectories,
+    /// returning at most the first `limit` matches in precedence order, like
+    /// [`XdgApp::search_app_config_files`] does.
+    ///
+    /// The search stops as soon as `limit` matches are found, sparing the
+    /// lookups in the remaining, lower-precedence, subdirectories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for app_config_file in xdg.search_app_config_files_bounded("file", 2)? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_files_bounded<P>(
+        &self,
+        file: P,
+        limit: usize,
+    ) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_files(XdgDir::Config, file, limit)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration** app
+    /// subdirectory and every _system-wide_ XDG **configuration** app
+    /// subdirectory, returning the most recently modified match.
+    ///
+    /// Among matches modified at the same time, the one in the
+    /// higher-precedence subdirectory is returned.
+    ///
+    /// # Note
+    ///
+    /// This method returns `None` if `file` is **not** found inside any of the
+    /// XDG subdirectories for the current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the modification time of one of the matches can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.search_app_config_file_newest("file")? {
+    ///     Some(app_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_config_file_newest<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        Xdg::newest(self.search_app_files(XdgDir::Config, file, usize::MAX)?)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** app
+    /// subdirectory and every _system-wide_ XDG **data** app subdirectory,
+    /// returning all the matches in precedence order:
+    /// `$XDG_DATA_HOME/<app_name>` first, followed by each
+    /// `$XDG_DATA_DIRS/<app_name>`.
+    ///
+    /// # Note
+    ///
+    /// This method returns an empty [`Vec`] if `file` is **not** found inside
+    /// any of the XDG subdirectories for the current application.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for app_data_file in xdg.search_app_data_files("file")? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_data_files<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_files(XdgDir::Data, file, usize::MAX)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** app
+    /// subdirectory and the _system-wide_ XDG **data** app subdirectories,
+    /// returning at most the first `limit` matches in precedence order, like
+    /// [`XdgApp::search_app_data_files`] does.
+    ///
+    /// The search stops as soon as `limit` matches are found, sparing the
+    /// lookups in the remaining, lower-precedence, subdirectories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for app_data_file in xdg.search_app_data_files_bounded("file", 2)? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_app_data_files_bounded<P>(
+        &self,
+        file: P,
+        limit: usize,
+    ) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_app_files(XdgDir::Data, file, limit)
+    }
+
+    /// Lists the regular files inside every XDG app subdirectory, walking them
+    /// in precedence order. Files in higher-precedence subdirectories shadow
+    /// files with the same relative path in lower-precedence ones.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents invalid unicode.
+    #[cfg(feature = "fs")]
+    fn list_app_files(&self, dir: XdgDir) -> Result<BTreeMap<PathBuf, PathBuf>, XdgError> {
+        let mut dir_paths = vec![self.get_app_dir_path(dir)?];
+        if let Some(sys_dirs) = dir.to_sys() {
+            dir_paths.extend(self.get_app_sys_dir_paths(sys_dirs)?);
+        }
+
+        let mut files = BTreeMap::new();
+        for dir_path in dir_paths {
+            list_files_recursive(&dir_path, &dir_path, &mut files);
+        }
+
+        Ok(files)
+    }
+
+    /// Lists the regular files inside the XDG **config** app subdirectories,
+    /// returning a map from each unique relative path to the absolute path of
+    /// the file that takes precedence.
+    ///
+    /// The subdirectories are walked recursively in precedence order:
+    /// `$XDG_CONFIG_HOME/<app_name>` first, followed by each
+    /// `$XDG_CONFIG_DIRS/<app_name>`. A file found in a higher-precedence
+    /// subdirectory _shadows_ any file with the same relative path in the
+    /// lower-precedence ones, enabling "drop-in" style configuration.
+    ///
+    /// # Note
+    ///
+    /// Unreadable directories are silently skipped and symbolic links to
+    /// directories are **not** followed.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for (relative_path, path) in xdg.list_app_config_files()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn list_app_config_files(&self) -> Result<BTreeMap<PathBuf, PathBuf>, XdgError> {
+        self.list_app_files(XdgDir::Config)
+    }
+
+    /// Lists the regular files inside the XDG **data** app subdirectories,
+    /// returning a map from each unique relative path to the absolute path of
+    /// the file that takes precedence.
+    ///
+    /// See [`XdgApp::list_app_config_files`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn list_app_data_files(&self) -> Result<BTreeMap<PathBuf, PathBuf>, XdgError> {
+        self.list_app_files(XdgDir::Data)
+    }
+
+    /// Discovers the assets inside `subdir` of the XDG **data** app
+    /// subdirectories, returning a map from each asset name to the paths of
+    /// every layer providing it, in precedence order.
+    ///
+    /// Assets are the entries, either files or directories, found directly
+    /// inside `$XDG_DATA_HOME/<app_name>/<subdir>` first, followed by each
+    /// `$XDG_DATA_DIRS/<app_name>/<subdir>`: this suits merge-style assets,
+    /// e.g. dictionaries extended by the user.
+    ///
+    /// # Note
+    ///
+    /// Unreadable directories, hidden entries (i.e. starting with `.`) and
+    /// entries whose name is not valid unicode are silently skipped.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// for (dictionary, layers) in xdg.app_data_asset_layers("dictionaries")? {
+    ///     /* Merge the `layers`, lowest precedence first... */
+    ///     for path in layers.iter().rev() { /* ... */ }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn app_data_asset_layers<P>(
+        &self,
+        subdir: P,
+    ) -> Result<BTreeMap<String, Vec<PathBuf>>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut dir_paths = vec![self.get_app_dir_path(XdgDir::Data)?];
+        dir_paths.extend(self.get_app_sys_dir_paths(XdgSysDirs::Data)?);
+
+        let mut assets: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+        for dir_path in dir_paths {
+            let Ok(entries) = fs::read_dir(dir_path.append(&subdir)) else {
+                continue;
+            };
+
+            for entry in entries.filter_map(Result::ok) {
+                let Ok(name) = entry.file_name().into_string() else {
+                    continue;
+                };
+                if !name.starts_with('.') {
+                    assets.entry(name).or_default().push(entry.path());
+                }
+            }
+        }
+
+        Ok(assets)
+    }
+
+    /// Discovers the assets inside `subdir` of the XDG **data** app
+    /// subdirectories, returning a map from each asset name to the path of
+    /// the layer that takes precedence.
+    ///
+    /// This suits assets replaced as a whole, such as themes or templates: an
+    /// asset found in `$XDG_DATA_HOME/<app_name>/<subdir>` _shadows_ any asset
+    /// with the same name in `$XDG_DATA_DIRS/<app_name>/<subdir>`.
+    ///
+    /// See [`XdgApp::app_data_asset_layers`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let themes = xdg.app_data_assets("themes")?;
+    /// if let Some(theme_dir) = themes.get("dark") {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn app_data_assets<P>(&self, subdir: P) -> Result<BTreeMap<String, PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let layers = self.app_data_asset_layers(subdir)?;
+        Ok(layers.into_iter().map(|(name, mut paths)| (name, paths.swap_remove(0))).collect())
+    }
+
+    /// Searches for `file` inside the XDG **cache** directories and reads
+    /// its whole content into a byte vector.
+    ///
+    /// See [`Xdg::read_cache_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_cache_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.read_cache_file(file)
+    }
+
+    /// Searches for `file` inside the XDG **cache** directories and reads
+    /// its whole content into a string.
+    ///
+    /// See [`Xdg::read_cache_file_to_string`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_cache_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.read_cache_file_to_string(file)
+    }
+
+    /// Searches for `file` inside the XDG **configuration** directories and
+    /// reads its whole content into a byte vector.
+    ///
+    /// See [`Xdg::read_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_config_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.read_config_file(file)
+    }
+
+    /// Searches for `file` inside the XDG **configuration** directories and
+    /// reads its whole content into a string.
+    ///
+    /// See [`Xdg::read_config_file_to_string`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_config_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.read_config_file_to_string(file)
+    }
+
+    /// Searches for `file` inside the XDG **configuration** directories and
+    /// loads the configuration it contains, parsed through the given
+    /// [`ConfigFormat`].
+    ///
+    /// See [`Xdg::load_config`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]);
+    /// - `file` was found, but does not contain a valid configuration
+    ///   ([`XdgError::InvalidConfig`]).
+    #[inline]
+    #[cfg(all(feature = "fs", feature = "serde"))]
+    pub fn load_config<F, T, P>(&self, format: F, file: P) -> Result<T, XdgError>
+    where
+        F: ConfigFormat,
+        T: serde::de::DeserializeOwned,
+        P: AsRef<Path>,
+    {
+        self.xdg.load_config(format, file)
+    }
+
+    /// Searches for `file` inside the XDG **data** directories and reads
+    /// its whole content into a byte vector.
+    ///
+    /// See [`Xdg::read_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_data_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.read_data_file(file)
+    }
+
+    /// Searches for `file` inside the XDG **data** directories and reads
+    /// its whole content into a string.
+    ///
+    /// See [`Xdg::read_data_file_to_string`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_data_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.read_data_file_to_string(file)
+    }
+
+    /// Searches for `file` inside the XDG **state** directories and reads
+    /// its whole content into a byte vector.
+    ///
+    /// See [`Xdg::read_state_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_state_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.read_state_file(file)
+    }
+
+    /// Searches for `file` inside the XDG **state** directories and reads
+    /// its whole content into a string.
+    ///
+    /// See [`Xdg::read_state_file_to_string`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_state_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.xdg.read_state_file_to_string(file)
+    }
+
+    /// Searches for `file` inside the XDG **cache** app subdirectories and
+    /// reads its whole content into a byte vector.
+    ///
+    /// See [`XdgApp::search_app_cache_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.read_app_cache_file("file") {
+    ///     Ok(content) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_app_cache_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_cache_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::Cache);
+        Xdg::read_found(file, found, searched, |path| fs::read(path))
+    }
+
+    /// Searches for `file` inside the XDG **cache** app subdirectories and
+    /// reads its whole content into a string.
+    ///
+    /// See [`XdgApp::read_app_cache_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_app_cache_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_cache_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::Cache);
+        Xdg::read_found(file, found, searched, |path| fs::read_to_string(path))
+    }
+
+    /// Searches for `file` inside the XDG **configuration** app subdirectories
+    /// and reads its whole content into a byte vector.
+    ///
+    /// See [`XdgApp::search_app_config_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.read_app_config_file("file") {
+    ///     Ok(content) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_app_config_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_config_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::Config);
+        Xdg::read_found(file, found, searched, |path| fs::read(path))
+    }
+
+    /// Searches for `file` inside the XDG **configuration** app subdirectories
+    /// and reads its whole content into a string.
+    ///
+    /// See [`XdgApp::read_app_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_app_config_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_config_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::Config);
+        Xdg::read_found(file, found, searched, |path| fs::read_to_string(path))
+    }
+
+    /// Searches for `file` inside the XDG **configuration** app
+    /// subdirectories and loads the configuration it contains, parsed through
+    /// the given [`ConfigFormat`].
+    ///
+    /// See [`XdgApp::search_app_config_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]);
+    /// - `file` was found, but does not contain a valid configuration
+    ///   ([`XdgError::InvalidConfig`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.load_app_config::<_, Config, _>(Json, "config.json") {
+    ///     Ok(config) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(all(feature = "fs", feature = "serde"))]
+    pub fn load_app_config<F, T, P>(&self, format: F, file: P) -> Result<T, XdgError>
+    where
+        F: ConfigFormat,
+        T: serde::de::DeserializeOwned,
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_config_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::Config);
+        Xdg::load_found(format, file, found, searched)
+    }
+
+    /// Searches for `file` inside the XDG **data** app subdirectories and
+    /// reads its whole content into a byte vector.
+    ///
+    /// See [`XdgApp::search_app_data_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.read_app_data_file("file") {
+    ///     Ok(content) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_app_data_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_data_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::Data);
+        Xdg::read_found(file, found, searched, |path| fs::read(path))
+    }
+
+    /// Searches for `file` inside the XDG **data** app subdirectories and
+    /// reads its whole content into a string.
+    ///
+    /// See [`XdgApp::read_app_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_app_data_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_data_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::Data);
+        Xdg::read_found(file, found, searched, |path| fs::read_to_string(path))
+    }
+
+    /// Searches for `file` inside the XDG **state** app subdirectories and
+    /// reads its whole content into a byte vector.
+    ///
+    /// See [`XdgApp::search_app_state_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.read_app_state_file("file") {
+    ///     Ok(content) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_app_state_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_state_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::State);
+        Xdg::read_found(file, found, searched, |path| fs::read(path))
+    }
+
+    /// Searches for `file` inside the XDG **state** app subdirectories and
+    /// reads its whole content into a string.
+    ///
+    /// See [`XdgApp::read_app_state_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_app_state_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_state_file(&file)?;
+        let searched = || self.searched_app_dirs(XdgDir::State);
+        Xdg::read_found(file, found, searched, |path| fs::read_to_string(path))
+    }
+
+    /// Searches for `file` inside XDG app subdirectories and opens it in
+    /// read-only mode.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents a relative path or invalid unicode;
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be opened ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn open_app_file<P>(&self, dir: XdgDir, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_app_file(dir, &file, EntryType::File)?;
+        let searched = || self.searched_app_dirs(dir);
+        Xdg::read_found(file, found, searched, |path| File::open(path))
+    }
+
+    /// Creates (or truncates) `file` inside the _user-specific_ XDG app
+    /// subdirectory, opening it in write-only mode.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path or invalid unicode;
+    /// - the file or one of its parent directories could not be created
+    ///   ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn create_app_file<P>(&self, dir: XdgDir, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        Xdg::create_file(self.get_app_file_path(dir, file)?)
+    }
+
+    /// Searches for `file` inside the XDG **cache** app subdirectories and
+    /// opens it in read-only mode.
+    ///
+    /// See [`XdgApp::search_app_cache_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be opened ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn open_app_cache_file<P>(&self, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.open_app_file(XdgDir::Cache, file)
+    }
+
+    /// Searches for `file` inside the XDG **configuration** app subdirectories and
+    /// opens it in read-only mode.
+    ///
+    /// See [`XdgApp::search_app_config_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be opened ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::io::Read;
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.open_app_config_file("file") {
+    ///     Ok(mut file) => {
+    ///         let mut content = String::new();
+    ///         file.read_to_string(&mut content)?;
+    ///     },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn open_app_config_file<P>(&self, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.open_app_file(XdgDir::Config, file)
+    }
+
+    /// Searches for `file` inside the XDG **data** app subdirectories and
+    /// opens it in read-only mode.
+    ///
+    /// See [`XdgApp::search_app_data_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be opened ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn open_app_data_file<P>(&self, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.open_app_file(XdgDir::Data, file)
+    }
+
+    /// Searches for `file` inside the XDG **state** app subdirectories and
+    /// opens it in read-only mode.
+    ///
+    /// See [`XdgApp::search_app_state_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be opened ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn open_app_state_file<P>(&self, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.open_app_file(XdgDir::State, file)
+    }
+
+    /// Creates (or truncates) `file` inside the _user-specific_ XDG **cache**
+    /// app subdirectory as `$XDG_CACHE_HOME/<app_name>/<file>`, opening it in
+    /// write-only mode.
+    ///
+    /// # Note
+    ///
+    /// Missing parent directories are created with permissions set to `0700`,
+    /// while the file is created with permissions set to `0600` on Unix.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the file or one of its parent directories could not be created
+    ///   ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn create_app_cache_file<P>(&self, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.create_app_file(XdgDir::Cache, file)
+    }
+
+    /// Creates (or truncates) `file` inside the _user-specific_ XDG **configuration**
+    /// app subdirectory as `$XDG_CONFIG_HOME/<app_name>/<file>`, opening it in
+    /// write-only mode.
+    ///
+    /// # Note
+    ///
+    /// Missing parent directories are created with permissions set to `0700`,
+    /// while the file is created with permissions set to `0600` on Unix.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the file or one of its parent directories could not be created
+    ///   ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn create_app_config_file<P>(&self, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.create_app_file(XdgDir::Config, file)
+    }
+
+    /// Creates (or truncates) `file` inside the _user-specific_ XDG **data**
+    /// app subdirectory as `$XDG_DATA_HOME/<app_name>/<file>`, opening it in
+    /// write-only mode.
+    ///
+    /// # Note
+    ///
+    /// Missing parent directories are created with permissions set to `0700`,
+    /// while the file is created with permissions set to `0600` on Unix.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the file or one of its parent directories could not be created
+    ///   ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn create_app_data_file<P>(&self, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.create_app_file(XdgDir::Data, file)
+    }
+
+    /// Creates (or truncates) `file` inside the _user-specific_ XDG **state**
+    /// app subdirectory as `$XDG_STATE_HOME/<app_name>/<file>`, opening it in
+    /// write-only mode.
+    ///
+    /// # Note
+    ///
+    /// Missing parent directories are created with permissions set to `0700`,
+    /// while the file is created with permissions set to `0600` on Unix.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the file or one of its parent directories could not be created
+    ///   ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::io::Write;
+    /// # use microxdg::XdgApp;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let tmp_dir = tempfile::tempdir()?;
+    /// # std::env::set_var("XDG_STATE_HOME", tmp_dir.path());
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let mut file = xdg.create_app_state_file("history")?;
+    /// file.write_all(b"...")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn create_app_state_file<P>(&self, file: P) -> Result<File, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.create_app_file(XdgDir::State, file)
+    }
+
+    /// Atomically saves `contents` to `file` inside the _user-specific_ XDG
+    /// **state** app subdirectory as `$XDG_STATE_HOME/<app_name>/<file>`,
+    /// returning its path.
+    ///
+    /// The contents are written to a temporary file in the same directory,
+    /// flushed to disk and then renamed over `file`: readers observe either
+    /// the previous or the new version, never a partially written one.
+    ///
+    /// # Note
+    ///
+    /// Missing parent directories are created with permissions set to `0700`,
+    /// while the file is created with permissions set to `0600` on Unix. If
+    /// [`SaveOptions::backup`] is set, the previous version of the file, if
+    /// any, is kept as `<file>.bak`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the file, its backup or one of its parent directories could not be
+    ///   written ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{SaveOptions, XdgApp, XdgError};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let tmp_dir = tempfile::tempdir()?;
+    /// # std::env::set_var("XDG_STATE_HOME", tmp_dir.path());
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let options = SaveOptions::new().backup(true);
+    /// xdg.save_app_state("state.json", br#"{ "width": 800 }"#, options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn save_app_state<P, C>(
+        &self,
+        file: P,
+        contents: C,
+        options: SaveOptions,
+    ) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+        C: AsRef<[u8]>,
+    {
+        write_atomically(self.app_state_file(file)?, contents.as_ref(), options)
+    }
+
+    /// Rotates `file` inside the _user-specific_ XDG **state** app
+    /// subdirectory, renaming `<file>` to `<file>.1`, `<file>.1` to
+    /// `<file>.2` and so on, keeping at most `keep` generations. Returns
+    /// `false` if `file` does not exist, in which case nothing is rotated.
+    ///
+    /// This allows keeping crash-resilient history or session files: once
+    /// rotated, `file` no longer exists and the new version can be written.
+    ///
+    /// # Note
+    ///
+    /// The oldest generation, `<file>.<keep>`, is removed before rotating,
+    /// while `file` itself is removed if `keep` is `0`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the file or one of its generations could not be renamed or removed
+    ///   ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{SaveOptions, XdgApp, XdgError};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let tmp_dir = tempfile::tempdir()?;
+    /// # std::env::set_var("XDG_STATE_HOME", tmp_dir.path());
+    /// let xdg = XdgApp::new("app_name")?;
+    /// // Keeps `history.1`, `history.2` and `history.3`.
+    /// xdg.rotate_app_state_file("history", 3)?;
+    /// xdg.save_app_state("history", "ls\ncd\n", SaveOptions::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn rotate_app_state_file<P>(&self, file: P, keep: usize) -> Result<bool, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = self.app_state_file(file)?;
+        if fs::symlink_metadata(&path).is_err() {
+            return Ok(false);
+        }
+
+        let generation = |n: usize| {
+            let mut generation = path.clone().into_os_string();
+            generation.push(format!(".{n}"));
+            PathBuf::from(generation)
+        };
+        let oldest = if keep == 0 { path.clone() } else { generation(keep) };
+        match fs::remove_file(&oldest) {
+            Ok(()) => {},
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {},
+            Err(source) => return Err(XdgError::Io { path: oldest, source }),
+        }
+
+        for n in (0..keep).rev() {
+            let from = if n == 0 { path.clone() } else { generation(n) };
+            match fs::rename(&from, generation(n + 1)) {
+                Ok(()) => {},
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {},
+                Err(source) => return Err(XdgError::Io { path: from, source }),
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Atomically stores `config` to `file` inside the _user-specific_ XDG
+    /// **configuration** app subdirectory as
+    /// `$XDG_CONFIG_HOME/<app_name>/<file>`, serialized through the given
+    /// [`ConfigFormat`], returning its path.
+    ///
+    /// This is the counterpart of [`XdgApp::load_app_config`], completing the
+    /// load, modify and store round trip. See [`XdgApp::save_app_state`] for
+    /// how the file is written.
+    ///
+    /// # Note
+    ///
+    /// Missing parent directories are created with permissions set to `0700`,
+    /// while the file is created with permissions set to `0600` on Unix.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `config` could not be serialized ([`XdgError::InvalidConfig`]);
+    /// - the file or one of its parent directories could not be written
+    ///   ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let mut config: Config = xdg.load_app_config(Json, "config.json").unwrap_or_default();
+    /// config.theme = String::from("dark");
+    /// xdg.store_app_config(Json, "config.json", &config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "fs", feature = "serde"))]
+    pub fn store_app_config<F, T, P>(
+        &self,
+        format: F,
+        file: P,
+        config: &T,
+    ) -> Result<PathBuf, XdgError>
+    where
+        F: ConfigFormat,
+        T: serde::Serialize + ?Sized,
+        P: AsRef<Path>,
+    {
+        let path = self.app_config_file(file)?;
+        let contents = match format.emit(config) {
+            Ok(contents) => contents,
+            Err(err) => return Err(XdgError::InvalidConfig { path, message: err.to_string() }),
+        };
+
+        write_atomically(path, &contents, SaveOptions::new())
+    }
+
+    /// Acquires an advisory lock on `file` inside the _user-specific_ XDG
+    /// **configuration** app subdirectory, blocking until released by any
+    /// other process holding it. The lock is released when the returned
+    /// [`FileLock`] is dropped.
+    ///
+    /// Multiple instances of the application acquiring the lock before
+    /// writing `file` never interleave their writes.
+    ///
+    /// # Note
+    ///
+    /// The lock is held through the sidecar lock file
+    /// `$XDG_CONFIG_HOME/<app_name>/<file>.lock`, left in place once released.
+    /// Missing parent directories are created with permissions set to `0700`
+    /// on Unix. Locks held by crashed processes are released by the operating
+    /// system.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the lock file or one of its parent directories could not be created,
+    ///   or the lock file could not be locked ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let tmp_dir = tempfile::tempdir()?;
+    /// # std::env::set_var("XDG_CONFIG_HOME", tmp_dir.path());
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let _lock = xdg.lock_app_config_file("config.toml")?;
+    /// std::fs::write(xdg.app_config_file("config.toml")?, "...")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn lock_app_config_file<P>(&self, file: P) -> Result<FileLock, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        FileLock::acquire(&self.app_config_file(file)?)
+    }
+
+    /// Attempts to acquire an advisory lock on `file` inside the
+    /// _user-specific_ XDG **configuration** app subdirectory, returning
+    /// `None` if held by another process.
+    ///
+    /// See [`XdgApp::lock_app_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the lock file or one of its parent directories could not be created,
+    ///   or the lock file could not be locked ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn try_lock_app_config_file<P>(&self, file: P) -> Result<Option<FileLock>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        FileLock::try_acquire(&self.app_config_file(file)?)
+    }
+
+    /// Returns the path to the _user-specific_ XDG **config** app file
+    /// `$XDG_CONFIG_HOME/<app_name>/<file>`, seeding it from the
+    /// _system-wide_ configuration if needed.
+    ///
+    /// If `file` only exists inside one of the _system-wide_ XDG **config**
+    /// app subdirectories, the one with the highest precedence is copied
+    /// into the _user-specific_ XDG config app subdirectory, so that it can
+    /// be freely edited by the user. If `file` already exists inside the
+    /// _user-specific_ XDG config app subdirectory, it is left untouched.
+    ///
+    /// # Note
+    ///
+    /// Missing parent directories are created with permissions set to `0700`
+    /// on Unix, while the copied file is made writable by its owner.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` could not be copied ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// match xdg.promote_config_file("file") {
+    ///     Ok(app_config_file) => { /* Open `app_config_file` in an editor... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn promote_config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        if let Some(path) = self.search_app_usr_file(XdgDir::Config, &file, EntryType::File)? {
+            return Ok(path);
+        }
+
+        let sys_path = self.search_app_sys_file(XdgSysDirs::Config, &file, EntryType::File)?;
+        let sys_path =
+            Xdg::require_found(&file, sys_path, || self.searched_app_dirs(XdgDir::Config))?;
+
+        let path = self.app_config_file(file)?;
+        if let Some(parent) = path.parent() {
+            Xdg::create_dir_all(parent)?;
+        }
+
+        match fs::copy(&sys_path, &path).and_then(|_| set_owner_writable(&path)) {
+            Ok(()) => Ok(path),
+            Err(source) => Err(XdgError::Io { path, source }),
+        }
+    }
+
+    /// Watches the XDG **configuration** app subdirectories for changes from a
+    /// background thread, invoking `callback` with the path of each file
+    /// created, modified or removed.
+    ///
+    /// The whole precedence chain is watched, recursively: the
+    /// _user-specific_ app subdirectory, followed by the _system-wide_ ones
+    /// (app name aliases included), so that the callback may re-resolve the
+    /// configuration, e.g. through [`XdgApp::search_app_config_file`]. The
+    /// subdirectories are resolved once, when the watcher starts, and need
+    /// not exist: a missing subdirectory is watched for as soon as it gets
+    /// created.
+    ///
+    /// Changes are detected through the platform notification API (e.g.
+    /// _inotify_ on Linux), by means of the [`notify`](https://docs.rs/notify)
+    /// crate. The watcher stops once [`ConfigWatcher::stop`] is called or the
+    /// returned [`ConfigWatcher`] is dropped.
+    ///
+    /// [`ConfigWatcher`]: crate::ConfigWatcher
+    /// [`ConfigWatcher::stop`]: crate::ConfigWatcher::stop
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` or `XDG_CONFIG_DIRS` environment variable is
+    ///   set, but its value represents a relative path or invalid unicode;
+    /// - the platform notification API could not be initialized or the
+    ///   background thread could not be spawned ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{XdgApp, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = XdgApp::new("app_name")?;
+    /// let watcher = xdg.watch_app_config(|path| {
+    ///     /* Reload the configuration... */
+    /// })?;
+    /// /* ... */
+    /// watcher.stop();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "watch")]
+    pub fn watch_app_config<F>(&self, callback: F) -> Result<crate::ConfigWatcher, XdgError>
+    where
+        F: FnMut(&Path) + Send + 'static,
+    {
+        let mut dirs = self.get_aliased_app_dir_paths(XdgDir::Config)?;
+        let path = dirs[0].clone();
+        dirs.extend(self.get_aliased_app_sys_dir_paths(XdgSysDirs::Config)?);
+
+        crate::watch::spawn(dirs, callback).map_err(|source| XdgError::Io { path, source })
+    }
+}
+
+/// Prints the application name followed by one `<dir>: <path>` line per XDG
+/// app subdirectory, suitable for `--paths` output.
+///
+/// See the [`Display`](fmt::Display) implementation of [`Xdg`] for further
+/// details.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{XdgApp, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// std::env::remove_var("XDG_CONFIG_HOME");
+/// std::env::set_var("HOME", "/home/user");
+///
+/// let xdg = XdgApp::new("app_name")?;
+/// assert!(xdg.to_string().contains("config: /home/user/.config/app_name\n"));
+/// # Ok(())
+/// # }
+/// ```
+impl fmt::Display for XdgApp {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "app: {}", self.name)?;
+        write_summary_line(formatter, "cache", self.app_cache().map(|path| vec![path]))?;
+        write_summary_line(formatter, "config", self.app_config().map(|path| vec![path]))?;
+        write_summary_line(formatter, "data", self.app_data().map(|path| vec![path]))?;
+        write_summary_line(formatter, "state", self.app_state().map(|path| vec![path]))?;
+        let runtime = self.app_runtime().map(|runtime| runtime.into_iter().collect());
+        write_summary_line(formatter, "runtime", runtime)?;
+        write_summary_line(formatter, "sys_config", self.app_sys_config())?;
+        write_summary_line(formatter, "sys_data", self.app_sys_data())
+    }
+}
+
+/// Grants read and write permissions on `path` to its owner.
+#[cfg(all(unix, feature = "fs"))]
+fn set_owner_writable(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o600);
+    fs::set_permissions(path, permissions)
+}
+
+/// Grants write permissions on `path`.
+#[cfg(all(not(unix), feature = "fs"))]
+fn set_owner_writable(path: &Path) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    #[allow(clippy::permissions_set_readonly_false)]
+    permissions.set_readonly(false);
+    fs::set_permissions(path, permissions)
+}
+
+/// Searches for `file` inside `dir` and its subdirectories, descending at most
+/// `max_depth` levels. The search is breadth-first, so that shallower matches
+/// take precedence over deeper ones; subdirectories at the same level are
+/// visited in lexicographic order.
+///
+/// # Note
+///
+/// Unreadable directories are silently skipped and symbolic links to
+/// directories are **not** followed, while symbolic links to files are
+/// handled according to the [`SymlinkPolicy`] of `xdg`.
+#[cfg(feature = "fs")]
+fn search_file_recursive(
+    xdg: &Xdg,
+    dir: PathBuf,
+    file: &Path,
+    max_depth: usize,
+) -> Result<Option<PathBuf>, XdgError> {
+    let mut level = vec![dir];
+    for depth in 0..=max_depth {
+        let mut next_level = Vec::new();
+        for dir in level {
+            let path = dir.join(file);
+            if xdg.probe_file(&path)? {
+                return Ok(Some(path));
+            }
+
+            if depth == max_depth {
+                continue;
+            }
+
+            if let Ok(entries) = fs::read_dir(&dir) {
+                let mut subdirs: Vec<PathBuf> = entries
+                    .filter_map(Result::ok)
+                    .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+                    .map(|entry| entry.path())
+                    .collect();
+                subdirs.sort_unstable();
+                next_level.extend(subdirs);
+            }
+        }
+
+        level = next_level;
+    }
+
+    Ok(None)
+}
+
+/// Creates a uniquely named entry, starting with `prefix`, inside `dir` using
+/// the given `create` function, retrying with a new name as long as the entry
+/// already exists.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if `create` fails for any
+/// reason other than the entry already existing, or if no unique name could
+/// be found.
+#[cfg(feature = "fs")]
+fn create_unique<T, F>(dir: &Path, prefix: &OsStr, create: F) -> Result<(PathBuf, T), XdgError>
+where
+    F: Fn(&Path) -> io::Result<T>,
+{
+    const MAX_ATTEMPTS: u32 = 64;
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos());
+    let mut path = dir.to_path_buf();
+    for _ in 0..MAX_ATTEMPTS {
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let mut name = prefix.to_os_string();
+        name.push(format!("{:x}-{nanos:x}-{count:x}", std::process::id()));
+        path.push(name);
+        match create(&path) {
+            Ok(created) => return Ok((path, created)),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                path.pop();
+            },
+            Err(source) => return Err(XdgError::Io { path, source }),
+        }
+    }
+
+    let source = io::Error::new(io::ErrorKind::AlreadyExists, "no unique name available");
+    Err(XdgError::Io { path: dir.to_path_buf(), source })
+}
+
+/// Creates a uniquely named file, starting with `prefix`, inside `dir` with
+/// permissions set to `0600` on Unix, opening it in write-only mode.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if the file could not be
+/// created.
+#[inline]
+#[cfg(feature = "fs")]
+fn create_temp_file(dir: &Path, prefix: &OsStr) -> Result<(PathBuf, File), XdgError> {
+    create_unique(dir, prefix, |path| {
+        let mut open_options = fs::OpenOptions::new();
+        open_options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut open_options, 0o600);
+
+        open_options.open(path)
+    })
+}
+
+/// Returns `true` if `name` is a valid D-Bus well-known bus name.
+#[cfg(feature = "fs")]
+fn is_dbus_bus_name(name: &str) -> bool {
+    // Maximum length of a D-Bus name, in bytes.
+    const MAX_BUS_NAME_LEN: usize = 255;
+
+    name.len() <= MAX_BUS_NAME_LEN
+        && name.split('.').count() >= 2
+        && name.split('.').all(|element| {
+            element.bytes().next().is_some_and(|byte| !byte.is_ascii_digit())
+                && element
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-')
+        })
+}
+
+/// Atomically writes `contents` to the file at `path`, through a temporary
+/// file in the same directory renamed over `path`, creating its missing
+/// parent directories.
+///
+/// The temporary file is flushed to disk before being renamed, and so is the
+/// directory afterwards on Unix, for the new contents to survive a crash.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if the file, its backup or
+/// one of its parent directories could not be written.
+#[cfg(feature = "fs")]
+fn write_atomically(
+    path: PathBuf,
+    contents: &[u8],
+    options: SaveOptions,
+) -> Result<PathBuf, XdgError> {
+    let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+        let source = io::Error::new(io::ErrorKind::InvalidInput, "not a file path");
+        return Err(XdgError::Io { path, source });
+    };
+    let mut prefix = OsString::from(".");
+    prefix.push(file_name);
+    prefix.push(".");
+    Xdg::create_dir_all(dir)?;
+
+    let (tmp_path, mut tmp_file) = create_temp_file(dir, &prefix)?;
+    let written = tmp_file.write_all(contents).and_then(|()| tmp_file.sync_all());
+    if let Err(source) = written {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(XdgError::Io { path: tmp_path, source });
+    }
+
+    if options.backup {
+        let mut backup_name = file_name.to_os_string();
+        backup_name.push(".bak");
+        if let Err(err) = backup_atomically(&path, &path.with_file_name(backup_name), &prefix) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err);
+        }
+    }
+
+    if let Err(source) = fs::rename(&tmp_path, &path) {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(XdgError::Io { path, source });
+    }
+
+    #[cfg(unix)]
+    if let Err(source) = File::open(dir).and_then(|dir| dir.sync_all()) {
+        return Err(XdgError::Io { path: dir.to_path_buf(), source });
+    }
+
+    Ok(path)
+}
+
+/// Atomically replaces the file at `backup` with a copy of the file at `path`,
+/// if any, through a temporary file starting with `prefix` renamed over
+/// `backup`, so that an interrupted backup never leaves a truncated copy.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if the file at `path`
+/// could not be read or its copy could not be written.
+#[cfg(feature = "fs")]
+fn backup_atomically(path: &Path, backup: &Path, prefix: &OsStr) -> Result<(), XdgError> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(source) => return Err(XdgError::Io { path: path.to_path_buf(), source }),
+    };
+
+    let dir = backup.parent().unwrap_or(Path::new(""));
+    let (tmp_path, mut tmp_file) = create_temp_file(dir, prefix)?;
+    let copied = io::copy(&mut file, &mut tmp_file)
+        .and_then(|_| tmp_file.sync_all())
+        .and_then(|()| fs::rename(&tmp_path, backup));
+    if let Err(source) = copied {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(XdgError::Io { path: backup.to_path_buf(), source });
+    }
+
+    Ok(())
+}
+
+/// Returns the name of the host, as set in the kernel on Unix, or to the
+/// `COMPUTERNAME` environment variable on Windows.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::InvalidHostName`] error if the host
+/// name is not available or is not a valid single path component, and an
+/// [`XdgError::Unsupported`] error on other platforms.
+#[cfg(feature = "fs")]
+fn hostname() -> Result<String, XdgError> {
+    #[cfg(unix)]
+    let (origin, hostname) = ("gethostname", crate::sys::hostname().unwrap_or_default());
+    #[cfg(windows)]
+    let (origin, hostname) = ("COMPUTERNAME", env::var_os("COMPUTERNAME").unwrap_or_default());
+    #[cfg(not(any(unix, windows)))]
+    return Err(XdgError::Unsupported);
+
+    #[cfg(any(unix, windows))]
+    match hostname.to_str() {
+        Some(hostname) if validate_name(hostname).is_ok() => Ok(hostname.to_owned()),
+        _ => Err(XdgError::InvalidHostName { origin, hostname: hostname.to_string_lossy().into() }),
+    }
+}
+
+/// Returns an iterator over the readable entries of `dir`, empty if `dir` does
+/// not exist.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if `dir` exists but could
+/// not be read.
+#[cfg(feature = "fs")]
+fn read_dir_entries(dir: PathBuf) -> Result<impl Iterator<Item = fs::DirEntry>, XdgError> {
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => Some(entries),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(source) => return Err(XdgError::Io { path: dir, source }),
+    };
+
+    Ok(entries.into_iter().flatten().filter_map(Result::ok))
+}
+
+/// Collects the regular files inside `dir` and its subdirectories into
+/// `files`, mapping each path relative to `root` to its absolute path. Files
+/// already present in `files` are **not** replaced.
+///
+/// # Note
+///
+/// Unreadable directories are silently skipped and symbolic links to
+/// directories are **not** followed.
+#[cfg(feature = "fs")]
+fn list_files_recursive(root: &Path, dir: &Path, files: &mut BTreeMap<PathBuf, PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+
+        let path = entry.path();
+        if file_type.is_dir() {
+            list_files_recursive(root, &path, files);
+        } else if path.is_file() {
+            if let Ok(relative_path) = path.strip_prefix(root) {
+                files.entry(relative_path.to_path_buf()).or_insert(path);
+            }
+        }
+    }
+}
+
+/// Collects the regular files found inside `dir`, recursively, along with
+/// their last modification time and size.
+///
+/// # Note
+///
+/// Unreadable entries are silently skipped and symbolic links are **not**
+/// followed.
+#[cfg(feature = "fs")]
+pub(crate) fn collect_files_recursive(dir: &Path, files: &mut Vec<(PathBuf, SystemTime, u64)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            collect_files_recursive(&entry.path(), files);
+        } else if metadata.is_file() {
+            if let Ok(modified) = metadata.modified() {
+                files.push((entry.path(), modified, metadata.len()));
+            }
+        }
+    }
+}
+
+impl From<XdgApp> for Xdg {
+    #[inline]
+    fn from(xdg_app: XdgApp) -> Self {
+        xdg_app.xdg
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::env;
+    #[cfg(feature = "fs")]
+    use std::error::Error;
+    use std::ffi::OsStr;
+    #[cfg(feature = "fs")]
+    use std::fs;
+    use std::os::unix::prelude::OsStrExt;
+
+    use super::*;
+
+    const INVALID_UNICODE_BYTES: [u8; 4] = [0xF0, 0x90, 0x80, 0x67];
+
+    #[inline]
+    fn remove_xdg_vars() {
+        env::remove_var("USER");
+        env::remove_var("LOGNAME");
+        env::remove_var("HOME");
+
+        // User XDG environment variables
+        env::remove_var("XDG_CACHE_HOME");
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_STATE_HOME");
+        env::remove_var("XDG_BIN_HOME");
+        env::remove_var("XDG_RUNTIME_DIR");
+
+        // User XDG environment variables
+        env::remove_var("XDG_CONFIG_DIRS");
+        env::remove_var("XDG_DATA_DIRS");
+
+        // Desktop environment and session variables
+        env::remove_var("XDG_CURRENT_DESKTOP");
+        env::remove_var("XDG_SESSION_TYPE");
+        env::remove_var("XDG_SESSION_CLASS");
+        env::remove_var("XDG_SESSION_ID");
+        env::remove_var("XDG_SEAT");
+        env::remove_var("XDG_VTNR");
+
+        // Sandbox environment variables
+        env::remove_var("FLATPAK_ID");
+        env::remove_var("SNAP");
+        env::remove_var("SNAP_NAME");
+        env::remove_var("SNAP_USER_DATA");
+        env::remove_var("SNAP_USER_COMMON");
+        env::remove_var("SUDO_USER");
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn new_xdg_app() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user2");
+        env::set_var("HOME", "/home/user1");
+
+        assert_eq!(
+            Path::new("/home/user1"),
+            XdgApp::new("app_name")?.home(),
+        );
+        assert_eq!(
+            Path::new("/home/user1"),
+            XdgApp::from_xdg(Xdg::new()?, "app_name")?.home(),
+        );
+
+        env::remove_var("HOME");
+
+        assert_eq!(
+            Path::new("/home/user2"),
             XdgApp::new("app_name")?.home(),
         );
         assert_eq!(
-            Path::new("/home/user1"),
-            XdgApp::from_xdg(Xdg::new()?, "app_name").home(),
+            Path::new("/home/user2"),
+            XdgApp::from_xdg(Xdg::new()?, "app_name")?.home(),
+        );
+
+        env::remove_var("USER");
+
+        #[cfg(not(feature = "passwd"))]
+        assert_eq!(
+            XdgError::HomeNotFound,
+            XdgApp::new("app_name").unwrap_err(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_app_name() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("HOME", "/home/user");
+        for name in ["", ".", "..", "../../etc", "app/name", "/app", "app\0name"] {
+            assert_eq!(
+                XdgError::InvalidAppName { name: String::from(name) },
+                XdgApp::new(name).unwrap_err(),
+            );
+            assert_eq!(
+                XdgError::InvalidAppName { name: String::from(name) },
+                XdgApp::new_with_sudo_policy(name, SudoPolicy::Ignore).unwrap_err(),
+            );
+        }
+        assert_eq!(Path::new("/home/user/.config/.app"), XdgApp::new(".app")?.app_config()?);
+        assert_eq!(
+            Path::new("/home/user/.config/app..name"),
+            XdgApp::new("app..name")?.app_config()?,
+        );
+
+        let invalid = |name: &str| XdgError::InvalidAppName { name: name.to_owned() };
+        assert_eq!(invalid(".."), XdgApp::with_home("/home/user", "..").unwrap_err());
+        assert_eq!(
+            invalid("../../etc"),
+            XdgApp::from_xdg(Xdg::with_home("/home/user"), "../../etc").unwrap_err(),
+        );
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(invalid("../../x"), xdg.clone().with_profile("../../x").unwrap_err());
+        assert_eq!(invalid(""), xdg.clone().with_profile("").unwrap_err());
+        #[cfg(windows)]
+        assert_eq!(invalid("..\\x"), xdg.clone().with_profile("..\\x").unwrap_err());
+
+        #[cfg(feature = "serde")]
+        {
+            use serde::de::value::{Error, StrDeserializer};
+            use serde::de::IntoDeserializer;
+
+            let deserializer: StrDeserializer<Error> = "app_name".into_deserializer();
+            assert_eq!(Arc::from("app_name"), deserialize_name(deserializer).unwrap());
+            let deserializer: StrDeserializer<Error> = "../../etc".into_deserializer();
+            assert!(deserialize_name(deserializer).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_xdg_app_sudo() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("HOME", "/root");
+        env::set_var("SUDO_USER", "user");
+        assert_eq!(
+            Path::new("/root/.config/app_name"),
+            XdgApp::new_with_sudo_policy("app_name", SudoPolicy::Ignore)?.app_config()?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.config/app_name"),
+            XdgApp::new_with_sudo_policy("app_name", SudoPolicy::InvokingUser)?.app_config()?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_xdg_app_strict() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        assert_eq!(XdgError::HomeNotFound, XdgApp::new_strict("app_name").unwrap_err());
+
+        env::set_var("HOME", "/home/user");
+        let xdg = XdgApp::new_strict("app_name")?;
+        assert_eq!(Path::new("/home/user/.config/app_name"), xdg.app_config()?);
+        assert_eq!(
+            XdgError::InvalidAppName { name: String::from("..") },
+            XdgApp::new_strict("..").unwrap_err(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn usr_base_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user1");
+        env::set_var("HOME", "/home/user1");
+
+        let xdg = XdgApp::new("app_name")?;
+
+        assert_eq!(Path::new("/home/user1"), xdg.home());
+        assert_eq!(Path::new("/home/user1/.cache"), xdg.cache()?);
+        assert_eq!(Path::new("/home/user1/.config"), xdg.config()?);
+        assert_eq!(Path::new("/home/user1/.local/share"), xdg.data()?);
+        assert_eq!(Path::new("/home/user1/.local/state"), xdg.state()?);
+        assert_eq!(Path::new("/home/user1/.local/bin"), xdg.bin()?);
+        assert_eq!(None, xdg.runtime()?);
+
+        env::set_var("XDG_CACHE_HOME", "/home/user2/.cache");
+        env::set_var("XDG_CONFIG_HOME", "/home/user2/.config");
+        env::set_var("XDG_DATA_HOME", "/home/user2/.local/share");
+        env::set_var("XDG_STATE_HOME", "/home/user2/.local/state");
+        env::set_var("XDG_BIN_HOME", "/home/user2/.local/bin");
+        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
+        assert_eq!(Path::new("/home/user2/.cache"), xdg.cache()?);
+        assert_eq!(Path::new("/home/user2/.config"), xdg.config()?);
+        assert_eq!(Path::new("/home/user2/.local/share"), xdg.data()?);
+        assert_eq!(Path::new("/home/user2/.local/state"), xdg.state()?);
+        assert_eq!(Path::new("/home/user2/.local/bin"), xdg.bin()?);
+        assert_eq!(Some(PathBuf::from("/run/user/1000")), xdg.runtime()?);
+
+        env::set_var("XDG_CACHE_HOME", "");
+        env::set_var("XDG_CONFIG_HOME", "");
+        env::set_var("XDG_DATA_HOME", "");
+        env::set_var("XDG_STATE_HOME", "");
+        env::set_var("XDG_BIN_HOME", "");
+        env::set_var("XDG_RUNTIME_DIR", "");
+        assert_eq!(Path::new("/home/user1/.cache"), xdg.cache()?);
+        assert_eq!(Path::new("/home/user1/.config"), xdg.config()?);
+        assert_eq!(Path::new("/home/user1/.local/share"), xdg.data()?);
+        assert_eq!(Path::new("/home/user1/.local/bin"), xdg.bin()?);
+        assert_eq!(Path::new("/home/user1/.local/state"), xdg.state()?);
+        assert_eq!(None, xdg.runtime()?);
+
+        env::set_var("XDG_CACHE_HOME", "./cache");
+        env::set_var("XDG_CONFIG_HOME", "./config");
+        env::set_var("XDG_DATA_HOME", "./data");
+        env::set_var("XDG_STATE_HOME", "./state");
+        env::set_var("XDG_BIN_HOME", "./bin");
+        env::set_var("XDG_RUNTIME_DIR", "./runtime");
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_CACHE_HOME",
+                path: PathBuf::from("./cache"),
+            }),
+            xdg.cache(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_CONFIG_HOME",
+                path: PathBuf::from("./config"),
+            }),
+            xdg.config(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_DATA_HOME",
+                path: PathBuf::from("./data"),
+            }),
+            xdg.data(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_STATE_HOME",
+                path: PathBuf::from("./state"),
+            }),
+            xdg.state(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_BIN_HOME",
+                path: PathBuf::from("./bin"),
+            }),
+            xdg.bin(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_RUNTIME_DIR",
+                path: PathBuf::from("./runtime"),
+            }),
+            xdg.runtime(),
+        );
+
+        let invalid_unicode = OsStr::from_bytes(&INVALID_UNICODE_BYTES);
+        env::set_var("XDG_CACHE_HOME", invalid_unicode);
+        env::set_var("XDG_CONFIG_HOME", invalid_unicode);
+        env::set_var("XDG_DATA_HOME", invalid_unicode);
+        env::set_var("XDG_STATE_HOME", invalid_unicode);
+        env::set_var("XDG_BIN_HOME", invalid_unicode);
+        env::set_var("XDG_RUNTIME_DIR", invalid_unicode);
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_CACHE_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.cache(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_CONFIG_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.config(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_DATA_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.data(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_STATE_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.state(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_BIN_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.bin(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_RUNTIME_DIR",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.runtime(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn sys_base_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+
+        assert_eq!(
+            vec![PathBuf::from("/etc/xdg")],
+            XdgApp::sys_config()?,
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/usr/local/share"),
+                PathBuf::from("/usr/share")
+            ],
+            XdgApp::sys_data()?,
+        );
+
+        env::set_var(
+            "XDG_CONFIG_DIRS",
+            "/config/dir1:/config/dir2:/config/dir3:/config/dir4",
+        );
+        env::set_var(
+            "XDG_DATA_DIRS",
+            "/data/dir1:/data/dir2:/data/dir3:/data/dir4",
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/config/dir1"),
+                PathBuf::from("/config/dir2"),
+                PathBuf::from("/config/dir3"),
+                PathBuf::from("/config/dir4"),
+            ],
+            XdgApp::sys_config()?,
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/data/dir1"),
+                PathBuf::from("/data/dir2"),
+                PathBuf::from("/data/dir3"),
+                PathBuf::from("/data/dir4"),
+            ],
+            XdgApp::sys_data()?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn usr_file() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+
+        let xdg = XdgApp::new("app_name")?;
+
+        assert_eq!(
+            Path::new("/home/user/.cache/file"),
+            xdg.cache_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.config/file"),
+            xdg.config_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.local/share/file"),
+            xdg.data_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.local/state/file"),
+            xdg.state_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.local/bin/file"),
+            xdg.bin_file("file")?,
+        );
+
+        env::set_var("XDG_CACHE_HOME", "/home/user1/.cache");
+        env::set_var("XDG_CONFIG_HOME", "/home/user1/.config");
+        env::set_var("XDG_DATA_HOME", "/home/user1/.local/share");
+        env::set_var("XDG_STATE_HOME", "/home/user1/.local/state");
+        env::set_var("XDG_BIN_HOME", "/home/user1/.local/bin");
+
+        assert_eq!(
+            Path::new("/home/user1/.cache/file"),
+            xdg.cache_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.config/file"),
+            xdg.config_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/share/file"),
+            xdg.data_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/state/file"),
+            xdg.state_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/bin/file"),
+            xdg.bin_file("file")?,
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let cache_home = tmp_dir_builder.tempdir()?;
+        let config_home = tmp_dir_builder.tempdir()?;
+        let data_home = tmp_dir_builder.tempdir()?;
+        let state_home = tmp_dir_builder.tempdir()?;
+        let bin_home = tmp_dir_builder.tempdir()?;
+
+        env::set_var("XDG_CACHE_HOME", cache_home.path());
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_STATE_HOME", state_home.path());
+        env::set_var("XDG_BIN_HOME", bin_home.path());
+
+        let mut tmp_file_builder = tempfile::Builder::new();
+        tmp_file_builder.prefix("microxdg");
+        tmp_file_builder.rand_bytes(0);
+
+        let cache_file = tmp_file_builder.tempfile_in(cache_home.path())?;
+        let config_file = tmp_file_builder.tempfile_in(config_home.path())?;
+        let data_file = tmp_file_builder.tempfile_in(data_home.path())?;
+        let state_file = tmp_file_builder.tempfile_in(state_home.path())?;
+        let bin_file = tmp_file_builder.tempfile_in(bin_home.path())?;
+
+        let xdg = XdgApp::new("app_name")?;
+
+        assert_eq!(
+            Some(cache_file.path().into()),
+            xdg.search_cache_file("microxdg")?,
+        );
+        assert_eq!(
+            Some(config_file.path().into()),
+            xdg.search_config_file("microxdg")?,
+        );
+        assert_eq!(
+            Some(data_file.path().into()),
+            xdg.search_data_file("microxdg")?,
+        );
+        assert_eq!(
+            Some(state_file.path().into()),
+            xdg.search_state_file("microxdg")?,
+        );
+        assert_eq!(
+            Some(bin_file.path().into()),
+            xdg.search_bin_file("microxdg")?,
+        );
+
+        remove_xdg_vars();
+
+        let data_dirs = tmp_dir_builder.tempdir()?;
+        let config_dirs = tmp_dir_builder.tempdir()?;
+
+        env::set_var("XDG_DATA_DIRS", data_dirs.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        let data_file = tmp_file_builder.tempfile_in(data_dirs.path())?;
+        let config_file = tmp_file_builder.tempfile_in(config_dirs.path())?;
+
+        assert_eq!(
+            Some(data_file.path().into()),
+            xdg.search_data_file("microxdg")?
+        );
+        assert_eq!(
+            Some(config_file.path().into()),
+            xdg.search_config_file("microxdg")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn app_usr_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user1");
+        env::set_var("HOME", "/home/user1");
+
+        let xdg = XdgApp::new("app_name")?;
+        assert_eq!(
+            Path::new("/home/user1/.cache/app_name"),
+            xdg.app_cache()?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.config/app_name"),
+            xdg.app_config()?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/share/app_name"),
+            xdg.app_data()?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/state/app_name"),
+            xdg.app_state()?,
+        );
+
+        env::set_var("XDG_CACHE_HOME", "/home/user2/.cache");
+        env::set_var("XDG_CONFIG_HOME", "/home/user2/.config");
+        env::set_var("XDG_DATA_HOME", "/home/user2/.local/share");
+        env::set_var("XDG_STATE_HOME", "/home/user2/.local/state");
+        assert_eq!(
+            Path::new("/home/user2/.cache/app_name"),
+            xdg.app_cache()?,
+        );
+        assert_eq!(
+            Path::new("/home/user2/.config/app_name"),
+            xdg.app_config()?,
+        );
+        assert_eq!(
+            Path::new("/home/user2/.local/share/app_name"),
+            xdg.app_data()?,
+        );
+        assert_eq!(
+            Path::new("/home/user2/.local/state/app_name"),
+            xdg.app_state()?,
+        );
+
+        env::set_var("XDG_CACHE_HOME", "");
+        env::set_var("XDG_CONFIG_HOME", "");
+        env::set_var("XDG_DATA_HOME", "");
+        env::set_var("XDG_STATE_HOME", "");
+        assert_eq!(
+            Path::new("/home/user1/.cache/app_name"),
+            xdg.app_cache()?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.config/app_name"),
+            xdg.app_config()?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/share/app_name"),
+            xdg.app_data()?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/state/app_name"),
+            xdg.app_state()?,
+        );
+
+        env::set_var("XDG_CACHE_HOME", "./app_name/cache");
+        env::set_var("XDG_CONFIG_HOME", "./app_name/config");
+        env::set_var("XDG_DATA_HOME", "./app_name/data");
+        env::set_var("XDG_STATE_HOME", "./app_name/state");
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_CACHE_HOME",
+                path: PathBuf::from("./app_name/cache"),
+            }),
+            xdg.app_cache(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_CONFIG_HOME",
+                path: PathBuf::from("./app_name/config")
+            }),
+            xdg.app_config(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_DATA_HOME",
+                path: PathBuf::from("./app_name/data")
+            }),
+            xdg.app_data(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_STATE_HOME",
+                path: PathBuf::from("./app_name/state")
+            }),
+            xdg.app_state(),
+        );
+
+        let invalid_unicode = OsStr::from_bytes(&INVALID_UNICODE_BYTES);
+        env::set_var("XDG_CACHE_HOME", invalid_unicode);
+        env::set_var("XDG_CONFIG_HOME", invalid_unicode);
+        env::set_var("XDG_DATA_HOME", invalid_unicode);
+        env::set_var("XDG_STATE_HOME", invalid_unicode);
+        env::set_var("XDG_BIN_HOME", invalid_unicode);
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_CACHE_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.app_cache(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_CONFIG_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.app_config(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_DATA_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.app_data(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_STATE_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.app_state(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn app_sys_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+
+        let xdg = XdgApp::new("app_name")?;
+
+        assert_eq!(
+            vec![PathBuf::from("/etc/xdg/app_name")],
+            xdg.app_sys_config()?,
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/usr/local/share/app_name"),
+                PathBuf::from("/usr/share/app_name"),
+            ],
+            xdg.app_sys_data()?,
+        );
+
+        env::set_var(
+            "XDG_CONFIG_DIRS",
+            "/config/dir1:/config/dir2:/config/dir3:/config/dir4",
+        );
+        env::set_var(
+            "XDG_DATA_DIRS",
+            "/data/dir1:/data/dir2:/data/dir3:/data/dir4",
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/config/dir1/app_name"),
+                PathBuf::from("/config/dir2/app_name"),
+                PathBuf::from("/config/dir3/app_name"),
+                PathBuf::from("/config/dir4/app_name"),
+            ],
+            xdg.app_sys_config()?,
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/data/dir1/app_name"),
+                PathBuf::from("/data/dir2/app_name"),
+                PathBuf::from("/data/dir3/app_name"),
+                PathBuf::from("/data/dir4/app_name"),
+            ],
+            xdg.app_sys_data()?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn app_all_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+
+        env::set_var("XDG_CONFIG_DIRS", "/config/dir1:/config/dir2");
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.config/app_name"),
+                PathBuf::from("/config/dir1/app_name"),
+                PathBuf::from("/config/dir2/app_name"),
+            ],
+            xdg.app_config_dirs_all()?.collect::<Vec<_>>(),
+        );
+
+        env::set_var("XDG_DATA_HOME", "/data/home");
+        assert_eq!(
+            vec![
+                PathBuf::from("/data/home/app_name"),
+                PathBuf::from("/usr/local/share/app_name"),
+                PathBuf::from("/usr/share/app_name"),
+            ],
+            xdg.app_data_dirs_all()?.collect::<Vec<_>>(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn app_usr_file() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+
+        let xdg = XdgApp::new("app_name")?;
+        assert_eq!(
+            Path::new("/home/user/.cache/app_name/file"),
+            xdg.app_cache_file("file")?
+        );
+        assert_eq!(
+            Path::new("/home/user/.config/app_name/file"),
+            xdg.app_config_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.local/share/app_name/file"),
+            xdg.app_data_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.local/state/app_name/file"),
+            xdg.app_state_file("file")?,
+        );
+
+        env::set_var("XDG_CACHE_HOME", "/home/user1/.cache");
+        env::set_var("XDG_CONFIG_HOME", "/home/user1/.config");
+        env::set_var("XDG_DATA_HOME", "/home/user1/.local/share");
+        env::set_var("XDG_STATE_HOME", "/home/user1/.local/state");
+        assert_eq!(
+            Path::new("/home/user1/.cache/app_name/file"),
+            xdg.app_cache_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.config/app_name/file"),
+            xdg.app_config_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/share/app_name/file"),
+            xdg.app_data_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user1/.local/state/app_name/file"),
+            xdg.app_state_file("file")?,
+        );
+
+        env::set_var("USER", "user2");
+        env::remove_var("HOME");
+
+        env::set_var("XDG_CACHE_HOME", "");
+        env::set_var("XDG_CONFIG_HOME", "");
+        env::set_var("XDG_DATA_HOME", "");
+        env::set_var("XDG_STATE_HOME", "");
+
+        let xdg = XdgApp::new("app_name")?;
+        assert_eq!(
+            Path::new("/home/user2/.cache/app_name/file"),
+            xdg.app_cache_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user2/.config/app_name/file"),
+            xdg.app_config_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user2/.local/share/app_name/file"),
+            xdg.app_data_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user2/.local/state/app_name/file"),
+            xdg.app_state_file("file")?,
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_app_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+
+        let xdg = XdgApp::new("app_name")?;
+
+        assert_eq!(None, xdg.search_app_cache_file("microxdg")?);
+        assert_eq!(None, xdg.search_app_config_file("microxdg")?);
+        assert_eq!(None, xdg.search_app_data_file("microxdg")?);
+        assert_eq!(None, xdg.search_app_state_file("microxdg")?);
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let cache_home = tmp_dir_builder.tempdir()?;
+        let app_cache_dir = cache_home.path().join("app_name");
+        fs::create_dir(&app_cache_dir)?;
+        let config_home = tmp_dir_builder.tempdir()?;
+        let app_config_dir = config_home.path().join("app_name");
+        fs::create_dir(&app_config_dir)?;
+        let data_home = tmp_dir_builder.tempdir()?;
+        let app_data_dir = data_home.path().join("app_name");
+        fs::create_dir(&app_data_dir)?;
+        let state_home = tmp_dir_builder.tempdir()?;
+        let app_state_dir = state_home.path().join("app_name");
+        fs::create_dir(&app_state_dir)?;
+
+        env::set_var("XDG_CACHE_HOME", cache_home.path());
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_STATE_HOME", state_home.path());
+
+        let mut tmp_file_builder = tempfile::Builder::new();
+        tmp_file_builder.prefix("microxdg");
+        tmp_file_builder.rand_bytes(0);
+
+        let cache_file = tmp_file_builder.tempfile_in(app_cache_dir)?;
+        let config_file = tmp_file_builder.tempfile_in(app_config_dir)?;
+        let data_file = tmp_file_builder.tempfile_in(app_data_dir)?;
+        let state_file = tmp_file_builder.tempfile_in(app_state_dir)?;
+
+        assert_eq!(
+            Some(cache_file.path().into()),
+            xdg.search_app_cache_file("microxdg")?,
+        );
+        assert_eq!(
+            Some(config_file.path().into()),
+            xdg.search_app_config_file("microxdg")?,
+        );
+        assert_eq!(
+            Some(data_file.path().into()),
+            xdg.search_app_data_file("microxdg")?,
+        );
+        assert_eq!(
+            Some(state_file.path().into()),
+            xdg.search_app_state_file("microxdg")?,
+        );
+
+        env::remove_var("XDG_CACHE_HOME");
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_STATE_HOME");
+
+        let data_dirs = tmp_dir_builder.tempdir()?;
+        let app_data_dirs = data_dirs.path().join("app_name");
+        fs::create_dir(&app_data_dirs)?;
+        let config_dirs = tmp_dir_builder.tempdir()?;
+        let app_config_dirs = config_dirs.path().join("app_name");
+        fs::create_dir(&app_config_dirs)?;
+
+        env::set_var("XDG_DATA_DIRS", &app_data_dirs);
+        env::set_var("XDG_CONFIG_DIRS", &app_config_dirs);
+
+        let data_file = tmp_file_builder.tempfile_in(app_data_dirs)?;
+        let config_file = tmp_file_builder.tempfile_in(app_config_dirs)?;
+
+        assert_eq!(
+            Some(data_file.path().into()),
+            xdg.search_data_file("microxdg")?
+        );
+        assert_eq!(
+            Some(config_file.path().into()),
+            xdg.search_config_file("microxdg")?
         );
 
-        env::remove_var("HOME");
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_app_files() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+
+        assert!(xdg.search_app_config_files("microxdg")?.is_empty());
+        assert!(xdg.search_app_data_files("microxdg")?.is_empty());
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let data_home = tmp_dir_builder.tempdir()?;
+        let app_data_home = data_home.path().join("app_name");
+        fs::create_dir(&app_data_home)?;
+        let data_dir1 = tmp_dir_builder.tempdir()?;
+        let data_dir2 = tmp_dir_builder.tempdir()?;
+        let app_data_dir2 = data_dir2.path().join("app_name");
+        fs::create_dir(&app_data_dir2)?;
+
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_DATA_DIRS", env::join_paths([data_dir1.path(), data_dir2.path()])?);
+
+        let mut tmp_file_builder = tempfile::Builder::new();
+        tmp_file_builder.prefix("microxdg");
+        tmp_file_builder.rand_bytes(0);
 
+        let data_file = tmp_file_builder.tempfile_in(&app_data_home)?;
+        let data_file2 = tmp_file_builder.tempfile_in(&app_data_dir2)?;
         assert_eq!(
-            Path::new("/home/user2"),
-            XdgApp::new("app_name")?.home(),
+            vec![data_file.path().to_path_buf(), data_file2.path().to_path_buf()],
+            xdg.search_app_data_files("microxdg")?,
         );
+        assert!(xdg.search_data_files("microxdg")?.is_empty());
         assert_eq!(
-            Path::new("/home/user2"),
-            XdgApp::from_xdg(Xdg::new()?, "app_name").home(),
+            vec![data_file.path().to_path_buf()],
+            xdg.search_app_data_files_bounded("microxdg", 1)?,
         );
+        assert!(xdg.search_app_data_files_bounded("microxdg", 0)?.is_empty());
+        assert_eq!(2, xdg.search_app_data_files_bounded("microxdg", 3)?.len());
 
-        env::remove_var("USER");
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_app_file_with_ext() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let config_home = tmp_dir_builder.tempdir()?;
+        let app_config_home = config_home.path().join("app_name");
+        fs::create_dir(&app_config_home)?;
+        let config_dirs = tmp_dir_builder.tempdir()?;
+        let app_config_dirs = config_dirs.path().join("app_name");
+        fs::create_dir(&app_config_dirs)?;
+
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        assert_eq!(None, xdg.search_app_config_file_with_ext("config", &["toml", "yml"])?);
 
+        fs::write(app_config_dirs.join("config.toml"), "")?;
         assert_eq!(
-            XdgError::HomeNotFound,
-            XdgApp::new("app_name").unwrap_err(),
+            Some(app_config_dirs.join("config.toml")),
+            xdg.search_app_config_file_with_ext("config", &["toml", "yml"])?,
+        );
+
+        fs::write(app_config_home.join("config.yml"), "")?;
+        assert_eq!(
+            Some(app_config_home.join("config.yml")),
+            xdg.search_app_config_file_with_ext("config", &["toml", "yml"])?,
+        );
+
+        fs::write(app_config_home.join("config.toml"), "")?;
+        assert_eq!(
+            Some(app_config_home.join("config.toml")),
+            xdg.search_app_config_file_with_ext("config", &["toml", "yml"])?,
+        );
+        assert_eq!(
+            Some(app_config_home.join("config.yml")),
+            xdg.search_app_config_file_with_ext("config", &["yml", "toml"])?,
         );
 
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
-    fn usr_base_dirs() -> Result<(), XdgError> {
+    fn search_app_file_recursive() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user1");
-        env::set_var("HOME", "/home/user1");
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
 
-        let xdg = XdgApp::new("app_name")?;
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
 
-        assert_eq!(Path::new("/home/user1"), xdg.home());
-        assert_eq!(Path::new("/home/user1/.cache"), xdg.cache()?);
-        assert_eq!(Path::new("/home/user1/.config"), xdg.config()?);
-        assert_eq!(Path::new("/home/user1/.local/share"), xdg.data()?);
-        assert_eq!(Path::new("/home/user1/.local/state"), xdg.state()?);
-        assert_eq!(Path::new("/home/user1/.local/bin"), xdg.bin()?);
-        assert_eq!(None, xdg.runtime()?);
+        let config_home = tmp_dir_builder.tempdir()?;
+        let app_config_home = config_home.path().join("app_name");
+        fs::create_dir_all(app_config_home.join("conf.d/include"))?;
+        fs::create_dir_all(app_config_home.join("b.d"))?;
+        let config_dirs = tmp_dir_builder.tempdir()?;
+        let app_config_dirs = config_dirs.path().join("app_name");
+        fs::create_dir(&app_config_dirs)?;
 
-        env::set_var("XDG_CACHE_HOME", "/home/user2/.cache");
-        env::set_var("XDG_CONFIG_HOME", "/home/user2/.config");
-        env::set_var("XDG_DATA_HOME", "/home/user2/.local/share");
-        env::set_var("XDG_STATE_HOME", "/home/user2/.local/state");
-        env::set_var("XDG_BIN_HOME", "/home/user2/.local/bin");
-        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
-        assert_eq!(Path::new("/home/user2/.cache"), xdg.cache()?);
-        assert_eq!(Path::new("/home/user2/.config"), xdg.config()?);
-        assert_eq!(Path::new("/home/user2/.local/share"), xdg.data()?);
-        assert_eq!(Path::new("/home/user2/.local/state"), xdg.state()?);
-        assert_eq!(Path::new("/home/user2/.local/bin"), xdg.bin()?);
-        assert_eq!(Some(PathBuf::from("/run/user/1000")), xdg.runtime()?);
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
 
-        env::set_var("XDG_CACHE_HOME", "");
-        env::set_var("XDG_CONFIG_HOME", "");
-        env::set_var("XDG_DATA_HOME", "");
-        env::set_var("XDG_STATE_HOME", "");
-        env::set_var("XDG_BIN_HOME", "");
-        env::set_var("XDG_RUNTIME_DIR", "");
-        assert_eq!(Path::new("/home/user1/.cache"), xdg.cache()?);
-        assert_eq!(Path::new("/home/user1/.config"), xdg.config()?);
-        assert_eq!(Path::new("/home/user1/.local/share"), xdg.data()?);
-        assert_eq!(Path::new("/home/user1/.local/bin"), xdg.bin()?);
-        assert_eq!(Path::new("/home/user1/.local/state"), xdg.state()?);
-        assert_eq!(None, xdg.runtime()?);
+        assert_eq!(None, xdg.search_app_config_file_recursive("file", 2)?);
 
-        env::set_var("XDG_CACHE_HOME", "./cache");
-        env::set_var("XDG_CONFIG_HOME", "./config");
-        env::set_var("XDG_DATA_HOME", "./data");
-        env::set_var("XDG_STATE_HOME", "./state");
-        env::set_var("XDG_BIN_HOME", "./bin");
-        env::set_var("XDG_RUNTIME_DIR", "./runtime");
+        fs::write(app_config_home.join("conf.d/include/file"), "")?;
+        assert_eq!(None, xdg.search_app_config_file_recursive("file", 1)?);
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_CACHE_HOME",
-                path: PathBuf::from("./cache"),
+            Some(app_config_home.join("conf.d/include/file")),
+            xdg.search_app_config_file_recursive("file", 2)?,
+        );
+
+        fs::write(app_config_home.join("conf.d/file"), "")?;
+        fs::write(app_config_home.join("b.d/file"), "")?;
+        assert_eq!(
+            Some(app_config_home.join("b.d/file")),
+            xdg.search_app_config_file_recursive("file", 2)?,
+        );
+
+        fs::write(app_config_dirs.join("file"), "")?;
+        assert_eq!(
+            Some(app_config_dirs.join("file")),
+            xdg.search_app_config_file_recursive("file", 0)?,
+        );
+        assert_eq!(
+            Some(app_config_home.join("b.d/file")),
+            xdg.search_app_config_file_recursive("file", 1)?,
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn list_app_files() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let config_home = tmp_dir_builder.tempdir()?;
+        let app_config_home = config_home.path().join("app_name");
+        fs::create_dir_all(app_config_home.join("conf.d"))?;
+        let config_dirs = tmp_dir_builder.tempdir()?;
+        let app_config_dirs = config_dirs.path().join("app_name");
+        fs::create_dir_all(app_config_dirs.join("conf.d"))?;
+
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        assert!(xdg.list_app_config_files()?.is_empty());
+
+        fs::write(app_config_dirs.join("config"), "")?;
+        fs::write(app_config_dirs.join("conf.d/10-default"), "")?;
+        fs::write(app_config_dirs.join("conf.d/20-extra"), "")?;
+        fs::write(app_config_home.join("conf.d/10-default"), "")?;
+        fs::write(app_config_home.join("conf.d/30-user"), "")?;
+
+        assert_eq!(
+            BTreeMap::from([
+                (PathBuf::from("conf.d/10-default"), app_config_home.join("conf.d/10-default")),
+                (PathBuf::from("conf.d/20-extra"), app_config_dirs.join("conf.d/20-extra")),
+                (PathBuf::from("conf.d/30-user"), app_config_home.join("conf.d/30-user")),
+                (PathBuf::from("config"), app_config_dirs.join("config")),
+            ]),
+            xdg.list_app_config_files()?,
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn app_data_assets() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let user_themes = data_home.path().join("app_name/themes");
+        let sys_themes = data_dirs.path().join("app_name/themes");
+        fs::create_dir_all(user_themes.join("dark"))?;
+        fs::create_dir_all(sys_themes.join("dark"))?;
+        fs::create_dir_all(sys_themes.join("light"))?;
+        fs::write(sys_themes.join("contrast.toml"), "")?;
+        fs::write(sys_themes.join(".hidden"), "")?;
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_DATA_DIRS", data_dirs.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(
+            BTreeMap::from([
+                (String::from("contrast.toml"), sys_themes.join("contrast.toml")),
+                (String::from("dark"), user_themes.join("dark")),
+                (String::from("light"), sys_themes.join("light")),
+            ]),
+            xdg.app_data_assets("themes")?,
+        );
+        assert_eq!(
+            BTreeMap::from([
+                (String::from("contrast.toml"), vec![sys_themes.join("contrast.toml")]),
+                (String::from("dark"), vec![user_themes.join("dark"), sys_themes.join("dark")]),
+                (String::from("light"), vec![sys_themes.join("light")]),
+            ]),
+            xdg.app_data_asset_layers("themes")?,
+        );
+        assert!(xdg.app_data_assets("missing")?.is_empty());
+
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_DATA_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn read_app_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let state_home = tmp_dir_builder.tempdir()?;
+        let app_state_home = state_home.path().join("app_name");
+        fs::create_dir(&app_state_home)?;
+
+        env::set_var("XDG_STATE_HOME", state_home.path());
+
+        assert_eq!(
+            Err(XdgError::FileNotFound {
+                file: PathBuf::from("file"),
+                searched: vec![app_state_home.clone()],
             }),
-            xdg.cache(),
+            xdg.read_app_state_file("file"),
         );
+
+        fs::write(app_state_home.join("file"), "state")?;
+        assert_eq!(b"state".as_slice(), xdg.read_app_state_file("file")?);
+        assert_eq!("state", xdg.read_app_state_file_to_string("file")?);
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_CONFIG_HOME",
-                path: PathBuf::from("./config"),
+            Err(XdgError::FileNotFound {
+                file: PathBuf::from("file"),
+                searched: vec![state_home.path().to_path_buf()],
             }),
-            xdg.config(),
+            xdg.read_state_file("file"),
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn open_create_app_file() -> Result<(), Box<dyn Error>> {
+        use std::io::{Read, Write};
+        use std::os::unix::fs::PermissionsExt;
+
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let state_home = tmp_dir_builder.tempdir()?;
+        env::set_var("XDG_STATE_HOME", state_home.path());
+
+        assert_eq!(
+            XdgError::FileNotFound {
+                file: PathBuf::from("file"),
+                searched: vec![state_home.path().join("app_name")],
+            },
+            xdg.open_app_state_file("file").unwrap_err(),
+        );
+
+        xdg.create_app_state_file("file")?.write_all(b"state")?;
+
+        let app_state_home = state_home.path().join("app_name");
+        assert_eq!(0o700, fs::metadata(&app_state_home)?.permissions().mode() & 0o777);
+        assert_eq!(
+            0o600,
+            fs::metadata(app_state_home.join("file"))?.permissions().mode() & 0o777,
+        );
+
+        let mut content = String::new();
+        xdg.open_app_state_file("file")?.read_to_string(&mut content)?;
+        assert_eq!("state", content);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn promote_config_file() -> Result<(), Box<dyn Error>> {
+        use std::os::unix::fs::PermissionsExt;
+
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let config_home = tmp_dir_builder.tempdir()?;
+        let config_dirs = tmp_dir_builder.tempdir()?;
+        let app_config_dirs = config_dirs.path().join("app_name");
+        fs::create_dir(&app_config_dirs)?;
+
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        assert_eq!(
+            XdgError::FileNotFound {
+                file: PathBuf::from("file"),
+                searched: vec![config_home.path().join("app_name"), app_config_dirs.clone()],
+            },
+            xdg.promote_config_file("file").unwrap_err(),
+        );
+
+        let sys_file = app_config_dirs.join("file");
+        fs::write(&sys_file, "default")?;
+        fs::set_permissions(&sys_file, fs::Permissions::from_mode(0o444))?;
+
+        let app_config_file = config_home.path().join("app_name/file");
+        assert_eq!(app_config_file, xdg.promote_config_file("file")?);
+        assert_eq!("default", fs::read_to_string(&app_config_file)?);
+        assert_eq!(0o644, fs::metadata(&app_config_file)?.permissions().mode() & 0o777);
+
+        fs::write(&app_config_file, "user")?;
+        assert_eq!(app_config_file, xdg.promote_config_file("file")?);
+        assert_eq!("user", fs::read_to_string(&app_config_file)?);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn ensure_app_dirs() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let home = tmp_dir_builder.tempdir()?;
+        let xdg = XdgApp::with_home(home.path(), "app_name")?;
+
+        let app_dirs = xdg.ensure_app_dirs()?;
+        assert_eq!(
+            XdgAppDirs {
+                cache: home.path().join(".cache/app_name"),
+                config: home.path().join(".config/app_name"),
+                data: home.path().join(".local/share/app_name"),
+                state: home.path().join(".local/state/app_name"),
+                runtime: None,
+            },
+            app_dirs,
         );
+        assert!(app_dirs.iter().all(|path| path.is_dir()));
+
+        let runtime_dir = tmp_dir_builder.tempdir()?;
+        env::set_var("XDG_RUNTIME_DIR", runtime_dir.path());
+
+        let app_dirs = xdg.ensure_app_dirs()?;
+        assert_eq!(Some(runtime_dir.path().join("app_name")), app_dirs.runtime);
+        assert_eq!(5, app_dirs.iter().filter(|path| path.is_dir()).count());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn remove_app_dirs() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let home = tmp_dir_builder.tempdir()?;
+        let xdg = XdgApp::with_home(home.path(), "app_name")?;
+        let app_dirs = xdg.ensure_app_dirs()?;
+        fs::write(app_dirs.cache.join("file"), "cached")?;
+
+        let all = RemoveOptions::new().config(true).data(true);
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_DATA_HOME",
-                path: PathBuf::from("./data"),
-            }),
-            xdg.data(),
+            vec![
+                app_dirs.cache.clone(),
+                app_dirs.config.clone(),
+                app_dirs.data.clone(),
+                app_dirs.state.clone(),
+            ],
+            xdg.remove_app_dirs(all.dry_run(true))?,
         );
+        assert!(app_dirs.iter().all(|path| path.is_dir()));
+
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_STATE_HOME",
-                path: PathBuf::from("./state"),
-            }),
-            xdg.state(),
+            vec![app_dirs.cache.clone(), app_dirs.state.clone()],
+            xdg.remove_app_dirs(RemoveOptions::default())?,
         );
+        assert!(!app_dirs.cache.exists());
+        assert!(!app_dirs.state.exists());
+        assert!(app_dirs.config.is_dir());
+        assert!(app_dirs.data.is_dir());
+
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_BIN_HOME",
-                path: PathBuf::from("./bin"),
-            }),
-            xdg.bin(),
+            vec![app_dirs.config.clone(), app_dirs.data.clone()],
+            xdg.remove_app_dirs(all)?,
         );
+        assert!(app_dirs.iter().all(|path| !path.exists()));
+        assert!(xdg.remove_app_dirs(all)?.is_empty());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn prune_app_cache() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let home = tmp_dir_builder.tempdir()?;
+        let xdg = XdgApp::with_home(home.path(), "app_name")?;
+        let app_cache = xdg.app_cache()?;
+        assert!(xdg.prune_app_cache(PruneOptions::new().max_total_size(0))?.is_empty());
+
+        fs::create_dir_all(app_cache.join("subdir"))?;
+        let now = SystemTime::now();
+        let files = [("old", 400), ("subdir/older_than_new", 300), ("new", 200), ("newest", 100)];
+        for (file, age) in files {
+            let file = File::create(app_cache.join(file))?;
+            file.set_len(10)?;
+            file.set_modified(now - std::time::Duration::from_secs(age))?;
+        }
+
+        assert!(xdg.prune_app_cache(PruneOptions::new())?.is_empty());
+        assert!(xdg.prune_app_cache(PruneOptions::new().max_total_size(40))?.is_empty());
+
+        let max_age = std::time::Duration::from_secs(350);
+        let expired = xdg.prune_app_cache(PruneOptions::new().max_age(max_age))?;
+        assert_eq!(vec![app_cache.join("old")], expired);
+
+        let evicted = xdg.prune_app_cache(PruneOptions::new().max_total_size(15))?;
+        assert_eq!(vec![app_cache.join("subdir/older_than_new"), app_cache.join("new")], evicted);
+        assert!(app_cache.join("newest").is_file());
+        assert!(app_cache.join("subdir").is_dir());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn app_runtime_socket() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(Err(XdgError::RuntimeDirNotSet), xdg.app_runtime_socket("control.sock"));
+
+        env::set_var("XDG_RUNTIME_DIR", "./run/user/1000");
         assert_eq!(
             Err(XdgError::RelativePath {
                 env_var_key: "XDG_RUNTIME_DIR",
-                path: PathBuf::from("./runtime"),
+                path: PathBuf::from("./run/user/1000"),
             }),
-            xdg.runtime(),
+            xdg.app_runtime_socket("control.sock"),
         );
 
-        let invalid_unicode = OsStr::from_bytes(&INVALID_UNICODE_BYTES);
-        env::set_var("XDG_CACHE_HOME", invalid_unicode);
-        env::set_var("XDG_CONFIG_HOME", invalid_unicode);
-        env::set_var("XDG_DATA_HOME", invalid_unicode);
-        env::set_var("XDG_STATE_HOME", invalid_unicode);
-        env::set_var("XDG_BIN_HOME", invalid_unicode);
-        env::set_var("XDG_RUNTIME_DIR", invalid_unicode);
+        let runtime_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_RUNTIME_DIR", runtime_dir.path());
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_CACHE_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.cache(),
+            runtime_dir.path().join("app_name/control.sock"),
+            xdg.app_runtime_socket("control.sock")?,
         );
+        assert!(runtime_dir.path().join("app_name").is_dir());
+        assert!(!runtime_dir.path().join("app_name/control.sock").exists());
+
+        let socket = "s".repeat(120);
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_CONFIG_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
+            Err(XdgError::SocketPathTooLong {
+                path: runtime_dir.path().join("app_name").join(&socket),
+                max_len: if cfg!(target_os = "linux") { 107 } else { 103 },
             }),
-            xdg.config(),
+            xdg.app_runtime_socket(&socket),
         );
+
+        // Sockets never escape the app subdirectory.
+        for socket in ["/tmp/x.sock", "../x.sock", "sub/x.sock", "..", ""] {
+            assert_eq!(
+                Err(XdgError::InvalidAppName { name: socket.to_owned() }),
+                xdg.app_runtime_socket(socket),
+            );
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let metadata = fs::metadata(runtime_dir.path().join("app_name"))?;
+            assert_eq!(0o700, metadata.permissions().mode() & 0o777);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn app_sys_dirs_lossy() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(vec![PathBuf::from("/etc/xdg/app_name")], xdg.app_sys_config_lossy());
+
+        env::set_var("XDG_CONFIG_DIRS", "/etc/xdg:./relative::/opt/xdg");
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_DATA_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.data(),
+            vec![PathBuf::from("/etc/xdg/app_name"), PathBuf::from("/opt/xdg/app_name")],
+            xdg.app_sys_config_lossy(),
         );
+
+        let invalid_unicode = [b"relative:/usr/".as_slice(), &INVALID_UNICODE_BYTES].concat();
+        env::set_var("XDG_DATA_DIRS", OsStr::from_bytes(&invalid_unicode));
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_STATE_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.state(),
+            vec![Path::new(OsStr::from_bytes(&invalid_unicode[9..])).join("app_name")],
+            xdg.app_sys_data_lossy(),
         );
+        assert!(xdg.app_sys_data().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn app_thumbnail_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_BIN_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.bin(),
+            PathBuf::from("/home/user/.cache/thumbnails/normal"),
+            xdg.thumbnail_dir(ThumbnailSize::Normal)?,
         );
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_RUNTIME_DIR",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.runtime(),
+            PathBuf::from("/home/user/.cache/thumbnails/fail/app_name"),
+            xdg.app_thumbnail_fail_dir()?,
         );
 
         Ok(())
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn sys_base_dirs() -> Result<(), XdgError> {
+    fn autostart_dirs() -> Result<(), XdgError> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user");
-        env::set_var("HOME", "/home/user");
+        let xdg = XdgApp::with_home("/home/user", "app_name")?.with_profile("work")?;
+        assert_eq!(PathBuf::from("/home/user/.config/autostart"), xdg.autostart()?);
+        assert_eq!(vec![PathBuf::from("/etc/xdg/autostart")], xdg.sys_autostart()?);
+        assert_eq!(
+            PathBuf::from("/home/user/.config/autostart/app_name.desktop"),
+            xdg.autostart_entry()?,
+        );
 
+        env::set_var("XDG_CONFIG_HOME", "/config");
+        env::set_var("XDG_CONFIG_DIRS", "/etc/xdg:/opt/xdg");
         assert_eq!(
-            vec![PathBuf::from("/etc/xdg")],
-            XdgApp::sys_config()?,
+            PathBuf::from("/config/autostart/app_name.desktop"),
+            xdg.autostart_entry()?,
         );
         assert_eq!(
-            vec![
-                PathBuf::from("/usr/local/share"),
-                PathBuf::from("/usr/share")
-            ],
-            XdgApp::sys_data()?,
+            vec![PathBuf::from("/etc/xdg/autostart"), PathBuf::from("/opt/xdg/autostart")],
+            xdg.sys_autostart()?,
         );
 
-        env::set_var(
-            "XDG_CONFIG_DIRS",
-            "/config/dir1:/config/dir2:/config/dir3:/config/dir4",
-        );
-        env::set_var(
-            "XDG_DATA_DIRS",
-            "/data/dir1:/data/dir2:/data/dir3:/data/dir4",
-        );
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn install_dbus_service() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
         assert_eq!(
             vec![
-                PathBuf::from("/config/dir1"),
-                PathBuf::from("/config/dir2"),
-                PathBuf::from("/config/dir3"),
-                PathBuf::from("/config/dir4"),
+                PathBuf::from("/home/user/.local/share/dbus-1/services"),
+                PathBuf::from("/usr/local/share/dbus-1/services"),
+                PathBuf::from("/usr/share/dbus-1/services"),
             ],
-            XdgApp::sys_config()?,
+            xdg.dbus_services_dirs()?,
         );
         assert_eq!(
             vec![
-                PathBuf::from("/data/dir1"),
-                PathBuf::from("/data/dir2"),
-                PathBuf::from("/data/dir3"),
-                PathBuf::from("/data/dir4"),
+                PathBuf::from("/usr/local/share/dbus-1/system-services"),
+                PathBuf::from("/usr/share/dbus-1/system-services"),
             ],
-            XdgApp::sys_data()?,
+            xdg.dbus_system_services_dirs()?,
+        );
+
+        let data_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_DATA_HOME", data_dir.path());
+
+        let path = xdg.install_dbus_service("org.example.App", "/usr/bin/app")?;
+        assert_eq!(data_dir.path().join("dbus-1/services/org.example.App.service"), path);
+        assert_eq!(
+            "[D-BUS Service]\nName=org.example.App\nExec=/usr/bin/app\n",
+            fs::read_to_string(path)?,
+        );
+
+        // Service files never escape the services directory, nor get extra
+        // keys injected.
+        let invalid_input = |err: XdgError| io::Error::from(err).kind() == io::ErrorKind::InvalidInput;
+        for bus_name in ["../../x", "org", "org..App", "org.1App", "org.App/x", ".org.App"] {
+            assert!(invalid_input(xdg.install_dbus_service(bus_name, "app").unwrap_err()));
+        }
+        let bus_name = format!("org.{}", "a".repeat(252));
+        assert!(invalid_input(xdg.install_dbus_service(&bus_name, "app").unwrap_err()));
+        let exec = "app\nUser=root";
+        assert!(invalid_input(xdg.install_dbus_service("org.example.App", exec).unwrap_err()));
+        assert_eq!(
+            "[D-BUS Service]\nName=org.example.App\nExec=/usr/bin/app\n",
+            fs::read_to_string(data_dir.path().join("dbus-1/services/org.example.App.service"))?,
         );
+        assert!(xdg.install_dbus_service("org.example_App-2.x1", "/usr/bin/app").is_ok());
 
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
-    #[rustfmt::skip]
-    fn usr_file() -> Result<(), XdgError> {
+    fn install_autostart() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user");
-        env::set_var("HOME", "/home/user");
+        let config_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_CONFIG_HOME", config_dir.path());
 
-        let xdg = XdgApp::new("app_name")?;
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert!(!xdg.remove_autostart()?);
 
+        let entry = AutostartEntry {
+            name: String::from("App"),
+            exec: String::from("app --minimized"),
+            ..Default::default()
+        };
+        let path = xdg.install_autostart(&entry)?;
+        assert_eq!(config_dir.path().join("autostart/app_name.desktop"), path);
         assert_eq!(
-            Path::new("/home/user/.cache/file"),
-            xdg.cache_file("file")?,
+            "[Desktop Entry]\nType=Application\nName=App\nExec=app --minimized\nTerminal=false\n",
+            fs::read_to_string(&path)?,
         );
+
+        assert!(xdg.remove_autostart()?);
+        assert!(!path.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn app_subpaths() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
         assert_eq!(
-            Path::new("/home/user/.config/file"),
-            xdg.config_file("file")?,
+            Path::new("/home/user/.cache/app_name/a/b/c"),
+            xdg.app_cache_path(["a", "b", "c"])?,
         );
         assert_eq!(
-            Path::new("/home/user/.local/share/file"),
-            xdg.data_file("file")?,
+            Path::new("/home/user/.local/share/app_name/a/b"),
+            xdg.app_data_path(vec![String::from("a"), String::from("b")])?,
         );
+        assert_eq!(Path::new("/home/user/.local/state/app_name"), xdg.app_state_path([""; 0])?);
+
+        env::set_var("XDG_CONFIG_HOME", "/config");
+        let xdg = xdg.with_profile("work")?;
         assert_eq!(
-            Path::new("/home/user/.local/state/file"),
-            xdg.state_file("file")?,
+            Path::new("/config/app_name/work/themes/dark/colors.toml"),
+            xdg.app_config_path([Path::new("themes"), Path::new("dark/colors.toml")])?,
         );
+
+        env::set_var("XDG_CONFIG_HOME", "config");
         assert_eq!(
-            Path::new("/home/user/.local/bin/file"),
-            xdg.bin_file("file")?,
+            XdgError::RelativePath {
+                env_var_key: "XDG_CONFIG_HOME",
+                path: PathBuf::from("config"),
+            },
+            xdg.app_config_path(["file"]).unwrap_err(),
         );
 
-        env::set_var("XDG_CACHE_HOME", "/home/user1/.cache");
-        env::set_var("XDG_CONFIG_HOME", "/home/user1/.config");
-        env::set_var("XDG_DATA_HOME", "/home/user1/.local/share");
-        env::set_var("XDG_STATE_HOME", "/home/user1/.local/state");
-        env::set_var("XDG_BIN_HOME", "/home/user1/.local/bin");
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn app_aliases() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let config_home = tmp_dir_builder.tempdir()?;
+        let config_dir = tmp_dir_builder.tempdir()?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dir.path());
 
+        let xdg = XdgApp::with_home("/home/user", "new_name")?;
         assert_eq!(
-            Path::new("/home/user1/.cache/file"),
-            xdg.cache_file("file")?,
+            XdgError::InvalidAppName { name: String::from("../invalid") },
+            xdg.clone().with_aliases(["old_name", "../invalid"]).unwrap_err(),
         );
+
+        let xdg = xdg.with_aliases(["old_name", "older_name"])?;
+        assert_eq!([Arc::from("old_name"), Arc::from("older_name")], xdg.aliases());
+        assert_eq!(None, xdg.search_app_config_file("config")?);
+
+        fs::create_dir_all(config_dir.path().join("new_name"))?;
+        fs::write(config_dir.path().join("new_name/config"), "")?;
+        fs::create_dir_all(config_home.path().join("older_name"))?;
+        fs::write(config_home.path().join("older_name/config"), "")?;
         assert_eq!(
-            Path::new("/home/user1/.config/file"),
-            xdg.config_file("file")?,
+            Some(config_home.path().join("older_name/config")),
+            xdg.search_app_config_file("config")?,
         );
+
+        fs::create_dir_all(config_home.path().join("old_name"))?;
+        fs::write(config_home.path().join("old_name/config"), "")?;
         assert_eq!(
-            Path::new("/home/user1/.local/share/file"),
-            xdg.data_file("file")?,
+            Some(config_home.path().join("old_name/config")),
+            xdg.search_app_config_file("config")?,
         );
+        fs::write(config_home.path().join("older_name/settings.toml"), "")?;
         assert_eq!(
-            Path::new("/home/user1/.local/state/file"),
-            xdg.state_file("file")?,
+            Some(config_home.path().join("older_name/settings.toml")),
+            xdg.search_app_config_file_with_ext("settings", &["yml", "toml"])?,
         );
         assert_eq!(
-            Path::new("/home/user1/.local/bin/file"),
-            xdg.bin_file("file")?,
+            vec![
+                config_home.path().join("old_name/config"),
+                config_home.path().join("older_name/config"),
+                config_dir.path().join("new_name/config"),
+            ],
+            xdg.search_app_config_files("config")?,
+        );
+
+        fs::create_dir_all(config_home.path().join("new_name"))?;
+        fs::write(config_home.path().join("new_name/config"), "")?;
+        assert_eq!(
+            Some(config_home.path().join("new_name/config")),
+            xdg.search_app_config_file_recursive("config", 1)?,
+        );
+        assert_eq!(
+            vec![
+                config_home.path().join("new_name/config"),
+                config_dir.path().join("new_name/config"),
+            ],
+            XdgApp::with_home("/home/user", "new_name")?.search_app_config_files("config")?,
         );
 
         Ok(())
     }
 
     #[test]
-    fn search_file() -> Result<(), Box<dyn Error>> {
+    fn app_env_overrides() -> Result<(), XdgError> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user");
-        env::set_var("HOME", "/home/user");
+        env::set_var("MY_APP_CACHE_DIR", "/tmp/cache");
+        env::set_var("MY_APP_CONFIG_DIR", "/etc/my-app");
+        env::set_var("MY_APP_DATA_DIR", "data");
+        env::set_var("MA_STATE_DIR", "/var/lib/my-app");
+
+        let xdg = XdgApp::with_home("/home/user", "my-app")?;
+        assert_eq!(Path::new("/home/user/.config/my-app"), xdg.app_config()?);
+
+        let xdg = xdg.with_env_overrides(true);
+        assert_eq!(Path::new("/tmp/cache"), xdg.app_cache()?);
+        assert_eq!(Path::new("/etc/my-app"), xdg.app_config()?);
+        assert_eq!(Path::new("/etc/my-app/file"), xdg.app_config_file("file")?);
+        assert_eq!(Path::new("/home/user/.local/share/my-app"), xdg.app_data()?);
+        assert_eq!(Path::new("/home/user/.local/state/my-app"), xdg.app_state()?);
+        assert_eq!(Path::new("/home/user/.config"), xdg.config()?);
+        assert_eq!(
+            Path::new("/etc/my-app/dev/file"),
+            xdg.clone().with_profile("dev")?.app_config_file("file")?,
+        );
+
+        let xdg = xdg.with_env_prefix("MA");
+        assert_eq!(Path::new("/home/user/.config/my-app"), xdg.app_config()?);
+        assert_eq!(Path::new("/var/lib/my-app"), xdg.app_state()?);
+
+        env::remove_var("MY_APP_CACHE_DIR");
+        env::remove_var("MY_APP_CONFIG_DIR");
+        env::remove_var("MY_APP_DATA_DIR");
+        env::remove_var("MA_STATE_DIR");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "watch")]
+    fn watch_app_config() -> Result<(), Box<dyn Error>> {
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        remove_xdg_vars();
 
         let mut tmp_dir_builder = tempfile::Builder::new();
         tmp_dir_builder.prefix("microxdg");
         tmp_dir_builder.rand_bytes(4);
 
-        let cache_home = tmp_dir_builder.tempdir()?;
         let config_home = tmp_dir_builder.tempdir()?;
-        let data_home = tmp_dir_builder.tempdir()?;
-        let state_home = tmp_dir_builder.tempdir()?;
-        let bin_home = tmp_dir_builder.tempdir()?;
-
-        env::set_var("XDG_CACHE_HOME", cache_home.path());
+        let config_dir = tmp_dir_builder.tempdir()?;
         env::set_var("XDG_CONFIG_HOME", config_home.path());
-        env::set_var("XDG_DATA_HOME", data_home.path());
-        env::set_var("XDG_STATE_HOME", state_home.path());
-        env::set_var("XDG_BIN_HOME", bin_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dir.path());
+
+        let app_config_dir = config_dir.path().join("app_name");
+        fs::create_dir(&app_config_dir)?;
+        fs::write(app_config_dir.join("config"), "")?;
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let (sender, receiver) = mpsc::channel();
+        let watcher = xdg.watch_app_config(move |path| {
+            let _ = sender.send(path.to_path_buf());
+        })?;
+        // Events may be reported more than once, e.g. on both creation and
+        // modification of a file.
+        let wait_for = |expected: PathBuf| loop {
+            match receiver.recv_timeout(Duration::from_secs(5)) {
+                Ok(path) if path == expected => return true,
+                Ok(_) => continue,
+                Err(_) => return false,
+            }
+        };
+
+        fs::write(app_config_dir.join("config"), "key = value")?;
+        assert!(wait_for(app_config_dir.join("config")));
+
+        let app_config_home = config_home.path().join("app_name");
+        fs::create_dir(&app_config_home)?;
+        fs::write(app_config_home.join("config"), "key = value")?;
+        assert!(wait_for(app_config_home.join("config")));
+
+        fs::remove_file(app_config_dir.join("config"))?;
+        assert!(wait_for(app_config_dir.join("config")));
+
+        // Once stopped, the callback has been dropped along with the thread.
+        watcher.stop();
+        receiver.try_iter().for_each(drop);
+        assert_eq!(Err(mpsc::TryRecvError::Disconnected), receiver.try_recv());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn app_checked_dirs() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir(tmp_dir.path().join("app_name"))?;
+        env::set_var("XDG_CONFIG_HOME", tmp_dir.path());
+        env::set_var("XDG_DATA_HOME", tmp_dir.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(Some(tmp_dir.path().join("app_name")), xdg.app_config_checked()?);
+        assert_eq!(Some(tmp_dir.path().join("app_name")), xdg.app_data_checked()?);
+        assert_eq!(None, xdg.clone().with_profile("dev")?.app_config_checked()?);
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
 
-        let mut tmp_file_builder = tempfile::Builder::new();
-        tmp_file_builder.prefix("microxdg");
-        tmp_file_builder.rand_bytes(0);
+        Ok(())
+    }
 
-        let cache_file = tmp_file_builder.tempfile_in(cache_home.path())?;
-        let config_file = tmp_file_builder.tempfile_in(config_home.path())?;
-        let data_file = tmp_file_builder.tempfile_in(data_home.path())?;
-        let state_file = tmp_file_builder.tempfile_in(state_home.path())?;
-        let bin_file = tmp_file_builder.tempfile_in(bin_home.path())?;
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_app_entry() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
 
-        let xdg = XdgApp::new("app_name")?;
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(tmp_dir.path().join("app_name/plugins"))?;
+        fs::write(tmp_dir.path().join("app_name/config"), "")?;
+        env::set_var("XDG_CONFIG_HOME", tmp_dir.path());
 
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(None, xdg.search_app_config_file("plugins")?);
         assert_eq!(
-            Some(cache_file.path().into()),
-            xdg.search_cache_file("microxdg")?,
-        );
-        assert_eq!(
-            Some(config_file.path().into()),
-            xdg.search_config_file("microxdg")?,
+            Some(tmp_dir.path().join("app_name/plugins")),
+            xdg.search_app_config_entry("plugins", EntryType::Dir)?,
         );
         assert_eq!(
-            Some(data_file.path().into()),
-            xdg.search_data_file("microxdg")?,
+            Some(tmp_dir.path().join("app_name/config")),
+            xdg.search_app_config_entry("config", EntryType::Any)?,
         );
         assert_eq!(
-            Some(state_file.path().into()),
-            xdg.search_state_file("microxdg")?,
+            Some(tmp_dir.path().join("app_name/plugins")),
+            xdg.search_config_entry("app_name/plugins", EntryType::Dir)?,
         );
         assert_eq!(
-            Some(bin_file.path().into()),
-            xdg.search_bin_file("microxdg")?,
+            Some(tmp_dir.path().join("app_name/plugins")),
+            xdg.search_app_config_dir("plugins")?,
         );
+        assert_eq!(None, xdg.search_app_config_dir("config")?);
 
-        remove_xdg_vars();
+        env::remove_var("XDG_CONFIG_HOME");
 
-        let data_dirs = tmp_dir_builder.tempdir()?;
-        let config_dirs = tmp_dir_builder.tempdir()?;
+        Ok(())
+    }
 
-        env::set_var("XDG_DATA_DIRS", data_dirs.path());
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_app_order() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(config_home.path().join("app_name"))?;
+        fs::create_dir_all(config_dirs.path().join("app_name"))?;
+        fs::write(config_home.path().join("app_name/policy"), "")?;
+        fs::write(config_dirs.path().join("app_name/policy"), "")?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
         env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
 
-        let data_file = tmp_file_builder.tempfile_in(data_dirs.path())?;
-        let config_file = tmp_file_builder.tempfile_in(config_dirs.path())?;
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(
+            Some(config_home.path().join("app_name/policy")),
+            xdg.search_app_config_file("policy")?,
+        );
 
+        let xdg = xdg.with_search_order(SearchOrder::SystemFirst);
         assert_eq!(
-            Some(data_file.path().into()),
-            xdg.search_data_file("microxdg")?
+            Some(config_dirs.path().join("app_name/policy")),
+            xdg.search_app_config_file("policy")?,
         );
         assert_eq!(
-            Some(config_file.path().into()),
-            xdg.search_config_file("microxdg")?
+            Some(config_dirs.path().join("app_name/policy")),
+            xdg.search_config_file("app_name/policy")?,
         );
 
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
-    #[rustfmt::skip]
-    fn app_usr_dirs() -> Result<(), XdgError> {
+    fn search_app_file_with() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user1");
-        env::set_var("HOME", "/home/user1");
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(data_home.path().join("app_name"))?;
+        fs::create_dir_all(data_dirs.path().join("app_name"))?;
+        fs::write(data_home.path().join("app_name/file"), "")?;
+        fs::write(data_dirs.path().join("app_name/file"), "contents")?;
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_DATA_DIRS", data_dirs.path());
 
-        let xdg = XdgApp::new("app_name")?;
-        assert_eq!(
-            Path::new("/home/user1/.cache/app_name"),
-            xdg.app_cache()?,
-        );
-        assert_eq!(
-            Path::new("/home/user1/.config/app_name"),
-            xdg.app_config()?,
-        );
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
         assert_eq!(
-            Path::new("/home/user1/.local/share/app_name"),
-            xdg.app_data()?,
+            Some(data_home.path().join("app_name/file")),
+            xdg.search_app_data_file_with("file", |_, _| true)?,
         );
         assert_eq!(
-            Path::new("/home/user1/.local/state/app_name"),
-            xdg.app_state()?,
+            Some(data_dirs.path().join("app_name/file")),
+            xdg.search_app_data_file_with("file", |_, metadata| metadata.len() > 0)?,
         );
+        assert_eq!(None, xdg.search_app_config_file_with("file", |_, _| true)?);
 
-        env::set_var("XDG_CACHE_HOME", "/home/user2/.cache");
-        env::set_var("XDG_CONFIG_HOME", "/home/user2/.config");
-        env::set_var("XDG_DATA_HOME", "/home/user2/.local/share");
-        env::set_var("XDG_STATE_HOME", "/home/user2/.local/state");
+        let xdg = xdg.with_search_order(SearchOrder::SystemFirst);
         assert_eq!(
-            Path::new("/home/user2/.cache/app_name"),
-            xdg.app_cache()?,
+            Some(data_home.path().join("app_name/file")),
+            xdg.search_app_data_file_with("file", |_, metadata| metadata.len() == 0)?,
         );
         assert_eq!(
-            Path::new("/home/user2/.config/app_name"),
-            xdg.app_config()?,
+            Some(data_dirs.path().join("app_name/file")),
+            xdg.search_data_file_with("app_name/file", |_, _| true)?,
         );
+
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_DATA_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_app_file_newest() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(config_home.path().join("app_name"))?;
+        fs::create_dir_all(config_dirs.path().join("app_name"))?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(None, xdg.search_app_config_file_newest("file")?);
+
+        fs::write(config_home.path().join("app_name/file"), "")?;
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        fs::write(config_dirs.path().join("app_name/file"), "")?;
         assert_eq!(
-            Path::new("/home/user2/.local/share/app_name"),
-            xdg.app_data()?,
+            Some(config_dirs.path().join("app_name/file")),
+            xdg.search_app_config_file_newest("file")?,
         );
         assert_eq!(
-            Path::new("/home/user2/.local/state/app_name"),
-            xdg.app_state()?,
+            Some(config_dirs.path().join("app_name/file")),
+            xdg.search_config_file_newest("app_name/file")?,
         );
 
-        env::set_var("XDG_CACHE_HOME", "");
-        env::set_var("XDG_CONFIG_HOME", "");
-        env::set_var("XDG_DATA_HOME", "");
-        env::set_var("XDG_STATE_HOME", "");
-        assert_eq!(
-            Path::new("/home/user1/.cache/app_name"),
-            xdg.app_cache()?,
-        );
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn require_app_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(data_dirs.path().join("app_name"))?;
+        fs::write(data_dirs.path().join("app_name/file"), "")?;
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_DATA_DIRS", data_dirs.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?.with_aliases(["old_name"])?;
+        assert_eq!(data_dirs.path().join("app_name/file"), xdg.require_app_data_file("file")?);
         assert_eq!(
-            Path::new("/home/user1/.config/app_name"),
-            xdg.app_config()?,
+            XdgError::FileNotFound {
+                file: PathBuf::from("missing"),
+                searched: vec![
+                    data_home.path().join("app_name"),
+                    data_home.path().join("old_name"),
+                    data_dirs.path().join("app_name"),
+                    data_dirs.path().join("old_name"),
+                ],
+            },
+            xdg.require_app_data_file("missing").unwrap_err(),
         );
         assert_eq!(
-            Path::new("/home/user1/.local/share/app_name"),
-            xdg.app_data()?,
+            XdgError::FileNotFound {
+                file: PathBuf::from("missing"),
+                searched: vec![data_dirs.path().to_path_buf(), data_home.path().to_path_buf()],
+            },
+            xdg.with_search_order(SearchOrder::SystemFirst)
+                .require_data_file("missing")
+                .unwrap_err(),
         );
+
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_DATA_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn check_files() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(config_home.path().join("app_name"))?;
+        fs::write(config_home.path().join("app_name/config.toml"), "")?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_DATA_HOME", data_home.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let files = xdg.check_files([
+            ("config.toml", XdgDir::Config),
+            ("keymap", XdgDir::Config),
+            ("db.sqlite", XdgDir::Data),
+        ])?;
+        let config_file = config_home.path().join("app_name/config.toml");
         assert_eq!(
-            Path::new("/home/user1/.local/state/app_name"),
-            xdg.app_state()?,
+            BTreeMap::from([
+                (PathBuf::from("config.toml"), Some(config_file)),
+                (PathBuf::from("db.sqlite"), None),
+                (PathBuf::from("keymap"), None),
+            ]),
+            files,
         );
+        assert!(xdg.check_files::<[(&str, XdgDir); 0], _>([])?.is_empty());
 
-        env::set_var("XDG_CACHE_HOME", "./app_name/cache");
-        env::set_var("XDG_CONFIG_HOME", "./app_name/config");
-        env::set_var("XDG_DATA_HOME", "./app_name/data");
-        env::set_var("XDG_STATE_HOME", "./app_name/state");
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_app_sys_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(config_home.path().join("app_name"))?;
+        fs::create_dir_all(config_dirs.path().join("app_name"))?;
+        fs::write(config_home.path().join("app_name/config"), "")?;
+        fs::write(config_dirs.path().join("app_name/config"), "")?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+        env::set_var("XDG_DATA_DIRS", config_dirs.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_CACHE_HOME",
-                path: PathBuf::from("./app_name/cache"),
-            }),
-            xdg.app_cache(),
+            Some(config_home.path().join("app_name/config")),
+            xdg.search_app_config_file("config")?,
         );
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_CONFIG_HOME",
-                path: PathBuf::from("./app_name/config")
-            }),
-            xdg.app_config(),
+            Some(config_dirs.path().join("app_name/config")),
+            xdg.search_app_sys_config_file("config")?,
         );
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_DATA_HOME",
-                path: PathBuf::from("./app_name/data")
-            }),
-            xdg.app_data(),
+            Some(config_dirs.path().join("app_name/config")),
+            xdg.search_app_sys_data_file("config")?,
         );
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_STATE_HOME",
-                path: PathBuf::from("./app_name/state")
-            }),
-            xdg.app_state(),
+            Some(config_dirs.path().join("app_name/config")),
+            xdg.search_sys_config_file("app_name/config")?,
         );
+        assert_eq!(None, xdg.search_app_sys_config_file("missing")?);
 
-        let invalid_unicode = OsStr::from_bytes(&INVALID_UNICODE_BYTES);
-        env::set_var("XDG_CACHE_HOME", invalid_unicode);
-        env::set_var("XDG_CONFIG_HOME", invalid_unicode);
-        env::set_var("XDG_DATA_HOME", invalid_unicode);
-        env::set_var("XDG_STATE_HOME", invalid_unicode);
-        env::set_var("XDG_BIN_HOME", invalid_unicode);
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+        env::remove_var("XDG_DATA_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_app_usr_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(config_home.path().join("app_name"))?;
+        fs::create_dir_all(config_dirs.path().join("app_name"))?;
+        fs::write(config_home.path().join("app_name/credentials"), "")?;
+        fs::write(config_dirs.path().join("app_name/defaults"), "")?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_DATA_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_CACHE_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.app_cache(),
+            Some(config_dirs.path().join("app_name/defaults")),
+            xdg.search_app_config_file("defaults")?,
         );
+        assert_eq!(None, xdg.search_app_usr_config_file("defaults")?);
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_CONFIG_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.app_config(),
+            Some(config_home.path().join("app_name/credentials")),
+            xdg.search_app_usr_config_file("credentials")?,
         );
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_DATA_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.app_data(),
+            Some(config_home.path().join("app_name/credentials")),
+            xdg.search_app_usr_data_file("credentials")?,
         );
+        assert_eq!(None, xdg.search_usr_config_file("app_name/defaults")?);
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn read_app_dir() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(tmp_dir.path().join("app_name/profiles"))?;
+        fs::write(tmp_dir.path().join("app_name/config"), "")?;
+        env::set_var("XDG_CONFIG_HOME", tmp_dir.path());
+        env::set_var("XDG_DATA_HOME", tmp_dir.path().join("missing"));
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let mut entries: Vec<PathBuf> = xdg.read_app_config_dir()?.map(|entry| entry.path()).collect();
+        entries.sort_unstable();
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_STATE_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.app_state(),
+            vec![tmp_dir.path().join("app_name/config"), tmp_dir.path().join("app_name/profiles")],
+            entries,
         );
+        assert_eq!(0, xdg.read_app_data_dir()?.count());
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn install_app_data_file() -> Result<(), Box<dyn Error>> {
+        use std::os::unix::fs::PermissionsExt;
+
+        remove_xdg_vars();
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let src = tmp_dir.path().join("src.toml");
+        fs::write(&src, "theme = \"dark\"")?;
+        fs::set_permissions(&src, fs::Permissions::from_mode(0o640))?;
+        env::set_var("XDG_DATA_HOME", tmp_dir.path().join("data"));
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let dest = tmp_dir.path().join("data/app_name/themes/dark.toml");
+        assert!(xdg.install_app_data_file(&src, "themes/dark.toml", InstallOptions::new())?);
+        assert_eq!("theme = \"dark\"", fs::read_to_string(&dest)?);
+        assert_eq!(0o640, fs::metadata(&dest)?.permissions().mode() & 0o777);
+
+        fs::write(&dest, "theme = \"light\"")?;
+        let options = InstallOptions::new().skip_newer(true);
+        assert!(!xdg.install_app_data_file(&src, "themes/dark.toml", options)?);
+        assert_eq!("theme = \"light\"", fs::read_to_string(&dest)?);
+        assert!(xdg.install_app_data_file(&src, "themes/dark.toml", InstallOptions::new())?);
+        assert_eq!("theme = \"dark\"", fs::read_to_string(&dest)?);
+
+        assert!(matches!(
+            xdg.install_app_data_file(tmp_dir.path().join("missing"), "file", options),
+            Err(XdgError::Io { .. }),
+        ));
+
+        env::remove_var("XDG_DATA_HOME");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn app_cache_tempfile() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_CACHE_HOME", tmp_dir.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let (path, mut file) = xdg.app_cache_tempfile("download-")?;
+        file.write_all(b"partial")?;
+        assert_eq!(Some(tmp_dir.path().join("app_name").as_path()), path.parent());
+        assert!(path.file_name().unwrap().to_string_lossy().starts_with("download-"));
+        assert_eq!("partial", fs::read_to_string(&path)?);
+
+        let (other_path, _) = xdg.app_cache_tempfile("download-")?;
+        assert_ne!(path, other_path);
+
+        let dir = xdg.app_cache_tempdir("unpack-")?;
+        assert!(dir.is_dir());
+        assert!(dir.starts_with(tmp_dir.path().join("app_name")));
+
+        env::remove_var("XDG_CACHE_HOME");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn rotate_app_state_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let state_dir = tmp_dir.path().join("app_name");
+        fs::create_dir_all(&state_dir)?;
+        env::set_var("XDG_STATE_HOME", tmp_dir.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert!(!xdg.rotate_app_state_file("history", 2)?);
+
+        for version in ["v1", "v2", "v3"] {
+            fs::write(state_dir.join("history"), version)?;
+            assert!(xdg.rotate_app_state_file("history", 2)?);
+            assert!(!state_dir.join("history").exists());
+        }
+        assert_eq!("v3", fs::read_to_string(state_dir.join("history.1"))?);
+        assert_eq!("v2", fs::read_to_string(state_dir.join("history.2"))?);
+        assert!(!state_dir.join("history.3").exists());
+
+        fs::write(state_dir.join("history"), "v4")?;
+        assert!(xdg.rotate_app_state_file("history", 0)?);
+        assert!(!state_dir.join("history").exists());
+        assert_eq!("v3", fs::read_to_string(state_dir.join("history.1"))?);
+
+        env::remove_var("XDG_STATE_HOME");
 
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
-    fn app_sys_dirs() -> Result<(), XdgError> {
+    fn save_app_state() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user");
-        env::set_var("HOME", "/home/user");
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_STATE_HOME", tmp_dir.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let path = xdg.save_app_state("state.json", "v1", SaveOptions::new())?;
+        assert_eq!(tmp_dir.path().join("app_name/state.json"), path);
+        assert_eq!("v1", fs::read_to_string(&path)?);
+
+        let backup = tmp_dir.path().join("app_name/state.json.bak");
+        xdg.save_app_state("state.json", "v2", SaveOptions::new())?;
+        assert_eq!("v2", fs::read_to_string(&path)?);
+        assert!(!backup.exists());
+
+        xdg.save_app_state("state.json", "v3", SaveOptions::new().backup(true))?;
+        assert_eq!("v3", fs::read_to_string(&path)?);
+        assert_eq!("v2", fs::read_to_string(&backup)?);
+
+        // No temporary file is left behind.
+        assert_eq!(2, fs::read_dir(tmp_dir.path().join("app_name"))?.count());
+
+        // File names need not be valid unicode.
+        let name = OsStr::from_bytes(b"state-\xFF");
+        let mut backup_name = name.to_os_string();
+        backup_name.push(".bak");
+        xdg.save_app_state(name, "v1", SaveOptions::new())?;
+        xdg.save_app_state(name, "v2", SaveOptions::new().backup(true))?;
+        assert_eq!("v2", fs::read_to_string(tmp_dir.path().join("app_name").join(name))?);
+        let backup_path = tmp_dir.path().join("app_name").join(backup_name);
+        assert_eq!("v1", fs::read_to_string(backup_path)?);
+        assert_eq!(4, fs::read_dir(tmp_dir.path().join("app_name"))?.count());
+
+        #[cfg(feature = "async")]
+        {
+            use crate::unblock::test::block_on;
+
+            block_on(xdg.save_app_state_async("state.json", "v4", SaveOptions::new()))?;
+            let found = block_on(xdg.search_app_state_file_async("state.json"))?;
+            assert_eq!(Some(path.clone()), found);
+            assert_eq!("v4", fs::read_to_string(&path)?);
+        }
 
-        let xdg = XdgApp::new("app_name")?;
+        env::remove_var("XDG_STATE_HOME");
 
-        assert_eq!(
-            vec![PathBuf::from("/etc/xdg/app_name")],
-            xdg.app_sys_config()?,
-        );
-        assert_eq!(
-            vec![
-                PathBuf::from("/usr/local/share/app_name"),
-                PathBuf::from("/usr/share/app_name"),
-            ],
-            xdg.app_sys_data()?,
-        );
+        Ok(())
+    }
 
-        env::set_var(
-            "XDG_CONFIG_DIRS",
-            "/config/dir1:/config/dir2:/config/dir3:/config/dir4",
-        );
-        env::set_var(
-            "XDG_DATA_DIRS",
-            "/data/dir1:/data/dir2:/data/dir3:/data/dir4",
-        );
-        assert_eq!(
-            vec![
-                PathBuf::from("/config/dir1/app_name"),
-                PathBuf::from("/config/dir2/app_name"),
-                PathBuf::from("/config/dir3/app_name"),
-                PathBuf::from("/config/dir4/app_name"),
-            ],
-            xdg.app_sys_config()?,
-        );
-        assert_eq!(
-            vec![
-                PathBuf::from("/data/dir1/app_name"),
-                PathBuf::from("/data/dir2/app_name"),
-                PathBuf::from("/data/dir3/app_name"),
-                PathBuf::from("/data/dir4/app_name"),
-            ],
-            xdg.app_sys_data()?,
-        );
+    #[cfg(feature = "fs")]
+    #[test]
+    fn lock_app_config_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_CONFIG_HOME", tmp_dir.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let lock = xdg.lock_app_config_file("config.toml")?;
+        assert_eq!(tmp_dir.path().join("app_name/config.toml.lock"), lock.path());
+        assert!(xdg.try_lock_app_config_file("config.toml")?.is_none());
+        assert!(xdg.try_lock_app_config_file("other.toml")?.is_some());
+
+        drop(lock);
+        assert!(xdg.try_lock_app_config_file("config.toml")?.is_some());
+
+        env::remove_var("XDG_CONFIG_HOME");
 
         Ok(())
     }
 
+    /// Configuration format parsing the whole file as a string, unable to
+    /// serialize configurations.
+    #[cfg(all(feature = "fs", feature = "serde"))]
+    struct Text;
+
+    #[cfg(all(feature = "fs", feature = "serde"))]
+    impl ConfigFormat for Text {
+        type Error = serde::de::value::Error;
+
+        fn parse<T>(&self, contents: &[u8]) -> Result<T, Self::Error>
+        where
+            T: serde::de::DeserializeOwned,
+        {
+            use serde::de::{Error, IntoDeserializer};
+
+            let contents = std::str::from_utf8(contents).map_err(Error::custom)?;
+            T::deserialize(contents.into_deserializer())
+        }
+
+        fn emit<T>(&self, _config: &T) -> Result<Vec<u8>, Self::Error>
+        where
+            T: serde::Serialize + ?Sized,
+        {
+            Err(serde::ser::Error::custom("unsupported"))
+        }
+    }
+
+    #[cfg(all(feature = "fs", feature = "serde"))]
     #[test]
-    fn app_usr_file() -> Result<(), XdgError> {
+    fn load_app_config() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user");
-        env::set_var("HOME", "/home/user");
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir(tmp_dir.path().join("app_name"))?;
+        fs::write(tmp_dir.path().join("app_name/config"), "theme=dark")?;
+        fs::write(tmp_dir.path().join("app_name/invalid"), [0xFF, 0xFE])?;
+        env::set_var("XDG_CONFIG_HOME", tmp_dir.path());
 
-        let xdg = XdgApp::new("app_name")?;
-        assert_eq!(
-            Path::new("/home/user/.cache/app_name/file"),
-            xdg.app_cache_file("file")?
-        );
-        assert_eq!(
-            Path::new("/home/user/.config/app_name/file"),
-            xdg.app_config_file("file")?,
-        );
-        assert_eq!(
-            Path::new("/home/user/.local/share/app_name/file"),
-            xdg.app_data_file("file")?,
-        );
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!("theme=dark", xdg.load_app_config::<_, String, _>(Text, "config")?);
+        assert_eq!("theme=dark", xdg.load_config::<_, String, _>(Text, "app_name/config")?);
         assert_eq!(
-            Path::new("/home/user/.local/state/app_name/file"),
-            xdg.app_state_file("file")?,
+            XdgError::FileNotFound {
+                file: PathBuf::from("missing"),
+                searched: vec![tmp_dir.path().join("app_name"), PathBuf::from("/etc/xdg/app_name")],
+            },
+            xdg.load_app_config::<_, String, _>(Text, "missing").unwrap_err(),
         );
+        let invalid = tmp_dir.path().join("app_name/invalid");
+        assert!(matches!(
+            xdg.load_app_config::<_, String, _>(Text, "invalid"),
+            Err(XdgError::InvalidConfig { path, .. }) if path == invalid,
+        ));
 
-        env::set_var("XDG_CACHE_HOME", "/home/user1/.cache");
-        env::set_var("XDG_CONFIG_HOME", "/home/user1/.config");
-        env::set_var("XDG_DATA_HOME", "/home/user1/.local/share");
-        env::set_var("XDG_STATE_HOME", "/home/user1/.local/state");
-        assert_eq!(
-            Path::new("/home/user1/.cache/app_name/file"),
-            xdg.app_cache_file("file")?,
-        );
-        assert_eq!(
-            Path::new("/home/user1/.config/app_name/file"),
-            xdg.app_config_file("file")?,
-        );
-        assert_eq!(
-            Path::new("/home/user1/.local/share/app_name/file"),
-            xdg.app_data_file("file")?,
-        );
-        assert_eq!(
-            Path::new("/home/user1/.local/state/app_name/file"),
-            xdg.app_state_file("file")?,
-        );
+        env::remove_var("XDG_CONFIG_HOME");
 
-        env::set_var("USER", "user2");
-        env::remove_var("HOME");
+        Ok(())
+    }
 
-        env::set_var("XDG_CACHE_HOME", "");
-        env::set_var("XDG_CONFIG_HOME", "");
-        env::set_var("XDG_DATA_HOME", "");
-        env::set_var("XDG_STATE_HOME", "");
+    #[cfg(all(feature = "fs", feature = "serde"))]
+    #[test]
+    fn store_app_config() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
 
-        let xdg = XdgApp::new("app_name")?;
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_CONFIG_HOME", tmp_dir.path());
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let path = tmp_dir.path().join("app_name/config");
+        let message = String::from("unsupported");
         assert_eq!(
-            Path::new("/home/user2/.cache/app_name/file"),
-            xdg.app_cache_file("file")?,
+            Err(XdgError::InvalidConfig { path: path.clone(), message }),
+            xdg.store_app_config(Text, "config", "theme=dark"),
         );
+        assert!(!path.exists());
+
+        env::remove_var("XDG_CONFIG_HOME");
+
+        Ok(())
+    }
+
+    #[test]
+    fn display() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
         assert_eq!(
-            Path::new("/home/user2/.config/app_name/file"),
-            xdg.app_config_file("file")?,
+            "app: app_name\n\
+             cache: /home/user/.cache/app_name\n\
+             config: /home/user/.config/app_name\n\
+             data: /home/user/.local/share/app_name\n\
+             state: /home/user/.local/state/app_name\n\
+             runtime: /run/user/1000/app_name\n\
+             sys_config: /etc/xdg/app_name\n\
+             sys_data: /usr/local/share/app_name:/usr/share/app_name\n",
+            xdg.to_string(),
         );
+
+        env::remove_var("XDG_RUNTIME_DIR");
+
+        Ok(())
+    }
+
+    #[test]
+    fn preallocated_app_paths() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = XdgApp::with_home("/home/user", "app_name")?.with_profile("dev")?;
+        let additional = Path::new("file").append_len();
+
+        // Appending the file reuses the buffer, without reallocating it.
+        let app_data_dir = xdg.get_app_dir_path_with_capacity(XdgDir::Data, additional)?;
+        assert!(app_data_dir.capacity() >= app_data_dir.as_os_str().len() + additional);
+        let ptr = app_data_dir.as_os_str().as_bytes().as_ptr();
+        let app_data_file = app_data_dir.append("file");
+        assert_eq!(Path::new("/home/user/.local/share/app_name/dev/file"), app_data_file);
+        assert_eq!(ptr, app_data_file.as_os_str().as_bytes().as_ptr());
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_name() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let name = format!("app_{}", 42);
+        let xdg = XdgApp::with_home("/home/user", name)?;
+        assert_eq!("app_42", xdg.name());
+        assert_eq!(Path::new("/home/user/.config/app_42"), xdg.app_config()?);
+
+        let cloned_xdg = xdg.clone();
+        assert!(Arc::ptr_eq(&xdg.name, &cloned_xdg.name));
+
+        let handle = std::thread::spawn(move || cloned_xdg.app_cache());
         assert_eq!(
-            Path::new("/home/user2/.local/share/app_name/file"),
-            xdg.app_data_file("file")?,
+            Path::new("/home/user/.cache/app_42"),
+            handle.join().expect("thread panicked")?,
         );
+
         assert_eq!(
-            Path::new("/home/user2/.local/state/app_name/file"),
-            xdg.app_state_file("file")?,
+            XdgError::InvalidAppName { name: String::from("../app") },
+            XdgApp::new(String::from("../app")).unwrap_err(),
         );
 
         Ok(())
     }
 
+    #[cfg(all(unix, feature = "fs"))]
     #[test]
-    fn search_app_file() -> Result<(), Box<dyn Error>> {
+    fn app_state_host_dir() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user");
-        env::set_var("HOME", "/home/user");
+        env::set_var("XDG_STATE_HOME", "/state");
+
+        // The kernel host name is used, regardless of the `HOSTNAME` shell
+        // variable.
+        env::set_var("HOSTNAME", "../escape");
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        let hostname = crate::sys::hostname()?;
+        assert_eq!(Path::new("/state/app_name").join(hostname), xdg.app_state_host_dir()?);
+        if let Ok(hostname) = fs::read_to_string("/proc/sys/kernel/hostname") {
+            assert_eq!(
+                Path::new("/state/app_name").join(hostname.trim()),
+                xdg.app_state_host_dir()?,
+            );
+        }
+        env::remove_var("HOSTNAME");
 
-        let xdg = XdgApp::new("app_name")?;
+        env::set_var("XDG_STATE_HOME", "state");
+        assert!(matches!(xdg.app_state_host_dir(), Err(XdgError::RelativePath { .. })));
 
-        assert_eq!(None, xdg.search_app_cache_file("microxdg")?);
-        assert_eq!(None, xdg.search_app_config_file("microxdg")?);
-        assert_eq!(None, xdg.search_app_data_file("microxdg")?);
-        assert_eq!(None, xdg.search_app_state_file("microxdg")?);
+        env::remove_var("XDG_STATE_HOME");
 
-        let mut tmp_dir_builder = tempfile::Builder::new();
-        tmp_dir_builder.prefix("microxdg");
-        tmp_dir_builder.rand_bytes(4);
+        Ok(())
+    }
 
-        let cache_home = tmp_dir_builder.tempdir()?;
-        let app_cache_dir = cache_home.path().join("app_name");
-        fs::create_dir(&app_cache_dir)?;
-        let config_home = tmp_dir_builder.tempdir()?;
-        let app_config_dir = config_home.path().join("app_name");
-        fs::create_dir(&app_config_dir)?;
-        let data_home = tmp_dir_builder.tempdir()?;
-        let app_data_dir = data_home.path().join("app_name");
-        fs::create_dir(&app_data_dir)?;
-        let state_home = tmp_dir_builder.tempdir()?;
-        let app_state_dir = state_home.path().join("app_name");
-        fs::create_dir(&app_state_dir)?;
+    #[test]
+    fn caching() -> Result<(), XdgError> {
+        remove_xdg_vars();
 
-        env::set_var("XDG_CACHE_HOME", cache_home.path());
-        env::set_var("XDG_CONFIG_HOME", config_home.path());
-        env::set_var("XDG_DATA_HOME", data_home.path());
-        env::set_var("XDG_STATE_HOME", state_home.path());
+        env::set_var("XDG_CONFIG_HOME", "/config");
 
-        let mut tmp_file_builder = tempfile::Builder::new();
-        tmp_file_builder.prefix("microxdg");
-        tmp_file_builder.rand_bytes(0);
+        let mut xdg = XdgApp::with_home("/home/user", "app_name")?.with_caching(true);
+        assert_eq!(Path::new("/config/app_name"), xdg.app_config()?);
 
-        let cache_file = tmp_file_builder.tempfile_in(app_cache_dir)?;
-        let config_file = tmp_file_builder.tempfile_in(app_config_dir)?;
-        let data_file = tmp_file_builder.tempfile_in(app_data_dir)?;
-        let state_file = tmp_file_builder.tempfile_in(app_state_dir)?;
+        env::set_var("XDG_CONFIG_HOME", "/other");
+        assert_eq!(Path::new("/config/app_name/file"), xdg.app_config_file("file")?);
 
+        xdg.refresh();
+        assert_eq!(Path::new("/other/app_name"), xdg.app_config()?);
+
+        env::remove_var("XDG_CONFIG_HOME");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_cache() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::create_dir_all(config_home.path().join("app_name"))?;
+        fs::create_dir_all(config_dirs.path().join("app_name"))?;
+        fs::write(config_dirs.path().join("app_name/config"), "")?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        let mut xdg = XdgApp::with_home("/home/user", "app_name")?.with_search_cache(true);
+        assert!(xdg.search_cache());
         assert_eq!(
-            Some(cache_file.path().into()),
-            xdg.search_app_cache_file("microxdg")?,
+            Some(config_dirs.path().join("app_name/config")),
+            xdg.search_app_config_file("config")?,
         );
+
+        // The cached path is still valid: the higher-precedence file is not
+        // picked up, unlike on a clone.
+        fs::write(config_home.path().join("app_name/config"), "")?;
         assert_eq!(
-            Some(config_file.path().into()),
-            xdg.search_app_config_file("microxdg")?,
+            Some(config_dirs.path().join("app_name/config")),
+            xdg.search_app_config_file("config")?,
         );
         assert_eq!(
-            Some(data_file.path().into()),
-            xdg.search_app_data_file("microxdg")?,
+            Some(config_home.path().join("app_name/config")),
+            xdg.clone().search_app_config_file("config")?,
         );
+
+        xdg.refresh();
         assert_eq!(
-            Some(state_file.path().into()),
-            xdg.search_app_state_file("microxdg")?,
+            Some(config_home.path().join("app_name/config")),
+            xdg.search_app_config_file("config")?,
         );
 
-        env::remove_var("XDG_CACHE_HOME");
+        // The cached path is no longer valid: the whole walk is performed.
+        fs::remove_file(config_home.path().join("app_name/config"))?;
+        assert_eq!(
+            Some(config_dirs.path().join("app_name/config")),
+            xdg.search_app_config_file("config")?,
+        );
+        fs::remove_file(config_dirs.path().join("app_name/config"))?;
+        assert_eq!(None, xdg.search_app_config_file("config")?);
+
         env::remove_var("XDG_CONFIG_HOME");
-        env::remove_var("XDG_DATA_HOME");
-        env::remove_var("XDG_STATE_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
 
-        let data_dirs = tmp_dir_builder.tempdir()?;
-        let app_data_dirs = data_dirs.path().join("app_name");
-        fs::create_dir(&app_data_dirs)?;
-        let config_dirs = tmp_dir_builder.tempdir()?;
-        let app_config_dirs = config_dirs.path().join("app_name");
-        fs::create_dir(&app_config_dirs)?;
+        Ok(())
+    }
 
-        env::set_var("XDG_DATA_DIRS", &app_data_dirs);
-        env::set_var("XDG_CONFIG_DIRS", &app_config_dirs);
+    #[test]
+    // The directory cache is interior mutable, but left out of the hash.
+    #[allow(clippy::mutable_key_type)]
+    fn eq_hash() -> Result<(), XdgError> {
+        let xdg = XdgApp::with_home("/home/user", "app_name")?;
+        assert_eq!(xdg, xdg.clone());
+        assert_ne!(xdg, XdgApp::with_home("/home/user", "other_app")?);
+        assert_ne!(xdg, xdg.clone().with_profile("dev")?);
+
+        let xdgs: HashSet<XdgApp> = [
+            xdg.clone(),
+            XdgApp::with_home("/home/user", "app_name")?,
+            XdgApp::with_home("/home/user", "other_app")?,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(2, xdgs.len());
+        assert!(xdgs.contains(&xdg));
 
-        let data_file = tmp_file_builder.tempfile_in(app_data_dirs)?;
-        let config_file = tmp_file_builder.tempfile_in(app_config_dirs)?;
+        Ok(())
+    }
+
+    #[test]
+    fn app_profile() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+
+        let xdg = XdgApp::new("app_name")?;
+        assert_eq!(None, xdg.profile());
 
+        let xdg = xdg.with_profile(String::from("dev"))?;
+        assert_eq!(Some("dev"), xdg.profile());
         assert_eq!(
-            Some(data_file.path().into()),
-            xdg.search_data_file("microxdg")?
+            Path::new("/home/user/.cache/app_name/dev"),
+            xdg.app_cache()?,
         );
         assert_eq!(
-            Some(config_file.path().into()),
-            xdg.search_config_file("microxdg")?
+            Path::new("/home/user/.config/app_name/dev"),
+            xdg.app_config()?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.local/share/app_name/dev/file"),
+            xdg.app_data_file("file")?,
+        );
+        assert_eq!(
+            vec![PathBuf::from("/etc/xdg/app_name/dev")],
+            xdg.app_sys_config()?,
         );
 
         Ok(())
@@ -2255,14 +10583,24 @@ mod test {
 
         let xdg = XdgApp::new("app_name")?;
         assert_eq!(
-            "XdgApp { xdg: Xdg { home: \"/home/user\" }, name: \"app_name\" }",
+            "XdgApp { xdg: Xdg { home: \"/home/user\", relative_path_policy: Error, \
+             non_unicode: false, expand_vars: false, strict: false, canonicalize: false, \
+             symlink_policy: Follow, search_order: UserFirst, scope: User, cache: None, \
+             overrides: None }, \
+             name: \"app_name\", profile: None, aliases: [], env_overrides: false, \
+             env_prefix: None, search_cache: None }",
             format!("{xdg:?}")
         );
 
         #[allow(clippy::redundant_clone)]
         let cloned_xdg = xdg.clone();
         assert_eq!(
-            "XdgApp { xdg: Xdg { home: \"/home/user\" }, name: \"app_name\" }",
+            "XdgApp { xdg: Xdg { home: \"/home/user\", relative_path_policy: Error, \
+             non_unicode: false, expand_vars: false, strict: false, canonicalize: false, \
+             symlink_policy: Follow, search_order: UserFirst, scope: User, cache: None, \
+             overrides: None }, \
+             name: \"app_name\", profile: None, aliases: [], env_overrides: false, \
+             env_prefix: None, search_cache: None }",
             format!("{cloned_xdg:?}")
         );
 
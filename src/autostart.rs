@@ -0,0 +1,98 @@
+/// Autostart desktop entry, as defined by the [_Desktop Application Autostart Specification_](<https://specifications.freedesktop.org/autostart-spec/latest/>).
+///
+/// Serialized by [`XdgApp::install_autostart`](crate::XdgApp::install_autostart)
+/// into a minimal `Type=Application` desktop entry.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::AutostartEntry;
+/// let entry = AutostartEntry {
+///     name: String::from("App"),
+///     exec: String::from("/usr/bin/app --minimized"),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutostartEntry {
+    /// Name of the application (`Name` key).
+    pub name: String,
+    /// Command line starting the application (`Exec` key), written as is:
+    /// arguments must already be quoted according to the _Desktop Entry
+    /// Specification_.
+    pub exec: String,
+    /// Tooltip describing the application (`Comment` key).
+    pub comment: Option<String>,
+    /// Icon name or absolute icon path (`Icon` key).
+    pub icon: Option<String>,
+    /// Whether the application runs in a terminal (`Terminal` key).
+    pub terminal: bool,
+}
+
+/// Escapes `value` as a desktop entry `string` value.
+#[cfg(feature = "fs")]
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+impl AutostartEntry {
+    /// Serializes the entry as the content of a `.desktop` file.
+    #[cfg(feature = "fs")]
+    pub(crate) fn to_desktop_entry(&self) -> String {
+        let mut content = String::from("[Desktop Entry]\nType=Application\n");
+        content.push_str(&format!("Name={}\n", escape(&self.name)));
+        if let Some(comment) = &self.comment {
+            content.push_str(&format!("Comment={}\n", escape(comment)));
+        }
+        if let Some(icon) = &self.icon {
+            content.push_str(&format!("Icon={}\n", escape(icon)));
+        }
+        content.push_str(&format!("Exec={}\n", escape(&self.exec)));
+        content.push_str(&format!("Terminal={}\n", self.terminal));
+
+        content
+    }
+}
+
+#[cfg(all(test, feature = "fs"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn desktop_entry() {
+        let entry = AutostartEntry {
+            name: String::from("App"),
+            exec: String::from("/usr/bin/app --minimized"),
+            ..Default::default()
+        };
+        assert_eq!(
+            "[Desktop Entry]\nType=Application\nName=App\nExec=/usr/bin/app --minimized\n\
+             Terminal=false\n",
+            entry.to_desktop_entry(),
+        );
+
+        let entry = AutostartEntry {
+            name: String::from("App"),
+            exec: String::from("app"),
+            comment: Some(String::from("Multi\nline\\comment")),
+            icon: Some(String::from("app-icon")),
+            terminal: true,
+        };
+        assert_eq!(
+            "[Desktop Entry]\nType=Application\nName=App\nComment=Multi\\nline\\\\comment\n\
+             Icon=app-icon\nExec=app\nTerminal=true\n",
+            entry.to_desktop_entry(),
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Xdg, XdgDir, XdgError, XdgSysDirs};
+
+/// Directories overriding the ones resolved out of the XDG environment
+/// variables.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct DirOverrides {
+    cache: Option<PathBuf>,
+    config: Option<PathBuf>,
+    data: Option<PathBuf>,
+    state: Option<PathBuf>,
+    bin: Option<PathBuf>,
+    runtime: Option<PathBuf>,
+    fallbacks: Fallbacks,
+}
+
+/// Fallback directories replacing the built-in ones, used whenever the XDG
+/// environment variables are not set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Fallbacks {
+    cache: Option<PathBuf>,
+    config: Option<PathBuf>,
+    data: Option<PathBuf>,
+    state: Option<PathBuf>,
+    bin: Option<PathBuf>,
+    sys_config: Option<Vec<PathBuf>>,
+    sys_data: Option<Vec<PathBuf>>,
+}
+
+impl DirOverrides {
+    /// Returns the directory overriding the `dir` XDG base directory, if any.
+    #[inline]
+    pub(crate) fn get(&self, dir: XdgDir) -> Option<&Path> {
+        match dir {
+            XdgDir::Cache => self.cache.as_deref(),
+            XdgDir::Config => self.config.as_deref(),
+            XdgDir::Data => self.data.as_deref(),
+            XdgDir::State => self.state.as_deref(),
+            XdgDir::Bin => self.bin.as_deref(),
+        }
+    }
+
+    /// Returns the directory overriding the runtime directory, if any.
+    #[inline]
+    pub(crate) fn runtime(&self) -> Option<&Path> {
+        self.runtime.as_deref()
+    }
+
+    /// Returns the fallback replacing the built-in one of the `dir` XDG base
+    /// directory, if any.
+    #[inline]
+    pub(crate) fn fallback(&self, dir: XdgDir) -> Option<&Path> {
+        let fallbacks = &self.fallbacks;
+        match dir {
+            XdgDir::Cache => fallbacks.cache.as_deref(),
+            XdgDir::Config => fallbacks.config.as_deref(),
+            XdgDir::Data => fallbacks.data.as_deref(),
+            XdgDir::State => fallbacks.state.as_deref(),
+            XdgDir::Bin => fallbacks.bin.as_deref(),
+        }
+    }
+
+    /// Returns the fallbacks replacing the built-in ones of the `dirs`
+    /// _system-wide_ XDG base directories, if any.
+    #[inline]
+    pub(crate) fn sys_fallback(&self, dirs: XdgSysDirs) -> Option<&[PathBuf]> {
+        match dirs {
+            XdgSysDirs::Config => self.fallbacks.sys_config.as_deref(),
+            XdgSysDirs::Data => self.fallbacks.sys_data.as_deref(),
+        }
+    }
+
+    /// Returns `true` if neither a directory nor a fallback is overridden.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        let fallbacks = &self.fallbacks;
+        let dirs = [
+            &self.cache,
+            &self.config,
+            &self.data,
+            &self.state,
+            &self.bin,
+            &self.runtime,
+            &fallbacks.cache,
+            &fallbacks.config,
+            &fallbacks.data,
+            &fallbacks.state,
+            &fallbacks.bin,
+        ];
+
+        dirs.iter().all(|dir| dir.is_none())
+            && fallbacks.sys_config.is_none()
+            && fallbacks.sys_data.is_none()
+    }
+}
+
+/// Builder of [`Xdg`] instances, overriding individual _user-specific_ XDG
+/// base directories while every other directory follows the environment.
+///
+/// Overridden directories take precedence over the XDG environment variables
+/// and are used as they are, e.g. to force the cache into a ramdisk, to
+/// point the configuration directory to a test fixture or to honor a
+/// `--config-dir` command line flag. Every method of the built [`Xdg`] (and
+/// of any [`XdgApp`](crate::XdgApp) upgrading it) resolving the overridden
+/// directory, including files and app subdirectories inside it, is affected.
+///
+/// Fallbacks, instead, replace the built-in defaults used whenever the XDG
+/// environment variables are not set (e.g. `$HOME/.config` or `/etc/xdg`),
+/// for systems whose layout differs, such as NixOS or embedded images.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::path::Path;
+/// # use microxdg::{XdgBuilder, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// std::env::set_var("XDG_CACHE_HOME", "/home/user/.cache");
+///
+/// let xdg = XdgBuilder::new()
+///     .home("/home/user")
+///     .config("/tmp/fixtures/config")
+///     .build()?;
+/// assert_eq!(Path::new("/tmp/fixtures/config"), xdg.config()?);
+/// assert_eq!(Path::new("/tmp/fixtures/config/file"), xdg.config_file("file")?);
+/// assert_eq!(Path::new("/home/user/.cache"), xdg.cache()?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct XdgBuilder {
+    home: Option<PathBuf>,
+    overrides: DirOverrides,
+}
+
+impl XdgBuilder {
+    /// Constructs a new [`XdgBuilder`], overriding no directory.
+    #[inline]
+    #[must_use]
+    pub fn new() -> XdgBuilder {
+        XdgBuilder::default()
+    }
+
+    /// Sets the **home** directory, instead of resolving it out of the
+    /// environment as [`Xdg::new`] does.
+    #[inline]
+    #[must_use]
+    pub fn home<P>(mut self, home: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.home = Some(home.into());
+        self
+    }
+
+    /// Overrides the _user-specific_ XDG **cache** directory.
+    #[inline]
+    #[must_use]
+    pub fn cache<P>(mut self, cache: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.cache = Some(cache.into());
+        self
+    }
+
+    /// Overrides the _user-specific_ XDG **configuration** directory.
+    #[inline]
+    #[must_use]
+    pub fn config<P>(mut self, config: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.config = Some(config.into());
+        self
+    }
+
+    /// Overrides the _user-specific_ XDG **data** directory.
+    #[inline]
+    #[must_use]
+    pub fn data<P>(mut self, data: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.data = Some(data.into());
+        self
+    }
+
+    /// Overrides the _user-specific_ XDG **state** directory.
+    #[inline]
+    #[must_use]
+    pub fn state<P>(mut self, state: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.state = Some(state.into());
+        self
+    }
+
+    /// Overrides the _user-specific_ XDG **executable** directory.
+    #[inline]
+    #[must_use]
+    pub fn bin<P>(mut self, bin: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.bin = Some(bin.into());
+        self
+    }
+
+    /// Overrides the _user-specific_ XDG **runtime** directory.
+    #[inline]
+    #[must_use]
+    pub fn runtime<P>(mut self, runtime: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.runtime = Some(runtime.into());
+        self
+    }
+
+    /// Replaces the `.cache` fallback of the _user-specific_ XDG **cache**
+    /// directory. A relative `fallback` is resolved against the home
+    /// directory.
+    #[inline]
+    #[must_use]
+    pub fn cache_fallback<P>(mut self, fallback: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.fallbacks.cache = Some(fallback.into());
+        self
+    }
+
+    /// Replaces the `.config` fallback of the _user-specific_ XDG
+    /// **configuration** directory. A relative `fallback` is resolved
+    /// against the home directory.
+    #[inline]
+    #[must_use]
+    pub fn config_fallback<P>(mut self, fallback: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.fallbacks.config = Some(fallback.into());
+        self
+    }
+
+    /// Replaces the `.local/share` fallback of the _user-specific_ XDG
+    /// **data** directory. A relative `fallback` is resolved against the
+    /// home directory.
+    #[inline]
+    #[must_use]
+    pub fn data_fallback<P>(mut self, fallback: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.fallbacks.data = Some(fallback.into());
+        self
+    }
+
+    /// Replaces the `.local/state` fallback of the _user-specific_ XDG
+    /// **state** directory. A relative `fallback` is resolved against the
+    /// home directory.
+    #[inline]
+    #[must_use]
+    pub fn state_fallback<P>(mut self, fallback: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.fallbacks.state = Some(fallback.into());
+        self
+    }
+
+    /// Replaces the `.local/bin` fallback of the _user-specific_ XDG
+    /// **executable** directory. A relative `fallback` is resolved against
+    /// the home directory.
+    #[inline]
+    #[must_use]
+    pub fn bin_fallback<P>(mut self, fallback: P) -> XdgBuilder
+    where
+        P: Into<PathBuf>,
+    {
+        self.overrides.fallbacks.bin = Some(fallback.into());
+        self
+    }
+
+    /// Replaces the `/etc/xdg` fallback of the _system-wide_ XDG
+    /// **configuration** directories with the preference-ordered
+    /// `fallbacks`.
+    #[inline]
+    #[must_use]
+    pub fn sys_config_fallback<I, P>(mut self, fallbacks: I) -> XdgBuilder
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        let fallbacks = fallbacks.into_iter().map(Into::into).collect();
+        self.overrides.fallbacks.sys_config = Some(fallbacks);
+        self
+    }
+
+    /// Replaces the `/usr/local/share:/usr/share` fallback of the
+    /// _system-wide_ XDG **data** directories with the preference-ordered
+    /// `fallbacks`.
+    #[inline]
+    #[must_use]
+    pub fn sys_data_fallback<I, P>(mut self, fallbacks: I) -> XdgBuilder
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        let fallbacks = fallbacks.into_iter().map(Into::into).collect();
+        self.overrides.fallbacks.sys_data = Some(fallbacks);
+        self
+    }
+
+    /// Builds the [`Xdg`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the home directory is not set through
+    /// [`XdgBuilder::home`] and neither `HOME` or `USER` environment variable
+    /// is set.
+    pub fn build(self) -> Result<Xdg, XdgError> {
+        let mut xdg = match self.home {
+            Some(home) => Xdg::with_home(home),
+            None => Xdg::new()?,
+        };
+        if !self.overrides.is_empty() {
+            xdg.overrides = Some(Box::new(self.overrides));
+        }
+
+        Ok(xdg)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dir_overrides() {
+        let overrides = XdgBuilder::new().cache("/cache").runtime("/run").overrides;
+        assert!(!overrides.is_empty());
+        assert_eq!(Some(Path::new("/cache")), overrides.get(XdgDir::Cache));
+        assert_eq!(None, overrides.get(XdgDir::Config));
+        assert_eq!(Some(Path::new("/run")), overrides.runtime());
+
+        assert!(DirOverrides::default().is_empty());
+    }
+
+    #[test]
+    fn fallbacks() {
+        let overrides =
+            XdgBuilder::new().config_fallback("/cfg").sys_data_fallback(["/share"]).overrides;
+        assert!(!overrides.is_empty());
+        assert_eq!(Some(Path::new("/cfg")), overrides.fallback(XdgDir::Config));
+        assert_eq!(None, overrides.fallback(XdgDir::Data));
+        assert_eq!(
+            Some([PathBuf::from("/share")].as_slice()),
+            overrides.sys_fallback(XdgSysDirs::Data),
+        );
+        assert_eq!(None, overrides.sys_fallback(XdgSysDirs::Config));
+    }
+}
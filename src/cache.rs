@@ -0,0 +1,88 @@
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::{XdgDir, XdgError, XdgSysDirs};
+
+/// Cache of the resolved XDG base directories, enabled through
+/// [`Xdg::with_caching`](crate::Xdg::with_caching).
+///
+/// Each directory is resolved lazily on first access, while the ones failing
+/// to resolve are left out, so that resolution errors are never cached.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DirCache {
+    cache: OnceLock<PathBuf>,
+    config: OnceLock<PathBuf>,
+    data: OnceLock<PathBuf>,
+    state: OnceLock<PathBuf>,
+    bin: OnceLock<PathBuf>,
+    sys_config: OnceLock<Vec<PathBuf>>,
+    sys_data: OnceLock<Vec<PathBuf>>,
+}
+
+/// Returns the value of `cell`, initializing it through `resolve` if empty
+/// and leaving it empty if `resolve` fails.
+fn get_or_try_init<T, F>(cell: &OnceLock<T>, resolve: F) -> Result<&T, XdgError>
+where
+    F: FnOnce() -> Result<T, XdgError>,
+{
+    if let Some(value) = cell.get() {
+        return Ok(value);
+    }
+
+    let value = resolve()?;
+    Ok(cell.get_or_init(|| value))
+}
+
+impl DirCache {
+    /// Returns the cached `dir` XDG base directory, resolving it through
+    /// `resolve` if not cached yet.
+    #[inline]
+    pub(crate) fn dir<F>(&self, dir: XdgDir, resolve: F) -> Result<&Path, XdgError>
+    where
+        F: FnOnce() -> Result<PathBuf, XdgError>,
+    {
+        let cell = match dir {
+            XdgDir::Cache => &self.cache,
+            XdgDir::Config => &self.config,
+            XdgDir::Data => &self.data,
+            XdgDir::State => &self.state,
+            XdgDir::Bin => &self.bin,
+        };
+
+        get_or_try_init(cell, resolve).map(PathBuf::as_path)
+    }
+
+    /// Returns the cached `dirs` _system-wide_ XDG base directories,
+    /// resolving them through `resolve` if not cached yet.
+    #[inline]
+    pub(crate) fn sys_dirs<F>(&self, dirs: XdgSysDirs, resolve: F) -> Result<&[PathBuf], XdgError>
+    where
+        F: FnOnce() -> Result<Vec<PathBuf>, XdgError>,
+    {
+        let cell = match dirs {
+            XdgSysDirs::Config => &self.sys_config,
+            XdgSysDirs::Data => &self.sys_data,
+        };
+
+        get_or_try_init(cell, resolve).map(Vec::as_slice)
+    }
+}
+
+// The cached directories are filled lazily through a shared reference: they
+// are left out of both equality and the hash, so that `Xdg` instances never
+// depend on the directories read so far, and stay stable while stored in a
+// hashed collection.
+impl PartialEq for DirCache {
+    #[inline]
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for DirCache {}
+
+impl Hash for DirCache {
+    #[inline]
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
@@ -0,0 +1,213 @@
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Configuration file format, parsing and serializing any configuration type
+/// implementing serde's `Deserialize` and `Serialize` traits.
+///
+/// Configurations are loaded through
+/// [`Xdg::load_config`](crate::Xdg::load_config) and
+/// [`XdgApp::load_app_config`](crate::XdgApp::load_app_config), and stored
+/// through [`XdgApp::store_app_config`](crate::XdgApp::store_app_config).
+/// Implementing this trait plugs a format into the search, read and atomic
+/// write of the configuration file, once for every configuration type.
+///
+/// The `Json`, `Toml` and `Yaml` formats are built in, enabled by the
+/// `json`, `toml` and `yaml` features respectively.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// # use microxdg::{ConfigFormat, XdgApp, XdgError};
+/// # use serde::{de::DeserializeOwned, Deserialize, Serialize};
+/// struct Ron;
+///
+/// impl ConfigFormat for Ron {
+///     type Error = ron::Error;
+///
+///     fn parse<T: DeserializeOwned>(&self, contents: &[u8]) -> Result<T, Self::Error> {
+///         ron::de::from_bytes(contents).map_err(|err| err.code)
+///     }
+///
+///     fn emit<T: Serialize + ?Sized>(&self, config: &T) -> Result<Vec<u8>, Self::Error> {
+///         ron::to_string(config).map(String::into_bytes)
+///     }
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     theme: String,
+/// }
+///
+/// # fn main() -> Result<(), XdgError> {
+/// let xdg = XdgApp::new("app_name")?;
+/// match xdg.load_app_config::<_, Config, _>(Ron, "config.ron") {
+///     Ok(config) => { /* ... */ },
+///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+///     Err(err) => return Err(err),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait ConfigFormat {
+    /// Error returned when the contents are not a valid configuration, or
+    /// the configuration could not be serialized, surfaced as an
+    /// [`XdgError::InvalidConfig`](crate::XdgError::InvalidConfig) error.
+    type Error: fmt::Display;
+
+    /// Parses the configuration out of the `contents` of the configuration
+    /// file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if `contents` is not a valid
+    /// configuration.
+    fn parse<T>(&self, contents: &[u8]) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned;
+
+    /// Serializes the configuration into the contents of the configuration
+    /// file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the configuration could not be
+    /// serialized.
+    fn emit<T>(&self, config: &T) -> Result<Vec<u8>, Self::Error>
+    where
+        T: Serialize + ?Sized;
+}
+
+/// [JSON](<https://www.json.org>) configuration format, enabled by the `json`
+/// feature.
+///
+/// Configurations are stored pretty-printed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{ConfigFormat, Json};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Config {
+///     theme: String,
+/// }
+///
+/// let config: Config = Json.parse(br#"{ "theme": "dark" }"#).unwrap();
+/// assert_eq!(Config { theme: String::from("dark") }, config);
+/// assert_eq!(b"{\n  \"theme\": \"dark\"\n}\n", &Json.emit(&config).unwrap()[..]);
+/// ```
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl ConfigFormat for Json {
+    type Error = serde_json::Error;
+
+    #[inline]
+    fn parse<T>(&self, contents: &[u8]) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned,
+    {
+        serde_json::from_slice(contents)
+    }
+
+    fn emit<T>(&self, config: &T) -> Result<Vec<u8>, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut contents = serde_json::to_vec_pretty(config)?;
+        contents.push(b'\n');
+        Ok(contents)
+    }
+}
+
+/// [TOML](<https://toml.io>) configuration format, enabled by the `toml`
+/// feature.
+///
+/// Configurations are stored pretty-printed. Being a TOML document a table,
+/// the configuration type must serialize to a struct or a map.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{ConfigFormat, Toml};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Config {
+///     theme: String,
+/// }
+///
+/// let config: Config = Toml.parse(b"theme = 'dark'").unwrap();
+/// assert_eq!(Config { theme: String::from("dark") }, config);
+/// assert_eq!(b"theme = \"dark\"\n", &Toml.emit(&config).unwrap()[..]);
+/// ```
+#[cfg(feature = "toml")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Toml;
+
+#[cfg(feature = "toml")]
+impl ConfigFormat for Toml {
+    /// Either a [`std::str::Utf8Error`], a [`toml::de::Error`] or a
+    /// [`toml::ser::Error`].
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn parse<T>(&self, contents: &[u8]) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(toml::from_str(std::str::from_utf8(contents)?)?)
+    }
+
+    #[inline]
+    fn emit<T>(&self, config: &T) -> Result<Vec<u8>, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(toml::to_string_pretty(config)?.into_bytes())
+    }
+}
+
+/// [YAML](<https://yaml.org>) configuration format, enabled by the `yaml`
+/// feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{ConfigFormat, Yaml};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Config {
+///     theme: String,
+/// }
+///
+/// let config: Config = Yaml.parse(b"theme: dark").unwrap();
+/// assert_eq!(Config { theme: String::from("dark") }, config);
+/// assert_eq!(b"theme: dark\n", &Yaml.emit(&config).unwrap()[..]);
+/// ```
+#[cfg(feature = "yaml")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Yaml;
+
+#[cfg(feature = "yaml")]
+impl ConfigFormat for Yaml {
+    type Error = serde_yaml::Error;
+
+    #[inline]
+    fn parse<T>(&self, contents: &[u8]) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned,
+    {
+        serde_yaml::from_slice(contents)
+    }
+
+    #[inline]
+    fn emit<T>(&self, config: &T) -> Result<Vec<u8>, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde_yaml::to_string(config).map(String::into_bytes)
+    }
+}
@@ -0,0 +1,52 @@
+use std::path::{Path, PathBuf};
+
+/// Searches for the desktop entry identified by `desktop_file_id` inside the
+/// `applications` directory `dir`.
+///
+/// As mandated by the [_Desktop Entry Specification_](<https://specifications.freedesktop.org/desktop-entry-spec/latest/file-naming.html#desktop-file-id>),
+/// each `-` in the desktop file ID may stand for a path separator, i.e.
+/// `kde-org.example.App.desktop` also matches `kde/org.example.App.desktop`.
+pub(crate) fn find_entry(dir: &Path, desktop_file_id: &str) -> Option<PathBuf> {
+    let path = dir.join(desktop_file_id);
+    if path.is_file() {
+        return Some(path);
+    }
+
+    desktop_file_id.match_indices('-').find_map(|(idx, _)| {
+        let subdir = dir.join(&desktop_file_id[..idx]);
+        match subdir.is_dir() {
+            true => find_entry(&subdir, &desktop_file_id[idx + 1..]),
+            false => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn find_desktop_entry() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let dir = dir.path();
+        fs::create_dir_all(dir.join("kde/games"))?;
+        fs::write(dir.join("org.example.App.desktop"), "")?;
+        fs::write(dir.join("kde/games/org.example-chess.desktop"), "")?;
+
+        assert_eq!(
+            Some(dir.join("org.example.App.desktop")),
+            find_entry(dir, "org.example.App.desktop"),
+        );
+        assert_eq!(
+            Some(dir.join("kde/games/org.example-chess.desktop")),
+            find_entry(dir, "kde-games-org.example-chess.desktop"),
+        );
+        assert_eq!(None, find_entry(dir, "kde-org.example-chess.desktop"));
+        assert_eq!(None, find_entry(dir, "missing.desktop"));
+
+        Ok(())
+    }
+}
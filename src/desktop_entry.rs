@@ -0,0 +1,314 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::XdgError;
+
+/// Name of the main group of desktop entry files.
+const DESKTOP_ENTRY_GROUP: &str = "Desktop Entry";
+
+/// Desktop entry file, as defined by the [_Desktop Entry Specification_](<https://specifications.freedesktop.org/desktop-entry-spec/latest/>).
+///
+/// Entries are stored as group/key/value triples, where localized keys keep
+/// their locale suffix (e.g. `Name[it]`). Values are unescaped (`\s`, `\n`,
+/// `\t`, `\r` and `\\`) but otherwise returned as is.
+///
+/// # Note
+///
+/// Parsing is lenient: comments, blank lines, lines outside of any group and
+/// malformed lines are skipped. When a key is repeated within a group, the
+/// last occurrence wins.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::DesktopEntry;
+/// let entry = DesktopEntry::parse(
+///     "[Desktop Entry]\nType=Application\nName=App\nName[it]=Applicazione\nExec=app %U\n",
+/// );
+/// assert_eq!(Some("Application"), entry.entry_type());
+/// assert_eq!(Some("App"), entry.name());
+/// assert_eq!(Some("Applicazione"), entry.localized("Name", "it_IT.UTF-8"));
+/// assert_eq!(Some("app %U"), entry.exec());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DesktopEntry {
+    groups: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// Unescapes a desktop entry value.
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('s') => unescaped.push(' '),
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some(c) => {
+                // Unknown escape sequences (e.g. `\;` in lists) are kept.
+                if c != '\\' {
+                    unescaped.push('\\');
+                }
+                unescaped.push(c);
+            },
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+/// Returns the locale suffixes to look up for `locale`, from the most to the
+/// least specific, as mandated by the specification.
+///
+/// The locale has the form `lang_COUNTRY.ENCODING@MODIFIER`, where the
+/// `_COUNTRY`, `.ENCODING` and `@MODIFIER` parts are optional and the
+/// encoding is ignored.
+fn locale_candidates(locale: &str) -> Vec<String> {
+    let (locale, modifier) = match locale.split_once('@') {
+        Some((locale, modifier)) => (locale, Some(modifier)),
+        None => (locale, None),
+    };
+    let locale = locale.split_once('.').map_or(locale, |(locale, _)| locale);
+    let (lang, country) = match locale.split_once('_') {
+        Some((lang, country)) => (lang, Some(country)),
+        None => (locale, None),
+    };
+
+    let mut candidates = Vec::with_capacity(4);
+    if let (Some(country), Some(modifier)) = (country, modifier) {
+        candidates.push(format!("{lang}_{country}@{modifier}"));
+    }
+    if let Some(country) = country {
+        candidates.push(format!("{lang}_{country}"));
+    }
+    if let Some(modifier) = modifier {
+        candidates.push(format!("{lang}@{modifier}"));
+    }
+    candidates.push(lang.to_owned());
+
+    candidates
+}
+
+impl DesktopEntry {
+    /// Parses the content of a desktop entry file.
+    #[must_use]
+    pub fn parse(content: &str) -> DesktopEntry {
+        let mut groups: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        let mut group = None;
+
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                groups.entry(name.to_owned()).or_default();
+                group = Some(name.to_owned());
+                continue;
+            }
+
+            let (Some(group), Some((key, value))) = (&group, line.split_once('=')) else {
+                continue;
+            };
+            if let Some(entries) = groups.get_mut(group) {
+                entries.insert(key.trim_end().to_owned(), unescape(value.trim_start()));
+            }
+        }
+
+        DesktopEntry { groups }
+    }
+
+    /// Reads and parses the desktop entry file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an [`XdgError::Io`] error if the file could not
+    /// be read or does not contain valid unicode.
+    pub fn from_file<P>(path: P) -> Result<DesktopEntry, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        fs::read_to_string(path)
+            .map(|content| DesktopEntry::parse(&content))
+            .map_err(|source| XdgError::Io { path: path.into(), source })
+    }
+
+    /// Returns an iterator over the names of the groups, in lexicographic
+    /// order.
+    pub fn groups(&self) -> impl Iterator<Item = &str> {
+        self.groups.keys().map(String::as_str)
+    }
+
+    /// Returns the value of `key` inside `group`, if any.
+    #[must_use]
+    pub fn group_value(&self, group: &str, key: &str) -> Option<&str> {
+        self.groups.get(group)?.get(key).map(String::as_str)
+    }
+
+    /// Returns the value of `key` inside the `Desktop Entry` group, if any.
+    #[inline]
+    #[must_use]
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.group_value(DESKTOP_ENTRY_GROUP, key)
+    }
+
+    /// Returns the value of the localized `key` inside the `Desktop Entry`
+    /// group that best matches `locale` (e.g. `it_IT.UTF-8`), falling back to
+    /// the unlocalized value.
+    #[must_use]
+    pub fn localized(&self, key: &str, locale: &str) -> Option<&str> {
+        locale_candidates(locale)
+            .iter()
+            .find_map(|locale| self.value(&format!("{key}[{locale}]")))
+            .or_else(|| self.value(key))
+    }
+
+    /// Returns the `Type` key (e.g. `Application`, `Link` or `Directory`).
+    #[inline]
+    #[must_use]
+    pub fn entry_type(&self) -> Option<&str> {
+        self.value("Type")
+    }
+
+    /// Returns the unlocalized `Name` key.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.value("Name")
+    }
+
+    /// Returns the unlocalized `GenericName` key.
+    #[inline]
+    #[must_use]
+    pub fn generic_name(&self) -> Option<&str> {
+        self.value("GenericName")
+    }
+
+    /// Returns the unlocalized `Comment` key.
+    #[inline]
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.value("Comment")
+    }
+
+    /// Returns the unlocalized `Icon` key.
+    #[inline]
+    #[must_use]
+    pub fn icon(&self) -> Option<&str> {
+        self.value("Icon")
+    }
+
+    /// Returns the `Exec` key, with field codes (e.g. `%U`) left untouched.
+    #[inline]
+    #[must_use]
+    pub fn exec(&self) -> Option<&str> {
+        self.value("Exec")
+    }
+
+    /// Returns the `Path` key, i.e. the working directory of the program.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&str> {
+        self.value("Path")
+    }
+
+    /// Returns `true` if the `Terminal` key is set to `true`.
+    #[inline]
+    #[must_use]
+    pub fn terminal(&self) -> bool {
+        self.value("Terminal") == Some("true")
+    }
+
+    /// Returns `true` if the `NoDisplay` key is set to `true`.
+    #[inline]
+    #[must_use]
+    pub fn no_display(&self) -> bool {
+        self.value("NoDisplay") == Some("true")
+    }
+
+    /// Returns `true` if the `Hidden` key is set to `true`, i.e. the entry
+    /// must be treated as deleted.
+    #[inline]
+    #[must_use]
+    pub fn hidden(&self) -> bool {
+        self.value("Hidden") == Some("true")
+    }
+
+    /// Returns an iterator over the items of the `;` separated list `key`
+    /// (e.g. `Categories` or `MimeType`), skipping empty items.
+    pub fn list<'entry>(&'entry self, key: &str) -> impl Iterator<Item = &'entry str> {
+        self.value(key)
+            .unwrap_or_default()
+            .split(';')
+            .filter(|item| !item.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ENTRY: &str = "\
+# Comment
+[Desktop Entry]
+Type=Application
+Name=Text Editor
+Name[it]=Editor di testo
+Name[sr@latin]=Uređivač teksta
+Name[de_CH]=Texteditor
+GenericName = Editor
+Comment=Edit\\stext\\nfiles
+Exec=editor --new-window %F
+Icon=text-editor
+Terminal=false
+Categories=Utility;TextEditor;
+MimeType=text/plain;
+
+[Desktop Action new-window]
+Name=New Window
+Exec=editor --new-window
+";
+
+    #[test]
+    fn parse_desktop_entry() {
+        let entry = DesktopEntry::parse(ENTRY);
+        assert_eq!(
+            vec!["Desktop Action new-window", "Desktop Entry"],
+            entry.groups().collect::<Vec<_>>(),
+        );
+        assert_eq!(Some("Application"), entry.entry_type());
+        assert_eq!(Some("Text Editor"), entry.name());
+        assert_eq!(Some("Editor"), entry.generic_name());
+        assert_eq!(Some("Edit text\nfiles"), entry.comment());
+        assert_eq!(Some("editor --new-window %F"), entry.exec());
+        assert_eq!(Some("text-editor"), entry.icon());
+        assert_eq!(None, entry.path());
+        assert!(!entry.terminal());
+        assert!(!entry.hidden());
+        assert_eq!(vec!["Utility", "TextEditor"], entry.list("Categories").collect::<Vec<_>>());
+        assert_eq!(0, entry.list("Keywords").count());
+        assert_eq!(Some("New Window"), entry.group_value("Desktop Action new-window", "Name"));
+
+        assert_eq!(Some("Editor di testo"), entry.localized("Name", "it_IT.UTF-8"));
+        assert_eq!(Some("Uređivač teksta"), entry.localized("Name", "sr_RS@latin"));
+        assert_eq!(Some("Texteditor"), entry.localized("Name", "de_CH"));
+        assert_eq!(Some("Text Editor"), entry.localized("Name", "de_DE"));
+        assert_eq!(Some("Editor"), entry.localized("GenericName", "it"));
+    }
+
+    #[test]
+    fn unescape_values() {
+        assert_eq!("a b\nc\td\re\\f", unescape("a\\sb\\nc\\td\\re\\\\f"));
+        assert_eq!("a\\;b", unescape("a\\;b"));
+        assert_eq!("trailing\\", unescape("trailing\\"));
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+
+/// Desktop environment, as listed in the `XDG_CURRENT_DESKTOP` environment
+/// variable.
+///
+/// Known variants map to the names registered by the [_Desktop Menu Specification_](<https://specifications.freedesktop.org/menu-spec/latest/onlyshowin-registry.html>)
+/// (plus a few widespread unregistered ones), matched case-insensitively;
+/// any other name is kept as is in [`DesktopEnvironment::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DesktopEnvironment {
+    /// Budgie Desktop (`Budgie`).
+    Budgie,
+    /// Cinnamon Desktop (`Cinnamon`).
+    Cinnamon,
+    /// COSMIC Desktop (`COSMIC`).
+    Cosmic,
+    /// Deepin Desktop Environment (`DDE`).
+    Deepin,
+    /// Enlightenment Desktop (`Enlightenment`).
+    Enlightenment,
+    /// GNOME Desktop (`GNOME`).
+    Gnome,
+    /// Hyprland compositor (`Hyprland`).
+    Hyprland,
+    /// KDE Desktop (`KDE`).
+    Kde,
+    /// LXDE Desktop (`LXDE`).
+    Lxde,
+    /// LXQt Desktop (`LXQt`).
+    Lxqt,
+    /// MATE Desktop (`MATE`).
+    Mate,
+    /// Elementary's Pantheon Desktop (`Pantheon`).
+    Pantheon,
+    /// Sway compositor (`sway`).
+    Sway,
+    /// Trinity Desktop (`TDE`).
+    Trinity,
+    /// Unity Shell (`Unity`).
+    Unity,
+    /// XFCE Desktop (`XFCE`).
+    Xfce,
+    /// Any other desktop environment, holding its name as is.
+    Other(String),
+}
+
+impl DesktopEnvironment {
+    /// Parses a desktop environment name, case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::DesktopEnvironment;
+    /// assert_eq!(DesktopEnvironment::Kde, DesktopEnvironment::parse("KDE"));
+    /// assert_eq!(DesktopEnvironment::Gnome, DesktopEnvironment::parse("gnome"));
+    /// assert_eq!(
+    ///     DesktopEnvironment::Other(String::from("ubuntu")),
+    ///     DesktopEnvironment::parse("ubuntu"),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn parse(name: &str) -> DesktopEnvironment {
+        match name.to_ascii_lowercase().as_str() {
+            "budgie" => DesktopEnvironment::Budgie,
+            "cinnamon" | "x-cinnamon" => DesktopEnvironment::Cinnamon,
+            "cosmic" => DesktopEnvironment::Cosmic,
+            "dde" | "deepin" => DesktopEnvironment::Deepin,
+            "enlightenment" => DesktopEnvironment::Enlightenment,
+            "gnome" => DesktopEnvironment::Gnome,
+            "hyprland" => DesktopEnvironment::Hyprland,
+            "kde" => DesktopEnvironment::Kde,
+            "lxde" => DesktopEnvironment::Lxde,
+            "lxqt" => DesktopEnvironment::Lxqt,
+            "mate" => DesktopEnvironment::Mate,
+            "pantheon" => DesktopEnvironment::Pantheon,
+            "sway" => DesktopEnvironment::Sway,
+            "tde" => DesktopEnvironment::Trinity,
+            "unity" => DesktopEnvironment::Unity,
+            "xfce" => DesktopEnvironment::Xfce,
+            _ => DesktopEnvironment::Other(name.to_owned()),
+        }
+    }
+
+    /// Returns the canonical name of the desktop environment.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            DesktopEnvironment::Budgie => "Budgie",
+            DesktopEnvironment::Cinnamon => "Cinnamon",
+            DesktopEnvironment::Cosmic => "COSMIC",
+            DesktopEnvironment::Deepin => "DDE",
+            DesktopEnvironment::Enlightenment => "Enlightenment",
+            DesktopEnvironment::Gnome => "GNOME",
+            DesktopEnvironment::Hyprland => "Hyprland",
+            DesktopEnvironment::Kde => "KDE",
+            DesktopEnvironment::Lxde => "LXDE",
+            DesktopEnvironment::Lxqt => "LXQt",
+            DesktopEnvironment::Mate => "MATE",
+            DesktopEnvironment::Pantheon => "Pantheon",
+            DesktopEnvironment::Sway => "sway",
+            DesktopEnvironment::Trinity => "TDE",
+            DesktopEnvironment::Unity => "Unity",
+            DesktopEnvironment::Xfce => "XFCE",
+            DesktopEnvironment::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for DesktopEnvironment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses the colon separated `XDG_CURRENT_DESKTOP` value, skipping empty
+/// entries.
+pub(crate) fn parse_list(value: &str) -> Vec<DesktopEnvironment> {
+    value.split(':').filter(|name| !name.is_empty()).map(DesktopEnvironment::parse).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_desktops() {
+        assert_eq!(
+            vec![
+                DesktopEnvironment::Other(String::from("ubuntu")),
+                DesktopEnvironment::Gnome,
+            ],
+            parse_list("ubuntu:GNOME"),
+        );
+        assert_eq!(vec![DesktopEnvironment::Cinnamon], parse_list("X-Cinnamon"));
+        assert_eq!(
+            vec![DesktopEnvironment::Kde, DesktopEnvironment::Xfce],
+            parse_list("KDE::xfce"),
+        );
+        assert!(parse_list("").is_empty());
+
+        assert_eq!("LXQt", DesktopEnvironment::parse("lxqt").to_string());
+        assert_eq!("ubuntu", DesktopEnvironment::parse("ubuntu").as_str());
+    }
+}
@@ -0,0 +1,176 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::{env, fmt};
+
+use crate::{Xdg, XdgDir, XdgError, XdgSysDirs};
+
+/// State of an environment variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VarState {
+    /// Environment variable not set.
+    Unset,
+    /// Environment variable set to an empty value, hence treated as unset.
+    Empty,
+    /// Environment variable set to a non-empty value.
+    Set(OsString),
+}
+
+impl VarState {
+    /// Reads the state of the `key` environment variable.
+    fn read(key: &str) -> VarState {
+        match env::var_os(key) {
+            None => VarState::Unset,
+            Some(val) if val.is_empty() => VarState::Empty,
+            Some(val) => VarState::Set(val),
+        }
+    }
+
+    /// Returns the non-empty value of the environment variable, if set.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> Option<&OsString> {
+        match self {
+            VarState::Set(val) => Some(val),
+            VarState::Unset | VarState::Empty => None,
+        }
+    }
+}
+
+impl fmt::Display for VarState {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VarState::Unset => formatter.write_str("unset"),
+            VarState::Empty => formatter.write_str("empty"),
+            VarState::Set(val) => formatter.write_fmt(format_args!("set to {val:?}")),
+        }
+    }
+}
+
+/// Diagnostics of a single environment variable, as part of an
+/// [`XdgDiagnostics`] report.
+#[derive(Debug, PartialEq, Eq)]
+pub struct VarDiagnostics {
+    /// Environment variable key (variable name).
+    pub key: &'static str,
+    /// State of the environment variable.
+    pub state: VarState,
+    /// Problem with the value of the environment variable, if any: either the
+    /// error preventing the directory from being resolved, or a relative path
+    /// ignored or resolved against the home directory according to the
+    /// [`RelativePathPolicy`](crate::RelativePathPolicy).
+    pub problem: Option<XdgError>,
+    /// Effective directories resolved out of the environment variable or its
+    /// fallback; empty if not resolved.
+    pub resolved: Vec<PathBuf>,
+}
+
+impl VarDiagnostics {
+    /// Constructs the diagnostics of the `key` environment variable.
+    fn new(key: &'static str, resolved: Result<Vec<PathBuf>, XdgError>) -> VarDiagnostics {
+        let state = VarState::read(key);
+        let (problem, resolved) = match resolved {
+            Ok(resolved) => (relative_path(key, &state), resolved),
+            Err(err) => (Some(err), Vec::new()),
+        };
+
+        VarDiagnostics { key, state, problem, resolved }
+    }
+}
+
+/// Returns a [`XdgError::RelativePath`] for the first relative path set to the
+/// `key` environment variable, if any.
+fn relative_path(key: &'static str, state: &VarState) -> Option<XdgError> {
+    env::split_paths(state.value()?)
+        .find(|path| !path.as_os_str().is_empty() && path.is_relative())
+        .map(|path| XdgError::RelativePath { env_var_key: key, path })
+}
+
+/// Report of every environment variable relevant to the XDG base directories,
+/// returned by [`Xdg::diagnostics`].
+///
+/// The [`Display`](fmt::Display) implementation prints one line per
+/// environment variable, suitable for `--debug` output and bug reports.
+#[derive(Debug, PartialEq, Eq)]
+pub struct XdgDiagnostics {
+    /// Home directory of the user owning the process.
+    pub home: PathBuf,
+    /// Diagnostics of each environment variable, in the order `HOME`, `USER`,
+    /// the _user-specific_ XDG variables, `XDG_RUNTIME_DIR`, and the
+    /// _system-wide_ XDG variables.
+    pub vars: Vec<VarDiagnostics>,
+}
+
+impl XdgDiagnostics {
+    /// Collects the diagnostics for the given [`Xdg`] instance.
+    pub(crate) fn collect(xdg: &Xdg) -> XdgDiagnostics {
+        let home = xdg.home().to_path_buf();
+        let mut vars = vec![
+            VarDiagnostics::new("HOME", Ok(vec![home.clone()])),
+            VarDiagnostics::new("USER", Ok(Vec::new())),
+        ];
+
+        for dir in [XdgDir::Cache, XdgDir::Config, XdgDir::Data, XdgDir::State, XdgDir::Bin] {
+            let resolved = xdg.get_dir_path(dir).map(|path| vec![path]);
+            vars.push(VarDiagnostics::new(dir.env_var(), resolved));
+        }
+        vars.push(VarDiagnostics::new(
+            XdgDir::RUNTIME_ENV_VAR,
+            xdg.runtime().map(|runtime| runtime.into_iter().collect()),
+        ));
+        for dirs in [XdgSysDirs::Config, XdgSysDirs::Data] {
+            vars.push(VarDiagnostics::new(dirs.env_var(), xdg.resolve_sys_dir_paths(dirs)));
+        }
+
+        XdgDiagnostics { home, vars }
+    }
+
+    /// Returns the diagnostics of the `key` environment variable, if part of
+    /// the report.
+    #[must_use]
+    pub fn var(&self, key: &str) -> Option<&VarDiagnostics> {
+        self.vars.iter().find(|var| var.key == key)
+    }
+
+    /// Returns `true` if any environment variable has a problem.
+    #[must_use]
+    pub fn has_problems(&self) -> bool {
+        self.vars.iter().any(|var| var.problem.is_some())
+    }
+}
+
+impl fmt::Display for XdgDiagnostics {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "home: {}", self.home.display())?;
+        for var in &self.vars {
+            write!(formatter, "{}: {}", var.key, var.state)?;
+            for (index, path) in var.resolved.iter().enumerate() {
+                let separator = if index == 0 { " -> " } else { ":" };
+                write!(formatter, "{separator}{}", path.display())?;
+            }
+            if let Some(problem) = &var.problem {
+                write!(formatter, " ({problem})")?;
+            }
+            writeln!(formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn var_state() {
+        env::set_var("XDG_TEST_VAR", "");
+        assert_eq!(VarState::Empty, VarState::read("XDG_TEST_VAR"));
+        env::set_var("XDG_TEST_VAR", "/tmp");
+        assert_eq!(VarState::Set(OsString::from("/tmp")), VarState::read("XDG_TEST_VAR"));
+        env::remove_var("XDG_TEST_VAR");
+        assert_eq!(VarState::Unset, VarState::read("XDG_TEST_VAR"));
+
+        assert_eq!("unset", VarState::Unset.to_string());
+        assert_eq!("set to \"/tmp\"", VarState::Set(OsString::from("/tmp")).to_string());
+    }
+}
@@ -1,12 +1,20 @@
 use std::ffi::OsString;
 use std::path::PathBuf;
-use std::{error, fmt};
+use std::{error, fmt, io};
 
 /// [_XDG Base Directory Specification_](<https://specifications.freedesktop.org/basedir-spec/basedir-spec-latest.html>)
 /// errors.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// New variants may be added in future releases, hence the enum is marked as
+/// `#[non_exhaustive]`.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
 pub enum XdgError {
-    /// Unable to retrieve user's home directory.
+    /// Unable to retrieve user's home directory: none of the `HOME`, `USER`
+    /// or `LOGNAME` (Unix), `USERPROFILE` or `HOMEDRIVE` and `HOMEPATH`
+    /// (Windows) environment variables is set, nor is the user found in the
+    /// user database (`passwd` feature).
     HomeNotFound,
     /// XDG environment variable contains a relative path.
     RelativePath {
@@ -22,14 +30,148 @@ pub enum XdgError {
         /// XDG environment variable value.
         env_var_val: OsString,
     },
+    /// Directory not joinable into an XDG path list environment variable, as
+    /// it contains the platform path list separator.
+    InvalidPathList {
+        /// XDG environment variable key (variable name).
+        env_var_key: &'static str,
+        /// Directory containing the path list separator.
+        path: PathBuf,
+    },
+    /// File not found inside any of the XDG directories.
+    FileNotFound {
+        /// Searched file.
+        file: PathBuf,
+        /// Directories searched for the file, in the order they were
+        /// consulted.
+        searched: Vec<PathBuf>,
+    },
+    /// Dangling symbolic link found while searching, with
+    /// [`SymlinkPolicy::ErrorOnDangling`](crate::SymlinkPolicy::ErrorOnDangling).
+    DanglingSymlink {
+        /// Dangling symbolic link.
+        path: PathBuf,
+    },
+    /// `XDG_RUNTIME_DIR` environment variable not set.
+    RuntimeDirNotSet,
+    /// Runtime directory not owned by the user or with an access mode other
+    /// than `0700`, rejected in strict mode.
+    InsecureRuntimeDir {
+        /// Runtime directory.
+        path: PathBuf,
+    },
+    /// Unix domain socket path longer than the platform `sun_path` limit,
+    /// hence unusable to bind a socket.
+    SocketPathTooLong {
+        /// Socket path.
+        path: PathBuf,
+        /// Maximum socket path length, in bytes.
+        max_len: usize,
+    },
+    /// Application name not usable as a single path component.
+    InvalidAppName {
+        /// Invalid application name.
+        name: String,
+    },
+    /// Host name not available, or not usable as a single path component.
+    InvalidHostName {
+        /// Source of the host name: the `gethostname` system call on Unix, or
+        /// the `COMPUTERNAME` environment variable on Windows.
+        origin: &'static str,
+        /// Host name, empty if not available.
+        hostname: String,
+    },
+    /// Configuration file found, but not containing a valid configuration.
+    InvalidConfig {
+        /// Configuration file.
+        path: PathBuf,
+        /// Error message of the configuration format.
+        message: String,
+    },
+    /// XDG Base Directories not supported on the target platform, e.g. on
+    /// `wasm32-unknown-unknown`, lacking both environment and filesystem.
+    Unsupported,
+    /// I/O error while accessing a path inside an XDG directory.
+    Io {
+        /// Accessed path.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serde_helpers::serialize_io_error")
+        )]
+        source: io::Error,
+    },
 }
 
+impl PartialEq for XdgError {
+    /// Compares two errors, considering [`XdgError::Io`] errors equal if they
+    /// refer to the same path and have the same [`io::ErrorKind`].
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (XdgError::HomeNotFound, XdgError::HomeNotFound) => true,
+            (XdgError::RuntimeDirNotSet, XdgError::RuntimeDirNotSet) => true,
+            (XdgError::Unsupported, XdgError::Unsupported) => true,
+            (
+                XdgError::RelativePath { env_var_key, path },
+                XdgError::RelativePath { env_var_key: other_env_var_key, path: other_path },
+            ) => env_var_key == other_env_var_key && path == other_path,
+            (
+                XdgError::InvalidUnicode { env_var_key, env_var_val },
+                XdgError::InvalidUnicode {
+                    env_var_key: other_env_var_key,
+                    env_var_val: other_env_var_val,
+                },
+            ) => env_var_key == other_env_var_key && env_var_val == other_env_var_val,
+            (
+                XdgError::InvalidPathList { env_var_key, path },
+                XdgError::InvalidPathList { env_var_key: other_env_var_key, path: other_path },
+            ) => env_var_key == other_env_var_key && path == other_path,
+            (
+                XdgError::FileNotFound { file, searched },
+                XdgError::FileNotFound { file: other_file, searched: other_searched },
+            ) => file == other_file && searched == other_searched,
+            (
+                XdgError::DanglingSymlink { path },
+                XdgError::DanglingSymlink { path: other_path },
+            ) => path == other_path,
+            (
+                XdgError::SocketPathTooLong { path, max_len },
+                XdgError::SocketPathTooLong { path: other_path, max_len: other_max_len },
+            ) => path == other_path && max_len == other_max_len,
+            (XdgError::InvalidAppName { name }, XdgError::InvalidAppName { name: other_name }) => {
+                name == other_name
+            },
+            (
+                XdgError::InsecureRuntimeDir { path },
+                XdgError::InsecureRuntimeDir { path: other_path },
+            ) => path == other_path,
+            (
+                XdgError::InvalidHostName { origin, hostname },
+                XdgError::InvalidHostName { origin: other_origin, hostname: other_hostname },
+            ) => origin == other_origin && hostname == other_hostname,
+            (
+                XdgError::InvalidConfig { path, message },
+                XdgError::InvalidConfig { path: other_path, message: other_message },
+            ) => path == other_path && message == other_message,
+            (
+                XdgError::Io { path, source },
+                XdgError::Io { path: other_path, source: other_source },
+            ) => path == other_path && source.kind() == other_source.kind(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for XdgError {}
+
 impl fmt::Display for XdgError {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             XdgError::HomeNotFound => formatter.write_str(
-                "Unable to retrieve user's home directory, neither HOME nor USER environment \
-                 variable set",
+                "Unable to retrieve user's home directory, none of HOME, USER/LOGNAME, \
+                 USERPROFILE or HOMEDRIVE and HOMEPATH environment variables set, nor found in \
+                 the user database",
             ),
             XdgError::RelativePath { env_var_key, path } => formatter.write_fmt(format_args!(
                 "The `{env_var_key}` environment variable contains a relative path, while paths \
@@ -42,11 +184,199 @@ impl fmt::Display for XdgError {
                      {env_var_val:?}",
                 ))
             },
+            XdgError::InvalidPathList { env_var_key, path } => formatter.write_fmt(format_args!(
+                "The `{path}` directory contains the path list separator, hence it cannot be \
+                 joined into the `{env_var_key}` environment variable",
+                path = path.display()
+            )),
+            XdgError::FileNotFound { file, searched } => {
+                formatter.write_fmt(format_args!(
+                    "Unable to find `{file}` inside XDG directories",
+                    file = file.display()
+                ))?;
+                for (index, dir) in searched.iter().enumerate() {
+                    let separator = if index == 0 { ", searched: " } else { ", " };
+                    formatter.write_fmt(format_args!("{separator}`{dir}`", dir = dir.display()))?;
+                }
+                Ok(())
+            },
+            XdgError::DanglingSymlink { path } => formatter.write_fmt(format_args!(
+                "The symbolic link `{path}` points to a missing file",
+                path = path.display()
+            )),
+            XdgError::RuntimeDirNotSet => formatter.write_str(
+                "Unable to locate the runtime directory, the `XDG_RUNTIME_DIR` environment \
+                 variable is not set",
+            ),
+            XdgError::InsecureRuntimeDir { path } => formatter.write_fmt(format_args!(
+                "The runtime directory `{path}` must be owned by the user and have access mode \
+                 0700",
+                path = path.display()
+            )),
+            XdgError::SocketPathTooLong { path, max_len } => formatter.write_fmt(format_args!(
+                "The socket path `{path}` exceeds the maximum socket path length of {max_len} \
+                 bytes",
+                path = path.display()
+            )),
+            XdgError::InvalidAppName { name } => formatter.write_fmt(format_args!(
+                "Invalid application name {name:?}, must be a non-empty path component other \
+                 than `.` and `..`, without path separators and NUL bytes",
+            )),
+            XdgError::InvalidHostName { origin, hostname } if hostname.is_empty() => {
+                formatter.write_fmt(format_args!("Host name not available through `{origin}`"))
+            },
+            XdgError::InvalidHostName { origin, hostname } => formatter.write_fmt(format_args!(
+                "Invalid host name {hostname:?} returned by `{origin}`, must be a single path \
+                 component",
+            )),
+            XdgError::InvalidConfig { path, message } => formatter.write_fmt(format_args!(
+                "Invalid configuration file `{path}`: {message}",
+                path = path.display()
+            )),
+            XdgError::Unsupported => formatter
+                .write_str("XDG Base Directories are not supported on the target platform"),
+            // The underlying I/O error is exposed through `Error::source`, so that
+            // error reporters don't print it twice.
+            XdgError::Io { path, .. } => formatter.write_fmt(format_args!(
+                "I/O error while accessing `{path}`",
+                path = path.display()
+            )),
+        }
+    }
+}
+
+impl error::Error for XdgError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            XdgError::Io { source, .. } => Some(source),
+            _ => None,
         }
     }
 }
 
-impl error::Error for XdgError {}
+/// Deserialization counterpart of [`XdgError`], owning the environment
+/// variable keys until mapped back onto the known `&'static str` keys.
+///
+/// Deriving `Deserialize` on [`XdgError`] would borrow its `&'static str`
+/// fields from the input, restricting deserialization to `'static` data.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "XdgError")]
+enum XdgErrorDe {
+    HomeNotFound,
+    RelativePath {
+        env_var_key: String,
+        path: PathBuf,
+    },
+    InvalidUnicode {
+        env_var_key: String,
+        env_var_val: OsString,
+    },
+    InvalidPathList {
+        env_var_key: String,
+        path: PathBuf,
+    },
+    FileNotFound {
+        file: PathBuf,
+        #[serde(default)]
+        searched: Vec<PathBuf>,
+    },
+    DanglingSymlink {
+        path: PathBuf,
+    },
+    RuntimeDirNotSet,
+    InsecureRuntimeDir {
+        path: PathBuf,
+    },
+    SocketPathTooLong {
+        path: PathBuf,
+        max_len: usize,
+    },
+    InvalidAppName {
+        name: String,
+    },
+    InvalidHostName {
+        origin: String,
+        hostname: String,
+    },
+    InvalidConfig {
+        path: PathBuf,
+        message: String,
+    },
+    Unsupported,
+    Io {
+        path: PathBuf,
+        #[serde(deserialize_with = "crate::serde_helpers::deserialize_io_error")]
+        source: io::Error,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for XdgError {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use crate::serde_helpers::{env_var_key, host_name_origin};
+
+        Ok(match XdgErrorDe::deserialize(deserializer)? {
+            XdgErrorDe::HomeNotFound => XdgError::HomeNotFound,
+            XdgErrorDe::RelativePath { env_var_key: key, path } => {
+                XdgError::RelativePath { env_var_key: env_var_key(&key)?, path }
+            },
+            XdgErrorDe::InvalidUnicode { env_var_key: key, env_var_val } => {
+                XdgError::InvalidUnicode { env_var_key: env_var_key(&key)?, env_var_val }
+            },
+            XdgErrorDe::InvalidPathList { env_var_key: key, path } => {
+                XdgError::InvalidPathList { env_var_key: env_var_key(&key)?, path }
+            },
+            XdgErrorDe::FileNotFound { file, searched } => {
+                XdgError::FileNotFound { file, searched }
+            },
+            XdgErrorDe::DanglingSymlink { path } => XdgError::DanglingSymlink { path },
+            XdgErrorDe::RuntimeDirNotSet => XdgError::RuntimeDirNotSet,
+            XdgErrorDe::InsecureRuntimeDir { path } => XdgError::InsecureRuntimeDir { path },
+            XdgErrorDe::SocketPathTooLong { path, max_len } => {
+                XdgError::SocketPathTooLong { path, max_len }
+            },
+            XdgErrorDe::InvalidAppName { name } => XdgError::InvalidAppName { name },
+            XdgErrorDe::InvalidHostName { origin, hostname } => {
+                XdgError::InvalidHostName { origin: host_name_origin(&origin)?, hostname }
+            },
+            XdgErrorDe::InvalidConfig { path, message } => {
+                XdgError::InvalidConfig { path, message }
+            },
+            XdgErrorDe::Unsupported => XdgError::Unsupported,
+            XdgErrorDe::Io { path, source } => XdgError::Io { path, source },
+        })
+    }
+}
+
+impl From<XdgError> for io::Error {
+    /// Converts an [`XdgError`] into an [`io::Error`] of the matching
+    /// [`io::ErrorKind`], wrapping the original error so that it can be
+    /// retrieved through [`io::Error::into_inner`].
+    fn from(err: XdgError) -> Self {
+        let kind = match &err {
+            XdgError::HomeNotFound
+            | XdgError::FileNotFound { .. }
+            | XdgError::DanglingSymlink { .. }
+            | XdgError::RuntimeDirNotSet => io::ErrorKind::NotFound,
+            XdgError::RelativePath { .. }
+            | XdgError::InvalidPathList { .. }
+            | XdgError::SocketPathTooLong { .. }
+            | XdgError::InvalidAppName { .. } => io::ErrorKind::InvalidInput,
+            XdgError::InvalidUnicode { .. }
+            | XdgError::InvalidHostName { .. }
+            | XdgError::InvalidConfig { .. } => io::ErrorKind::InvalidData,
+            XdgError::InsecureRuntimeDir { .. } => io::ErrorKind::PermissionDenied,
+            XdgError::Unsupported => io::ErrorKind::Unsupported,
+            XdgError::Io { source, .. } => source.kind(),
+        };
+
+        io::Error::new(kind, err)
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -61,8 +391,8 @@ mod test {
     #[test]
     fn display_error() -> Result<(), Box<dyn Error>> {
         assert_eq!(
-            "Unable to retrieve user's home directory, neither HOME nor USER environment variable \
-             set",
+            "Unable to retrieve user's home directory, none of HOME, USER/LOGNAME, USERPROFILE or \
+             HOMEDRIVE and HOMEPATH environment variables set, nor found in the user database",
             XdgError::HomeNotFound.to_string()
         );
         assert_eq!(
@@ -83,7 +413,173 @@ mod test {
             }
             .to_string(),
         );
+        assert_eq!(
+            "The `/usr/share:/opt/share` directory contains the path list separator, hence it \
+             cannot be joined into the `XDG_DATA_DIRS` environment variable",
+            XdgError::InvalidPathList {
+                env_var_key: "XDG_DATA_DIRS",
+                path: PathBuf::from("/usr/share:/opt/share"),
+            }
+            .to_string(),
+        );
+        assert_eq!(
+            "Unable to find `file` inside XDG directories",
+            XdgError::FileNotFound { file: PathBuf::from("file"), searched: Vec::new() }
+                .to_string(),
+        );
+        assert_eq!(
+            "Unable to find `file` inside XDG directories, searched: `/home/user/.config`, \
+             `/etc/xdg`",
+            XdgError::FileNotFound {
+                file: PathBuf::from("file"),
+                searched: vec![PathBuf::from("/home/user/.config"), PathBuf::from("/etc/xdg")],
+            }
+            .to_string(),
+        );
+        assert_eq!(
+            "The symbolic link `/home/user/.config/file` points to a missing file",
+            XdgError::DanglingSymlink { path: PathBuf::from("/home/user/.config/file") }
+                .to_string(),
+        );
+        assert_eq!(
+            "Unable to locate the runtime directory, the `XDG_RUNTIME_DIR` environment variable \
+             is not set",
+            XdgError::RuntimeDirNotSet.to_string(),
+        );
+        assert_eq!(
+            "The runtime directory `/run/user/1000` must be owned by the user and have access \
+             mode 0700",
+            XdgError::InsecureRuntimeDir { path: PathBuf::from("/run/user/1000") }.to_string(),
+        );
+        assert_eq!(
+            "The socket path `/run/user/1000/app_name/control.sock` exceeds the maximum socket \
+             path length of 107 bytes",
+            XdgError::SocketPathTooLong {
+                path: PathBuf::from("/run/user/1000/app_name/control.sock"),
+                max_len: 107,
+            }
+            .to_string(),
+        );
+        assert_eq!(
+            "Invalid application name \"../app\", must be a non-empty path component other than \
+             `.` and `..`, without path separators and NUL bytes",
+            XdgError::InvalidAppName { name: String::from("../app") }.to_string(),
+        );
+        assert_eq!(
+            "Host name not available through `gethostname`",
+            XdgError::InvalidHostName { origin: "gethostname", hostname: String::new() }
+                .to_string(),
+        );
+        assert_eq!(
+            "Invalid host name \"../host\" returned by `gethostname`, must be a single path \
+             component",
+            XdgError::InvalidHostName { origin: "gethostname", hostname: String::from("../host") }
+                .to_string(),
+        );
+        assert_eq!(
+            "Invalid configuration file `/home/user/.config/app_name/config`: missing key",
+            XdgError::InvalidConfig {
+                path: PathBuf::from("/home/user/.config/app_name/config"),
+                message: String::from("missing key"),
+            }
+            .to_string(),
+        );
+        assert_eq!(
+            "XDG Base Directories are not supported on the target platform",
+            XdgError::Unsupported.to_string(),
+        );
+        assert_eq!(
+            "I/O error while accessing `/home/user/.config/file`",
+            XdgError::Io {
+                path: PathBuf::from("/home/user/.config/file"),
+                source: io::Error::new(io::ErrorKind::PermissionDenied, "permission denied"),
+            }
+            .to_string(),
+        );
 
         Ok(())
     }
+
+    #[test]
+    fn error_source() {
+        assert!(XdgError::HomeNotFound.source().is_none());
+        let err = XdgError::FileNotFound { file: PathBuf::from("file"), searched: Vec::new() };
+        assert!(err.source().is_none());
+
+        let err = XdgError::Io {
+            path: PathBuf::from("/home/user/.config/file"),
+            source: io::Error::new(io::ErrorKind::PermissionDenied, "permission denied"),
+        };
+        let source = err.source().and_then(|source| source.downcast_ref::<io::Error>());
+        assert_eq!(Some(io::ErrorKind::PermissionDenied), source.map(io::Error::kind));
+    }
+
+    #[test]
+    fn into_io_error() {
+        let errors = [
+            (io::ErrorKind::NotFound, XdgError::HomeNotFound),
+            (
+                io::ErrorKind::InvalidInput,
+                XdgError::RelativePath {
+                    env_var_key: "XDG_CONFIG_HOME",
+                    path: PathBuf::from("./config"),
+                },
+            ),
+            (
+                io::ErrorKind::InvalidData,
+                XdgError::InvalidUnicode {
+                    env_var_key: "XDG_CONFIG_HOME",
+                    env_var_val: OsStr::from_bytes(&INVALID_UNICODE_BYTES).to_os_string(),
+                },
+            ),
+            (
+                io::ErrorKind::InvalidInput,
+                XdgError::InvalidPathList {
+                    env_var_key: "XDG_DATA_DIRS",
+                    path: PathBuf::from("/usr/share:/opt/share"),
+                },
+            ),
+            (
+                io::ErrorKind::NotFound,
+                XdgError::FileNotFound { file: PathBuf::from("file"), searched: Vec::new() },
+            ),
+            (io::ErrorKind::NotFound, XdgError::DanglingSymlink { path: PathBuf::from("file") }),
+            (io::ErrorKind::NotFound, XdgError::RuntimeDirNotSet),
+            (
+                io::ErrorKind::PermissionDenied,
+                XdgError::InsecureRuntimeDir { path: PathBuf::from("/run/user/1000") },
+            ),
+            (
+                io::ErrorKind::InvalidInput,
+                XdgError::SocketPathTooLong { path: PathBuf::from("control.sock"), max_len: 0 },
+            ),
+            (io::ErrorKind::InvalidInput, XdgError::InvalidAppName { name: String::new() }),
+            (
+                io::ErrorKind::InvalidData,
+                XdgError::InvalidHostName { origin: "COMPUTERNAME", hostname: String::new() },
+            ),
+            (
+                io::ErrorKind::InvalidData,
+                XdgError::InvalidConfig { path: PathBuf::from("config"), message: String::new() },
+            ),
+            (io::ErrorKind::Unsupported, XdgError::Unsupported),
+            (
+                io::ErrorKind::PermissionDenied,
+                XdgError::Io {
+                    path: PathBuf::from("/home/user/.config/file"),
+                    source: io::Error::new(io::ErrorKind::PermissionDenied, "permission denied"),
+                },
+            ),
+        ];
+
+        for (kind, err) in errors {
+            let message = err.to_string();
+            let io_err = io::Error::from(err);
+            assert_eq!(kind, io_err.kind());
+            assert_eq!(message, io_err.to_string());
+
+            let inner = io_err.into_inner().and_then(|inner| inner.downcast::<XdgError>().ok());
+            assert!(inner.is_some());
+        }
+    }
 }
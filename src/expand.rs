@@ -0,0 +1,89 @@
+use std::env;
+
+/// Returns `true` if `c` may appear in a variable name.
+#[inline]
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Expands `$VAR` and `${VAR}` references inside `value`, resolving each
+/// variable name through `lookup`.
+///
+/// References to variables `lookup` cannot resolve, as well as malformed
+/// references (e.g. an unterminated `${`), are left untouched.
+pub(crate) fn expand_with<F>(value: &str, lookup: F) -> String
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find('$') {
+        expanded.push_str(&rest[..start]);
+        let reference = &rest[start + 1..];
+
+        let (name, len) = match reference.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            },
+            None => {
+                let end = reference.find(|c| !is_name_char(c)).unwrap_or(reference.len());
+                (&reference[..end], end)
+            },
+        };
+
+        let valid = !name.is_empty()
+            && name.chars().all(is_name_char)
+            && !name.starts_with(|c: char| c.is_ascii_digit());
+        match valid.then(|| lookup(name)).flatten() {
+            Some(value) => expanded.push_str(&value),
+            None => expanded.push_str(&rest[start..=start + len]),
+        }
+
+        rest = &reference[len..];
+    }
+
+    expanded.push_str(rest);
+    expanded
+}
+
+/// Expands `$VAR` and `${VAR}` references inside `value`, resolving `HOME`
+/// to `home` and any other variable through the process environment.
+pub(crate) fn expand(value: &str, home: Option<&str>) -> String {
+    expand_with(value, |name| match name {
+        "HOME" => home.map(str::to_owned).or_else(|| env::var(name).ok()),
+        _ => env::var(name).ok(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn expand_vars() {
+        let lookup = |name: &str| match name {
+            "HOME" => Some(String::from("/home/user")),
+            "USER" => Some(String::from("user")),
+            "XDG_VAR" => Some(String::from("/xdg")),
+            _ => None,
+        };
+
+        assert_eq!("/home/user/.config", expand_with("$HOME/.config", lookup));
+        assert_eq!("/home/user/.config", expand_with("${HOME}/.config", lookup));
+        assert_eq!("/home/user/.cache", expand_with("/home/$USER/.cache", lookup));
+        assert_eq!("/xdg/user:/xdg", expand_with("${XDG_VAR}/$USER:$XDG_VAR", lookup));
+        assert_eq!("/no/vars", expand_with("/no/vars", lookup));
+
+        // Unresolved and malformed references are left untouched.
+        assert_eq!("$UNSET/dir", expand_with("$UNSET/dir", lookup));
+        assert_eq!("${UNSET}/dir", expand_with("${UNSET}/dir", lookup));
+        assert_eq!("/dir/$", expand_with("/dir/$", lookup));
+        assert_eq!("/dir/$/sub", expand_with("/dir/$/sub", lookup));
+        assert_eq!("${HOME/dir", expand_with("${HOME/dir", lookup));
+        assert_eq!("${}/dir", expand_with("${}/dir", lookup));
+        assert_eq!("${HO ME}/dir", expand_with("${HO ME}/dir", lookup));
+        assert_eq!("$1/dir", expand_with("$1/dir", lookup));
+    }
+}
@@ -0,0 +1,99 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Returns `true` if `name` matches `pattern`, where:
+/// - `*` matches any (possibly empty) sequence of characters;
+/// - `?` matches exactly one character;
+/// - any other character matches itself.
+pub(crate) fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` in the pattern and of the matching character in
+    // the name, used to backtrack on mismatches.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            },
+            Some('?') => {
+                p += 1;
+                n += 1;
+            },
+            Some(&c) if c == name[n] => {
+                p += 1;
+                n += 1;
+            },
+            _ => match backtrack {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    backtrack = Some((star_p, star_n + 1));
+                },
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns `true` if the path component contains wildcards.
+#[inline]
+fn is_pattern(component: &str) -> bool {
+    component.contains(['*', '?'])
+}
+
+/// Returns the entries of `dir` whose name matches `component`, sorted by
+/// name. Unreadable directories and non-unicode names are skipped.
+fn expand(dir: &Path, component: &str) -> Vec<PathBuf> {
+    if !is_pattern(component) {
+        let path = dir.join(component);
+        return if path.exists() { vec![path] } else { Vec::new() };
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_str().is_some_and(|name| matches(component, name)))
+        .map(|entry| entry.path())
+        .collect();
+    paths.sort_unstable();
+    paths
+}
+
+/// Returns the regular files inside `base` matching the `/` separated
+/// `pattern`, sorted by path.
+pub(crate) fn glob(base: PathBuf, pattern: &str) -> Vec<PathBuf> {
+    let mut paths = vec![base];
+    for component in pattern.split('/').filter(|component| !component.is_empty()) {
+        paths = paths.iter().flat_map(|path| expand(path, component)).collect();
+    }
+
+    paths.retain(|path| path.is_file());
+    paths
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn glob_matches() {
+        assert!(matches("*.toml", "dark.toml"));
+        assert!(matches("*.toml", ".toml"));
+        assert!(matches("d?rk.*", "dark.toml"));
+        assert!(matches("*a*a*", "banana"));
+        assert!(matches("*", ""));
+        assert!(!matches("*.toml", "dark.yml"));
+        assert!(!matches("d?rk", "drk"));
+        assert!(!matches("dark", "darker"));
+    }
+}
@@ -50,15 +50,109 @@
 #![deny(rustdoc::invalid_html_tags)]
 #![deny(rustdoc::invalid_rust_codeblocks)]
 
+/// Emits a [`tracing`](https://docs.rs/tracing) debug event if the `tracing`
+/// feature is enabled, expanding to nothing otherwise.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
 mod app;
+mod autostart;
+mod builder;
+mod cache;
+#[cfg(feature = "serde")]
+mod config;
+#[cfg(feature = "fs")]
+mod desktop;
+mod desktop_env;
+mod diagnostics;
+#[cfg(feature = "desktop-entry")]
+mod desktop_entry;
 mod error;
+mod expand;
+#[cfg(feature = "glob")]
+mod glob;
+#[cfg(feature = "fs")]
+mod lock;
+mod mime;
+#[cfg(feature = "mime-glob")]
+mod mime_glob;
+mod options;
+#[cfg(all(unix, feature = "passwd"))]
+mod passwd;
+mod paths;
+#[cfg(feature = "fs")]
+mod recent;
+mod sandbox;
+mod search_cache;
+#[cfg(feature = "serde")]
+mod serde_helpers;
+mod session;
+#[cfg(all(unix, feature = "fs"))]
+mod sys;
+mod thumbnail;
+#[cfg(feature = "fs")]
+mod trash;
+#[cfg(feature = "async")]
+mod unblock;
+#[cfg(feature = "watch")]
+mod watch;
 
-use std::env;
+use std::borrow::Cow;
+use std::collections::HashSet;
 use std::env::VarError;
+use std::ffi::{OsStr, OsString};
+#[cfg(feature = "fs")]
+use std::fs::{File, OpenOptions};
+use std::{env, fmt, iter};
+#[cfg(feature = "fs")]
+use std::{fs, io};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 
 pub use app::XdgApp;
+pub use autostart::AutostartEntry;
+pub use builder::XdgBuilder;
+#[cfg(feature = "serde")]
+pub use config::ConfigFormat;
+#[cfg(feature = "json")]
+pub use config::Json;
+#[cfg(feature = "toml")]
+pub use config::Toml;
+#[cfg(feature = "yaml")]
+pub use config::Yaml;
+pub use desktop_env::DesktopEnvironment;
+pub use diagnostics::{VarDiagnostics, VarState, XdgDiagnostics};
+#[cfg(feature = "desktop-entry")]
+pub use desktop_entry::DesktopEntry;
 pub use error::XdgError;
+#[cfg(feature = "fs")]
+pub use lock::FileLock;
+#[cfg(feature = "mime-glob")]
+pub use mime_glob::MimeGlobs;
+pub use options::{
+    EntryType, InstallOptions, PruneOptions, RelativePathPolicy, RemoveOptions, SaveOptions,
+    Scope, SearchOrder, Shell, SudoPolicy, SymlinkPolicy,
+};
+pub use paths::{XdgAppDirs, XdgPaths};
+#[cfg(feature = "fs")]
+pub use recent::RecentFile;
+pub use sandbox::{Sandbox, SandboxKind};
+pub use session::{SessionClass, SessionType};
+pub use thumbnail::ThumbnailSize;
+#[cfg(feature = "fs")]
+pub use trash::TrashEntry;
+#[cfg(feature = "async")]
+pub use unblock::Unblock;
+#[cfg(feature = "watch")]
+pub use watch::ConfigWatcher;
+
+/// Whether the target platform provides the environment XDG Base Directories
+/// are resolved out of; `wasm32-unknown-unknown` and the like don't.
+const SUPPORTED_TARGET: bool = !cfg!(all(target_family = "wasm", not(target_os = "wasi")));
 
 trait Append {
     fn append<P>(self, path: P) -> Self
@@ -77,13 +171,71 @@ impl Append for PathBuf {
     }
 }
 
-/// XDG Base Directory Specification's directories.
-#[derive(Debug, Clone, Copy)]
-enum XdgDir {
+trait AppendLen {
+    /// Returns the number of bytes required to append the path to another
+    /// one, separator included.
+    fn append_len(&self) -> usize;
+}
+
+impl AppendLen for Path {
+    #[inline]
+    fn append_len(&self) -> usize {
+        self.as_os_str().len() + 1
+    }
+}
+
+/// Returns a predicate retaining only the first occurrence of each path, used
+/// to deduplicate _system-wide_ directory lists while preserving their order.
+fn first_occurrence() -> impl FnMut(&PathBuf) -> bool {
+    let mut seen = HashSet::new();
+    move |path| seen.insert(path.clone())
+}
+
+/// Returns `path` only if it is an existing directory.
+#[cfg(feature = "fs")]
+pub(crate) fn existing_dir(path: PathBuf) -> Option<PathBuf> {
+    path.is_dir().then_some(path)
+}
+
+/// Returns `true` if the entry with the given `metadata` can be executed, i.e.
+/// has any of the execute permission bits set on Unix.
+#[cfg(feature = "fs")]
+fn is_executable(_path: &Path, metadata: &fs::Metadata) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        metadata.is_file()
+    }
+}
+
+/// XDG Base Directory Specification's _user-specific_ directories, selecting
+/// the directory resolved by the generic [`Xdg::dir`] and [`Xdg::file`]
+/// methods.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::path::PathBuf;
+/// # use microxdg::{Xdg, XdgDir, XdgError};
+/// fn log_file(xdg: &Xdg, dir: XdgDir) -> Result<PathBuf, XdgError> {
+///     xdg.file(dir, "app_name/log")
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum XdgDir {
+    /// **Cache** directory (`XDG_CACHE_HOME`).
     Cache,
+    /// **Configuration** directory (`XDG_CONFIG_HOME`).
     Config,
+    /// **Data** directory (`XDG_DATA_HOME`).
     Data,
+    /// **State** directory (`XDG_STATE_HOME`).
     State,
+    /// **Executable** directory (`XDG_BIN_HOME`).
     Bin,
 }
 
@@ -93,7 +245,7 @@ impl XdgDir {
     /// Returns the XDG environment variable associated to the XDG base
     /// directory.
     #[inline]
-    fn env_var(self) -> &'static str {
+    const fn env_var(self) -> &'static str {
         match self {
             XdgDir::Cache => "XDG_CACHE_HOME",
             XdgDir::Config => "XDG_CONFIG_HOME",
@@ -103,10 +255,21 @@ impl XdgDir {
         }
     }
 
-    /// Returns the _user-specific_ fallback directory in the case the XDG
-    /// environment variable is not set.
+    /// Returns the built-in _user-specific_ fallback directory, relative to
+    /// the home directory, used in the case the XDG environment variable is
+    /// not set or is set to an empty value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::XdgDir;
+    /// const CONFIG_FALLBACK: &str = XdgDir::Config.fallback();
+    /// assert_eq!(".config", CONFIG_FALLBACK);
+    /// assert_eq!(".local/share", XdgDir::Data.fallback());
+    /// ```
     #[inline]
-    fn fallback(self) -> &'static str {
+    #[must_use]
+    pub const fn fallback(self) -> &'static str {
         match self {
             XdgDir::Cache => ".cache",
             XdgDir::Config => ".config",
@@ -116,8 +279,33 @@ impl XdgDir {
         }
     }
 
+    /// Returns the built-in absolute fallback directory used in
+    /// [`Scope::System`], in the case the XDG environment variable is not set
+    /// or is set to an empty value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::XdgDir;
+    /// assert_eq!("/var/lib", XdgDir::State.system_fallback());
+    /// assert_eq!("/usr/local/share", XdgDir::Data.system_fallback());
+    /// assert_eq!("/etc", XdgDir::Config.system_fallback());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn system_fallback(self) -> &'static str {
+        match self {
+            XdgDir::Cache => "/var/cache",
+            XdgDir::Config => "/etc",
+            XdgDir::Data => "/usr/local/share",
+            XdgDir::State => "/var/lib",
+            XdgDir::Bin => "/usr/local/bin",
+        }
+    }
+
     /// Returns the associated variant of [`XdgSysDirs`].
     #[inline]
+    #[cfg(feature = "fs")]
     fn to_sys(self) -> Option<XdgSysDirs> {
         match self {
             XdgDir::Cache | XdgDir::State | XdgDir::Bin => None,
@@ -127,16 +315,20 @@ impl XdgDir {
     }
 }
 
-/// XDG Base Directory Specification's _system-wide_ directories.
-#[derive(Debug, Clone, Copy)]
-enum XdgSysDirs {
+/// XDG Base Directory Specification's _system-wide_ directories, selecting
+/// the directories resolved by the generic [`Xdg::sys_dirs`] method.
+///
+/// Directory lists are split through [`env::split_paths`], so that the
+/// separator follows the platform convention, i.e. `:` on Unix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum XdgSysDirs {
+    /// **Configuration** directories (`XDG_CONFIG_DIRS`).
     Config,
+    /// **Data** directories (`XDG_DATA_DIRS`).
     Data,
 }
 
 impl XdgSysDirs {
-    const SEPARATOR: char = ':';
-
     /// Returns the XDG environment variable associated to the XDG base
     /// directories.
     fn env_var(self) -> &'static str {
@@ -146,16 +338,131 @@ impl XdgSysDirs {
         }
     }
 
-    /// Returns the fallback directories in the case the XDG environment
-    /// variable is not set.
+    /// Returns the built-in, preference-ordered, fallback directories used in
+    /// the case the XDG environment variable is not set or is set to an empty
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::XdgSysDirs;
+    /// const CONFIG_FALLBACK: &[&str] = XdgSysDirs::Config.fallback();
+    /// assert_eq!(["/etc/xdg"], CONFIG_FALLBACK);
+    /// assert_eq!(["/usr/local/share", "/usr/share"], XdgSysDirs::Data.fallback());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn fallback(self) -> &'static [&'static str] {
+        match self {
+            XdgSysDirs::Config => &["/etc/xdg"],
+            XdgSysDirs::Data => &["/usr/local/share", "/usr/share"],
+        }
+    }
+
+    /// Returns the built-in fallback directories as paths.
+    #[inline]
+    fn fallback_paths(self) -> impl Iterator<Item = PathBuf> {
+        self.fallback().iter().map(PathBuf::from)
+    }
+}
+
+/// Labels of the XDG Base Directory Specification's _user-specific_
+/// directories, including the runtime directory, yielded by [`Xdg::dirs`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{Xdg, XdgDir, XdgDirKind};
+/// assert_eq!("config", XdgDirKind::Config.name());
+/// assert_eq!("XDG_RUNTIME_DIR", XdgDirKind::Runtime.env_var());
+/// assert_eq!(Some(XdgDir::Data), XdgDirKind::Data.to_dir());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum XdgDirKind {
+    /// **Cache** directory (`XDG_CACHE_HOME`).
+    Cache,
+    /// **Configuration** directory (`XDG_CONFIG_HOME`).
+    Config,
+    /// **Data** directory (`XDG_DATA_HOME`).
+    Data,
+    /// **State** directory (`XDG_STATE_HOME`).
+    State,
+    /// **Executable** directory (`XDG_BIN_HOME`).
+    Bin,
+    /// **Runtime** directory (`XDG_RUNTIME_DIR`).
+    Runtime,
+}
+
+impl XdgDirKind {
+    /// Every XDG base directory label, in the order yielded by
+    /// [`Xdg::dirs`].
+    pub const ALL: [XdgDirKind; 6] = [
+        XdgDirKind::Cache,
+        XdgDirKind::Config,
+        XdgDirKind::Data,
+        XdgDirKind::State,
+        XdgDirKind::Bin,
+        XdgDirKind::Runtime,
+    ];
+
+    /// Returns the lowercase name of the XDG base directory, e.g. `config`.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            XdgDirKind::Cache => "cache",
+            XdgDirKind::Config => "config",
+            XdgDirKind::Data => "data",
+            XdgDirKind::State => "state",
+            XdgDirKind::Bin => "bin",
+            XdgDirKind::Runtime => "runtime",
+        }
+    }
+
+    /// Returns the XDG environment variable associated to the XDG base
+    /// directory.
+    #[inline]
+    #[must_use]
+    pub const fn env_var(self) -> &'static str {
+        match self.to_dir() {
+            Some(dir) => dir.env_var(),
+            None => XdgDir::RUNTIME_ENV_VAR,
+        }
+    }
+
+    /// Returns the associated [`XdgDir`], or `None` for the runtime directory.
     #[inline]
-    fn fallback(self) -> impl Iterator<Item = PathBuf> {
+    #[must_use]
+    pub const fn to_dir(self) -> Option<XdgDir> {
         match self {
-            XdgSysDirs::Config => ["/etc/xdg"].as_slice(),
-            XdgSysDirs::Data => ["/usr/local/share", "/usr/share"].as_slice(),
+            XdgDirKind::Cache => Some(XdgDir::Cache),
+            XdgDirKind::Config => Some(XdgDir::Config),
+            XdgDirKind::Data => Some(XdgDir::Data),
+            XdgDirKind::State => Some(XdgDir::State),
+            XdgDirKind::Bin => Some(XdgDir::Bin),
+            XdgDirKind::Runtime => None,
+        }
+    }
+}
+
+impl From<XdgDir> for XdgDirKind {
+    #[inline]
+    fn from(dir: XdgDir) -> Self {
+        match dir {
+            XdgDir::Cache => XdgDirKind::Cache,
+            XdgDir::Config => XdgDirKind::Config,
+            XdgDir::Data => XdgDirKind::Data,
+            XdgDir::State => XdgDirKind::State,
+            XdgDir::Bin => XdgDirKind::Bin,
         }
-        .iter()
-        .map(PathBuf::from)
+    }
+}
+
+impl fmt::Display for XdgDirKind {
+    #[inline]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.name())
     }
 }
 
@@ -235,10 +542,43 @@ impl XdgSysDirs {
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Xdg {
     /// Home directory of the user owning the process.
     home: PathBuf,
+    /// Policy governing XDG environment variables set to relative paths.
+    relative_path_policy: RelativePathPolicy,
+    /// Whether XDG environment variables set to non-unicode values are
+    /// accepted.
+    non_unicode: bool,
+    /// Whether variable references inside XDG environment variables are
+    /// expanded.
+    expand_vars: bool,
+    /// Whether behaviors diverging from the letter of the specification are
+    /// disabled.
+    #[cfg_attr(feature = "serde", serde(default))]
+    strict: bool,
+    /// Whether paths returned by the `search_*` methods are canonicalized.
+    #[cfg_attr(feature = "serde", serde(default))]
+    canonicalize: bool,
+    /// Policy governing symbolic links found by the `search_*` methods.
+    #[cfg_attr(feature = "serde", serde(default))]
+    symlink_policy: SymlinkPolicy,
+    /// Order in which the `search_*` methods search the XDG directories.
+    #[cfg_attr(feature = "serde", serde(default))]
+    search_order: SearchOrder,
+    /// Scope selecting the fallback _user-specific_ directories.
+    #[cfg_attr(feature = "serde", serde(default))]
+    scope: Scope,
+    /// Cache of the resolved directories, if enabled through
+    /// [`Xdg::with_caching`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cache: Option<Box<cache::DirCache>>,
+    /// Directories overriding the XDG environment variables, set through
+    /// [`XdgBuilder`].
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    overrides: Option<Box<builder::DirOverrides>>,
 }
 
 impl Xdg {
@@ -249,808 +589,1057 @@ impl Xdg {
     where
         P: Into<PathBuf>,
     {
-        Xdg { home: home.into() }
+        Xdg {
+            home: home.into(),
+            relative_path_policy: RelativePathPolicy::Error,
+            non_unicode: false,
+            expand_vars: false,
+            strict: false,
+            canonicalize: false,
+            symlink_policy: SymlinkPolicy::Follow,
+            search_order: SearchOrder::UserFirst,
+            scope: Scope::User,
+            cache: None,
+            overrides: None,
+        }
     }
 
-    /// Constructs a new [`Xdg`] instance.
+    /// Sets the [`RelativePathPolicy`] governing XDG environment variables set
+    /// to relative paths. Defaults to [`RelativePathPolicy::Error`].
     ///
-    /// # Errors
+    /// # Note
     ///
-    /// This function returns an error if neither `HOME` or `USER` environment
-    /// variable is set.
-    pub fn new() -> Result<Xdg, XdgError> {
-        if let Ok(home) = env::var("HOME") {
-            return Ok(Xdg::with_home(home));
-        }
-
-        if let Ok(user) = env::var("USER") {
-            return Ok(Xdg::with_home(format!("/home/{user}")));
-        }
-
-        Err(XdgError::HomeNotFound)
-    }
-
-    /// Constructs a new [`XdgApp`] instance.
+    /// The policy applies to every method taking `&self`, while associated
+    /// functions such as [`Xdg::sys_config`] and [`Xdg::sys_data`] always
+    /// behave as with [`RelativePathPolicy::Error`].
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// This function returns an error if neither `HOME` or `USER` environment
-    /// variable is set.
-    pub fn new_app(app_name: &'static str) -> Result<XdgApp, XdgError> {
-        XdgApp::new(app_name)
-    }
-
-    /// Returns the **home** directory of the user owning the process.
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{RelativePathPolicy, Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// std::env::set_var("XDG_DATA_HOME", ".local/share");
+    ///
+    /// let xdg = Xdg::with_home("/home/user")
+    ///     .with_relative_path_policy(RelativePathPolicy::ResolveAgainstHome);
+    /// assert_eq!(PathBuf::from("/home/user/.local/share"), xdg.data()?);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
     #[must_use]
-    pub fn home(&self) -> &Path {
-        &self.home
+    pub fn with_relative_path_policy(mut self, policy: RelativePathPolicy) -> Xdg {
+        self.relative_path_policy = policy;
+        self.clear_cache();
+        self
     }
 
-    /// Returns a validated path from an XDG environment variable.
+    /// Sets whether XDG environment variables set to non-unicode values are
+    /// accepted. Defaults to `false`.
     ///
-    /// # Errors
+    /// Paths on Unix need not be valid UTF-8: when enabled, XDG environment
+    /// variables are read as [`OsString`]s and turned into paths as they are,
+    /// instead of failing with [`XdgError::InvalidUnicode`].
     ///
-    /// This function returns an error if the XDG environment variable is set,
-    /// but its value represents a relative path: XDG environment variables must
-    /// be set to absolute paths.
+    /// # Note
+    ///
+    /// This setting applies to every method taking `&self`, while associated
+    /// functions such as [`Xdg::sys_config`] and [`Xdg::sys_data`] always
+    /// reject non-unicode values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::ffi::OsStr;
+    /// # use std::os::unix::ffi::OsStrExt;
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let config_home = OsStr::from_bytes(b"/home/user/.config-\xFF");
+    /// std::env::set_var("XDG_CONFIG_HOME", config_home);
+    ///
+    /// let xdg = Xdg::with_home("/home/user").with_non_unicode(true);
+    /// assert_eq!(PathBuf::from(config_home), xdg.config()?);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
-    fn validate_path<P>(env_var_key: &'static str, env_var_val: P) -> Result<PathBuf, XdgError>
-    where
-        P: Into<PathBuf>,
-    {
-        let path: PathBuf = env_var_val.into();
-        if path.is_relative() {
-            // XDG environment variable set, but its value represents a relative path.
-            return Err(XdgError::RelativePath { env_var_key, path });
-        }
+    #[must_use]
+    pub fn with_non_unicode(mut self, non_unicode: bool) -> Xdg {
+        self.non_unicode = non_unicode;
+        self.clear_cache();
+        self
+    }
 
-        Ok(path)
+    /// Returns whether XDG environment variables set to non-unicode values
+    /// are accepted.
+    #[inline]
+    #[must_use]
+    pub fn non_unicode(&self) -> bool {
+        self.non_unicode
     }
 
-    /// Returns the value of an XDG environment variable.
+    /// Sets whether `$VAR` and `${VAR}` references inside XDG environment
+    /// variables are expanded. Defaults to `false`.
+    ///
+    /// Login scripts and systemd environment files commonly produce values
+    /// such as `$HOME/.config`: when enabled, `$HOME` resolves to the home
+    /// directory of this instance, while any other variable (e.g. `$USER`) is
+    /// read from the process environment.
     ///
     /// # Note
     ///
-    /// This method returns:
-    /// - `Some` if the XDG environment variable is set;
-    /// - `None` if the XDG environment variable is missing or set to an empty
-    ///   value.
+    /// References to unset variables are left untouched, as are non-unicode
+    /// values.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// This method returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// std::env::set_var("XDG_CONFIG_HOME", "$HOME/.config");
+    ///
+    /// let xdg = Xdg::with_home("/home/user").with_expand_vars(true);
+    /// assert_eq!(PathBuf::from("/home/user/.config"), xdg.config()?);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
-    fn get_env_var(env_var_key: &'static str) -> Result<Option<String>, XdgError> {
-        match env::var(env_var_key) {
-            // XDG environment variable is set to a non-empty value.
-            Ok(env_var_val) if !env_var_val.is_empty() => Ok(Some(env_var_val)),
-            // XDG environment variable is set, but its value represents invalid unicode.
-            Err(VarError::NotUnicode(env_var_val)) => {
-                Err(XdgError::InvalidUnicode { env_var_key, env_var_val })
-            },
-            // XDG environment variable is not set or set to an empty value.
-            _ => Ok(None),
-        }
+    #[must_use]
+    pub fn with_expand_vars(mut self, expand_vars: bool) -> Xdg {
+        self.expand_vars = expand_vars;
+        self.clear_cache();
+        self
     }
 
-    /// Returns the path set to an XDG environment variable or a fallback in the
-    /// case the environment variable is not set or is set to an empty
-    /// value.
-    ///
-    /// # Errors
-    ///
-    /// This method returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
+    /// Returns whether variable references inside XDG environment variables
+    /// are expanded.
     #[inline]
-    fn get_dir_path(&self, dir: XdgDir) -> Result<PathBuf, XdgError> {
-        let env_var_key = dir.env_var();
-        match Xdg::get_env_var(env_var_key)? {
-            Some(env_var_val) => Xdg::validate_path(env_var_key, env_var_val),
-            None => Ok(self.home.join(dir.fallback())),
-        }
+    #[must_use]
+    pub fn expand_vars(&self) -> bool {
+        self.expand_vars
     }
 
-    /// Returns the _user-specific_ XDG **cache** directory specified by the
-    /// `XDG_CACHE_HOME` environment variable. Falls back to `$HOME/.cache`
-    /// if `XDG_CACHE_HOME` is not set or is set to an empty value.
+    /// Sets whether strict specification-compliance mode is enabled. Defaults
+    /// to `false`.
     ///
-    /// # Errors
+    /// In strict mode, [`Xdg::runtime`] (and every method built on top of it)
+    /// fails with [`XdgError::InsecureRuntimeDir`] unless the runtime
+    /// directory is owned by the user and has access mode `0700`, as mandated
+    /// by the specification. See [`Xdg::new_strict`] to also disable the
+    /// `/home/$USER` home directory guess.
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CACHE_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// # Note
     ///
-    /// # Exapmles
+    /// On platforms other than Unix, permissions are not checked.
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{Xdg, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let cache_dir = xdg.cache()?;
-    /// # Ok(())
-    /// # }
+    /// std::env::set_var("XDG_RUNTIME_DIR", "/tmp");
+    ///
+    /// let xdg = Xdg::with_home("/home/user").with_strict(true);
+    /// assert!(matches!(xdg.runtime(), Err(XdgError::InsecureRuntimeDir { .. })));
     /// ```
     #[inline]
-    pub fn cache(&self) -> Result<PathBuf, XdgError> {
-        self.get_dir_path(XdgDir::Cache)
+    #[must_use]
+    pub fn with_strict(mut self, strict: bool) -> Xdg {
+        self.strict = strict;
+        self.clear_cache();
+        self
     }
 
-    /// Returns the _user-specific_ XDG **configuration** directory specified by
-    /// the `XDG_CONFIG_HOME` environment variable. Falls back to
-    /// `$HOME/.config` if `XDG_CONFIG_HOME` is not set or is set to an
-    /// empty value.
-    ///
-    /// # Errors
+    /// Returns whether strict specification-compliance mode is enabled.
+    #[inline]
+    #[must_use]
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether paths returned by the `search_*` methods are
+    /// canonicalized, resolving symbolic links and `..` components. Defaults
+    /// to `false`.
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CONFIG_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// Canonical paths are stable: comparing them, or computing their parent
+    /// directories, gives the same results regardless of symbolic links
+    /// inside the XDG directories. When enabled, the `search_*` methods
+    /// return an [`XdgError::Io`] error if a found path can't be
+    /// canonicalized.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let config_dir = xdg.config()?;
+    /// let xdg = Xdg::new()?.with_canonicalize(true);
+    /// if let Some(config_file) = xdg.search_config_file("app_name/config.toml")? {
+    ///     assert_eq!(config_file, config_file.canonicalize().unwrap());
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn config(&self) -> Result<PathBuf, XdgError> {
-        self.get_dir_path(XdgDir::Config)
+    #[must_use]
+    #[cfg(feature = "fs")]
+    pub fn with_canonicalize(mut self, canonicalize: bool) -> Xdg {
+        self.canonicalize = canonicalize;
+        self.clear_cache();
+        self
     }
 
-    /// Returns the _user-specific_ XDG **data** directory specified by the
-    /// `XDG_DATA_HOME` environment variable. Falls back to
-    /// `$HOME/.local/share` if `XDG_DATA_HOME` is not set or is set to an
-    /// empty value.
-    ///
-    /// # Errors
-    ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_DATA_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_DATA_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// Returns whether paths returned by the `search_*` methods are
+    /// canonicalized.
+    #[inline]
+    #[must_use]
+    pub fn canonicalize(&self) -> bool {
+        self.canonicalize
+    }
+
+    /// Sets the [`SymlinkPolicy`] governing symbolic links found by the
+    /// `search_*` methods. Defaults to [`SymlinkPolicy::Follow`].
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
+    /// # use microxdg::{SymlinkPolicy, Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let data_dir = xdg.data()?;
+    /// // Only regular files count, symlinked configuration files are ignored.
+    /// let xdg = Xdg::new()?.with_symlink_policy(SymlinkPolicy::DontFollow);
+    /// let config_file = xdg.search_config_file("app_name/config.toml")?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn data(&self) -> Result<PathBuf, XdgError> {
-        self.get_dir_path(XdgDir::Data)
+    #[must_use]
+    #[cfg(feature = "fs")]
+    pub fn with_symlink_policy(mut self, policy: SymlinkPolicy) -> Xdg {
+        self.symlink_policy = policy;
+        self.clear_cache();
+        self
     }
 
-    /// Returns the _user-specific_ XDG **state** directory specified by the
-    /// `XDG_STATE_HOME` environment variable. Falls back to
-    /// `$HOME/.local/state` if `XDG_STATE_HOME` is not set or is set to an
-    /// empty value.
+    /// Returns the [`SymlinkPolicy`] in use.
+    #[inline]
+    #[must_use]
+    pub fn symlink_policy(&self) -> SymlinkPolicy {
+        self.symlink_policy
+    }
+
+    /// Sets the [`SearchOrder`] of the `search_*` methods returning the first
+    /// match. Defaults to [`SearchOrder::UserFirst`].
     ///
-    /// # Errors
+    /// # Note
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_STATE_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// The `search_*_files` methods, collecting every match, always return
+    /// the matches in precedence order, as mandated by the specification.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
+    /// # use microxdg::{SearchOrder, Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let state_dir = xdg.state()?;
+    /// // Policy files installed in `/etc/xdg` win over the user's overrides.
+    /// let xdg = Xdg::new()?.with_search_order(SearchOrder::SystemFirst);
+    /// let policy_file = xdg.search_config_file("app_name/policy.toml")?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn state(&self) -> Result<PathBuf, XdgError> {
-        self.get_dir_path(XdgDir::State)
+    #[must_use]
+    #[cfg(feature = "fs")]
+    pub fn with_search_order(mut self, order: SearchOrder) -> Xdg {
+        self.search_order = order;
+        self.clear_cache();
+        self
     }
 
-    /// Returns the _user-specific_ XDG **binary** directory specified by the
-    /// `XDG_BIN_HOME` environment variable. Falls back to
-    /// `$HOME/.local/bin` if `XDG_BIN_HOME` is not set or is set to an
-    /// empty value.
+    /// Returns the [`SearchOrder`] in use.
+    #[inline]
+    #[must_use]
+    pub fn search_order(&self) -> SearchOrder {
+        self.search_order
+    }
+
+    /// Sets the [`Scope`] selecting the fallback directories used when the
+    /// _user-specific_ XDG environment variables are not set. Defaults to
+    /// [`Scope::User`].
     ///
-    /// # Errors
+    /// # Note
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_BIN_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_BIN_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// The XDG environment variables, as well as the fallback directories set
+    /// through [`XdgBuilder`], still take precedence in [`Scope::System`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
+    /// # use std::path::Path;
+    /// # use microxdg::{Scope, Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let bin_dir = xdg.bin()?;
+    /// std::env::remove_var("XDG_CACHE_HOME");
+    /// std::env::remove_var("XDG_CONFIG_HOME");
+    ///
+    /// // A daemon running as `root` must not end up in `/root/.config`.
+    /// let xdg = Xdg::new()?.with_scope(Scope::System);
+    /// assert_eq!(Path::new("/var/cache"), xdg.cache()?);
+    /// assert_eq!(Path::new("/etc"), xdg.config()?);
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn bin(&self) -> Result<PathBuf, XdgError> {
-        self.get_dir_path(XdgDir::Bin)
+    #[must_use]
+    pub fn with_scope(mut self, scope: Scope) -> Xdg {
+        self.scope = scope;
+        self.clear_cache();
+        self
     }
 
-    /// Returns the XDG **runtime** directory specified by the `XDG_RUNTIME_DIR`
-    /// environment variable.
-    ///
-    /// # Note
+    /// Returns the [`Scope`] in use.
+    #[inline]
+    #[must_use]
+    pub fn scope(&self) -> Scope {
+        self.scope
+    }
+
+    /// Sets whether the XDG base directories are cached. Defaults to `false`.
     ///
-    /// This method returns:
-    /// - `Some` if the `XDG_RUNTIME_DIR` environment variable is set;
-    /// - `None` if the `XDG_RUNTIME_DIR` environment variable is not set or is
-    ///   set to an empty value.
+    /// When enabled, every directory is resolved out of the environment once,
+    /// while later calls return the cached directory without reading and
+    /// validating the XDG environment variables again. Directories failing to
+    /// resolve are not cached, so that the error is reported on each call.
+    /// Long-running processes can deliberately pick up changes of the
+    /// environment through [`Xdg::refresh`].
     ///
-    /// # Errors
+    /// # Note
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_RUNTIME_DIR` environment is set, but its value represents
-    ///   invalid unicode.
+    /// The cache is used by every method resolving the directories, and is
+    /// filled lazily, one directory at a time. Every other setting clears it,
+    /// since the cached directories depend on them. Instances caching
+    /// different directories compare unequal, while the cache is neither
+    /// hashed nor serialized.
     ///
     /// # Examples
     ///
     /// ```rust
+    /// # use std::path::Path;
     /// # use microxdg::{Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// match xdg.runtime()? {
-    ///     Some(runtime_dir) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
+    /// std::env::set_var("XDG_CONFIG_HOME", "/home/user/.config");
+    ///
+    /// let mut xdg = Xdg::new()?.with_caching(true);
+    /// assert_eq!(Path::new("/home/user/.config"), xdg.config()?);
+    ///
+    /// std::env::set_var("XDG_CONFIG_HOME", "/home/user/.other");
+    /// assert_eq!(Path::new("/home/user/.config"), xdg.config()?);
+    ///
+    /// xdg.refresh();
+    /// assert_eq!(Path::new("/home/user/.other"), xdg.config()?);
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn runtime(&self) -> Result<Option<PathBuf>, XdgError> {
-        Xdg::get_env_var(XdgDir::RUNTIME_ENV_VAR)?
-            .map(|env_var_val| Xdg::validate_path(XdgDir::RUNTIME_ENV_VAR, env_var_val))
-            .transpose()
+    #[must_use]
+    pub fn with_caching(mut self, caching: bool) -> Xdg {
+        self.cache = caching.then(Box::default);
+        self
     }
 
-    /// Returns an iterator over the _sistem-wide_ directories set to a system
-    /// XDG environment variable.
+    /// Returns whether the XDG base directories are cached.
     #[inline]
-    fn iter_sys_dir_paths<'val>(
-        env_var_key: &'static str,
-        env_var_val: &'val str,
-    ) -> impl Iterator<Item = Result<PathBuf, XdgError>> + 'val {
-        env_var_val
-            .split(XdgSysDirs::SEPARATOR)
-            .map(move |path| Xdg::validate_path(env_var_key, path))
+    #[must_use]
+    pub fn caching(&self) -> bool {
+        self.cache.is_some()
     }
 
-    /// Returns the _system-wide_, preference-ordered, XDG directories or a
-    /// fallback if the environment variable is not set or is set to an
-    /// empty value.
+    /// Clears the cached XDG base directories, so that they are resolved out
+    /// of the environment again, if caching is enabled through
+    /// [`Xdg::with_caching`].
+    #[inline]
+    pub fn refresh(&mut self) {
+        self.clear_cache();
+    }
+
+    /// Clears the cached XDG base directories, if caching is enabled.
+    #[inline]
+    fn clear_cache(&mut self) {
+        if let Some(cache) = &mut self.cache {
+            **cache = cache::DirCache::default();
+        }
+    }
+
+    /// Returns `true` if `path` is an existing regular file according to the
+    /// [`SymlinkPolicy`], emitting a debug event for the probed path.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
+    /// This method returns an [`XdgError::DanglingSymlink`] error if `path`
+    /// is a dangling symbolic link and the policy is
+    /// [`SymlinkPolicy::ErrorOnDangling`].
     #[inline]
-    fn get_sys_dir_paths(dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
-        let env_var_key = dirs.env_var();
-        match Xdg::get_env_var(env_var_key)? {
-            Some(env_var_val) => Xdg::iter_sys_dir_paths(env_var_key, &env_var_val).collect(),
-            None => Ok(dirs.fallback().collect()),
-        }
+    #[cfg(feature = "fs")]
+    pub(crate) fn probe_file(&self, path: &Path) -> Result<bool, XdgError> {
+        self.probe_entry(path, EntryType::File)
     }
 
-    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
-    /// directories specified by the `XDG_CONFIG_DIRS` environment variable.
-    /// Falls back to `/etc/xdg` if `XDG_CONFIG_DIRS` is not set or is set
-    /// to an empty value.
-    ///
-    /// # Note
+    /// Returns the metadata of `path` according to the [`SymlinkPolicy`], or
+    /// `None` if there is no such entry.
     ///
-    /// Used to search for config files in addition to the `XDG_CONFIG_HOME`
-    /// user-specific base directory.
+    /// # Errors
     ///
-    /// The order denotes the importance: the first directory the most
-    /// important, the last directory the least important.
+    /// This method returns an [`XdgError::DanglingSymlink`] error if `path`
+    /// is a dangling symbolic link and the policy is
+    /// [`SymlinkPolicy::ErrorOnDangling`].
+    #[cfg(feature = "fs")]
+    fn probe_metadata(&self, path: &Path) -> Result<Option<fs::Metadata>, XdgError> {
+        let metadata = match self.symlink_policy {
+            SymlinkPolicy::Follow => fs::metadata(path).ok(),
+            SymlinkPolicy::DontFollow => fs::symlink_metadata(path)
+                .ok()
+                .filter(|metadata| !metadata.file_type().is_symlink()),
+            SymlinkPolicy::ErrorOnDangling => match fs::metadata(path) {
+                Ok(metadata) => Some(metadata),
+                Err(_) if fs::symlink_metadata(path).is_ok() => {
+                    return Err(XdgError::DanglingSymlink { path: path.to_path_buf() });
+                },
+                Err(_) => None,
+            },
+        };
+
+        Ok(metadata)
+    }
+
+    /// Returns `true` if `path` is an existing entry of the given
+    /// [`EntryType`] according to the [`SymlinkPolicy`], emitting a debug
+    /// event for the probed path.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
-    ///   path(s) in the colon separated value represents a relative path;
-    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// This method returns an [`XdgError::DanglingSymlink`] error if `path`
+    /// is a dangling symbolic link and the policy is
+    /// [`SymlinkPolicy::ErrorOnDangling`].
+    #[cfg(feature = "fs")]
+    pub(crate) fn probe_entry(&self, path: &Path, entry_type: EntryType) -> Result<bool, XdgError> {
+        let found = self
+            .probe_metadata(path)?
+            .is_some_and(|metadata| entry_type.matches(&metadata.file_type()));
+        trace!("probed `{}`: {}", path.display(), if found { "found" } else { "not found" });
+
+        Ok(found)
+    }
+
+    /// Returns the first of `paths` found by [`Xdg::probe_entry`], if any.
     ///
-    /// # Examples
+    /// # Errors
     ///
-    /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let sys_config_dirs = Xdg::sys_config()?;
-    /// # Ok(())
-    /// # }
-    /// ```
-    #[inline]
-    pub fn sys_config() -> Result<Vec<PathBuf>, XdgError> {
-        Xdg::get_sys_dir_paths(XdgSysDirs::Config)
+    /// This method returns an error if probing one of the paths fails.
+    #[cfg(feature = "fs")]
+    pub(crate) fn probe_first<I>(
+        &self,
+        paths: I,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        I: IntoIterator<Item = PathBuf>,
+    {
+        for path in paths {
+            if self.probe_entry(&path, entry_type)? {
+                return Ok(Some(path));
+            }
+        }
+
+        Ok(None)
     }
 
-    /// Returns the system-wide, preference-ordered, XDG **data** directories
-    /// specified by the `XDG_DATA_DIRS` environment variable. Falls back to
-    /// `/usr/local/share:/usr/share` if `XDG_DATA_DIRS` is not set or is
-    /// set to an empty value.
-    ///
-    /// # Note
+    /// Returns the first of `paths` being an existing regular file whose
+    /// metadata satisfies `predicate`, if any.
     ///
-    /// Used to search for data files in addition to the `XDG_DATA_HOME`
-    /// user-specific base directory.
+    /// # Errors
     ///
-    /// The order denotes the importance: the first directory the most
-    /// important, the last directory the least important.
+    /// This method returns an error if probing one of the paths fails.
+    #[cfg(feature = "fs")]
+    pub(crate) fn probe_first_with<I, F>(
+        &self,
+        paths: I,
+        mut predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        I: IntoIterator<Item = PathBuf>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        for path in paths {
+            let found = self
+                .probe_metadata(&path)?
+                .is_some_and(|metadata| metadata.is_file() && predicate(&path, &metadata));
+            trace!("probed `{}`: {}", path.display(), if found { "found" } else { "not found" });
+
+            if found {
+                return Ok(Some(path));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns up to `limit` of `paths` found by [`Xdg::probe_file`], without
+    /// probing the remaining ones once the limit is reached.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
-    ///   path(s) in the colon separated value represents a relative path;
-    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// This method returns an error if probing one of the paths fails.
+    #[cfg(feature = "fs")]
+    pub(crate) fn probe_all<I>(&self, paths: I, limit: usize) -> Result<Vec<PathBuf>, XdgError>
+    where
+        I: IntoIterator<Item = PathBuf>,
+    {
+        let mut found = Vec::new();
+        for path in paths {
+            if found.len() >= limit {
+                break;
+            }
+
+            if self.probe_file(&path)? {
+                found.push(path);
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// Canonicalizes a `path` found by a `search_*` method, if enabled
+    /// through [`Xdg::with_canonicalize`].
     ///
-    /// # Examples
+    /// # Errors
     ///
-    /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let sys_data_dirs = Xdg::sys_data()?;
-    /// # Ok(())
-    /// # }
-    /// ```
-    #[inline]
-    pub fn sys_data() -> Result<Vec<PathBuf>, XdgError> {
-        Xdg::get_sys_dir_paths(XdgSysDirs::Data)
+    /// This method returns an [`XdgError::Io`] error if the path can't be
+    /// canonicalized.
+    #[cfg(feature = "fs")]
+    pub(crate) fn found(&self, path: PathBuf) -> Result<PathBuf, XdgError> {
+        if !self.canonicalize {
+            return Ok(path);
+        }
+
+        fs::canonicalize(&path).map_err(|source| XdgError::Io { path, source })
     }
 
-    /// Returns the _user-specific_ XDG file path as `<xdg_dir>/<file>`.
+    /// Returns the most recently modified of `paths`, preferring the first
+    /// one among equally recent paths.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
+    /// This function returns an [`XdgError::Io`] error if the modification
+    /// time of one of the paths can't be read.
+    #[cfg(feature = "fs")]
+    pub(crate) fn newest(paths: Vec<PathBuf>) -> Result<Option<PathBuf>, XdgError> {
+        let mut newest = None;
+        for path in paths {
+            let modified = match fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+                Ok(modified) => modified,
+                Err(source) => return Err(XdgError::Io { path, source }),
+            };
+
+            if newest.as_ref().map_or(true, |(newest, _)| modified > *newest) {
+                newest = Some((modified, path));
+            }
+        }
+
+        Ok(newest.map(|(_, path)| path))
+    }
+
+    /// Returns the [`RelativePathPolicy`] in use.
     #[inline]
-    fn get_file_path<P>(&self, dir: XdgDir, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.get_dir_path(dir).map(|path| path.append(file))
+    #[must_use]
+    pub fn relative_path_policy(&self) -> RelativePathPolicy {
+        self.relative_path_policy
     }
 
-    /// Returns the _user-specific_ XDG **cache** file as
-    /// `$XDG_CACHE_HOME/<file>`. Falls back to `$HOME/.cache/<file>` if
-    /// `XDG_CACHE_HOME` is not set or is set to an empty value.
+    /// Constructs a new [`Xdg`] instance.
     ///
-    /// # Note
+    /// The home directory is resolved out of the first of the following
+    /// sources available:
+    /// - the `HOME` environment variable;
+    /// - on Windows, the `USERPROFILE` environment variable, or the
+    ///   `HOMEDRIVE` and `HOMEPATH` environment variables joined;
+    /// - with the `passwd` feature enabled, the home directory of the user
+    ///   named by `USER` or `LOGNAME` (or, if neither is set, of the user
+    ///   owning the process) in the system user database, or in the
+    ///   `/etc/passwd` file as a last resort;
+    /// - `/home/$USER`, or `/home/$LOGNAME` on Unix.
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// # Errors
+    ///
+    /// This function returns an error if none of the sources above is
+    /// available.
+    ///
+    /// On targets lacking an environment, such as `wasm32-unknown-unknown`,
+    /// this function always returns [`XdgError::Unsupported`].
+    pub fn new() -> Result<Xdg, XdgError> {
+        Xdg::new_with_sudo_policy(SudoPolicy::Ignore)
+    }
+
+    /// Constructs a new [`Xdg`] instance in strict specification-compliance
+    /// mode (see [`Xdg::with_strict`]).
+    ///
+    /// Unlike [`Xdg::new`], the home directory is resolved out of the `HOME`
+    /// environment variable only, never guessed as `/home/$USER` nor looked
+    /// up in the user database.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CACHE_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// This function returns an error if the `HOME` environment variable is
+    /// not set.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{Xdg, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let cache_file = xdg.cache_file("file")?;
-    /// # Ok(())
-    /// # }
+    /// std::env::remove_var("HOME");
+    /// std::env::set_var("USER", "user");
+    ///
+    /// assert!(Xdg::new().is_ok());
+    /// assert_eq!(XdgError::HomeNotFound, Xdg::new_strict().unwrap_err());
     /// ```
-    #[inline]
-    pub fn cache_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.get_file_path(XdgDir::Cache, file)
+    pub fn new_strict() -> Result<Xdg, XdgError> {
+        if !SUPPORTED_TARGET {
+            return Err(XdgError::Unsupported);
+        }
+
+        match env::var("HOME") {
+            Ok(home) if !home.is_empty() => Ok(Xdg::with_home(home).with_strict(true)),
+            _ => Err(XdgError::HomeNotFound),
+        }
     }
 
-    /// Returns the _user-specific_ XDG **config** file as
-    /// `$XDG_CONFIG_HOME/<file>`. Falls back to `$HOME/.config/<file>` if
-    /// `XDG_CONFIG_HOME` is not set or is set to an empty value.
-    ///
-    /// # Note
+    /// Constructs a new [`Xdg`] instance, resolving the home directory
+    /// according to the given [`SudoPolicy`].
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// With [`SudoPolicy::InvokingUser`], if the `SUDO_USER` environment
+    /// variable is set, the home directory is the one returned by
+    /// [`Xdg::sudo_user_home`]; otherwise this behaves as [`Xdg::new`].
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CONFIG_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// This function returns an error if neither `HOME` or `USER` environment
+    /// variable is set, and the home directory of the invoking user is not
+    /// resolved.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
+    /// # use std::path::Path;
+    /// # use microxdg::{SudoPolicy, Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let config_file = xdg.config_file("file")?;
+    /// std::env::set_var("HOME", "/root");
+    /// std::env::set_var("SUDO_USER", "user");
+    ///
+    /// let xdg = Xdg::new_with_sudo_policy(SudoPolicy::InvokingUser)?;
+    /// assert_eq!(Path::new("/home/user/.config"), xdg.config()?);
     /// # Ok(())
     /// # }
     /// ```
+    pub fn new_with_sudo_policy(policy: SudoPolicy) -> Result<Xdg, XdgError> {
+        if !SUPPORTED_TARGET {
+            return Err(XdgError::Unsupported);
+        }
+
+        if policy == SudoPolicy::InvokingUser {
+            if let Some(home) = Xdg::sudo_user_home() {
+                trace!("home directory `{}` of the SUDO_USER invoking user", home.display());
+                return Ok(Xdg::with_home(home));
+            }
+        }
+
+        if let Ok(home) = env::var("HOME") {
+            trace!("home directory `{home}` set by HOME");
+            return Ok(Xdg::with_home(home));
+        }
+
+        #[cfg(windows)]
+        if let Some(home) = Xdg::windows_home() {
+            trace!("HOME not set, home directory `{}` set by the Windows profile", home.display());
+            return Ok(Xdg::with_home(home));
+        }
+
+        let user = Xdg::user_name();
+
+        #[cfg(all(unix, feature = "passwd"))]
+        if let Some(home) = passwd::home_dir(user.as_deref()) {
+            trace!("HOME not set, home directory `{}` found in the user database", home.display());
+            return Ok(Xdg::with_home(home));
+        }
+
+        if let Some(user) = user {
+            trace!("HOME not set, guessing home directory `/home/{user}` out of the user name");
+            return Ok(Xdg::with_home(format!("/home/{user}")));
+        }
+
+        Err(XdgError::HomeNotFound)
+    }
+
+    /// Returns the name of the user owning the process, as set by the `USER`
+    /// environment variable or, on Unix, by the `LOGNAME` one.
     #[inline]
-    pub fn config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.get_file_path(XdgDir::Config, file)
+    fn user_name() -> Option<String> {
+        let user = env::var("USER").ok();
+        #[cfg(unix)]
+        let user = user.or_else(|| env::var("LOGNAME").ok());
+
+        user
     }
 
-    /// Returns the _user-specific_ XDG **data** file as
-    /// `$XDG_DATA_HOME/<file>`. Falls back to `$HOME/.local/share/<file>`
-    /// if `XDG_DATA_HOME` is not set or is set to an empty value.
+    /// Returns the home directory set by the `USERPROFILE` environment
+    /// variable or, if not set, by the `HOMEDRIVE` and `HOMEPATH` ones.
+    #[cfg(windows)]
+    fn windows_home() -> Option<PathBuf> {
+        if let Some(home) = env::var_os("USERPROFILE").filter(|home| !home.is_empty()) {
+            return Some(PathBuf::from(home));
+        }
+
+        match (env::var_os("HOMEDRIVE"), env::var_os("HOMEPATH")) {
+            (Some(drive), Some(path)) if !drive.is_empty() && !path.is_empty() => {
+                let mut home = drive;
+                home.push(path);
+                Some(PathBuf::from(home))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the home directory of the user invoking `sudo`, as named by the
+    /// `SUDO_USER` environment variable, if set to a user other than `root`.
+    ///
+    /// The home directory is looked up in the system user database if the
+    /// `passwd` feature is enabled, falling back to `/home/$SUDO_USER`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::Xdg;
+    /// std::env::set_var("SUDO_USER", "user");
+    /// assert_eq!(Some(PathBuf::from("/home/user")), Xdg::sudo_user_home());
+    ///
+    /// std::env::set_var("SUDO_USER", "root");
+    /// assert_eq!(None, Xdg::sudo_user_home());
+    /// ```
+    #[must_use]
+    pub fn sudo_user_home() -> Option<PathBuf> {
+        let user = session::var("SUDO_USER").filter(|user| user != "root")?;
+
+        #[cfg(all(unix, feature = "passwd"))]
+        if let Some(home) = passwd::home_dir(Some(&user)) {
+            return Some(home);
+        }
+
+        Some(PathBuf::from(format!("/home/{user}")))
+    }
+
+    /// Constructs a new [`Xdg`] instance for the user named `user`, looking up
+    /// their home directory in the system user database (e.g. `/etc/passwd`,
+    /// LDAP or `systemd-homed`, through the name service switch).
+    ///
+    /// This allows backup tools, administration daemons and multi-user
+    /// services to resolve the XDG base directories of other users.
     ///
     /// # Note
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// The XDG environment variables are still read from the environment of
+    /// the current process, which is unlikely to describe another user's
+    /// session: consider combining this constructor with
+    /// [`Xdg::with_strict`] and [`XdgBuilder`] overrides, or relying on the
+    /// fallback directories only.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_DATA_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_DATA_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// This function returns an [`XdgError::HomeNotFound`] error if the user
+    /// is not found in the user database, or if their home directory is not
+    /// set.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
+    /// # use std::path::Path;
     /// # use microxdg::{Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let data_file = xdg.data_file("file")?;
+    /// let xdg = Xdg::for_user("root")?;
+    /// assert_eq!(Path::new("/root"), xdg.home());
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
-    pub fn data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.get_file_path(XdgDir::Data, file)
+    #[cfg(all(unix, feature = "passwd"))]
+    pub fn for_user(user: &str) -> Result<Xdg, XdgError> {
+        match passwd::home_dir(Some(user)) {
+            Some(home) => {
+                trace!(
+                    "home directory `{}` of user `{user}` found in the user database",
+                    home.display()
+                );
+                Ok(Xdg::with_home(home))
+            },
+            None => Err(XdgError::HomeNotFound),
+        }
     }
 
-    /// Returns the _user-specific_ XDG **state** file as
-    /// `$XDG_STATE_HOME/<file>`. Falls back to `$HOME/.local/state/<file>`
-    /// if `XDG_STATE_HOME` is not set or is set to an empty value.
+    /// Returns a process-wide [`Xdg`] instance, lazily constructed on first
+    /// access.
     ///
-    /// # Note
-    ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// The home directory is read from the environment only once: subsequent
+    /// calls return the very same instance, regardless of later changes to
+    /// the `HOME` and `USER` environment variables. Failures are not cached,
+    /// so that a later call succeeds once the environment is fixed.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_STATE_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// This function returns the error of [`Xdg::new`] if the instance is not
+    /// constructed yet and neither `HOME` or `USER` environment variable is
+    /// set.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let state_file = xdg.state_file("file")?;
+    /// let config_dir = Xdg::global()?.config()?;
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
-    pub fn state_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    pub fn global() -> Result<&'static Xdg, XdgError> {
+        static GLOBAL: OnceLock<Xdg> = OnceLock::new();
+
+        if let Some(xdg) = GLOBAL.get() {
+            return Ok(xdg);
+        }
+        let xdg = Xdg::new()?;
+        Ok(GLOBAL.get_or_init(|| xdg))
+    }
+
+    /// Constructs a new [`XdgApp`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the app name is not a valid single
+    /// path component, or if neither `HOME` or `USER` environment variable is
+    /// set.
+    pub fn new_app<N>(app_name: N) -> Result<XdgApp, XdgError>
     where
-        P: AsRef<Path>,
+        N: Into<Arc<str>>,
     {
-        self.get_file_path(XdgDir::State, file)
+        XdgApp::new(app_name)
     }
 
-    /// Returns the _user-specific_ XDG **bin** file as
-    /// `$XDG_BIN_HOME/<file>`. Falls back to `$HOME/.local/bin/<file>`
-    /// if `XDG_BIN_HOME` is not set or is set to an empty value.
-    ///
-    /// # Note
+    /// Returns the **home** directory of the user owning the process.
+    #[inline]
+    #[must_use]
+    pub fn home(&self) -> &Path {
+        &self.home
+    }
+
+    /// Runs `f` on the Tokio blocking thread pool, passing it a clone of this
+    /// instance, and returns a future resolving to its output.
     ///
-    /// This method does not guarantee either the path exists or points to a
-    /// regular file.
+    /// This lets async code call any method touching the filesystem without
+    /// blocking its executor on directory stats over slow (e.g. network)
+    /// filesystems. The most common ones have dedicated asynchronous
+    /// counterparts, such as [`Xdg::search_config_file_async`] and
+    /// [`Xdg::read_config_file_async`].
     ///
-    /// # Errors
+    /// # Panics
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_BIN_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_BIN_HOME` environment is set, but its value represents
-    ///   invalid unicode.
+    /// This method panics if called outside the context of a Tokio runtime;
+    /// the returned [`Unblock`] future propagates any panic raised by `f`.
     ///
-    /// # Exapmles
+    /// # Examples
     ///
     /// ```rust
     /// # use microxdg::{Xdg, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// let bin_file = xdg.bin_file("file")?;
-    /// # Ok(())
-    /// # }
+    /// async fn find_config(xdg: &Xdg) -> Result<(), XdgError> {
+    ///     match xdg.unblock(|xdg| xdg.search_config_file("file")).await? {
+    ///         Some(config_file) => { /* ... */ },
+    ///         None => { /* ... */ },
+    ///     }
+    ///     Ok(())
+    /// }
     /// ```
-    #[inline]
-    pub fn bin_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    #[cfg(feature = "async")]
+    pub fn unblock<F, T>(&self, f: F) -> Unblock<T>
     where
-        P: AsRef<Path>,
+        F: FnOnce(&Xdg) -> T + Send + 'static,
+        T: Send + 'static,
     {
-        self.get_file_path(XdgDir::Bin, file)
+        let xdg = self.clone();
+        unblock::unblock(move || f(&xdg))
     }
 
-    /// Searches for `file` inside a _user-specific_ XDG base directory.
+    /// Asynchronous counterpart of [`Xdg::search_cache_file`], running the
+    /// search on the Tokio blocking thread pool.
     ///
-    /// # Note
+    /// # Errors
     ///
-    /// This method returns:
-    /// - `Some` if the file is found inside the specified XDG directory;
-    /// - `None` if the file is **not** found inside the specified XDG
-    ///   directory.
+    /// This method returns the same errors as [`Xdg::search_cache_file`].
     ///
-    /// # Errors
+    /// # Panics
     ///
-    /// This method returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a
-    ///   relative path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
-    #[inline]
-    fn search_usr_file<P>(&self, dir: XdgDir, file: P) -> Result<Option<PathBuf>, XdgError>
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn search_cache_file_async<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
     where
         P: AsRef<Path>,
     {
-        self.get_dir_path(dir).map(|mut path| {
-            path.push(file);
-            path.is_file().then_some(path)
-        })
+        let file = file.as_ref().to_path_buf();
+        self.unblock(move |xdg| xdg.search_cache_file(file)).await
     }
 
-    /// Searches for `file` inside a _system-wide_, preference-ordered, set of
-    /// XDG directories.
+    /// Asynchronous counterpart of [`Xdg::search_config_file`], running the
+    /// search on the Tokio blocking thread pool.
     ///
-    /// # Note
+    /// # Errors
     ///
-    /// This method returns:
-    /// - `Some` if the file is found inside one of the preference-ordered set of
-    ///   XDG system directories;
-    /// - `None` if the file is **not** found inside any of the preference-ordered
-    ///   set of XDG system directories.
+    /// This method returns the same errors as [`Xdg::search_config_file`].
     ///
-    /// # Errors
+    /// # Panics
     ///
-    /// This funciton returns an error in the following cases:
-    /// - the XDG environment variable is set, but its value represents a relative
-    ///   path;
-    /// - the XDG environment variable is set, but its value represents invalid
-    ///   unicode.
-    #[inline]
-    #[rustfmt::skip]
-    fn search_sys_file<P>(dirs: XdgSysDirs, file: P) -> Result<Option<PathBuf>, XdgError>
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn search_config_file_async<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
     where
         P: AsRef<Path>,
     {
-        let env_var_key = dirs.env_var();
-        match Xdg::get_env_var(env_var_key)? {
-            Some(env_var_val) => Xdg::iter_sys_dir_paths(env_var_key, &env_var_val)
-                .map(|result| result.map(|path| path.append(&file)))
-                .find(|path| path.as_ref().is_ok_and(|path| path.is_file()))
-                .transpose(),
-            None => Ok(dirs.fallback()
-                .map(|path| path.append(&file))
-                .find(|path| path.is_file())),
-        }
+        let file = file.as_ref().to_path_buf();
+        self.unblock(move |xdg| xdg.search_config_file(file)).await
     }
 
-    /// Searches for `file` inside XDG directories in the following order:
-    /// - _user-specific_ XDG base directory;
-    /// - _system-wide_, preference-ordered, set of XDG directories.
+    /// Asynchronous counterpart of [`Xdg::search_data_file`], running the
+    /// search on the Tokio blocking thread pool.
     ///
-    /// # Note
+    /// # Errors
     ///
-    /// This method returns:
-    /// - `Some` if the file is found inside one of the XDG directories;
-    /// - `None` if the file is **not** found inside one of the XDG directories.
+    /// This method returns the same errors as [`Xdg::search_data_file`].
     ///
-    /// # Errors
+    /// # Panics
     ///
-    /// This method returns an error in the following cases:
-    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDir`]) is set, but
-    ///   its value represents a relative path;
-    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDir`]) is set, but
-    ///   its value contains invalid unicode.
-    #[inline]
-    fn search_file<P>(&self, dir: XdgDir, file: P) -> Result<Option<PathBuf>, XdgError>
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn search_data_file_async<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
     where
         P: AsRef<Path>,
     {
-        if let Some(path) = self.search_usr_file(dir, &file)? {
-            return Ok(Some(path));
-        }
-
-        if let Some(sys_dirs) = dir.to_sys() {
-            if let Some(path) = Xdg::search_sys_file(sys_dirs, &file)? {
-                return Ok(Some(path));
-            }
-        }
-
-        Ok(None)
+        let file = file.as_ref().to_path_buf();
+        self.unblock(move |xdg| xdg.search_data_file(file)).await
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **cache** directory
-    /// specified by the `XDG_CACHE_HOME` environment variable. The search
-    /// falls back to `$HOME/.cache` if `XDG_CACHE_HOME` is not set or is
-    /// set to an empty value.
+    /// Asynchronous counterpart of [`Xdg::search_state_file`], running the
+    /// search on the Tokio blocking thread pool.
     ///
-    /// # Note
+    /// # Errors
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// This method returns the same errors as [`Xdg::search_state_file`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn search_state_file_async<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = file.as_ref().to_path_buf();
+        self.unblock(move |xdg| xdg.search_state_file(file)).await
+    }
+
+    /// Asynchronous counterpart of [`Xdg::read_config_file`], running the
+    /// search on the Tokio blocking thread pool and reading the file through
+    /// `tokio::fs`.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// This method returns the same errors as [`Xdg::read_config_file`].
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// match xdg.search_cache_file("file")? {
-    ///     Some(cache_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
-    /// # Ok(())
-    /// # }
-    /// ```
-    #[inline]
-    pub fn search_cache_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn read_config_file_async<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
     where
         P: AsRef<Path>,
     {
-        self.search_file(XdgDir::Cache, file)
+        let found = self.search_config_file_async(&file).await?;
+        let path = Xdg::require_found(file, found, || self.searched_dirs(XdgDir::Config))?;
+        tokio::fs::read(&path).await.map_err(|source| XdgError::Io { path, source })
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **configuration**
-    /// directory specified by the `XDG_CONFIG_HOME` environment variable.
-    /// If `XDG_CONFIG_HOME` is not set or is set to an empty value, the
-    /// search falls back to `$HOME/.config`.
+    /// Asynchronous counterpart of [`Xdg::read_config_file_to_string`],
+    /// running the search on the Tokio blocking thread pool and reading the
+    /// file through `tokio::fs`.
     ///
-    /// If `file` is not found inside the _user-specific_ XDG directory, a
-    /// lookup is performed on the _system-wide_, preference ordered
-    /// directories specified by the `XDG_CONFIG_DIRS`. If `XDG_CONFIG_DIRS`
-    /// is not set or is set to an empty value, the search falls back to
-    /// `/etc/xdg`.
+    /// # Errors
     ///
-    /// # Note
+    /// This method returns the same errors as
+    /// [`Xdg::read_config_file_to_string`].
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// # Panics
     ///
-    /// # Errors
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn read_config_file_to_string_async<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_config_file_async(&file).await?;
+        let path = Xdg::require_found(file, found, || self.searched_dirs(XdgDir::Config))?;
+        tokio::fs::read_to_string(&path).await.map_err(|source| XdgError::Io { path, source })
+    }
+
+    /// Asynchronous counterpart of [`Xdg::read_data_file`], running the
+    /// search on the Tokio blocking thread pool and reading the file through
+    /// `tokio::fs`.
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
-    ///   represents invalid unicode;
-    /// - `file` was **not** found inside the _user-specific_ XDG config
-    ///   directory and:
-    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but one (or
-    ///       more) path(s) in the colon separated value represents a relative
-    ///       path;
-    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but its value
-    ///       represents invalid unicode.
+    /// # Errors
     ///
+    /// This method returns the same errors as [`Xdg::read_data_file`].
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
-    /// # fn main() -> Result<(), XdgError> {
-    /// let xdg = Xdg::new()?;
-    /// match xdg.search_config_file("file")? {
-    ///     Some(config_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
-    /// # Ok(())
-    /// # }
-    /// ```
-    #[inline]
-    pub fn search_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn read_data_file_async<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
     where
         P: AsRef<Path>,
     {
-        self.search_file(XdgDir::Config, file)
+        let found = self.search_data_file_async(&file).await?;
+        let path = Xdg::require_found(file, found, || self.searched_dirs(XdgDir::Data))?;
+        tokio::fs::read(&path).await.map_err(|source| XdgError::Io { path, source })
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **data** directory
-    /// specified by the `XDG_DATA_HOME` environment variable. If
-    /// `XDG_DATA_HOME` is not set or is set to an empty value, the search
-    /// falls back to `$HOME/.local/share`.
+    /// Asynchronous counterpart of [`Xdg::read_data_file_to_string`],
+    /// running the search on the Tokio blocking thread pool and reading the
+    /// file through `tokio::fs`.
     ///
-    /// If `file` is not found inside the _user-specific_ XDG directory, a
-    /// lookup is performed on the _system-wide_, preference ordered
-    /// directories specified by the `XDG_DATA_DIRS`. If `XDG_DATA_DIRS` is
-    /// not set or is set to an empty value, the search falls back to
-    /// `/usr/local/share:/usr/share`.
+    /// # Errors
     ///
-    /// # Note
+    /// This method returns the same errors as
+    /// [`Xdg::read_data_file_to_string`].
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// # Panics
+    ///
+    /// This method panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn read_data_file_to_string_async<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_data_file_async(&file).await?;
+        let path = Xdg::require_found(file, found, || self.searched_dirs(XdgDir::Data))?;
+        tokio::fs::read_to_string(&path).await.map_err(|source| XdgError::Io { path, source })
+    }
+
+    /// Returns an [`XdgPaths`] snapshot of every XDG base directory, resolved
+    /// at once.
     ///
     /// # Errors
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_DATA_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_DATA_HOME` environment variable is set, but its value
-    ///   represents invalid unicode;
-    /// - `file` was **not** found inside the _user-specific_ XDG data directory
-    ///   and:
-    ///     - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
-    ///       path(s) in the colon separated value represents a relative path;
-    ///     - the `XDG_DATA_DIRS` environment variable is set, but its value
-    ///       represents invalid unicode.
+    /// This method returns an error if any of the XDG environment variables is
+    /// set, but its value represents a relative path or invalid unicode.
     ///
     /// # Examples
     ///
@@ -1058,39 +1647,22 @@ impl Xdg {
     /// # use microxdg::{Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = Xdg::new()?;
-    /// match xdg.search_data_file("file")? {
-    ///     Some(data_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
+    /// let paths = xdg.paths()?;
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn search_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.search_file(XdgDir::Data, file)
+    pub fn paths(&self) -> Result<XdgPaths, XdgError> {
+        XdgPaths::resolve(self)
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **state** directory
-    /// specified by the `XDG_STATE_HOME` environment variable. The search
-    /// falls back to `$HOME/.local/state` if `XDG_STATE_HOME` is not set or
-    /// is set to an empty value.
-    ///
-    /// # Note
-    ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
-    ///
-    /// # Errors
+    /// Returns an iterator over every _user-specific_ XDG base directory,
+    /// labeled by its [`XdgDirKind`] and resolved lazily, in the order of
+    /// [`XdgDirKind::ALL`].
     ///
-    /// This method returns an error in the following cases:
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_STATE_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// Unlike [`Xdg::paths`], a directory failing to resolve doesn't prevent
+    /// the others from being yielded. The runtime directory is yielded as an
+    /// [`XdgError::RuntimeDirNotSet`] error if `XDG_RUNTIME_DIR` is not set.
     ///
     /// # Examples
     ///
@@ -1098,365 +1670,6727 @@ impl Xdg {
     /// # use microxdg::{Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = Xdg::new()?;
-    /// match xdg.search_state_file("file")? {
-    ///     Some(state_file) => { /* ... */ },
-    ///     None => { /* ... */ },
+    /// for (kind, dir) in xdg.dirs() {
+    ///     match dir {
+    ///         Ok(dir) => println!("{kind}: {}", dir.display()),
+    ///         Err(err) => println!("{kind}: {err}"),
+    ///     }
     /// }
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn search_state_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
-    where
-        P: AsRef<Path>,
-    {
-        self.search_file(XdgDir::State, file)
+    pub fn dirs(&self) -> impl Iterator<Item = (XdgDirKind, Result<PathBuf, XdgError>)> + '_ {
+        XdgDirKind::ALL.into_iter().map(|kind| {
+            let dir = match kind.to_dir() {
+                Some(dir) => self.dir(dir),
+                None => self.runtime().and_then(|runtime| runtime.ok_or(XdgError::RuntimeDirNotSet)),
+            };
+            (kind, dir)
+        })
     }
 
-    /// Searches for `file` inside the _user-specific_ XDG **binary** directory
-    /// specified by the `XDG_BIN_HOME` environment variable. The search
-    /// falls back to `$HOME/.local/bin` if `XDG_BIN_HOME` is not set or
-    /// is set to an empty value.
+    /// Returns the shell lines exporting every resolved XDG base directory
+    /// into the environment, one per line, in the syntax of the given
+    /// [`Shell`].
     ///
-    /// # Note
+    /// Every _user-specific_ directory is exported, followed by the runtime
+    /// directory, if set, and the _system-wide_ `XDG_CONFIG_DIRS` and
+    /// `XDG_DATA_DIRS` directory lists. Values are single-quoted, so that they
+    /// are never expanded by the shell.
     ///
-    /// This method returns:
-    /// - `Some` if `file` is found inside one of the XDG directories;
-    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    /// This is useful for tools spawning login-like environments (e.g.
+    /// terminal emulators or sandbox wrappers), passing on the directories
+    /// resolved out of fallbacks.
     ///
     /// # Errors
     ///
     /// This method returns an error in the following cases:
-    /// - the `XDG_BIN_HOME` environment variable is set, but its value
-    ///   represents a relative path;
-    /// - the `XDG_BIN_HOME` environment variable is set, but its value
-    ///   represents invalid unicode.
+    /// - any of the XDG environment variables is set, but its value represents
+    ///   a relative path or invalid unicode;
+    /// - any of the resolved directories is not valid unicode
+    ///   ([`XdgError::InvalidUnicode`]);
+    /// - any of the _system-wide_ directories contains the platform path list
+    ///   separator ([`XdgError::InvalidPathList`]).
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use microxdg::{Xdg, XdgError};
+    /// # use microxdg::{Shell, Xdg, XdgError};
     /// # fn main() -> Result<(), XdgError> {
     /// let xdg = Xdg::new()?;
-    /// match xdg.search_bin_file("file")? {
-    ///     Some(bin_file) => { /* ... */ },
-    ///     None => { /* ... */ },
-    /// }
+    /// let exports = xdg.to_env_exports(Shell::Posix)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_env_exports(&self, shell: Shell) -> Result<String, XdgError> {
+        let mut vars = Vec::new();
+        for (kind, dir) in self.dirs() {
+            match dir {
+                Ok(dir) => vars.push((kind.env_var(), dir.into_os_string())),
+                Err(XdgError::RuntimeDirNotSet) => {},
+                Err(err) => return Err(err),
+            }
+        }
+        for sys_dirs in [XdgSysDirs::Config, XdgSysDirs::Data] {
+            let dirs = self.sys_dirs(sys_dirs)?;
+            let value = env::join_paths(&dirs).map_err(|_| XdgError::InvalidPathList {
+                env_var_key: sys_dirs.env_var(),
+                path: dirs
+                    .iter()
+                    .find(|dir| env::join_paths([dir]).is_err())
+                    .cloned()
+                    .unwrap_or_default(),
+            })?;
+            vars.push((sys_dirs.env_var(), value));
+        }
+
+        let mut exports = String::new();
+        for (env_var_key, env_var_val) in vars {
+            let Some(value) = env_var_val.to_str() else {
+                return Err(XdgError::InvalidUnicode { env_var_key, env_var_val });
+            };
+            shell.push_export(&mut exports, env_var_key, value);
+        }
+
+        Ok(exports)
+    }
+
+    /// Returns an [`XdgDiagnostics`] report of every environment variable
+    /// relevant to the XDG base directories: whether it is set, unset or
+    /// empty, its value, any problem with it and the effective directories
+    /// resolved out of it.
+    ///
+    /// Unlike [`Xdg::paths`], this method never fails: problems are recorded
+    /// in the report instead. The report can be printed, e.g. in `--debug`
+    /// output, through its [`Display`](std::fmt::Display) implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// std::env::set_var("XDG_CONFIG_HOME", "/home/user/.config");
+    /// std::env::set_var("XDG_DATA_HOME", "share");
+    ///
+    /// let diagnostics = Xdg::new()?.diagnostics();
+    /// let config = diagnostics.var("XDG_CONFIG_HOME").unwrap();
+    /// assert_eq!(vec![PathBuf::from("/home/user/.config")], config.resolved);
+    /// assert!(diagnostics.var("XDG_DATA_HOME").unwrap().problem.is_some());
+    ///
+    /// eprintln!("{diagnostics}");
     /// # Ok(())
     /// # }
     /// ```
+    #[must_use]
+    pub fn diagnostics(&self) -> XdgDiagnostics {
+        XdgDiagnostics::collect(self)
+    }
+
+    /// Returns a validated path from an XDG environment variable.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the XDG environment variable is set,
+    /// but its value represents a relative path: XDG environment variables must
+    /// be set to absolute paths.
     #[inline]
-    pub fn search_bin_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    fn validate_path<P>(env_var_key: &'static str, env_var_val: P) -> Result<PathBuf, XdgError>
     where
-        P: AsRef<Path>,
+        P: Into<PathBuf>,
     {
-        self.search_file(XdgDir::Bin, file)
+        let path: PathBuf = env_var_val.into();
+        if path.is_relative() {
+            // XDG environment variable set, but its value represents a relative path.
+            return Err(XdgError::RelativePath { env_var_key, path });
+        }
+
+        Ok(path)
     }
-}
 
-#[cfg(test)]
-mod test {
-    use std::error::Error;
-    use std::ffi::OsStr;
-    use std::os::unix::prelude::OsStrExt;
+    /// Resolves a path read from an XDG environment variable according to the
+    /// [`RelativePathPolicy`].
+    ///
+    /// # Note
+    ///
+    /// This method returns `None` if the path is relative and the policy is
+    /// [`RelativePathPolicy::Ignore`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the path is relative and the policy is
+    /// [`RelativePathPolicy::Error`].
+    #[inline]
+    fn resolve_path<P>(
+        &self,
+        env_var_key: &'static str,
+        env_var_val: P,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: Into<PathBuf>,
+    {
+        let path: PathBuf = env_var_val.into();
+        if path.is_absolute() {
+            return Ok(Some(path));
+        }
 
-    use super::*;
+        match self.relative_path_policy {
+            RelativePathPolicy::Error => Err(XdgError::RelativePath { env_var_key, path }),
+            RelativePathPolicy::Ignore => Ok(None),
+            RelativePathPolicy::ResolveAgainstHome => Ok(Some(self.home.join(path))),
+        }
+    }
 
-    const INVALID_UNICODE_BYTES: [u8; 4] = [0xF0, 0x90, 0x80, 0x67];
+    /// Returns the value of an XDG environment variable.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the XDG environment variable is set;
+    /// - `None` if the XDG environment variable is missing or set to an empty
+    ///   value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    fn get_env_var(env_var_key: &'static str) -> Result<Option<String>, XdgError> {
+        match env::var(env_var_key) {
+            // XDG environment variable is set to a non-empty value.
+            Ok(env_var_val) if !env_var_val.is_empty() => Ok(Some(env_var_val)),
+            // XDG environment variable is set, but its value represents invalid unicode.
+            Err(VarError::NotUnicode(env_var_val)) => {
+                Err(XdgError::InvalidUnicode { env_var_key, env_var_val })
+            },
+            // XDG environment variable is not set or set to an empty value.
+            _ => Ok(None),
+        }
+    }
 
+    /// Returns the value of an XDG environment variable as an [`OsString`],
+    /// accepting non-unicode values if enabled through
+    /// [`Xdg::with_non_unicode`] and expanding variable references if enabled
+    /// through [`Xdg::with_expand_vars`].
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the XDG environment variable is set;
+    /// - `None` if the XDG environment variable is missing or set to an empty
+    ///   value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the XDG environment variable is set,
+    /// but its value represents invalid unicode and non-unicode values are
+    /// not accepted.
     #[inline]
-    fn remove_xdg_vars() {
-        env::remove_var("USER");
-        env::remove_var("HOME");
+    fn get_env_var_os(&self, env_var_key: &'static str) -> Result<Option<OsString>, XdgError> {
+        let env_var_val = if self.non_unicode {
+            env::var_os(env_var_key).filter(|env_var_val| !env_var_val.is_empty())
+        } else {
+            Xdg::get_env_var(env_var_key)?.map(OsString::from)
+        };
 
-        // User XDG environment variables
-        env::remove_var("XDG_CACHE_HOME");
-        env::remove_var("XDG_CONFIG_HOME");
-        env::remove_var("XDG_DATA_HOME");
-        env::remove_var("XDG_STATE_HOME");
-        env::remove_var("XDG_BIN_HOME");
-        env::remove_var("XDG_RUNTIME_DIR");
+        if !self.expand_vars {
+            return Ok(env_var_val);
+        }
 
-        // User XDG environment variables
-        env::remove_var("XDG_CONFIG_DIRS");
-        env::remove_var("XDG_DATA_DIRS");
+        Ok(env_var_val.map(|env_var_val| match env_var_val.to_str() {
+            Some(env_var_val) => expand::expand(env_var_val, self.home.to_str()).into(),
+            None => env_var_val,
+        }))
     }
 
-    #[test]
-    #[rustfmt::skip]
-    fn new_xdg() -> Result<(), XdgError> {
-        remove_xdg_vars();
-
-        env::set_var("USER", "user2");
-        env::set_var("HOME", "/home/user1");
-        assert_eq!(Path::new("/home/user1"), Xdg::new()?.home());
-        assert_eq!(Path::new("/home/user1"), Xdg::new_app("app_name")?.home());
-
-        env::remove_var("HOME");
+    /// Returns the path set to an XDG environment variable or a fallback in the
+    /// case the environment variable is not set or is set to an empty
+    /// value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    fn get_dir_path(&self, dir: XdgDir) -> Result<PathBuf, XdgError> {
+        self.get_dir_path_with_capacity(dir, 0)
+    }
+
+    /// Returns the path set to an XDG environment variable or a fallback,
+    /// reserving room for `additional` bytes to be appended afterwards.
+    ///
+    /// # Note
+    ///
+    /// The value of the XDG environment variable is moved into the returned
+    /// path without copying, while the fallback is built into a buffer
+    /// allocated once with the exact capacity required, so that appending
+    /// subpaths does not trigger any reallocation.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    fn get_dir_path_with_capacity(
+        &self,
+        dir: XdgDir,
+        additional: usize,
+    ) -> Result<PathBuf, XdgError> {
+        match &self.cache {
+            Some(cache) => {
+                let path = cache.dir(dir, || self.read_dir_path_with_capacity(dir, 0))?;
+                let capacity = path.as_os_str().len() + additional;
+                Ok(PathBuf::with_capacity(capacity).append(path))
+            },
+            None => self.read_dir_path_with_capacity(dir, additional),
+        }
+    }
+
+    /// Reads the path set to an XDG environment variable or a fallback,
+    /// bypassing the cache, reserving room for `additional` bytes to be
+    /// appended afterwards.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    fn read_dir_path_with_capacity(
+        &self,
+        dir: XdgDir,
+        additional: usize,
+    ) -> Result<PathBuf, XdgError> {
+        let env_var_key = dir.env_var();
+        if let Some(path) = self.overrides.as_ref().and_then(|overrides| overrides.get(dir)) {
+            trace!("{env_var_key} overridden by `{}`", path.display());
+            let capacity = path.as_os_str().len() + additional;
+            return Ok(PathBuf::with_capacity(capacity).append(path));
+        }
+
+        let env_var_path = match self.get_env_var_os(env_var_key)? {
+            Some(env_var_val) => self.resolve_path(env_var_key, env_var_val)?,
+            None => None,
+        };
+
+        match env_var_path {
+            Some(mut path) => {
+                trace!("{env_var_key} set to `{}`", path.display());
+                path.reserve(additional);
+                Ok(path)
+            },
+            None => {
+                let fallback = self
+                    .overrides
+                    .as_ref()
+                    .and_then(|overrides| overrides.fallback(dir))
+                    .unwrap_or_else(|| match self.scope {
+                        Scope::User => Path::new(dir.fallback()),
+                        Scope::System => Path::new(dir.system_fallback()),
+                    });
+                let capacity =
+                    self.home.as_os_str().len() + 1 + fallback.as_os_str().len() + additional;
+                let path = PathBuf::with_capacity(capacity).append(&self.home).append(fallback);
+                trace!("{env_var_key} not set, falling back to `{}`", path.display());
+                Ok(path)
+            },
+        }
+    }
+
+    /// Returns the _user-specific_ XDG **cache** directory specified by the
+    /// `XDG_CACHE_HOME` environment variable. Falls back to `$HOME/.cache`
+    /// if `XDG_CACHE_HOME` is not set or is set to an empty value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let cache_dir = xdg.cache()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn cache(&self) -> Result<PathBuf, XdgError> {
+        self.get_dir_path(XdgDir::Cache)
+    }
+
+    /// Returns the _user-specific_ XDG **configuration** directory specified by
+    /// the `XDG_CONFIG_HOME` environment variable. Falls back to
+    /// `$HOME/.config` if `XDG_CONFIG_HOME` is not set or is set to an
+    /// empty value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let config_dir = xdg.config()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn config(&self) -> Result<PathBuf, XdgError> {
+        self.get_dir_path(XdgDir::Config)
+    }
+
+    /// Returns the _user-specific_ XDG **data** directory specified by the
+    /// `XDG_DATA_HOME` environment variable. Falls back to
+    /// `$HOME/.local/share` if `XDG_DATA_HOME` is not set or is set to an
+    /// empty value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let data_dir = xdg.data()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn data(&self) -> Result<PathBuf, XdgError> {
+        self.get_dir_path(XdgDir::Data)
+    }
+
+    /// Returns the _user-specific_ XDG **state** directory specified by the
+    /// `XDG_STATE_HOME` environment variable. Falls back to
+    /// `$HOME/.local/state` if `XDG_STATE_HOME` is not set or is set to an
+    /// empty value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let state_dir = xdg.state()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn state(&self) -> Result<PathBuf, XdgError> {
+        self.get_dir_path(XdgDir::State)
+    }
+
+    /// Returns the _user-specific_ XDG **binary** directory specified by the
+    /// `XDG_BIN_HOME` environment variable. Falls back to
+    /// `$HOME/.local/bin` if `XDG_BIN_HOME` is not set or is set to an
+    /// empty value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let bin_dir = xdg.bin()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn bin(&self) -> Result<PathBuf, XdgError> {
+        self.get_dir_path(XdgDir::Bin)
+    }
+
+    /// Returns the _user-specific_ XDG `dir` directory, as the matching
+    /// [`Xdg::cache`], [`Xdg::config`], [`Xdg::data`], [`Xdg::state`] or
+    /// [`Xdg::bin`] method does.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents a relative path;
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgDir, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// assert_eq!(xdg.config()?, xdg.dir(XdgDir::Config)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn dir(&self, dir: XdgDir) -> Result<PathBuf, XdgError> {
+        self.get_dir_path(dir)
+    }
+
+    /// Returns the _user-specific_ XDG `dir` directory as [`Xdg::dir`] does,
+    /// borrowing it from the cache instead of allocating a new path, if
+    /// caching is enabled through [`Xdg::with_caching`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents a relative path;
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # use std::path::Path;
+    /// # use microxdg::{Xdg, XdgDir, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// std::env::set_var("XDG_CONFIG_HOME", "/home/user/.config");
+    ///
+    /// let xdg = Xdg::new()?.with_caching(true);
+    /// let config_dir = xdg.dir_cow(XdgDir::Config)?;
+    /// assert!(matches!(config_dir, Cow::Borrowed(_)));
+    /// assert_eq!(Path::new("/home/user/.config"), config_dir);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dir_cow(&self, dir: XdgDir) -> Result<Cow<'_, Path>, XdgError> {
+        match &self.cache {
+            Some(cache) => {
+                cache.dir(dir, || self.read_dir_path_with_capacity(dir, 0)).map(Cow::Borrowed)
+            },
+            None => self.read_dir_path_with_capacity(dir, 0).map(Cow::Owned),
+        }
+    }
+
+    /// Returns the _user-specific_ XDG **cache** directory as [`Xdg::cache`]
+    /// does, borrowing it from the cache if enabled.
+    ///
+    /// See [`Xdg::dir_cow`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the same cases as [`Xdg::cache`].
+    #[inline]
+    pub fn cache_cow(&self) -> Result<Cow<'_, Path>, XdgError> {
+        self.dir_cow(XdgDir::Cache)
+    }
+
+    /// Returns the _user-specific_ XDG **configuration** directory as [`Xdg::config`]
+    /// does, borrowing it from the cache if enabled.
+    ///
+    /// See [`Xdg::dir_cow`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the same cases as [`Xdg::config`].
+    #[inline]
+    pub fn config_cow(&self) -> Result<Cow<'_, Path>, XdgError> {
+        self.dir_cow(XdgDir::Config)
+    }
+
+    /// Returns the _user-specific_ XDG **data** directory as [`Xdg::data`]
+    /// does, borrowing it from the cache if enabled.
+    ///
+    /// See [`Xdg::dir_cow`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the same cases as [`Xdg::data`].
+    #[inline]
+    pub fn data_cow(&self) -> Result<Cow<'_, Path>, XdgError> {
+        self.dir_cow(XdgDir::Data)
+    }
+
+    /// Returns the _user-specific_ XDG **state** directory as [`Xdg::state`]
+    /// does, borrowing it from the cache if enabled.
+    ///
+    /// See [`Xdg::dir_cow`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the same cases as [`Xdg::state`].
+    #[inline]
+    pub fn state_cow(&self) -> Result<Cow<'_, Path>, XdgError> {
+        self.dir_cow(XdgDir::State)
+    }
+
+    /// Returns the _user-specific_ XDG **binary** directory as [`Xdg::bin`]
+    /// does, borrowing it from the cache if enabled.
+    ///
+    /// See [`Xdg::dir_cow`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the same cases as [`Xdg::bin`].
+    #[inline]
+    pub fn bin_cow(&self) -> Result<Cow<'_, Path>, XdgError> {
+        self.dir_cow(XdgDir::Bin)
+    }
+
+    /// Returns the XDG **runtime** directory specified by the `XDG_RUNTIME_DIR`
+    /// environment variable.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the `XDG_RUNTIME_DIR` environment variable is set;
+    /// - `None` if the `XDG_RUNTIME_DIR` environment variable is not set or is
+    ///   set to an empty value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_RUNTIME_DIR` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.runtime()? {
+    ///     Some(runtime_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn runtime(&self) -> Result<Option<PathBuf>, XdgError> {
+        if let Some(path) = self.overrides.as_ref().and_then(|overrides| overrides.runtime()) {
+            trace!("{} overridden by `{}`", XdgDir::RUNTIME_ENV_VAR, path.display());
+            return Ok(Some(path.to_path_buf()));
+        }
+
+        let path = match self.get_env_var_os(XdgDir::RUNTIME_ENV_VAR)? {
+            Some(env_var_val) => self.resolve_path(XdgDir::RUNTIME_ENV_VAR, env_var_val)?,
+            None => {
+                trace!("{} not set", XdgDir::RUNTIME_ENV_VAR);
+                None
+            },
+        };
+
+        if let (true, Some(path)) = (self.strict, &path) {
+            Xdg::check_runtime_dir(path)?;
+        }
+
+        Ok(path)
+    }
+
+    /// Checks that the runtime directory `path` is owned by the user owning
+    /// the process and has access mode `0700`.
+    ///
+    /// # Note
+    ///
+    /// The owner is checked against the effective user ID of the process or,
+    /// without the `fs` feature, against the owner of `/proc/self`: where
+    /// unavailable, the runtime directory is always rejected.
+    #[cfg(unix)]
+    fn check_runtime_dir(path: &Path) -> Result<(), XdgError> {
+        use std::os::unix::fs::MetadataExt;
+
+        let metadata = std::fs::metadata(path)
+            .map_err(|source| XdgError::Io { path: path.to_path_buf(), source })?;
+        #[cfg(feature = "fs")]
+        let euid = Some(sys::euid());
+        #[cfg(not(feature = "fs"))]
+        let euid = std::fs::metadata("/proc/self").ok().map(|proc| proc.uid());
+        let owned = euid == Some(metadata.uid());
+        if !metadata.is_dir() || !owned || metadata.mode() & 0o777 != 0o700 {
+            return Err(XdgError::InsecureRuntimeDir { path: path.to_path_buf() });
+        }
+
+        Ok(())
+    }
+
+    /// Checks the runtime directory `path`, a no-op on platforms lacking Unix
+    /// permissions.
+    #[cfg(not(unix))]
+    fn check_runtime_dir(_path: &Path) -> Result<(), XdgError> {
+        Ok(())
+    }
+
+    /// Returns the _user-specific_ XDG **cache** directory, as returned by
+    /// [`Xdg::cache`], only if it exists and is a directory.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the directory exists;
+    /// - `None` if the directory does **not** exist or is not a directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// if let Some(cache_dir) = xdg.cache_checked()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn cache_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.cache().map(existing_dir)
+    }
+
+    /// Returns the _user-specific_ XDG **configuration** directory, as returned by
+    /// [`Xdg::config`], only if it exists and is a directory.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the directory exists;
+    /// - `None` if the directory does **not** exist or is not a directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// if let Some(config_dir) = xdg.config_checked()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn config_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.config().map(existing_dir)
+    }
+
+    /// Returns the _user-specific_ XDG **data** directory, as returned by
+    /// [`Xdg::data`], only if it exists and is a directory.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the directory exists;
+    /// - `None` if the directory does **not** exist or is not a directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// if let Some(data_dir) = xdg.data_checked()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn data_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.data().map(existing_dir)
+    }
+
+    /// Returns the _user-specific_ XDG **state** directory, as returned by
+    /// [`Xdg::state`], only if it exists and is a directory.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the directory exists;
+    /// - `None` if the directory does **not** exist or is not a directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// if let Some(state_dir) = xdg.state_checked()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn state_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.state().map(existing_dir)
+    }
+
+    /// Returns the _user-specific_ XDG **executable** directory, as returned by
+    /// [`Xdg::bin`], only if it exists and is a directory.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the directory exists;
+    /// - `None` if the directory does **not** exist or is not a directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// if let Some(bin_dir) = xdg.bin_checked()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn bin_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.bin().map(existing_dir)
+    }
+
+    /// Returns the XDG **runtime** directory, as returned by [`Xdg::runtime`],
+    /// only if it exists and is a directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_RUNTIME_DIR` environment variable is set, but its value
+    ///   represents invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn runtime_checked(&self) -> Result<Option<PathBuf>, XdgError> {
+        self.runtime().map(|runtime| runtime.and_then(existing_dir))
+    }
+
+    /// Returns the root directory of the thumbnail cache defined by the
+    /// [_Thumbnail Managing Standard_](<https://specifications.freedesktop.org/thumbnail-spec/latest/>)
+    /// as `$XDG_CACHE_HOME/thumbnails`. Falls back to `$HOME/.cache/thumbnails`
+    /// if `XDG_CACHE_HOME` is not set or is set to an empty value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let thumbnails_dir = xdg.thumbnails()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn thumbnails(&self) -> Result<PathBuf, XdgError> {
+        self.get_file_path(XdgDir::Cache, "thumbnails")
+    }
+
+    /// Returns the thumbnail cache directory holding thumbnails of the given
+    /// `size`, as `$XDG_CACHE_HOME/thumbnails/<size>` (e.g. `normal`).
+    ///
+    /// See [`Xdg::thumbnails`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{ThumbnailSize, Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let large_thumbnails_dir = xdg.thumbnail_dir(ThumbnailSize::Large)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn thumbnail_dir(&self, size: ThumbnailSize) -> Result<PathBuf, XdgError> {
+        self.thumbnails().map(|path| path.append(size.dir_name()))
+    }
+
+    /// Returns the thumbnail cache directory recording failed thumbnail
+    /// creations, as `$XDG_CACHE_HOME/thumbnails/fail`.
+    ///
+    /// # Note
+    ///
+    /// Each thumbnailer is expected to record its failures in its own
+    /// subdirectory, see [`XdgApp::app_thumbnail_fail_dir`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let thumbnail_fail_dir = xdg.thumbnail_fail_dir()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn thumbnail_fail_dir(&self) -> Result<PathBuf, XdgError> {
+        self.thumbnails().map(|path| path.append("fail"))
+    }
+
+    /// Returns the path of the thumbnail of the given `size` for the file
+    /// identified by `uri`, as `$XDG_CACHE_HOME/thumbnails/<size>/<md5>.png`,
+    /// where `<md5>` is the hexadecimal MD5 digest of `uri`.
+    ///
+    /// # Note
+    ///
+    /// `uri` must be the absolute, canonical URI of the original file, as
+    /// mandated by the specification (e.g. `file:///home/user/photo.png`, with
+    /// reserved characters percent-encoded). This method does not guarantee
+    /// either the thumbnail exists or is up to date.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::{ThumbnailSize, Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// std::env::set_var("XDG_CACHE_HOME", "/home/jens/.cache");
+    ///
+    /// let xdg = Xdg::new()?;
+    /// assert_eq!(
+    ///     PathBuf::from(
+    ///         "/home/jens/.cache/thumbnails/normal/c6ee772d9e49320e97ec29a7eb5b1697.png"
+    ///     ),
+    ///     xdg.thumbnail_path("file:///home/jens/photos/me.png", ThumbnailSize::Normal)?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn thumbnail_path(&self, uri: &str, size: ThumbnailSize) -> Result<PathBuf, XdgError> {
+        self.thumbnail_dir(size).map(|path| path.append(thumbnail::file_name(uri)))
+    }
+
+    /// Returns the _home trash_ directory, as defined by the
+    /// [_FreeDesktop.org Trash specification_](<https://specifications.freedesktop.org/trash-spec/latest/>),
+    /// i.e. `$XDG_DATA_HOME/Trash`.
+    ///
+    /// # Note
+    ///
+    /// Trashed files are stored in its `files` subdirectory, while their
+    /// metadata files are stored in its `info` subdirectory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let trash_dir = xdg.trash()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn trash(&self) -> Result<PathBuf, XdgError> {
+        self.get_file_path(XdgDir::Data, "Trash")
+    }
+
+    /// Moves the file or directory at `path` into the _home trash_, writing
+    /// its `.trashinfo` metadata file, and returns the resulting entry.
+    ///
+    /// # Note
+    ///
+    /// Relative paths are resolved against the current working directory. If
+    /// the trash already holds a file with the same name, the trashed file is
+    /// renamed (e.g. `file.1.txt`). The deletion date is recorded in UTC.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `path` does not exist, or could not be moved into the trash (e.g.
+    ///   because it lives on another filesystem);
+    /// - the trash directories or the metadata file could not be created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let entry = xdg.trash_put("notes.txt")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn trash_put<P>(&self, path: P) -> Result<TrashEntry, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        trash::put(&self.trash()?, path.as_ref())
+    }
+
+    /// Returns the entries of the _home trash_, sorted by name.
+    ///
+    /// # Note
+    ///
+    /// Malformed metadata files, as well as metadata files without a matching
+    /// trashed file, are skipped. A missing trash is treated as empty.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the trash `info` directory could not be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for entry in xdg.trash_list()? {
+    ///     println!("{}", entry.original_path.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn trash_list(&self) -> Result<Vec<TrashEntry>, XdgError> {
+        trash::list(&self.trash()?)
+    }
+
+    /// Moves the trashed file of `entry` back to its original path, removing
+    /// its metadata file, and returns the restored path.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an [`XdgError::Io`] error if the original path is
+    /// already taken, or the trashed file could not be moved back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// if let Some(entry) = xdg.trash_list()?.first() {
+    ///     xdg.trash_restore(entry)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn trash_restore(&self, entry: &TrashEntry) -> Result<PathBuf, XdgError> {
+        trash::restore(entry)
+    }
+
+    /// Returns the path of the desktop's recently used files list, as
+    /// `$XDG_DATA_HOME/recently-used.xbel`.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the file exists or is valid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let recently_used = xdg.recently_used()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn recently_used(&self) -> Result<PathBuf, XdgError> {
+        self.get_file_path(XdgDir::Data, "recently-used.xbel")
+    }
+
+    /// Returns the entries of the desktop's recently used files list (see
+    /// [`Xdg::recently_used`]), in file order.
+    ///
+    /// # Note
+    ///
+    /// A missing list is treated as empty. Parsing is lenient and only
+    /// extracts the bookmark URIs, their MIME types and dates.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the list exists but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for recent_file in xdg.recent_files()? {
+    ///     println!("{}", recent_file.uri);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn recent_files(&self) -> Result<Vec<RecentFile>, XdgError> {
+        recent::list(&self.recently_used()?)
+    }
+
+    /// Returns an iterator over the _sistem-wide_ directories set to a system
+    /// XDG environment variable, skipping empty entries and duplicates.
+    #[inline]
+    fn iter_sys_dir_paths<'val>(
+        env_var_key: &'static str,
+        env_var_val: &'val str,
+    ) -> impl Iterator<Item = Result<PathBuf, XdgError>> + 'val {
+        let mut first_occurrence = first_occurrence();
+        env::split_paths(env_var_val)
+            .filter(|path| !path.as_os_str().is_empty())
+            .map(move |path| Xdg::validate_path(env_var_key, path))
+            .filter(move |result| result.as_ref().map_or(true, &mut first_occurrence))
+    }
+
+    /// Returns an iterator over the _system-wide_ directories set to a system
+    /// XDG environment variable, resolved according to the
+    /// [`RelativePathPolicy`] and skipping empty entries and duplicates.
+    #[inline]
+    fn iter_resolved_sys_dir_paths<'val>(
+        &'val self,
+        env_var_key: &'static str,
+        env_var_val: &'val OsStr,
+    ) -> impl Iterator<Item = Result<PathBuf, XdgError>> + 'val {
+        let mut first_occurrence = first_occurrence();
+        env::split_paths(env_var_val)
+            .filter(|path| !path.as_os_str().is_empty())
+            .filter_map(move |path| self.resolve_path(env_var_key, path).transpose())
+            .filter(move |result| result.as_ref().map_or(true, &mut first_occurrence))
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG directories,
+    /// resolved according to the [`RelativePathPolicy`], or a fallback if the
+    /// environment variable is not set, is set to an empty value or contains
+    /// no valid entry.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path and the policy is [`RelativePathPolicy::Error`];
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    fn resolve_sys_dir_paths(&self, dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
+        match &self.cache {
+            Some(cache) => cache.sys_dirs(dirs, || self.read_sys_dir_paths(dirs)).map(<[_]>::to_vec),
+            None => self.read_sys_dir_paths(dirs),
+        }
+    }
+
+    /// Reads the _system-wide_, preference-ordered, XDG directories, bypassing
+    /// the cache.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path and the policy is [`RelativePathPolicy::Error`];
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    fn read_sys_dir_paths(&self, dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
+        let env_var_key = dirs.env_var();
+        let paths = match self.get_env_var_os(env_var_key)? {
+            Some(env_var_val) => self
+                .iter_resolved_sys_dir_paths(env_var_key, &env_var_val)
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(if paths.is_empty() { self.sys_fallback(dirs) } else { paths })
+    }
+
+    /// Reads the _system-wide_, preference-ordered, XDG directories, silently
+    /// dropping invalid entries and falling back if none is left.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the XDG environment variable is set,
+    /// but its value represents invalid unicode and non-unicode values are not
+    /// accepted.
+    #[cfg(feature = "fs")]
+    pub(crate) fn read_sys_dir_paths_lossy(
+        &self,
+        dirs: XdgSysDirs,
+    ) -> Result<Vec<PathBuf>, XdgError> {
+        let env_var_key = dirs.env_var();
+        let paths = match self.get_env_var_os(env_var_key)? {
+            Some(env_var_val) => self
+                .iter_resolved_sys_dir_paths(env_var_key, &env_var_val)
+                .filter_map(Result::ok)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(if paths.is_empty() { self.sys_fallback(dirs) } else { paths })
+    }
+
+    /// Returns the fallback _system-wide_ XDG directories, either set through
+    /// [`XdgBuilder`] or built-in.
+    #[inline]
+    fn sys_fallback(&self, dirs: XdgSysDirs) -> Vec<PathBuf> {
+        match self.overrides.as_ref().and_then(|overrides| overrides.sys_fallback(dirs)) {
+            Some(fallbacks) => fallbacks.to_vec(),
+            None => dirs.fallback_paths().collect(),
+        }
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG directories or a
+    /// fallback if the environment variable is not set, is set to an empty
+    /// value or contains no directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    fn get_sys_dir_paths(dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
+        let env_var_key = dirs.env_var();
+        if let Some(env_var_val) = Xdg::get_env_var(env_var_key)? {
+            trace!("{env_var_key} set to `{env_var_val}`");
+            let paths = Xdg::iter_sys_dir_paths(env_var_key, &env_var_val)
+                .collect::<Result<Vec<_>, _>>()?;
+            if !paths.is_empty() {
+                return Ok(paths);
+            }
+        }
+
+        trace!("{env_var_key} not set, falling back to the default directories");
+        Ok(dirs.fallback_paths().collect())
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
+    /// directories specified by the `XDG_CONFIG_DIRS` environment variable.
+    /// Falls back to `/etc/xdg` if `XDG_CONFIG_DIRS` is not set or is set
+    /// to an empty value.
+    ///
+    /// # Note
+    ///
+    /// Used to search for config files in addition to the `XDG_CONFIG_HOME`
+    /// user-specific base directory.
+    ///
+    /// The order denotes the importance: the first directory the most
+    /// important, the last directory the least important. Empty entries
+    /// (e.g. `/usr/share::/usr/local/share`) and duplicate directories are
+    /// skipped, retaining the first occurrence of each directory only.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let sys_config_dirs = Xdg::sys_config()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn sys_config() -> Result<Vec<PathBuf>, XdgError> {
+        Xdg::get_sys_dir_paths(XdgSysDirs::Config)
+    }
+
+    /// Returns the system-wide, preference-ordered, XDG **data** directories
+    /// specified by the `XDG_DATA_DIRS` environment variable. Falls back to
+    /// `/usr/local/share:/usr/share` if `XDG_DATA_DIRS` is not set or is
+    /// set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// Used to search for data files in addition to the `XDG_DATA_HOME`
+    /// user-specific base directory.
+    ///
+    /// The order denotes the importance: the first directory the most
+    /// important, the last directory the least important. Empty entries
+    /// (e.g. `/usr/share::/usr/local/share`) and duplicate directories are
+    /// skipped, retaining the first occurrence of each directory only.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let sys_data_dirs = Xdg::sys_data()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn sys_data() -> Result<Vec<PathBuf>, XdgError> {
+        Xdg::get_sys_dir_paths(XdgSysDirs::Data)
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG `dirs` directories.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`Xdg::sys_config`] and [`Xdg::sys_data`], this method honors
+    /// the [`RelativePathPolicy`] and the fallbacks set through
+    /// [`XdgBuilder`] of the instance.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable of `dirs` is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path and
+    ///   the policy is [`RelativePathPolicy::Error`];
+    /// - the XDG environment variable of `dirs` is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError, XdgSysDirs};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for dirs in [XdgSysDirs::Config, XdgSysDirs::Data] {
+    ///     println!("{dirs:?}: {:?}", xdg.sys_dirs(dirs)?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn sys_dirs(&self, dirs: XdgSysDirs) -> Result<Vec<PathBuf>, XdgError> {
+        self.resolve_sys_dir_paths(dirs)
+    }
+
+    /// Returns the candidate paths of the _system-wide_ XDG **configuration**
+    /// file as `<dir>/<file>` for each of the preference-ordered
+    /// `XDG_CONFIG_DIRS` directories, falling back to `/etc/xdg/<file>` if
+    /// `XDG_CONFIG_DIRS` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not check whether any of the paths exists, e.g. to
+    /// report the locations looked up or to create a file in a _system-wide_
+    /// location.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let sys_config_files = xdg.sys_config_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn sys_config_file<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let dir_paths = self.resolve_sys_dir_paths(XdgSysDirs::Config)?;
+        Ok(dir_paths.into_iter().map(|path| path.append(&file)).collect())
+    }
+
+    /// Returns the candidate paths of the _system-wide_ XDG **data** file as
+    /// `<dir>/<file>` for each of the preference-ordered `XDG_DATA_DIRS`
+    /// directories, falling back to `/usr/local/share/<file>:/usr/share/<file>`
+    /// if `XDG_DATA_DIRS` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not check whether any of the paths exists, e.g. to
+    /// report the locations looked up or to create a file in a _system-wide_
+    /// location.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let sys_data_files = xdg.sys_data_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn sys_data_file<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let dir_paths = self.resolve_sys_dir_paths(XdgSysDirs::Data)?;
+        Ok(dir_paths.into_iter().map(|path| path.append(&file)).collect())
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG directories,
+    /// silently dropping relative paths, or a fallback if the environment
+    /// variable is not set, is set to an empty value or contains no absolute
+    /// path.
+    ///
+    /// # Note
+    ///
+    /// Paths are split out of the environment variable value without
+    /// requiring it to be valid unicode.
+    #[inline]
+    fn get_sys_dir_paths_lossy(dirs: XdgSysDirs) -> Vec<PathBuf> {
+        let paths: Vec<PathBuf> = match env::var_os(dirs.env_var()) {
+            Some(env_var_val) => env::split_paths(&env_var_val)
+                .filter(|path| path.is_absolute())
+                .filter(first_occurrence())
+                .collect(),
+            None => Vec::new(),
+        };
+
+        if paths.is_empty() {
+            dirs.fallback_paths().collect()
+        } else {
+            paths
+        }
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **configuration**
+    /// directories specified by the `XDG_CONFIG_DIRS` environment variable,
+    /// ignoring invalid entries. Falls back to `/etc/xdg` if
+    /// `XDG_CONFIG_DIRS` is not set, is set to an empty value or contains no
+    /// valid entry.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`Xdg::sys_config`], which fails on the first invalid entry,
+    /// this method drops relative paths (as mandated by the specification)
+    /// and keeps the remaining ones. Non-unicode values are accepted as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use microxdg::Xdg;
+    /// std::env::set_var("XDG_CONFIG_DIRS", "/etc/xdg:./relative:/opt/xdg");
+    ///
+    /// assert_eq!(
+    ///     vec![PathBuf::from("/etc/xdg"), PathBuf::from("/opt/xdg")],
+    ///     Xdg::sys_config_lossy(),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sys_config_lossy() -> Vec<PathBuf> {
+        Xdg::get_sys_dir_paths_lossy(XdgSysDirs::Config)
+    }
+
+    /// Returns the _system-wide_, preference-ordered, XDG **data** directories
+    /// specified by the `XDG_DATA_DIRS` environment variable, ignoring invalid
+    /// entries. Falls back to `/usr/local/share:/usr/share` if
+    /// `XDG_DATA_DIRS` is not set, is set to an empty value or contains no
+    /// valid entry.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`Xdg::sys_data`], which fails on the first invalid entry, this
+    /// method drops relative paths (as mandated by the specification) and
+    /// keeps the remaining ones. Non-unicode values are accepted as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::Xdg;
+    /// for data_dir in Xdg::sys_data_lossy() {
+    ///     /* ... */
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sys_data_lossy() -> Vec<PathBuf> {
+        Xdg::get_sys_dir_paths_lossy(XdgSysDirs::Data)
+    }
+
+    /// Returns the _user-specific_ XDG **configuration** directory followed by
+    /// the _system-wide_, preference-ordered, XDG **configuration**
+    /// directories, i.e. every directory a configuration file should be
+    /// looked up into, from the most important to the least important.
+    ///
+    /// See [`Xdg::config`] and [`Xdg::sys_config`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for config_dir in xdg.config_dirs_all()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn config_dirs_all(&self) -> Result<impl Iterator<Item = PathBuf>, XdgError> {
+        Ok(iter::once(self.config()?).chain(self.resolve_sys_dir_paths(XdgSysDirs::Config)?))
+    }
+
+    /// Returns the _user-specific_ XDG **data** directory followed by the
+    /// _system-wide_, preference-ordered, XDG **data** directories, i.e.
+    /// every directory a data file should be looked up into, from the most
+    /// important to the least important.
+    ///
+    /// See [`Xdg::data`] and [`Xdg::sys_data`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for data_dir in xdg.data_dirs_all()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn data_dirs_all(&self) -> Result<impl Iterator<Item = PathBuf>, XdgError> {
+        Ok(iter::once(self.data()?).chain(self.resolve_sys_dir_paths(XdgSysDirs::Data)?))
+    }
+
+    /// Returns the _user-specific_ autostart directory, as defined by the
+    /// [_Desktop Application Autostart Specification_](<https://specifications.freedesktop.org/autostart-spec/latest/>),
+    /// i.e. `$XDG_CONFIG_HOME/autostart`.
+    ///
+    /// # Note
+    ///
+    /// Desktop entries placed in this directory are started during the
+    /// startup of the user's desktop environment, and take precedence over
+    /// the ones with the same name found in the _system-wide_ autostart
+    /// directories (see [`Xdg::sys_autostart`]).
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let autostart_dir = xdg.autostart()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn autostart(&self) -> Result<PathBuf, XdgError> {
+        self.get_file_path(XdgDir::Config, "autostart")
+    }
+
+    /// Returns the _system-wide_, preference-ordered, autostart directories,
+    /// i.e. the `autostart` subdirectory of each directory specified by the
+    /// `XDG_CONFIG_DIRS` environment variable (`/etc/xdg/autostart` if
+    /// `XDG_CONFIG_DIRS` is not set or is set to an empty value).
+    ///
+    /// See [`Xdg::autostart`] and [`Xdg::sys_config`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let sys_autostart_dirs = xdg.sys_autostart()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn sys_autostart(&self) -> Result<Vec<PathBuf>, XdgError> {
+        Ok(self
+            .resolve_sys_dir_paths(XdgSysDirs::Config)?
+            .into_iter()
+            .map(|path| path.append("autostart"))
+            .collect())
+    }
+
+    /// Returns the _user-specific_ menu directory, as defined by the
+    /// [_Desktop Menu Specification_](<https://specifications.freedesktop.org/menu-spec/latest/>),
+    /// i.e. `$XDG_CONFIG_HOME/menus`.
+    ///
+    /// # Note
+    ///
+    /// This directory holds the `.menu` files describing the layout of the
+    /// applications menu, which take precedence over the ones found in the
+    /// _system-wide_ menu directories (see [`Xdg::sys_menus`]).
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let menus_dir = xdg.menus()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn menus(&self) -> Result<PathBuf, XdgError> {
+        self.get_file_path(XdgDir::Config, "menus")
+    }
+
+    /// Returns the _system-wide_, preference-ordered, menu directories, i.e.
+    /// the `menus` subdirectory of each directory specified by the
+    /// `XDG_CONFIG_DIRS` environment variable (`/etc/xdg/menus` if
+    /// `XDG_CONFIG_DIRS` is not set or is set to an empty value).
+    ///
+    /// See [`Xdg::menus`] and [`Xdg::sys_config`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let sys_menus_dirs = xdg.sys_menus()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn sys_menus(&self) -> Result<Vec<PathBuf>, XdgError> {
+        Ok(self
+            .resolve_sys_dir_paths(XdgSysDirs::Config)?
+            .into_iter()
+            .map(|path| path.append("menus"))
+            .collect())
+    }
+
+    /// Returns the _user-specific_ desktop entry directory, as
+    /// `$XDG_DATA_HOME/applications`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let applications_dir = xdg.applications()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn applications(&self) -> Result<PathBuf, XdgError> {
+        self.get_file_path(XdgDir::Data, "applications")
+    }
+
+    /// Returns every desktop entry directory, i.e. the `applications`
+    /// subdirectory of the _user-specific_ XDG **data** directory followed by
+    /// the ones of the _system-wide_, preference-ordered, XDG **data**
+    /// directories, from the most important to the least important.
+    ///
+    /// See [`Xdg::data_dirs_all`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for applications_dir in xdg.applications_dirs()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn applications_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        Ok(self.data_dirs_all()?.map(|path| path.append("applications")).collect())
+    }
+
+    /// Searches for the desktop entry identified by `desktop_file_id` (e.g.
+    /// `org.example.App.desktop`) inside the desktop entry directories,
+    /// returning the first match.
+    ///
+    /// # Note
+    ///
+    /// The desktop entry directories are searched in precedence order (see
+    /// [`Xdg::applications_dirs`]), so that a _user-specific_ desktop entry
+    /// shadows the _system-wide_ ones. As mandated by the specification, each
+    /// `-` in the desktop file ID may also stand for a subdirectory (e.g.
+    /// `kde-org.example.App.desktop` matches `kde/org.example.App.desktop`).
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.find_desktop_entry("org.example.App.desktop")? {
+    ///     Some(desktop_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn find_desktop_entry(&self, desktop_file_id: &str) -> Result<Option<PathBuf>, XdgError> {
+        Ok(self
+            .applications_dirs()?
+            .iter()
+            .find_map(|dir| desktop::find_entry(dir, desktop_file_id)))
+    }
+
+    /// Searches for the desktop entry identified by `desktop_file_id` inside
+    /// the desktop entry directories and parses the first match.
+    ///
+    /// See [`Xdg::find_desktop_entry`] for the lookup order and
+    /// [`DesktopEntry`] for the parsing rules.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the desktop entry could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// if let Some(desktop_entry) = xdg.read_desktop_entry("org.example.App.desktop")? {
+    ///     let exec = desktop_entry.exec();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "desktop-entry")]
+    pub fn read_desktop_entry(
+        &self,
+        desktop_file_id: &str,
+    ) -> Result<Option<DesktopEntry>, XdgError> {
+        self.find_desktop_entry(desktop_file_id)?.map(DesktopEntry::from_file).transpose()
+    }
+
+    /// Returns the current desktop environments, as set to the colon
+    /// separated `XDG_CURRENT_DESKTOP` environment variable, in order.
+    ///
+    /// # Note
+    ///
+    /// The first desktop environment is the most specific one (e.g.
+    /// `ubuntu:GNOME`). Empty entries are skipped, while invalid unicode is
+    /// replaced by the replacement character. An empty list is returned if
+    /// `XDG_CURRENT_DESKTOP` is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{DesktopEnvironment, Xdg};
+    /// std::env::set_var("XDG_CURRENT_DESKTOP", "ubuntu:GNOME");
+    ///
+    /// assert_eq!(
+    ///     vec![DesktopEnvironment::Other(String::from("ubuntu")), DesktopEnvironment::Gnome],
+    ///     Xdg::current_desktop(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn current_desktop() -> Vec<DesktopEnvironment> {
+        env::var_os("XDG_CURRENT_DESKTOP")
+            .map(|value| desktop_env::parse_list(&value.to_string_lossy()))
+            .unwrap_or_default()
+    }
+
+    /// Returns the type of the current login session, as set to the
+    /// `XDG_SESSION_TYPE` environment variable (e.g. `wayland`), if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{SessionType, Xdg};
+    /// if Xdg::session_type() == Some(SessionType::Wayland) {
+    ///     /* ... */
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn session_type() -> Option<SessionType> {
+        session::var("XDG_SESSION_TYPE").map(|value| SessionType::parse(&value))
+    }
+
+    /// Returns the class of the current login session, as set to the
+    /// `XDG_SESSION_CLASS` environment variable (e.g. `user`), if any.
+    #[inline]
+    #[must_use]
+    pub fn session_class() -> Option<SessionClass> {
+        session::var("XDG_SESSION_CLASS").map(|value| SessionClass::parse(&value))
+    }
+
+    /// Returns the identifier of the current login session, as set to the
+    /// `XDG_SESSION_ID` environment variable, if any.
+    #[inline]
+    #[must_use]
+    pub fn session_id() -> Option<String> {
+        session::var("XDG_SESSION_ID")
+    }
+
+    /// Returns the seat of the current login session, as set to the
+    /// `XDG_SEAT` environment variable (e.g. `seat0`), if any.
+    #[inline]
+    #[must_use]
+    pub fn seat() -> Option<String> {
+        session::var("XDG_SEAT")
+    }
+
+    /// Returns the virtual terminal number of the current login session, as
+    /// set to the `XDG_VTNR` environment variable, if any.
+    ///
+    /// # Note
+    ///
+    /// This method returns `None` if `XDG_VTNR` is not a valid number.
+    #[inline]
+    #[must_use]
+    pub fn vtnr() -> Option<u32> {
+        session::var("XDG_VTNR").and_then(|value| value.parse().ok())
+    }
+
+    /// Detects the application sandbox (Flatpak or Snap) the current process
+    /// runs in, if any.
+    ///
+    /// # Note
+    ///
+    /// Flatpak is detected through the `/.flatpak-info` file or the
+    /// `FLATPAK_ID` environment variable, while Snap is detected through the
+    /// `SNAP` environment variable. Use [`Sandbox::contains`] to tell whether
+    /// a resolved path points inside the sandbox redirections, e.g. to explain
+    /// users where their configuration _really_ lives.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let config = xdg.config()?;
+    /// if let Some(sandbox) = xdg.sandbox() {
+    ///     if sandbox.contains(&config) {
+    ///         println!("configuration redirected by {:?}", sandbox.kind());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sandbox(&self) -> Option<Sandbox> {
+        sandbox::detect(&self.home)
+    }
+
+    /// Returns the icon search path, as defined by the [_Icon Theme Specification_](<https://specifications.freedesktop.org/icon-theme-spec/latest/>),
+    /// in precedence order:
+    /// - `$HOME/.icons` (for backwards compatibility);
+    /// - `$XDG_DATA_HOME/icons`;
+    /// - the `icons` subdirectory of each _system-wide_ XDG **data**
+    ///   directory;
+    /// - `/usr/share/pixmaps`.
+    ///
+    /// # Note
+    ///
+    /// Icon themes are looked up in every directory but the last one, which
+    /// only holds unthemed icons. This method does not guarantee either the
+    /// directories exist.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for icon_dir in xdg.icon_dirs()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn icon_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        let mut first_occurrence = first_occurrence();
+        Ok(iter::once(self.home.join(".icons"))
+            .chain(self.data_dirs_all()?.map(|path| path.append("icons")))
+            .chain(iter::once(PathBuf::from("/usr/share/pixmaps")))
+            .filter(|path| first_occurrence(path))
+            .collect())
+    }
+
+    /// Returns the shared MIME-info database directories, as defined by the
+    /// [_Shared MIME-info Database specification_](<https://specifications.freedesktop.org/shared-mime-info-spec/latest/>),
+    /// i.e. the `mime` subdirectory of the _user-specific_ XDG **data**
+    /// directory followed by the ones of the _system-wide_ XDG **data**
+    /// directories, in precedence order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for mime_dir in xdg.mime_dirs()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn mime_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        Ok(self.data_dirs_all()?.map(|path| path.append("mime")).collect())
+    }
+
+    /// Returns the `mimeapps.list` files holding the MIME type/application
+    /// associations, in the lookup order defined by the
+    /// [_Association between MIME types and applications_](<https://specifications.freedesktop.org/mime-apps-spec/latest/>)
+    /// specification:
+    /// - `$XDG_CONFIG_HOME`;
+    /// - each _system-wide_ XDG **configuration** directory;
+    /// - `$XDG_DATA_HOME/applications` (deprecated);
+    /// - the `applications` subdirectory of each _system-wide_ XDG **data**
+    ///   directory (for distribution-provided defaults).
+    ///
+    /// Within each directory, the `<desktop>-mimeapps.list` files of the
+    /// desktop environments set to the `XDG_CURRENT_DESKTOP` environment
+    /// variable (lowercased, in order) precede `mimeapps.list`.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the files exist or are valid.
+    /// User modifications are expected to be written to
+    /// `$XDG_CONFIG_HOME/mimeapps.list`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - one of the `XDG_CONFIG_HOME`, `XDG_DATA_HOME` environment variables
+    ///   is set, but its value represents a relative path or invalid unicode;
+    /// - one of the `XDG_CONFIG_DIRS`, `XDG_DATA_DIRS` environment variables
+    ///   is set, but one (or more) path(s) in the colon separated value
+    ///   represents a relative path, or its value represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for mimeapps_list in xdg.mimeapps_lists()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mimeapps_lists(&self) -> Result<Vec<PathBuf>, XdgError> {
+        let dirs = self.config_dirs_all()?.chain(self.applications_dirs()?);
+        Ok(mime::mimeapps_lists(dirs, &mime::current_desktops()))
+    }
+
+    /// Returns the desktop file ID (e.g. `org.example.App.desktop`) of the
+    /// default application for `mime_type` (e.g. `text/markdown`), much like
+    /// `xdg-mime query default`.
+    ///
+    /// # Note
+    ///
+    /// The `mimeapps.list` files are read in lookup order (see
+    /// [`Xdg::mimeapps_lists`]), returning the first installed application
+    /// listed in a `[Default Applications]` group or, as a fallback, in an
+    /// `[Added Associations]` group; associations listed in the
+    /// `[Removed Associations]` group of a file are ignored in the files with
+    /// lower precedence. An application is installed if its desktop entry is
+    /// found (see [`Xdg::find_desktop_entry`]). Unreadable `mimeapps.list`
+    /// files are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - one of the `XDG_CONFIG_HOME`, `XDG_DATA_HOME` environment variables
+    ///   is set, but its value represents a relative path or invalid unicode;
+    /// - one of the `XDG_CONFIG_DIRS`, `XDG_DATA_DIRS` environment variables
+    ///   is set, but one (or more) path(s) in the colon separated value
+    ///   represents a relative path, or its value represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.default_app_for("text/markdown")? {
+    ///     Some(desktop_file_id) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn default_app_for(&self, mime_type: &str) -> Result<Option<String>, XdgError> {
+        let lists: Vec<String> = self
+            .mimeapps_lists()?
+            .iter()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .collect();
+
+        mime::default_app(&lists, mime_type, |desktop_file_id| {
+            self.find_desktop_entry(desktop_file_id).map(|found| found.is_some())
+        })
+    }
+
+    /// Loads the file name to MIME type mapping out of the `globs2` files
+    /// inside the shared MIME-info database directories (see
+    /// [`Xdg::mime_dirs`]).
+    ///
+    /// # Note
+    ///
+    /// Missing or unreadable `globs2` files are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let mime_globs = xdg.mime_globs()?;
+    /// let mime_type = mime_globs.mime_type("notes.md");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "mime-glob")]
+    pub fn mime_globs(&self) -> Result<MimeGlobs, XdgError> {
+        Ok(MimeGlobs::load(&self.mime_dirs()?))
+    }
+
+    /// Returns the D-Bus **session** bus service directories, i.e. the
+    /// `dbus-1/services` subdirectory of the _user-specific_ XDG **data**
+    /// directory followed by the ones of the _system-wide_ XDG **data**
+    /// directories, in precedence order.
+    ///
+    /// # Note
+    ///
+    /// These directories hold the `.service` files used by the session bus to
+    /// activate services on demand.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for dbus_services_dir in xdg.dbus_services_dirs()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn dbus_services_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        Ok(self.data_dirs_all()?.map(|path| path.append("dbus-1/services")).collect())
+    }
+
+    /// Returns the D-Bus **system** bus service directories, i.e. the
+    /// `dbus-1/system-services` subdirectory of each _system-wide_ XDG
+    /// **data** directory, in precedence order.
+    ///
+    /// # Note
+    ///
+    /// The system bus does not look up _user-specific_ directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for dbus_system_services_dir in xdg.dbus_system_services_dirs()? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn dbus_system_services_dirs(&self) -> Result<Vec<PathBuf>, XdgError> {
+        Ok(self
+            .resolve_sys_dir_paths(XdgSysDirs::Data)?
+            .into_iter()
+            .map(|path| path.append("dbus-1/system-services"))
+            .collect())
+    }
+
+    /// Returns the _user-specific_ XDG file path as `<xdg_dir>/<file>`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    fn get_file_path<P>(&self, dir: XdgDir, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_dir_path_with_capacity(dir, file.as_ref().append_len())
+            .map(|path| path.append(file))
+    }
+
+    /// Returns the _user-specific_ XDG **cache** file as
+    /// `$XDG_CACHE_HOME/<file>`. Falls back to `$HOME/.cache/<file>` if
+    /// `XDG_CACHE_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let cache_file = xdg.cache_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn cache_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_file_path(XdgDir::Cache, file)
+    }
+
+    /// Returns the _user-specific_ XDG **config** file as
+    /// `$XDG_CONFIG_HOME/<file>`. Falls back to `$HOME/.config/<file>` if
+    /// `XDG_CONFIG_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let config_file = xdg.config_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_file_path(XdgDir::Config, file)
+    }
+
+    /// Returns the _user-specific_ XDG **data** file as
+    /// `$XDG_DATA_HOME/<file>`. Falls back to `$HOME/.local/share/<file>`
+    /// if `XDG_DATA_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let data_file = xdg.data_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_file_path(XdgDir::Data, file)
+    }
+
+    /// Returns the _user-specific_ XDG **state** file as
+    /// `$XDG_STATE_HOME/<file>`. Falls back to `$HOME/.local/state/<file>`
+    /// if `XDG_STATE_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let state_file = xdg.state_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn state_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_file_path(XdgDir::State, file)
+    }
+
+    /// Returns the _user-specific_ XDG **bin** file as
+    /// `$XDG_BIN_HOME/<file>`. Falls back to `$HOME/.local/bin/<file>`
+    /// if `XDG_BIN_HOME` is not set or is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment is set, but its value represents
+    ///   invalid unicode.
+    ///
+    /// # Exapmles
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// let bin_file = xdg.bin_file("file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn bin_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_file_path(XdgDir::Bin, file)
+    }
+
+    /// Returns the _user-specific_ XDG file as `<dir>/<file>`, as the
+    /// matching [`Xdg::cache_file`], [`Xdg::config_file`],
+    /// [`Xdg::data_file`], [`Xdg::state_file`] or [`Xdg::bin_file`] method
+    /// does.
+    ///
+    /// # Note
+    ///
+    /// This method does not guarantee either the path exists or points to a
+    /// regular file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents a relative path;
+    /// - the XDG environment variable of `dir` is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgDir, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// assert_eq!(xdg.data_file("file")?, xdg.file(XdgDir::Data, "file")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn file<P>(&self, dir: XdgDir, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_file_path(dir, file)
+    }
+
+    /// Searches for `file` inside a _user-specific_ XDG base directory.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the file is found inside the specified XDG directory;
+    /// - `None` if the file is **not** found inside the specified XDG
+    ///   directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a
+    ///   relative path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_usr_file<P>(
+        &self,
+        dir: XdgDir,
+        file: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut path = self.get_dir_path(dir)?;
+        path.push(file);
+        Ok(self.probe_entry(&path, entry_type)?.then_some(path))
+    }
+
+    /// Searches for `file` inside a _system-wide_, preference-ordered, set of
+    /// XDG directories.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the file is found inside one of the preference-ordered set of
+    ///   XDG system directories;
+    /// - `None` if the file is **not** found inside any of the preference-ordered
+    ///   set of XDG system directories.
+    ///
+    /// # Errors
+    ///
+    /// This funciton returns an error in the following cases:
+    /// - the XDG environment variable is set, but its value represents a relative
+    ///   path;
+    /// - the XDG environment variable is set, but its value represents invalid
+    ///   unicode.
+    #[inline]
+    #[rustfmt::skip]
+    #[cfg(feature = "fs")]
+    fn search_sys_file<P>(
+        &self,
+        dirs: XdgSysDirs,
+        file: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.probe_first(
+            self.read_sys_dir_paths_lossy(dirs)?.into_iter().map(|path| path.append(&file)),
+            entry_type,
+        )
+    }
+
+    /// Searches for `file` inside XDG directories in the following order:
+    /// - _user-specific_ XDG base directory;
+    /// - _system-wide_, preference-ordered, set of XDG directories.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if the file is found inside one of the XDG directories;
+    /// - `None` if the file is **not** found inside one of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDir`]) is set, but
+    ///   its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDir`]) is set, but
+    ///   its value contains invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_file<P>(
+        &self,
+        dir: XdgDir,
+        file: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let search_usr = || self.search_usr_file(dir, &file, entry_type);
+        let search_sys = || match dir.to_sys() {
+            Some(sys_dirs) => self.search_sys_file(sys_dirs, &file, entry_type),
+            None => Ok(None),
+        };
+
+        let path = match self.search_order {
+            SearchOrder::UserFirst => match search_usr()? {
+                Some(path) => Some(path),
+                None => search_sys()?,
+            },
+            SearchOrder::SystemFirst => match search_sys()? {
+                Some(path) => Some(path),
+                None => search_usr()?,
+            },
+        };
+
+        match path {
+            Some(path) => self.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for `file` inside XDG directories, in the same order as
+    /// [`Xdg::search_file`], returning the first regular file whose metadata
+    /// satisfies `predicate`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value contains invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_file_with<P, F>(
+        &self,
+        dir: XdgDir,
+        file: P,
+        predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        let usr_paths = vec![self.get_dir_path(dir)?.append(&file)];
+        let sys_paths = match dir.to_sys() {
+            Some(sys_dirs) => self.resolve_sys_dir_paths(sys_dirs)?,
+            None => Vec::new(),
+        };
+        let sys_paths: Vec<PathBuf> =
+            sys_paths.into_iter().map(|path| path.append(&file)).collect();
+
+        let paths = match self.search_order {
+            SearchOrder::UserFirst => [usr_paths, sys_paths],
+            SearchOrder::SystemFirst => [sys_paths, usr_paths],
+        };
+
+        match self.probe_first_with(paths.into_iter().flatten(), predicate)? {
+            Some(path) => self.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **cache** directory
+    /// specified by the `XDG_CACHE_HOME` environment variable. The search
+    /// falls back to `$HOME/.cache` if `XDG_CACHE_HOME` is not set or is
+    /// set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_cache_file("file")? {
+    ///     Some(cache_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_cache_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::Cache, file, EntryType::File)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory specified by the `XDG_CONFIG_HOME` environment variable.
+    /// If `XDG_CONFIG_HOME` is not set or is set to an empty value, the
+    /// search falls back to `$HOME/.config`.
+    ///
+    /// If `file` is not found inside the _user-specific_ XDG directory, a
+    /// lookup is performed on the _system-wide_, preference ordered
+    /// directories specified by the `XDG_CONFIG_DIRS`. If `XDG_CONFIG_DIRS`
+    /// is not set or is set to an empty value, the search falls back to
+    /// `/etc/xdg`.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG config
+    ///   directory and:
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but one (or
+    ///       more) path(s) in the colon separated value represents a relative
+    ///       path;
+    ///     - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_config_file("file")? {
+    ///     Some(config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::Config, file, EntryType::File)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// specified by the `XDG_DATA_HOME` environment variable. If
+    /// `XDG_DATA_HOME` is not set or is set to an empty value, the search
+    /// falls back to `$HOME/.local/share`.
+    ///
+    /// If `file` is not found inside the _user-specific_ XDG directory, a
+    /// lookup is performed on the _system-wide_, preference ordered
+    /// directories specified by the `XDG_DATA_DIRS`. If `XDG_DATA_DIRS` is
+    /// not set or is set to an empty value, the search falls back to
+    /// `/usr/local/share:/usr/share`.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG data directory
+    ///   and:
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///       path(s) in the colon separated value represents a relative path;
+    ///     - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///       represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_data_file("file")? {
+    ///     Some(data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::Data, file, EntryType::File)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and the _system-wide_ XDG **configuration** directories,
+    /// like [`Xdg::search_config_file`] does, returning the first regular
+    /// file whose metadata satisfies `predicate`.
+    ///
+    /// The predicate receives the path and the metadata of each candidate
+    /// during the walk, allowing to filter on size, modification time or
+    /// permissions without reading the metadata again afterwards.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - a candidate is a dangling symbolic link and the [`SymlinkPolicy`] is
+    ///   [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_config_file_with("file", |_, metadata| metadata.len() > 0)? {
+    ///     Some(config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_file_with<P, F>(
+        &self,
+        file: P,
+        predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        self.search_file_with(XdgDir::Config, file, predicate)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// and the _system-wide_ XDG **data** directories, like
+    /// [`Xdg::search_data_file`] does, returning the first regular file whose
+    /// metadata satisfies `predicate`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - a candidate is a dangling symbolic link and the [`SymlinkPolicy`] is
+    ///   [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_data_file_with("file", |_, metadata| metadata.len() > 0)? {
+    ///     Some(data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_file_with<P, F>(
+        &self,
+        file: P,
+        predicate: F,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path, &fs::Metadata) -> bool,
+    {
+        self.search_file_with(XdgDir::Data, file, predicate)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and the _system-wide_ XDG **configuration** directories, like
+    /// [`Xdg::search_config_file`] does, failing if `file` is **not** found.
+    ///
+    /// Unlike the returned [`XdgError::FileNotFound`] error, the `None`
+    /// returned by [`Xdg::search_config_file`] can't tell users which
+    /// directories were searched.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.require_config_file("file") {
+    ///     Ok(config_file) => { /* ... */ },
+    ///     Err(err @ XdgError::FileNotFound { .. }) => eprintln!("{err}"),
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn require_config_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_config_file(&file)?;
+        Xdg::require_found(file, found, || self.searched_dirs(XdgDir::Config))
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data**
+    /// directory and the _system-wide_ XDG **data** directories, like
+    /// [`Xdg::search_data_file`] does, failing if `file` is **not** found.
+    ///
+    /// Unlike the returned [`XdgError::FileNotFound`] error, the `None`
+    /// returned by [`Xdg::search_data_file`] can't tell users which
+    /// directories were searched.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.require_data_file("file") {
+    ///     Ok(data_file) => { /* ... */ },
+    ///     Err(err @ XdgError::FileNotFound { .. }) => eprintln!("{err}"),
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn require_data_file<P>(&self, file: P) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_data_file(&file)?;
+        Xdg::require_found(file, found, || self.searched_dirs(XdgDir::Data))
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory only, specified by the `XDG_CONFIG_HOME` environment variable,
+    /// never consulting the _system-wide_ `XDG_CONFIG_DIRS` ones.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside the _user-specific_ XDG directory;
+    /// - `None` if `file` is **not** found inside the _user-specific_ XDG
+    ///   directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_usr_config_file("file")? {
+    ///     Some(usr_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_usr_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        match self.search_usr_file(XdgDir::Config, file, EntryType::File)? {
+            Some(path) => self.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data**
+    /// directory only, specified by the `XDG_DATA_HOME` environment variable,
+    /// never consulting the _system-wide_ `XDG_DATA_DIRS` ones.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside the _user-specific_ XDG directory;
+    /// - `None` if `file` is **not** found inside the _user-specific_ XDG
+    ///   directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_usr_data_file("file")? {
+    ///     Some(usr_data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_usr_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        match self.search_usr_file(XdgDir::Data, file, EntryType::File)? {
+            Some(path) => self.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for `file` inside the _system-wide_, preference-ordered, XDG
+    /// **configuration** directories specified by the `XDG_CONFIG_DIRS` environment
+    /// variable, skipping the _user-specific_ one. If `XDG_CONFIG_DIRS` is not set
+    /// or is set to an empty value, the search falls back to
+    /// `/etc/xdg`.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the _system-wide_ XDG
+    ///   directories;
+    /// - `None` if `file` is **not** found inside any of the _system-wide_
+    ///   XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_sys_config_file("file")? {
+    ///     Some(sys_config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_sys_config_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        match self.search_sys_file(XdgSysDirs::Config, file, EntryType::File)? {
+            Some(path) => self.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for `file` inside the _system-wide_, preference-ordered, XDG
+    /// **data** directories specified by the `XDG_DATA_DIRS` environment
+    /// variable, skipping the _user-specific_ one. If `XDG_DATA_DIRS` is not set
+    /// or is set to an empty value, the search falls back to
+    /// `/usr/local/share:/usr/share`.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the _system-wide_ XDG
+    ///   directories;
+    /// - `None` if `file` is **not** found inside any of the _system-wide_
+    ///   XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_sys_data_file("file")? {
+    ///     Some(sys_data_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn search_sys_data_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        match self.search_sys_file(XdgSysDirs::Data, file, EntryType::File)? {
+            Some(path) => self.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **state** directory
+    /// specified by the `XDG_STATE_HOME` environment variable. The search
+    /// falls back to `$HOME/.local/state` if `XDG_STATE_HOME` is not set or
+    /// is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_state_file("file")? {
+    ///     Some(state_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_state_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::State, file, EntryType::File)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **binary** directory
+    /// specified by the `XDG_BIN_HOME` environment variable. The search
+    /// falls back to `$HOME/.local/bin` if `XDG_BIN_HOME` is not set or
+    /// is set to an empty value.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `file` is found inside one of the XDG directories;
+    /// - `None` if `file` is **not** found inside any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_bin_file("file")? {
+    ///     Some(bin_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_bin_file<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::Bin, file, EntryType::File)
+    }
+
+    /// Searches for the `executable` regular file inside the _user-specific_
+    /// XDG **binary** directory specified by the `XDG_BIN_HOME` environment
+    /// variable, falling back to `$HOME/.local/bin`.
+    ///
+    /// Unlike [`Xdg::search_bin_file`], files lacking the execute permission
+    /// bits are skipped on Unix.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `executable` is found inside the XDG binary directory;
+    /// - `None` if `executable` is **not** found inside the XDG binary
+    ///   directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.find_executable("tool")? {
+    ///     Some(tool) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn find_executable<P>(&self, executable: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = self.get_dir_path(XdgDir::Bin)?.append(executable);
+        match self.probe_first_with(iter::once(path), is_executable)? {
+            Some(path) => self.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for the `executable` regular file inside the _user-specific_
+    /// XDG **binary** directory, like [`Xdg::find_executable`] does, and then
+    /// inside each of the directories listed by the `PATH` environment
+    /// variable, in order.
+    ///
+    /// Relative directories in `PATH` are ignored, so that the result does not
+    /// depend on the current working directory.
+    ///
+    /// # Note
+    ///
+    /// This method returns:
+    /// - `Some` if `executable` is found inside one of the directories;
+    /// - `None` if `executable` is **not** found inside any of the directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_BIN_HOME` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.find_executable_in_path("tool")? {
+    ///     Some(tool) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs")]
+    pub fn find_executable_in_path<P>(&self, executable: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let bin_path = self.get_dir_path(XdgDir::Bin)?;
+        let path_dirs: Vec<PathBuf> = match env::var_os("PATH") {
+            Some(path) => env::split_paths(&path).filter(|dir| dir.is_absolute()).collect(),
+            None => Vec::new(),
+        };
+
+        let paths = iter::once(bin_path).chain(path_dirs).map(|dir| dir.append(&executable));
+        match self.probe_first_with(paths, is_executable)? {
+            Some(path) => self.found(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the
+    /// _user-specific_ XDG **cache** directory, like [`Xdg::search_cache_file`]
+    /// does for regular files.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{EntryType, Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_cache_entry("entry", EntryType::Any)? {
+    ///     Some(cache_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_cache_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::Cache, entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the
+    /// _user-specific_ XDG **configuration** directory and the _system-wide_
+    /// XDG **configuration** directories, like [`Xdg::search_config_file`] does
+    /// for regular files.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{EntryType, Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_config_entry("entry", EntryType::Any)? {
+    ///     Some(config_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::Config, entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the
+    /// _user-specific_ XDG **data** directory and the _system-wide_ XDG
+    /// **data** directories, like [`Xdg::search_data_file`] does for regular
+    /// files.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{EntryType, Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_data_entry("entry", EntryType::Any)? {
+    ///     Some(data_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::Data, entry, entry_type)
+    }
+
+    /// Searches for an `entry` of the given [`EntryType`] inside the
+    /// _user-specific_ XDG **state** directory, like [`Xdg::search_state_file`]
+    /// does for regular files.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching entry is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{EntryType, Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_state_entry("entry", EntryType::Any)? {
+    ///     Some(state_entry) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_state_entry<P>(
+        &self,
+        entry: P,
+        entry_type: EntryType,
+    ) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_file(XdgDir::State, entry, entry_type)
+    }
+
+    /// Searches for the `dir` subdirectory inside the _user-specific_ XDG
+    /// **cache** directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_cache_dir("app_name/cache")? {
+    ///     Some(cache_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_cache_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_cache_entry(dir, EntryType::Dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the _user-specific_ XDG
+    /// **configuration** directory and the _system-wide_ XDG **configuration**
+    /// directories, returning the first existing one in order of precedence.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_config_dir("app_name/themes")? {
+    ///     Some(themes_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_config_entry(dir, EntryType::Dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the _user-specific_ XDG
+    /// **data** directory and the _system-wide_ XDG **data** directories,
+    /// returning the first existing one in order of precedence.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_data_dir("app_name/plugins")? {
+    ///     Some(plugins_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_data_entry(dir, EntryType::Dir)
+    }
+
+    /// Searches for the `dir` subdirectory inside the _user-specific_ XDG
+    /// **state** directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the matching directory is a dangling symbolic link and the
+    ///   [`SymlinkPolicy`] is [`SymlinkPolicy::ErrorOnDangling`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_state_dir("app_name/sessions")? {
+    ///     Some(sessions_dir) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_state_dir<P>(&self, dir: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_state_entry(dir, EntryType::Dir)
+    }
+
+    /// Searches for `file` inside every XDG directory, collecting up to
+    /// `limit` matches in the following order:
+    /// - _user-specific_ XDG base directory;
+    /// - _system-wide_, preference-ordered, set of XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value represents a relative path;
+    /// - the XDG environment variable ([`XdgDir`] or [`XdgSysDirs`]) is set,
+    ///   but its value contains invalid unicode.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn search_files<P>(&self, dir: XdgDir, file: P, limit: usize) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut paths: Vec<PathBuf> =
+            self.search_usr_file(dir, &file, EntryType::File)?.into_iter().collect();
+
+        if let Some(sys_dirs) = dir.to_sys().filter(|_| paths.len() < limit) {
+            let sys_paths = self.resolve_sys_dir_paths(sys_dirs)?.into_iter();
+            let limit = limit - paths.len();
+            paths.extend(self.probe_all(sys_paths.map(|path| path.append(&file)), limit)?);
+        }
+
+        paths.into_iter().map(|path| self.found(path)).collect()
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and every _system-wide_ XDG **configuration** directory,
+    /// returning all the matches in precedence order: `XDG_CONFIG_HOME`
+    /// first, followed by `XDG_CONFIG_DIRS`.
+    ///
+    /// This is useful to layer configuration files, e.g. defaults provided in
+    /// `/etc/xdg` overridden by the user's configuration in `~/.config`.
+    ///
+    /// # Note
+    ///
+    /// This method returns an empty [`Vec`] if `file` is **not** found inside
+    /// any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for config_file in xdg.search_config_files("file")? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_files<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_files(XdgDir::Config, file, usize::MAX)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and the _system-wide_ XDG **configuration** directories,
+    /// returning at most the first `limit` matches in precedence order, like
+    /// [`Xdg::search_config_files`] does.
+    ///
+    /// The search stops as soon as `limit` matches are found, sparing the
+    /// lookups in the remaining, lower-precedence, directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// // Only the two most important layers are needed.
+    /// for config_file in xdg.search_config_files_bounded("file", 2)? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_files_bounded<P>(
+        &self,
+        file: P,
+        limit: usize,
+    ) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_files(XdgDir::Config, file, limit)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **configuration**
+    /// directory and every _system-wide_ XDG **configuration** directory,
+    /// returning the most recently modified match.
+    ///
+    /// Among matches modified at the same time, the one in the
+    /// higher-precedence directory is returned.
+    ///
+    /// # Note
+    ///
+    /// This method returns `None` if `file` is **not** found inside any of the
+    /// XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the modification time of one of the matches can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.search_config_file_newest("file")? {
+    ///     Some(config_file) => { /* ... */ },
+    ///     None => { /* ... */ },
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_config_file_newest<P>(&self, file: P) -> Result<Option<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        Xdg::newest(self.search_files(XdgDir::Config, file, usize::MAX)?)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// and every _system-wide_ XDG **data** directory, returning all the
+    /// matches in precedence order: `XDG_DATA_HOME` first, followed by
+    /// `XDG_DATA_DIRS`.
+    ///
+    /// # Note
+    ///
+    /// This method returns an empty [`Vec`] if `file` is **not** found inside
+    /// any of the XDG directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for data_file in xdg.search_data_files("file")? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_files<P>(&self, file: P) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_files(XdgDir::Data, file, usize::MAX)
+    }
+
+    /// Searches for `file` inside the _user-specific_ XDG **data** directory
+    /// and the _system-wide_ XDG **data** directories, returning at most the
+    /// first `limit` matches in precedence order, like
+    /// [`Xdg::search_data_files`] does.
+    ///
+    /// The search stops as soon as `limit` matches are found, sparing the
+    /// lookups in the remaining, lower-precedence, directories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for data_file in xdg.search_data_files_bounded("file", 2)? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn search_data_files_bounded<P>(
+        &self,
+        file: P,
+        limit: usize,
+    ) -> Result<Vec<PathBuf>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        self.search_files(XdgDir::Data, file, limit)
+    }
+
+    /// Searches for regular files matching `pattern` inside the
+    /// _user-specific_ XDG **data** directory and every _system-wide_ XDG
+    /// **data** directory, returning all the matches in precedence order:
+    /// `XDG_DATA_HOME` first, followed by `XDG_DATA_DIRS`. Matches found
+    /// inside the same directory are sorted by path.
+    ///
+    /// The pattern is made of `/` separated components, each of which may
+    /// contain the following wildcards:
+    /// - `*` matches any (possibly empty) sequence of characters;
+    /// - `?` matches exactly one character.
+    ///
+    /// # Note
+    ///
+    /// Unreadable directories and entries whose name is not valid unicode are
+    /// silently skipped.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but one (or more)
+    ///   path(s) in the colon separated value represents a relative path;
+    /// - the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// for theme in xdg.search_data_glob("themes/*.toml")? {
+    ///     /* ... */
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "glob")]
+    pub fn search_data_glob(&self, pattern: &str) -> Result<Vec<PathBuf>, XdgError> {
+        let mut paths = glob::glob(self.data()?, pattern);
+        for sys_path in self.resolve_sys_dir_paths(XdgSysDirs::Data)? {
+            paths.extend(glob::glob(sys_path, pattern));
+        }
+
+        paths.into_iter().map(|path| self.found(path)).collect()
+    }
+
+    /// Recursively creates `path` and all of its missing parent directories,
+    /// with permissions set to `0700` on Unix, as required by the
+    /// specification.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an [`XdgError::Io`] error if one of the
+    /// directories could not be created.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn create_dir_all(path: &Path) -> Result<(), XdgError> {
+        let mut dir_builder = fs::DirBuilder::new();
+        dir_builder.recursive(true);
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut dir_builder, 0o700);
+
+        dir_builder.create(path).map_err(|source| XdgError::Io { path: path.into(), source })
+    }
+
+    /// Creates (or truncates) the file at `path` for writing, with
+    /// permissions set to `0600` on Unix, creating its missing parent
+    /// directories.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an [`XdgError::Io`] error if the file or one of
+    /// its parent directories could not be created.
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn create_file(path: PathBuf) -> Result<File, XdgError> {
+        if let Some(parent) = path.parent() {
+            Xdg::create_dir_all(parent)?;
+        }
+
+        let mut open_options = OpenOptions::new();
+        open_options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut open_options, 0o600);
+
+        open_options.open(&path).map_err(|source| XdgError::Io { path, source })
+    }
+
+    /// Returns the path found by one of the search methods, or an
+    /// [`XdgError::FileNotFound`] error recording the `searched` directories
+    /// if `file` was **not** found.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub(crate) fn require_found<P, S>(
+        file: P,
+        found: Option<PathBuf>,
+        searched: S,
+    ) -> Result<PathBuf, XdgError>
+    where
+        P: AsRef<Path>,
+        S: FnOnce() -> Vec<PathBuf>,
+    {
+        found.ok_or_else(|| XdgError::FileNotFound { file: file.as_ref().into(), searched: searched() })
+    }
+
+    /// Returns the directories searched for a file inside the `dir` XDG
+    /// directories, in the [`SearchOrder`] they are consulted. Directories
+    /// failing to resolve are left out.
+    #[cfg(feature = "fs")]
+    pub(crate) fn searched_dirs(&self, dir: XdgDir) -> Vec<PathBuf> {
+        let usr_dirs: Vec<PathBuf> = self.get_dir_path(dir).into_iter().collect();
+        let sys_dirs = match dir.to_sys() {
+            Some(sys_dirs) => self.resolve_sys_dir_paths(sys_dirs).unwrap_or_default(),
+            None => Vec::new(),
+        };
+
+        let dirs = match self.search_order {
+            SearchOrder::UserFirst => [usr_dirs, sys_dirs],
+            SearchOrder::SystemFirst => [sys_dirs, usr_dirs],
+        };
+        dirs.into_iter().flatten().collect()
+    }
+
+    /// Reads the file found by one of the search methods, using the given
+    /// `read` function.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error in the following cases:
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]), inside the
+    ///   `searched` directories;
+    /// - `file` was found, but `read` failed ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    fn read_found<P, S, T, F>(
+        file: P,
+        found: Option<PathBuf>,
+        searched: S,
+        read: F,
+    ) -> Result<T, XdgError>
+    where
+        P: AsRef<Path>,
+        S: FnOnce() -> Vec<PathBuf>,
+        F: FnOnce(&Path) -> io::Result<T>,
+    {
+        let path = Xdg::require_found(file, found, searched)?;
+        read(&path).map_err(|source| XdgError::Io { path, source })
+    }
+
+    /// Loads the configuration out of the file found by one of the search
+    /// methods.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error in the following cases:
+    /// - `file` was **not** found ([`XdgError::FileNotFound`]), inside the
+    ///   `searched` directories;
+    /// - `file` was found, but could not be read ([`XdgError::Io`]);
+    /// - `file` was found, but does not contain a valid configuration
+    ///   ([`XdgError::InvalidConfig`]).
+    #[cfg(all(feature = "fs", feature = "serde"))]
+    pub(crate) fn load_found<F, T, P, S>(
+        format: F,
+        file: P,
+        found: Option<PathBuf>,
+        searched: S,
+    ) -> Result<T, XdgError>
+    where
+        F: ConfigFormat,
+        T: serde::de::DeserializeOwned,
+        P: AsRef<Path>,
+        S: FnOnce() -> Vec<PathBuf>,
+    {
+        let path = Xdg::require_found(file, found, searched)?;
+        let contents = fs::read(&path).map_err(|source| XdgError::Io { path: path.clone(), source })?;
+        format
+            .parse(&contents)
+            .map_err(|err| XdgError::InvalidConfig { path, message: err.to_string() })
+    }
+
+    /// Searches for `file` inside the XDG **cache** directories and reads
+    /// its whole content into a byte vector.
+    ///
+    /// See [`Xdg::search_cache_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.read_cache_file("file") {
+    ///     Ok(content) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_cache_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_cache_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::Cache);
+        Xdg::read_found(file, found, searched, |path| fs::read(path))
+    }
+
+    /// Searches for `file` inside the XDG **cache** directories and reads
+    /// its whole content into a string.
+    ///
+    /// See [`Xdg::read_cache_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CACHE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_cache_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_cache_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::Cache);
+        Xdg::read_found(file, found, searched, |path| fs::read_to_string(path))
+    }
+
+    /// Searches for `file` inside the XDG **configuration** directories and
+    /// reads its whole content into a byte vector.
+    ///
+    /// See [`Xdg::search_config_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.read_config_file("file") {
+    ///     Ok(content) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_config_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_config_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::Config);
+        Xdg::read_found(file, found, searched, |path| fs::read(path))
+    }
+
+    /// Searches for `file` inside the XDG **configuration** directories and
+    /// reads its whole content into a string.
+    ///
+    /// See [`Xdg::read_config_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_config_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_config_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::Config);
+        Xdg::read_found(file, found, searched, |path| fs::read_to_string(path))
+    }
+
+    /// Searches for `file` inside the XDG **configuration** directories and
+    /// loads the configuration it contains, parsed through the given
+    /// [`ConfigFormat`].
+    ///
+    /// See [`Xdg::search_config_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_CONFIG_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_CONFIG_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]);
+    /// - `file` was found, but does not contain a valid configuration
+    ///   ([`XdgError::InvalidConfig`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.load_config::<_, Config, _>(Json, "app_name/config.json") {
+    ///     Ok(config) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(all(feature = "fs", feature = "serde"))]
+    pub fn load_config<F, T, P>(&self, format: F, file: P) -> Result<T, XdgError>
+    where
+        F: ConfigFormat,
+        T: serde::de::DeserializeOwned,
+        P: AsRef<Path>,
+    {
+        let found = self.search_config_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::Config);
+        Xdg::load_found(format, file, found, searched)
+    }
+
+    /// Searches for `file` inside the XDG **data** directories and reads
+    /// its whole content into a byte vector.
+    ///
+    /// See [`Xdg::search_data_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.read_data_file("file") {
+    ///     Ok(content) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_data_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_data_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::Data);
+        Xdg::read_found(file, found, searched, |path| fs::read(path))
+    }
+
+    /// Searches for `file` inside the XDG **data** directories and reads
+    /// its whole content into a string.
+    ///
+    /// See [`Xdg::read_data_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_DATA_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside the _user-specific_ XDG directory
+    ///   and the `XDG_DATA_DIRS` environment variable is set, but its value
+    ///   represents a relative path or invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_data_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_data_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::Data);
+        Xdg::read_found(file, found, searched, |path| fs::read_to_string(path))
+    }
+
+    /// Searches for `file` inside the XDG **state** directories and reads
+    /// its whole content into a byte vector.
+    ///
+    /// See [`Xdg::search_state_file`] for the lookup order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read ([`XdgError::Io`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use microxdg::{Xdg, XdgError};
+    /// # fn main() -> Result<(), XdgError> {
+    /// let xdg = Xdg::new()?;
+    /// match xdg.read_state_file("file") {
+    ///     Ok(content) => { /* ... */ },
+    ///     Err(XdgError::FileNotFound { .. }) => { /* ... */ },
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_state_file<P>(&self, file: P) -> Result<Vec<u8>, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_state_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::State);
+        Xdg::read_found(file, found, searched, |path| fs::read(path))
+    }
+
+    /// Searches for `file` inside the XDG **state** directories and reads
+    /// its whole content into a string.
+    ///
+    /// See [`Xdg::read_state_file`] for further details.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error in the following cases:
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents a relative path;
+    /// - the `XDG_STATE_HOME` environment variable is set, but its value
+    ///   represents invalid unicode;
+    /// - `file` was **not** found inside any of the XDG directories
+    ///   ([`XdgError::FileNotFound`]);
+    /// - `file` was found, but could not be read or does not contain
+    ///   valid UTF-8 ([`XdgError::Io`]).
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub fn read_state_file_to_string<P>(&self, file: P) -> Result<String, XdgError>
+    where
+        P: AsRef<Path>,
+    {
+        let found = self.search_state_file(&file)?;
+        let searched = || self.searched_dirs(XdgDir::State);
+        Xdg::read_found(file, found, searched, |path| fs::read_to_string(path))
+    }
+}
+
+/// Prints one `<dir>: <path>` line per XDG base directory, suitable for
+/// `--paths` output: _system-wide_ directories are colon separated, while
+/// directories that could not be resolved report the error instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{Xdg, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// std::env::remove_var("XDG_CONFIG_HOME");
+/// std::env::set_var("HOME", "/home/user");
+///
+/// let xdg = Xdg::new()?;
+/// assert!(xdg.to_string().contains("config: /home/user/.config\n"));
+/// # Ok(())
+/// # }
+/// ```
+impl fmt::Display for Xdg {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "home: {}", self.home.display())?;
+        for (name, dir) in [
+            ("cache", XdgDir::Cache),
+            ("config", XdgDir::Config),
+            ("data", XdgDir::Data),
+            ("state", XdgDir::State),
+            ("bin", XdgDir::Bin),
+        ] {
+            write_summary_line(formatter, name, self.dir(dir).map(|path| vec![path]))?;
+        }
+        let runtime = self.runtime().map(|runtime| runtime.into_iter().collect());
+        write_summary_line(formatter, "runtime", runtime)?;
+        write_summary_line(formatter, "sys_config", self.sys_dirs(XdgSysDirs::Config))?;
+        write_summary_line(formatter, "sys_data", self.sys_dirs(XdgSysDirs::Data))
+    }
+}
+
+/// Writes a `<name>: <paths>` line of the [`Display`](fmt::Display) summary,
+/// printing `unset` if `paths` is empty and the error if not resolved.
+pub(crate) fn write_summary_line(
+    formatter: &mut fmt::Formatter<'_>,
+    name: &str,
+    paths: Result<Vec<PathBuf>, XdgError>,
+) -> fmt::Result {
+    write!(formatter, "{name}: ")?;
+    match paths {
+        Ok(paths) if paths.is_empty() => formatter.write_str("unset")?,
+        Ok(paths) => {
+            for (index, path) in paths.iter().enumerate() {
+                let separator = if index == 0 { "" } else { ":" };
+                write!(formatter, "{separator}{}", path.display())?;
+            }
+        },
+        Err(err) => write!(formatter, "error ({err})")?,
+    }
+    writeln!(formatter)
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+    use std::ffi::OsStr;
+    use std::os::unix::prelude::OsStrExt;
+
+    use super::*;
+
+    const INVALID_UNICODE_BYTES: [u8; 4] = [0xF0, 0x90, 0x80, 0x67];
+
+    #[inline]
+    fn remove_xdg_vars() {
+        env::remove_var("USER");
+        env::remove_var("LOGNAME");
+        env::remove_var("HOME");
+
+        // User XDG environment variables
+        env::remove_var("XDG_CACHE_HOME");
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_STATE_HOME");
+        env::remove_var("XDG_BIN_HOME");
+        env::remove_var("XDG_RUNTIME_DIR");
+
+        // User XDG environment variables
+        env::remove_var("XDG_CONFIG_DIRS");
+        env::remove_var("XDG_DATA_DIRS");
+
+        // Desktop environment and session variables
+        env::remove_var("XDG_CURRENT_DESKTOP");
+        env::remove_var("XDG_SESSION_TYPE");
+        env::remove_var("XDG_SESSION_CLASS");
+        env::remove_var("XDG_SESSION_ID");
+        env::remove_var("XDG_SEAT");
+        env::remove_var("XDG_VTNR");
+
+        // Sandbox environment variables
+        env::remove_var("FLATPAK_ID");
+        env::remove_var("SNAP");
+        env::remove_var("SNAP_NAME");
+        env::remove_var("SNAP_USER_DATA");
+        env::remove_var("SNAP_USER_COMMON");
+        env::remove_var("SUDO_USER");
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn new_xdg() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user2");
+        env::set_var("HOME", "/home/user1");
+        assert_eq!(Path::new("/home/user1"), Xdg::new()?.home());
+        assert_eq!(Path::new("/home/user1"), Xdg::new_app("app_name")?.home());
+
+        env::remove_var("HOME");
         assert_eq!(Path::new("/home/user2"), Xdg::new()?.home());
         assert_eq!(Path::new("/home/user2"), Xdg::new_app("app_name")?.home());
 
-        env::remove_var("USER");
-        assert_eq!(XdgError::HomeNotFound, Xdg::new().unwrap_err());
-        assert_eq!(XdgError::HomeNotFound, Xdg::new_app("app_name").unwrap_err());
+        env::remove_var("USER");
+        #[cfg(not(feature = "passwd"))]
+        assert_eq!(XdgError::HomeNotFound, Xdg::new().unwrap_err());
+        #[cfg(not(feature = "passwd"))]
+        assert_eq!(XdgError::HomeNotFound, Xdg::new_app("app_name").unwrap_err());
+        #[cfg(all(unix, feature = "passwd"))]
+        assert_eq!(passwd::home_dir(None).as_deref(), Xdg::new().ok().as_ref().map(Xdg::home));
+
+        // LOGNAME is an additional hint of the user name on Unix.
+        env::set_var("LOGNAME", "user3");
+        #[cfg(not(feature = "passwd"))]
+        assert_eq!(Path::new("/home/user3"), Xdg::new()?.home());
+        env::set_var("USER", "user2");
+        #[cfg(not(feature = "passwd"))]
+        assert_eq!(Path::new("/home/user2"), Xdg::new()?.home());
+        env::remove_var("USER");
+        env::remove_var("LOGNAME");
+
+        Ok(())
+    }
+
+    #[cfg(all(unix, feature = "passwd"))]
+    #[test]
+    fn for_user() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::for_user("root")?;
+        assert_eq!(Path::new("/root"), xdg.home());
+        assert_eq!(Path::new("/root/.config"), xdg.config()?);
+        assert_eq!(XdgError::HomeNotFound, Xdg::for_user("microxdg-missing-user").unwrap_err());
+        assert_eq!(XdgError::HomeNotFound, Xdg::for_user("").unwrap_err());
+
+        let xdg = XdgApp::for_user("root", "app_name")?;
+        assert_eq!(Path::new("/root/.config/app_name"), xdg.app_config()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sudo_policy() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("HOME", "/root");
+        assert_eq!(None, Xdg::sudo_user_home());
+        assert_eq!(Path::new("/root"), Xdg::new_with_sudo_policy(SudoPolicy::InvokingUser)?.home());
+
+        env::set_var("SUDO_USER", "user");
+        assert_eq!(Some(PathBuf::from("/home/user")), Xdg::sudo_user_home());
+        assert_eq!(Path::new("/root"), Xdg::new()?.home());
+        assert_eq!(Path::new("/root"), Xdg::new_with_sudo_policy(SudoPolicy::Ignore)?.home());
+        assert_eq!(
+            Path::new("/home/user"),
+            Xdg::new_with_sudo_policy(SudoPolicy::InvokingUser)?.home(),
+        );
+
+        env::remove_var("HOME");
+        assert_eq!(
+            Path::new("/home/user"),
+            Xdg::new_with_sudo_policy(SudoPolicy::InvokingUser)?.home(),
+        );
+
+        env::set_var("SUDO_USER", "root");
+        assert_eq!(None, Xdg::sudo_user_home());
+
+        Ok(())
+    }
+
+    #[test]
+    fn strict_mode() -> Result<(), Box<dyn Error>> {
+        use std::fs::{set_permissions, Permissions};
+        use std::os::unix::fs::PermissionsExt;
+
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        assert_eq!(Path::new("/home/user"), Xdg::new()?.home());
+        assert!(!Xdg::new()?.strict());
+        assert_eq!(XdgError::HomeNotFound, Xdg::new_strict().unwrap_err());
+
+        env::set_var("HOME", "/home/user");
+        let xdg = Xdg::new_strict()?;
+        assert!(xdg.strict());
+        assert_eq!(Path::new("/home/user/.config"), xdg.config()?);
+        assert_eq!(None, xdg.runtime()?);
+
+        let runtime_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        set_permissions(runtime_dir.path(), Permissions::from_mode(0o755))?;
+        env::set_var("XDG_RUNTIME_DIR", runtime_dir.path());
+        assert_eq!(Some(runtime_dir.path().to_path_buf()), Xdg::new()?.runtime()?);
+        assert_eq!(
+            XdgError::InsecureRuntimeDir { path: runtime_dir.path().to_path_buf() },
+            xdg.runtime().unwrap_err(),
+        );
+
+        set_permissions(runtime_dir.path(), Permissions::from_mode(0o700))?;
+        assert_eq!(Some(runtime_dir.path().to_path_buf()), xdg.runtime()?);
+
+        env::set_var("XDG_RUNTIME_DIR", runtime_dir.path().join("missing"));
+        assert!(matches!(xdg.runtime(), Err(XdgError::Io { .. })));
+        env::remove_var("XDG_RUNTIME_DIR");
+
+        Ok(())
+    }
+
+    #[test]
+    fn global_xdg() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        #[cfg(not(feature = "passwd"))]
+        assert_eq!(Some(XdgError::HomeNotFound), Xdg::global().err());
+
+        env::set_var("HOME", "/home/user1");
+        let xdg = Xdg::global()?;
+        assert_eq!(Path::new("/home/user1"), xdg.home());
+
+        env::set_var("HOME", "/home/user2");
+        assert!(std::ptr::eq(xdg, Xdg::global()?));
+        assert_eq!(Path::new("/home/user1"), Xdg::global()?.home());
+
+        Ok(())
+    }
+
+    #[test]
+    fn usr_base_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user1");
+        env::set_var("HOME", "/home/user1");
+
+        let xdg = Xdg::new()?;
+
+        assert_eq!(Path::new("/home/user1"), xdg.home());
+        assert_eq!(Path::new("/home/user1/.cache"), xdg.cache()?);
+        assert_eq!(Path::new("/home/user1/.config"), xdg.config()?);
+        assert_eq!(Path::new("/home/user1/.local/share"), xdg.data()?);
+        assert_eq!(Path::new("/home/user1/.local/state"), xdg.state()?);
+        assert_eq!(Path::new("/home/user1/.local/bin"), xdg.bin()?);
+        assert_eq!(None, xdg.runtime()?);
+
+        env::set_var("XDG_CACHE_HOME", "/home/user2/.cache");
+        env::set_var("XDG_CONFIG_HOME", "/home/user2/.config");
+        env::set_var("XDG_DATA_HOME", "/home/user2/.local/share");
+        env::set_var("XDG_STATE_HOME", "/home/user2/.local/state");
+        env::set_var("XDG_BIN_HOME", "/home/user2/.local/bin");
+        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
+        assert_eq!(Path::new("/home/user2/.cache"), xdg.cache()?);
+        assert_eq!(Path::new("/home/user2/.config"), xdg.config()?);
+        assert_eq!(Path::new("/home/user2/.local/share"), xdg.data()?);
+        assert_eq!(Path::new("/home/user2/.local/state"), xdg.state()?);
+        assert_eq!(Path::new("/home/user2/.local/bin"), xdg.bin()?);
+        assert_eq!(Some(PathBuf::from("/run/user/1000")), xdg.runtime()?);
+
+        env::set_var("XDG_CACHE_HOME", "");
+        env::set_var("XDG_CONFIG_HOME", "");
+        env::set_var("XDG_DATA_HOME", "");
+        env::set_var("XDG_STATE_HOME", "");
+        env::set_var("XDG_BIN_HOME", "");
+        env::set_var("XDG_RUNTIME_DIR", "");
+        assert_eq!(Path::new("/home/user1/.cache"), xdg.cache()?);
+        assert_eq!(Path::new("/home/user1/.config"), xdg.config()?);
+        assert_eq!(Path::new("/home/user1/.local/share"), xdg.data()?);
+        assert_eq!(Path::new("/home/user1/.local/state"), xdg.state()?);
+        assert_eq!(Path::new("/home/user1/.local/bin"), xdg.bin()?);
+        assert_eq!(None, xdg.runtime()?);
+
+        env::set_var("XDG_CACHE_HOME", "./cache");
+        env::set_var("XDG_CONFIG_HOME", "./config");
+        env::set_var("XDG_DATA_HOME", "./data");
+        env::set_var("XDG_STATE_HOME", "./state");
+        env::set_var("XDG_BIN_HOME", "./bin");
+        env::set_var("XDG_RUNTIME_DIR", "./runtime");
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_CACHE_HOME",
+                path: PathBuf::from("./cache"),
+            }),
+            xdg.cache(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_CONFIG_HOME",
+                path: PathBuf::from("./config"),
+            }),
+            xdg.config(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_DATA_HOME",
+                path: PathBuf::from("./data"),
+            }),
+            xdg.data(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_STATE_HOME",
+                path: PathBuf::from("./state"),
+            }),
+            xdg.state(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_BIN_HOME",
+                path: PathBuf::from("./bin"),
+            }),
+            xdg.bin(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_RUNTIME_DIR",
+                path: PathBuf::from("./runtime"),
+            }),
+            xdg.runtime(),
+        );
+
+        let invalid_unicode = OsStr::from_bytes(&INVALID_UNICODE_BYTES);
+        env::set_var("XDG_CACHE_HOME", invalid_unicode);
+        env::set_var("XDG_CONFIG_HOME", invalid_unicode);
+        env::set_var("XDG_DATA_HOME", invalid_unicode);
+        env::set_var("XDG_STATE_HOME", invalid_unicode);
+        env::set_var("XDG_BIN_HOME", invalid_unicode);
+        env::set_var("XDG_RUNTIME_DIR", invalid_unicode);
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_CACHE_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.cache(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_CONFIG_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.config(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_DATA_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.data(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_STATE_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.state(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_BIN_HOME",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.bin(),
+        );
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_RUNTIME_DIR",
+                env_var_val: invalid_unicode.to_os_string(),
+            }),
+            xdg.runtime(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn sys_base_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+        assert_eq!(
+            vec![PathBuf::from("/etc/xdg")],
+            Xdg::sys_config()?,
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/usr/local/share"),
+                PathBuf::from("/usr/share"),
+            ],
+            Xdg::sys_data()?,
+        );
+
+        env::set_var(
+            "XDG_CONFIG_DIRS",
+            "/config/dir1:/config/dir2:/config/dir3:/config/dir4",
+        );
+        env::set_var(
+            "XDG_DATA_DIRS",
+            "/data/dir1:/data/dir2:/data/dir3:/data/dir4",
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/config/dir1"),
+                PathBuf::from("/config/dir2"),
+                PathBuf::from("/config/dir3"),
+                PathBuf::from("/config/dir4"),
+            ],
+            Xdg::sys_config()?,
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/data/dir1"),
+                PathBuf::from("/data/dir2"),
+                PathBuf::from("/data/dir3"),
+                PathBuf::from("/data/dir4"),
+            ],
+            Xdg::sys_data()?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sys_base_dirs_lossy() {
+        remove_xdg_vars();
+
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], Xdg::sys_config_lossy());
+        assert_eq!(
+            vec![PathBuf::from("/usr/local/share"), PathBuf::from("/usr/share")],
+            Xdg::sys_data_lossy(),
+        );
+
+        env::set_var("XDG_CONFIG_DIRS", "/config/dir1:config/dir2::/config/dir3");
+        env::set_var("XDG_DATA_DIRS", "./data/dir1:data/dir2");
+        assert_eq!(
+            vec![PathBuf::from("/config/dir1"), PathBuf::from("/config/dir3")],
+            Xdg::sys_config_lossy(),
+        );
+        assert_eq!(
+            vec![PathBuf::from("/usr/local/share"), PathBuf::from("/usr/share")],
+            Xdg::sys_data_lossy(),
+        );
+
+        let invalid_unicode = [b"/data/".as_slice(), &INVALID_UNICODE_BYTES].concat();
+        env::set_var("XDG_DATA_DIRS", OsStr::from_bytes(&invalid_unicode));
+        assert_eq!(vec![PathBuf::from(OsStr::from_bytes(&invalid_unicode))], Xdg::sys_data_lossy());
+    }
+
+    #[test]
+    fn relative_path_policy() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("XDG_CONFIG_HOME", ".config");
+        env::set_var("XDG_RUNTIME_DIR", "run/user/1000");
+        env::set_var("XDG_CONFIG_DIRS", "/config/dir1:config/dir2");
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(RelativePathPolicy::Error, xdg.relative_path_policy());
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_CONFIG_HOME",
+                path: PathBuf::from(".config"),
+            }),
+            xdg.config(),
+        );
+        assert!(xdg.config_dirs_all().is_err());
+
+        let xdg = xdg.with_relative_path_policy(RelativePathPolicy::Ignore);
+        assert_eq!(PathBuf::from("/home/user/.config"), xdg.config()?);
+        assert_eq!(None, xdg.runtime()?);
+        assert_eq!(
+            vec![PathBuf::from("/home/user/.config"), PathBuf::from("/config/dir1")],
+            xdg.config_dirs_all()?.collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_CONFIG_DIRS",
+                path: PathBuf::from("config/dir2"),
+            }),
+            Xdg::sys_config(),
+        );
+
+        env::set_var("XDG_CONFIG_DIRS", "config/dir2");
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], xdg.sys_dirs(XdgSysDirs::Config)?);
+        env::set_var("XDG_CONFIG_DIRS", "/config/dir1:config/dir2");
+
+        let xdg = xdg.with_relative_path_policy(RelativePathPolicy::ResolveAgainstHome);
+        assert_eq!(PathBuf::from("/home/user/.config/file"), xdg.config_file("file")?);
+        assert_eq!(Some(PathBuf::from("/home/user/run/user/1000")), xdg.runtime()?);
+        assert_eq!(
+            vec![PathBuf::from("/config/dir1"), PathBuf::from("/home/user/config/dir2")],
+            xdg.paths()?.sys_config,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_unicode() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let config_home = [b"/config/home-".as_slice(), &INVALID_UNICODE_BYTES].concat();
+        let config_home = OsStr::from_bytes(&config_home);
+        let config_dirs =
+            [b"/config/dir1:/config/dir2-".as_slice(), &INVALID_UNICODE_BYTES].concat();
+        let config_dirs = OsStr::from_bytes(&config_dirs);
+        env::set_var("XDG_CONFIG_HOME", config_home);
+        env::set_var("XDG_CONFIG_DIRS", config_dirs);
+        env::set_var("XDG_RUNTIME_DIR", config_home);
+
+        let xdg = Xdg::with_home("/home/user");
+        assert!(!xdg.non_unicode());
+        assert_eq!(
+            Err(XdgError::InvalidUnicode {
+                env_var_key: "XDG_CONFIG_HOME",
+                env_var_val: config_home.to_os_string(),
+            }),
+            xdg.config(),
+        );
+
+        let xdg = xdg.with_non_unicode(true);
+        assert_eq!(PathBuf::from(config_home), xdg.config()?);
+        assert_eq!(Some(PathBuf::from(config_home)), xdg.runtime()?);
+        assert_eq!(
+            vec![
+                PathBuf::from(config_home),
+                PathBuf::from("/config/dir1"),
+                PathBuf::from(OsStr::from_bytes(&config_dirs.as_bytes()[13..])),
+            ],
+            xdg.config_dirs_all()?.collect::<Vec<_>>(),
+        );
+        assert!(Xdg::sys_config().is_err());
+
+        env::set_var("XDG_CONFIG_HOME", "");
+        assert_eq!(PathBuf::from("/home/user/.config"), xdg.config()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn expand_vars() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("XDG_CONFIG_HOME", "$HOME/.config");
+        env::set_var("XDG_DATA_DIRS", "/home/${USER}/.nix-profile/share:/usr/share");
+
+        let xdg = Xdg::with_home("/home/user");
+        assert!(!xdg.expand_vars());
+        assert!(xdg.config().is_err());
+
+        let xdg = xdg.with_expand_vars(true);
+        assert_eq!(PathBuf::from("/home/user/.config"), xdg.config()?);
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.local/share"),
+                PathBuf::from("/home/user/.nix-profile/share"),
+                PathBuf::from("/usr/share"),
+            ],
+            xdg.data_dirs_all()?.collect::<Vec<_>>(),
+        );
+
+        env::set_var("XDG_STATE_HOME", "$UNSET/state");
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_STATE_HOME",
+                path: PathBuf::from("$UNSET/state"),
+            }),
+            xdg.state(),
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn dedup_sys_dirs() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        env::set_var("XDG_CONFIG_DIRS", "/config/dir1:/config/dir2:/config/dir1");
+        env::set_var("XDG_DATA_DIRS", "/nix/share:/usr/share:/nix/share:/usr/share");
+        assert_eq!(
+            vec![PathBuf::from("/config/dir1"), PathBuf::from("/config/dir2")],
+            Xdg::sys_config()?,
+        );
+        assert_eq!(
+            vec![PathBuf::from("/nix/share"), PathBuf::from("/usr/share")],
+            Xdg::sys_data()?,
+        );
+        assert_eq!(
+            vec![PathBuf::from("/nix/share"), PathBuf::from("/usr/share")],
+            Xdg::sys_data_lossy(),
+        );
+
+        let data_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(data_dir.path().join("file"), "data")?;
+
+        let data_dir = data_dir.path().to_str().ok_or("non-unicode temp dir")?;
+        env::set_var("XDG_DATA_DIRS", format!("{data_dir}:/usr/share:{data_dir}"));
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(
+            vec![Path::new(data_dir).join("file")],
+            xdg.search_data_files("file")?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn skip_empty_sys_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        for config_dirs in [
+            ":/config/dir1:/config/dir2",
+            "/config/dir1:/config/dir2:",
+            "/config/dir1::/config/dir2",
+            "::/config/dir1:::/config/dir2::",
+        ] {
+            env::set_var("XDG_CONFIG_DIRS", config_dirs);
+            let expected = vec![PathBuf::from("/config/dir1"), PathBuf::from("/config/dir2")];
+            assert_eq!(expected, Xdg::sys_config()?);
+            assert_eq!(expected, Xdg::sys_config_lossy());
+            assert_eq!(expected, xdg.paths()?.sys_config);
+        }
+
+        env::set_var("XDG_CONFIG_DIRS", ":");
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], Xdg::sys_config()?);
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], Xdg::sys_config_lossy());
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], xdg.paths()?.sys_config);
+
+        Ok(())
+    }
+
+    #[test]
+    fn platform_path_separator() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let data_dirs = vec![PathBuf::from("/data/dir1"), PathBuf::from("/data/dir2")];
+        env::set_var("XDG_DATA_DIRS", env::join_paths(&data_dirs)?);
+        assert_eq!(data_dirs, Xdg::sys_data()?);
+        assert_eq!(data_dirs, Xdg::sys_data_lossy());
+        assert_eq!(data_dirs, Xdg::with_home("/home/user").paths()?.sys_data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn thumbnail_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(PathBuf::from("/home/user/.cache/thumbnails"), xdg.thumbnails()?);
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.cache/thumbnails/normal"),
+                PathBuf::from("/home/user/.cache/thumbnails/large"),
+                PathBuf::from("/home/user/.cache/thumbnails/x-large"),
+                PathBuf::from("/home/user/.cache/thumbnails/xx-large"),
+            ],
+            ThumbnailSize::ALL
+                .into_iter()
+                .map(|size| xdg.thumbnail_dir(size))
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        assert_eq!(PathBuf::from("/home/user/.cache/thumbnails/fail"), xdg.thumbnail_fail_dir()?);
+
+        env::set_var("XDG_CACHE_HOME", "/cache");
+        assert_eq!(
+            PathBuf::from("/cache/thumbnails/large"),
+            xdg.thumbnail_dir(ThumbnailSize::Large)?,
+        );
+        assert_eq!(512, ThumbnailSize::XLarge.pixels());
+        assert_eq!(
+            PathBuf::from("/cache/thumbnails/xx-large/c6ee772d9e49320e97ec29a7eb5b1697.png"),
+            xdg.thumbnail_path("file:///home/jens/photos/me.png", ThumbnailSize::XXLarge)?,
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn trash() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(PathBuf::from("/home/user/.local/share/Trash"), xdg.trash()?);
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_dir = tmp_dir.path().join("data");
+        env::set_var("XDG_DATA_HOME", &data_dir);
+        assert!(xdg.trash_list()?.is_empty());
+
+        let file = tmp_dir.path().join("my file.txt");
+        fs::write(&file, "first")?;
+        let first = xdg.trash_put(&file)?;
+        assert!(!file.exists());
+        assert_eq!(data_dir.join("Trash/files/my file.txt"), first.path);
+        assert_eq!(data_dir.join("Trash/info/my file.txt.trashinfo"), first.info_path);
+
+        let info = fs::read_to_string(&first.info_path)?;
+        assert!(info.starts_with("[Trash Info]\nPath=/"));
+        assert!(info.contains("/my%20file.txt\nDeletionDate="));
+
+        // Name collisions are disambiguated.
+        fs::write(&file, "second")?;
+        let second = xdg.trash_put(&file)?;
+        assert_eq!(data_dir.join("Trash/files/my file.1.txt"), second.path);
+        assert_eq!(vec![second.clone(), first.clone()], xdg.trash_list()?);
+
+        // Restoring fails if the original path is taken.
+        fs::write(&file, "third")?;
+        assert!(xdg.trash_restore(&first).is_err());
+        fs::remove_file(&file)?;
+
+        assert_eq!(file, xdg.trash_restore(&first)?);
+        assert_eq!("first", fs::read_to_string(&file)?);
+        assert!(!first.info_path.exists());
+        assert_eq!(vec![second], xdg.trash_list()?);
+
+        assert!(xdg.trash_put(tmp_dir.path().join("missing")).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn recent_files() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(
+            PathBuf::from("/home/user/.local/share/recently-used.xbel"),
+            xdg.recently_used()?,
+        );
+
+        let data_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_DATA_HOME", data_dir.path());
+        assert!(xdg.recent_files()?.is_empty());
+
+        let app = XdgApp::with_home("/home/user", "app_name")?;
+        assert!(app.add_recent_file("file:///home/user/notes.txt", "text/plain")?);
+        assert!(app.add_recent_file("file:///home/user/a&b.md", "text/markdown")?);
+        assert!(!app.add_recent_file("file:///home/user/notes.txt", "text/plain")?);
+
+        let recent_files = xdg.recent_files()?;
+        assert_eq!(
+            vec!["file:///home/user/notes.txt", "file:///home/user/a&b.md"],
+            recent_files.iter().map(|recent_file| recent_file.uri.as_str()).collect::<Vec<_>>(),
+        );
+        assert_eq!(Some("text/markdown"), recent_files[1].mime_type.as_deref());
+        assert!(recent_files[0].added.as_ref().is_some_and(|added| added.ends_with('Z')));
+
+        let content = fs::read_to_string(xdg.recently_used()?)?;
+        assert!(content.starts_with("<?xml"));
+        assert!(content.contains("<bookmark:application name=\"app_name\""));
+        assert!(content.trim_end().ends_with("</xbel>"));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn applications_dirs() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(PathBuf::from("/home/user/.local/share/applications"), xdg.applications()?);
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.local/share/applications"),
+                PathBuf::from("/usr/local/share/applications"),
+                PathBuf::from("/usr/share/applications"),
+            ],
+            xdg.applications_dirs()?,
+        );
+
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_DATA_DIRS", data_dir.path());
+
+        fs::create_dir_all(data_dir.path().join("applications/kde"))?;
+        fs::write(data_dir.path().join("applications/org.example.App.desktop"), "")?;
+        fs::write(data_dir.path().join("applications/kde/org.example.Kde.desktop"), "")?;
+        assert_eq!(
+            Some(data_dir.path().join("applications/org.example.App.desktop")),
+            xdg.find_desktop_entry("org.example.App.desktop")?,
+        );
+        assert_eq!(
+            Some(data_dir.path().join("applications/kde/org.example.Kde.desktop")),
+            xdg.find_desktop_entry("kde-org.example.Kde.desktop")?,
+        );
+
+        // User-specific desktop entries shadow system-wide ones.
+        fs::create_dir_all(data_home.path().join("applications"))?;
+        fs::write(data_home.path().join("applications/org.example.App.desktop"), "")?;
+        assert_eq!(
+            Some(data_home.path().join("applications/org.example.App.desktop")),
+            xdg.find_desktop_entry("org.example.App.desktop")?,
+        );
+        assert_eq!(None, xdg.find_desktop_entry("missing.desktop")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn menus_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(PathBuf::from("/home/user/.config/menus"), xdg.menus()?);
+        assert_eq!(vec![PathBuf::from("/etc/xdg/menus")], xdg.sys_menus()?);
+
+        env::set_var("XDG_CONFIG_HOME", "/config");
+        env::set_var("XDG_CONFIG_DIRS", "/etc/xdg:/opt/xdg");
+        assert_eq!(PathBuf::from("/config/menus"), xdg.menus()?);
+        assert_eq!(
+            vec![PathBuf::from("/etc/xdg/menus"), PathBuf::from("/opt/xdg/menus")],
+            xdg.sys_menus()?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn current_desktop() {
+        remove_xdg_vars();
+        assert!(Xdg::current_desktop().is_empty());
+
+        env::set_var("XDG_CURRENT_DESKTOP", "");
+        assert!(Xdg::current_desktop().is_empty());
+
+        env::set_var("XDG_CURRENT_DESKTOP", "ubuntu:GNOME");
+        assert_eq!(
+            vec![DesktopEnvironment::Other(String::from("ubuntu")), DesktopEnvironment::Gnome],
+            Xdg::current_desktop(),
+        );
+    }
+
+    #[test]
+    fn session_vars() {
+        remove_xdg_vars();
+        assert_eq!(None, Xdg::session_type());
+        assert_eq!(None, Xdg::session_class());
+        assert_eq!(None, Xdg::session_id());
+        assert_eq!(None, Xdg::seat());
+        assert_eq!(None, Xdg::vtnr());
+
+        env::set_var("XDG_SESSION_TYPE", "wayland");
+        env::set_var("XDG_SESSION_CLASS", "greeter");
+        env::set_var("XDG_SESSION_ID", "2");
+        env::set_var("XDG_SEAT", "seat0");
+        env::set_var("XDG_VTNR", "1");
+        assert_eq!(Some(SessionType::Wayland), Xdg::session_type());
+        assert_eq!(Some(SessionClass::Greeter), Xdg::session_class());
+        assert_eq!(Some(String::from("2")), Xdg::session_id());
+        assert_eq!(Some(String::from("seat0")), Xdg::seat());
+        assert_eq!(Some(1), Xdg::vtnr());
+
+        env::set_var("XDG_SESSION_TYPE", "");
+        env::set_var("XDG_VTNR", "tty1");
+        assert_eq!(None, Xdg::session_type());
+        assert_eq!(None, Xdg::vtnr());
+    }
+
+    #[test]
+    fn sandbox() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        if Path::new("/.flatpak-info").exists() {
+            return Ok(());
+        }
+        assert_eq!(None, xdg.sandbox());
+
+        env::set_var("FLATPAK_ID", "org.example.App");
+        env::set_var("XDG_CONFIG_HOME", "/home/user/.var/app/org.example.App/config");
+        let sandbox = xdg.sandbox().ok_or(XdgError::HomeNotFound)?;
+        assert_eq!(SandboxKind::Flatpak, sandbox.kind());
+        assert_eq!(Some("org.example.App"), sandbox.app_id());
+        assert!(sandbox.contains(xdg.config()?));
+        assert!(!sandbox.contains(xdg.data()?));
+        env::remove_var("FLATPAK_ID");
+
+        env::set_var("SNAP", "/snap/app/42");
+        env::set_var("SNAP_NAME", "app");
+        env::set_var("SNAP_USER_DATA", "/home/user/snap/app/42");
+        env::set_var("SNAP_USER_COMMON", "/home/user/snap/app/common");
+        let sandbox = xdg.sandbox().ok_or(XdgError::HomeNotFound)?;
+        assert_eq!(SandboxKind::Snap, sandbox.kind());
+        assert_eq!(Some("app"), sandbox.app_id());
+        assert!(sandbox.contains("/home/user/snap/app/common/file"));
+        assert!(!sandbox.contains("/home/user/.config"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn builder() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("XDG_CACHE_HOME", "/cache");
+        env::set_var("XDG_CONFIG_HOME", "/config");
+        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
+
+        let xdg = XdgBuilder::new()
+            .home("/home/user")
+            .config("/fixtures/config")
+            .data("/fixtures/data")
+            .runtime("/tmp/runtime")
+            .build()?;
+        assert_eq!(Path::new("/home/user"), xdg.home());
+        assert_eq!(Path::new("/cache"), xdg.cache()?);
+        assert_eq!(Path::new("/fixtures/config"), xdg.config()?);
+        assert_eq!(Path::new("/fixtures/config/file"), xdg.config_file("file")?);
+        assert_eq!(Path::new("/fixtures/data"), xdg.data()?);
+        assert_eq!(Path::new("/home/user/.local/state"), xdg.state()?);
+        assert_eq!(Some(PathBuf::from("/tmp/runtime")), xdg.runtime()?);
+
+        let app = XdgApp::from_xdg(xdg, "app_name")?;
+        assert_eq!(Path::new("/fixtures/config/app_name"), app.app_config()?);
+        assert_eq!(Path::new("/cache/app_name"), app.app_cache()?);
+
+        // Overrides take precedence over invalid XDG environment variables.
+        env::set_var("XDG_CONFIG_HOME", "config");
+        assert_eq!(
+            Path::new("/fixtures/config"),
+            XdgBuilder::new().home("/home/user").config("/fixtures/config").build()?.config()?,
+        );
+        assert!(XdgBuilder::new().home("/home/user").build()?.config().is_err());
+
+        env::set_var("HOME", "/home/user2");
+        assert_eq!(Path::new("/home/user2"), XdgBuilder::new().build()?.home());
+        env::remove_var("HOME");
+        #[cfg(not(feature = "passwd"))]
+        assert_eq!(XdgError::HomeNotFound, XdgBuilder::new().build().unwrap_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn generic_dir() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("XDG_STATE_HOME", "/state");
+
+        let xdg = Xdg::with_home("/home/user");
+        let dirs = [XdgDir::Cache, XdgDir::Config, XdgDir::Data, XdgDir::State, XdgDir::Bin];
+        let expected = [".cache", ".config", ".local/share", "/state", ".local/bin"];
+        for (dir, expected) in dirs.into_iter().zip(expected) {
+            let path = Path::new("/home/user").join(expected);
+            assert_eq!(path, xdg.dir(dir)?);
+            assert_eq!(path.join("file"), xdg.file(dir, "file")?);
+        }
+
+        env::set_var("XDG_DATA_DIRS", "/share");
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], xdg.sys_dirs(XdgSysDirs::Config)?);
+        assert_eq!(vec![PathBuf::from("/share")], xdg.sys_dirs(XdgSysDirs::Data)?);
+        env::remove_var("XDG_DATA_DIRS");
+
+        env::set_var("XDG_DATA_DIRS", "/share:/opt/share");
+        assert_eq!(
+            vec![PathBuf::from("/share/file"), PathBuf::from("/opt/share/file")],
+            xdg.sys_data_file("file")?,
+        );
+        assert_eq!(vec![PathBuf::from("/etc/xdg/file")], xdg.sys_config_file("file")?);
+
+        let app = XdgApp::from_xdg(xdg, "app_name")?;
+        assert_eq!(
+            vec![PathBuf::from("/share/app_name/file"), PathBuf::from("/opt/share/app_name/file")],
+            app.app_sys_data_file("file")?,
+        );
+        assert_eq!(vec![PathBuf::from("/etc/xdg/app_name/file")], app.app_sys_config_file("file")?);
+        assert_eq!(
+            vec![PathBuf::from("/share/file"), PathBuf::from("/opt/share/file")],
+            app.sys_data_file("file")?,
+        );
+        env::remove_var("XDG_DATA_DIRS");
+        assert_eq!(Path::new("/state/file"), app.file(XdgDir::State, "file")?);
+        assert_eq!(Xdg::sys_data()?, app.sys_dirs(XdgSysDirs::Data)?);
+
+        env::set_var("XDG_CONFIG_HOME", "config");
+        assert!(matches!(
+            app.dir(XdgDir::Config),
+            Err(XdgError::RelativePath { env_var_key: "XDG_CONFIG_HOME", .. }),
+        ));
+
+        env::remove_var("XDG_STATE_HOME");
+        env::remove_var("XDG_CONFIG_HOME");
+
+        Ok(())
+    }
+
+    #[test]
+    fn display() {
+        remove_xdg_vars();
+
+        env::set_var("XDG_DATA_DIRS", "/usr/share:/opt/share");
+        env::set_var("XDG_STATE_HOME", "state");
+
+        let xdg = Xdg::with_home("/home/user");
+        let err = XdgError::RelativePath { env_var_key: "XDG_STATE_HOME", path: "state".into() };
+        assert_eq!(
+            format!(
+                "home: /home/user\n\
+                 cache: /home/user/.cache\n\
+                 config: /home/user/.config\n\
+                 data: /home/user/.local/share\n\
+                 state: error ({err})\n\
+                     bin: /home/user/.local/bin\n\
+                 runtime: unset\n\
+                 sys_config: /etc/xdg\n\
+                 sys_data: /usr/share:/opt/share\n"
+            ),
+            xdg.to_string(),
+        );
+
+        env::remove_var("XDG_DATA_DIRS");
+        env::remove_var("XDG_STATE_HOME");
+    }
+
+    #[test]
+    // The directory cache is interior mutable, but left out of the hash.
+    #[allow(clippy::mutable_key_type)]
+    fn eq_hash() -> Result<(), XdgError> {
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(xdg, xdg.clone());
+        assert_ne!(xdg, Xdg::with_home("/home/other"));
+        assert_ne!(xdg, xdg.clone().with_strict(true));
+        assert_ne!(xdg, XdgBuilder::new().home("/home/user").config("/config").build()?);
+
+        let xdgs: HashSet<Xdg> = [
+            xdg.clone(),
+            Xdg::with_home("/home/user"),
+            Xdg::with_home("/home/other"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(2, xdgs.len());
+        assert!(xdgs.contains(&xdg));
+
+        Ok(())
+    }
+
+    #[test]
+    fn system_scope() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/root");
+        assert_eq!(Scope::User, xdg.scope());
+        assert_eq!(Path::new("/root/.config"), xdg.config()?);
+
+        let xdg = xdg.with_scope(Scope::System);
+        assert_eq!(Scope::System, xdg.scope());
+        assert_eq!(Path::new("/var/cache"), xdg.cache()?);
+        assert_eq!(Path::new("/etc"), xdg.config()?);
+        assert_eq!(Path::new("/usr/local/share"), xdg.data()?);
+        assert_eq!(Path::new("/var/lib"), xdg.state()?);
+        assert_eq!(Path::new("/usr/local/bin"), xdg.bin()?);
+        assert_eq!(Path::new("/etc/file"), xdg.config_file("file")?);
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], xdg.sys_dirs(XdgSysDirs::Config)?);
+
+        // XDG environment variables and custom fallbacks take precedence.
+        env::set_var("XDG_STATE_HOME", "/srv/state");
+        assert_eq!(Path::new("/srv/state"), xdg.state()?);
+        let xdg = XdgBuilder::new().home("/root").cache_fallback("/srv/cache").build()?;
+        let xdg = xdg.with_scope(Scope::System);
+        assert_eq!(Path::new("/srv/cache"), xdg.cache()?);
+        assert_eq!(Path::new("/etc"), xdg.config()?);
+
+        let app = XdgApp::from_xdg(xdg, "app_name")?;
+        assert_eq!(Path::new("/srv/state/app_name"), app.app_state()?);
+        assert_eq!(Path::new("/etc/app_name"), app.app_config()?);
+        assert_eq!(Path::new("/usr/local/share/app_name"), app.app_data()?);
+
+        // Application data and state never share a directory.
+        env::remove_var("XDG_STATE_HOME");
+        let app = XdgApp::with_home("/root", "app_name")?.with_scope(Scope::System);
+        assert_ne!(app.app_data()?, app.app_state()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn caching() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("XDG_DATA_HOME", "/data");
+        env::set_var("XDG_CONFIG_DIRS", "/etc/xdg");
+        env::set_var("XDG_STATE_HOME", "state");
+
+        let mut xdg = Xdg::with_home("/home/user").with_caching(true);
+        assert!(xdg.caching());
+        assert_eq!(Path::new("/data"), xdg.data()?);
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], xdg.sys_dirs(XdgSysDirs::Config)?);
+        assert!(xdg.state().is_err());
+
+        env::set_var("XDG_DATA_HOME", "/other");
+        env::set_var("XDG_CONFIG_DIRS", "/etc/other");
+        assert_eq!(Path::new("/data"), xdg.data()?);
+        assert_eq!(Path::new("/data/file"), xdg.data_file("file")?);
+        assert_eq!(vec![PathBuf::from("/etc/xdg")], xdg.sys_dirs(XdgSysDirs::Config)?);
+
+        // Errors are never cached.
+        env::remove_var("XDG_STATE_HOME");
+        assert_eq!(Path::new("/home/user/.local/state"), xdg.state()?);
+
+        xdg.refresh();
+        assert_eq!(Path::new("/other"), xdg.data()?);
+        assert_eq!(vec![PathBuf::from("/etc/other")], xdg.sys_dirs(XdgSysDirs::Config)?);
+        // Equality does not depend on the directories cached so far.
+        let other = Xdg::with_home("/home/user").with_caching(true);
+        assert_eq!(other, xdg);
+        other.config()?;
+        assert_eq!(other, Xdg::with_home("/home/user").with_caching(true));
+        env::set_var("HOME", "/home/user");
+        let xdg_new = Xdg::new()?;
+        xdg_new.config()?;
+        assert_eq!(xdg_new, Xdg::new()?);
+        env::remove_var("HOME");
+
+        // Settings clear the cache.
+        let xdg = xdg.with_relative_path_policy(RelativePathPolicy::Ignore);
+        env::set_var("XDG_DATA_HOME", "data");
+        env::set_var("XDG_STATE_HOME", "/state");
+        assert_eq!(Path::new("/home/user/.local/share"), xdg.data()?);
+        assert_eq!(Path::new("/state"), xdg.state()?);
+        let xdg = xdg.with_relative_path_policy(RelativePathPolicy::Error);
+        assert!(xdg.data().is_err());
+        env::remove_var("XDG_STATE_HOME");
+
+        let xdg = xdg.with_caching(false);
+        assert!(!xdg.caching());
+        env::set_var("XDG_DATA_HOME", "/data");
+        assert_eq!(Path::new("/data"), xdg.data()?);
+
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
+        Ok(())
+    }
+
+    #[test]
+    fn builtin_fallbacks() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        for dir in [XdgDir::Cache, XdgDir::Config, XdgDir::Data, XdgDir::State, XdgDir::Bin] {
+            assert_eq!(Path::new("/home/user").join(dir.fallback()), xdg.dir(dir)?);
+        }
+        for dirs in [XdgSysDirs::Config, XdgSysDirs::Data] {
+            let fallback: Vec<PathBuf> = dirs.fallback().iter().map(PathBuf::from).collect();
+            assert_eq!(fallback, xdg.sys_dirs(dirs)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn dirs() {
+        remove_xdg_vars();
+
+        env::set_var("XDG_CONFIG_HOME", "./config");
+        env::set_var("XDG_BIN_HOME", "/bin_home");
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(
+            vec![
+                (XdgDirKind::Cache, Ok(PathBuf::from("/home/user/.cache"))),
+                (
+                    XdgDirKind::Config,
+                    Err(XdgError::RelativePath {
+                        env_var_key: "XDG_CONFIG_HOME",
+                        path: PathBuf::from("./config"),
+                    }),
+                ),
+                (XdgDirKind::Data, Ok(PathBuf::from("/home/user/.local/share"))),
+                (XdgDirKind::State, Ok(PathBuf::from("/home/user/.local/state"))),
+                (XdgDirKind::Bin, Ok(PathBuf::from("/bin_home"))),
+                (XdgDirKind::Runtime, Err(XdgError::RuntimeDirNotSet)),
+            ],
+            xdg.dirs().collect::<Vec<_>>(),
+        );
+
+        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
+        assert_eq!(
+            Some((XdgDirKind::Runtime, Ok(PathBuf::from("/run/user/1000")))),
+            xdg.dirs().last(),
+        );
+
+        assert_eq!("state", XdgDirKind::State.to_string());
+        assert_eq!("XDG_BIN_HOME", XdgDirKind::Bin.env_var());
+        assert_eq!(XdgDirKind::Config, XdgDirKind::from(XdgDir::Config));
+        assert_eq!(None, XdgDirKind::Runtime.to_dir());
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_BIN_HOME");
+        env::remove_var("XDG_RUNTIME_DIR");
+    }
+
+    #[test]
+    fn env_exports() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user's");
+        assert_eq!(
+            "export XDG_CACHE_HOME='/home/user'\\''s/.cache'\n\
+             export XDG_CONFIG_HOME='/home/user'\\''s/.config'\n\
+             export XDG_DATA_HOME='/home/user'\\''s/.local/share'\n\
+             export XDG_STATE_HOME='/home/user'\\''s/.local/state'\n\
+             export XDG_BIN_HOME='/home/user'\\''s/.local/bin'\n\
+             export XDG_CONFIG_DIRS='/etc/xdg'\n\
+             export XDG_DATA_DIRS='/usr/local/share:/usr/share'\n",
+            xdg.to_env_exports(Shell::Posix)?,
+        );
+
+        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
+        env::set_var("XDG_BIN_HOME", "/bin\\home");
+        let exports = xdg.to_env_exports(Shell::Fish)?;
+        assert!(exports.starts_with("set -gx XDG_CACHE_HOME '/home/user\\'s/.cache'\n"));
+        assert!(exports.contains("set -gx XDG_BIN_HOME '/bin\\\\home'\n"));
+        assert!(exports.contains("set -gx XDG_RUNTIME_DIR '/run/user/1000'\n"));
+        assert!(exports.ends_with("set -gx XDG_DATA_DIRS '/usr/local/share:/usr/share'\n"));
+
+        env::set_var("XDG_STATE_HOME", "state");
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_STATE_HOME",
+                path: PathBuf::from("state"),
+            }),
+            xdg.to_env_exports(Shell::Posix),
+        );
+
+        env::remove_var("XDG_RUNTIME_DIR");
+        env::remove_var("XDG_BIN_HOME");
+        env::remove_var("XDG_STATE_HOME");
+
+        let xdg = XdgBuilder::new()
+            .home("/home/user")
+            .sys_data_fallback(["/usr/share", "/opt/share:/srv/share"])
+            .build()?;
+        assert_eq!(
+            Err(XdgError::InvalidPathList {
+                env_var_key: "XDG_DATA_DIRS",
+                path: PathBuf::from("/opt/share:/srv/share"),
+            }),
+            xdg.to_env_exports(Shell::Posix),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn builder_fallbacks() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("XDG_DATA_HOME", "/data");
+        env::set_var("XDG_CONFIG_DIRS", "/etc/xdg");
+
+        let xdg = XdgBuilder::new()
+            .home("/home/user")
+            .config_fallback("cfg")
+            .data_fallback("/ignored")
+            .state_fallback("/var/lib/user/state")
+            .sys_config_fallback(["/ignored"])
+            .sys_data_fallback(["/run/current-system/sw/share", "/nix/share"])
+            .build()?;
+        assert_eq!(Path::new("/home/user/cfg"), xdg.config()?);
+        assert_eq!(Path::new("/home/user/cfg/file"), xdg.config_file("file")?);
+        assert_eq!(Path::new("/data"), xdg.data()?);
+        assert_eq!(Path::new("/var/lib/user/state"), xdg.state()?);
+        assert_eq!(Path::new("/home/user/.cache"), xdg.cache()?);
+        assert_eq!(
+            vec![PathBuf::from("/home/user/cfg"), PathBuf::from("/etc/xdg")],
+            xdg.config_dirs_all()?.collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/data"),
+                PathBuf::from("/run/current-system/sw/share"),
+                PathBuf::from("/nix/share"),
+            ],
+            xdg.data_dirs_all()?.collect::<Vec<_>>(),
+        );
+
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn unblock_search() -> Result<(), Box<dyn Error>> {
+        use crate::unblock::test::block_on;
+
+        remove_xdg_vars();
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let config_home = tmp_dir_builder.tempdir()?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        fs::write(config_home.path().join("file"), "")?;
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(
+            Some(config_home.path().join("file")),
+            block_on(async { xdg.unblock(|xdg| xdg.search_config_file("file")).await })?,
+        );
+        let missing = block_on(async { xdg.unblock(|xdg| xdg.search_config_file("missing")).await });
+        assert_eq!(None, missing?);
+
+        fs::write(config_home.path().join("file"), "content")?;
+        assert_eq!(
+            Some(config_home.path().join("file")),
+            block_on(xdg.search_config_file_async("file"))?,
+        );
+        assert_eq!(b"content".to_vec(), block_on(xdg.read_config_file_async("file"))?);
+        assert_eq!("content", block_on(xdg.read_config_file_to_string_async("file"))?);
+        assert!(matches!(
+            block_on(xdg.read_config_file_async("missing")),
+            Err(XdgError::FileNotFound { .. }),
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn diagnostics() {
+        remove_xdg_vars();
+
+        env::set_var("XDG_CACHE_HOME", "");
+        env::set_var("XDG_CONFIG_HOME", "/tmp/config");
+        env::set_var("XDG_DATA_HOME", "data");
+        env::set_var("XDG_DATA_DIRS", "/usr/share:share");
+
+        let diagnostics = Xdg::with_home("/home/user").diagnostics();
+        assert_eq!(Path::new("/home/user"), diagnostics.home);
+        assert!(diagnostics.has_problems());
+        assert_eq!(10, diagnostics.vars.len());
+
+        let cache = diagnostics.var("XDG_CACHE_HOME").unwrap();
+        assert_eq!(VarState::Empty, cache.state);
+        assert_eq!(None, cache.problem);
+        assert_eq!(vec![PathBuf::from("/home/user/.cache")], cache.resolved);
+
+        let config = diagnostics.var("XDG_CONFIG_HOME").unwrap();
+        assert_eq!(VarState::Set(OsString::from("/tmp/config")), config.state);
+        assert_eq!(vec![PathBuf::from("/tmp/config")], config.resolved);
+
+        let data = diagnostics.var("XDG_DATA_HOME").unwrap();
+        assert_eq!(
+            Some(XdgError::RelativePath { env_var_key: "XDG_DATA_HOME", path: "data".into() }),
+            data.problem,
+        );
+        assert!(data.resolved.is_empty());
+
+        let runtime = diagnostics.var("XDG_RUNTIME_DIR").unwrap();
+        assert_eq!(VarState::Unset, runtime.state);
+        assert!(runtime.resolved.is_empty());
+
+        let diagnostics =
+            Xdg::with_home("/home/user").with_relative_path_policy(RelativePathPolicy::Ignore);
+        let diagnostics = diagnostics.diagnostics();
+        let data = diagnostics.var("XDG_DATA_HOME").unwrap();
+        assert!(data.problem.is_some());
+        assert_eq!(vec![PathBuf::from("/home/user/.local/share")], data.resolved);
+        let sys_data = diagnostics.var("XDG_DATA_DIRS").unwrap();
+        assert_eq!(
+            Some(XdgError::RelativePath { env_var_key: "XDG_DATA_DIRS", path: "share".into() }),
+            sys_data.problem,
+        );
+        assert_eq!(vec![PathBuf::from("/usr/share")], sys_data.resolved);
+
+        let report = diagnostics.to_string();
+        assert!(report.starts_with("home: /home/user\n"));
+        assert!(report.contains("XDG_CACHE_HOME: empty -> /home/user/.cache\n"));
+        assert!(report.contains("XDG_CONFIG_HOME: set to \"/tmp/config\" -> /tmp/config\n"));
+        assert!(report.contains("XDG_RUNTIME_DIR: unset\n"));
+
+        env::remove_var("XDG_CACHE_HOME");
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_DATA_DIRS");
+    }
+
+    #[test]
+    fn icon_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.icons"),
+                PathBuf::from("/home/user/.local/share/icons"),
+                PathBuf::from("/usr/local/share/icons"),
+                PathBuf::from("/usr/share/icons"),
+                PathBuf::from("/usr/share/pixmaps"),
+            ],
+            xdg.icon_dirs()?,
+        );
+
+        env::set_var("XDG_DATA_HOME", "/data");
+        env::set_var("XDG_DATA_DIRS", "/usr/share:/opt/share");
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.icons"),
+                PathBuf::from("/data/icons"),
+                PathBuf::from("/usr/share/icons"),
+                PathBuf::from("/opt/share/icons"),
+                PathBuf::from("/usr/share/pixmaps"),
+            ],
+            xdg.icon_dirs()?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mime_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.local/share/mime"),
+                PathBuf::from("/usr/local/share/mime"),
+                PathBuf::from("/usr/share/mime"),
+            ],
+            xdg.mime_dirs()?,
+        );
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.config/mimeapps.list"),
+                PathBuf::from("/etc/xdg/mimeapps.list"),
+                PathBuf::from("/home/user/.local/share/applications/mimeapps.list"),
+                PathBuf::from("/usr/local/share/applications/mimeapps.list"),
+                PathBuf::from("/usr/share/applications/mimeapps.list"),
+            ],
+            xdg.mimeapps_lists()?,
+        );
+
+        env::set_var("XDG_CURRENT_DESKTOP", "ubuntu:GNOME");
+        env::set_var("XDG_DATA_DIRS", "/usr/share");
+        assert_eq!(
+            vec![
+                PathBuf::from("/home/user/.config/ubuntu-mimeapps.list"),
+                PathBuf::from("/home/user/.config/gnome-mimeapps.list"),
+                PathBuf::from("/home/user/.config/mimeapps.list"),
+                PathBuf::from("/etc/xdg/ubuntu-mimeapps.list"),
+                PathBuf::from("/etc/xdg/gnome-mimeapps.list"),
+                PathBuf::from("/etc/xdg/mimeapps.list"),
+                PathBuf::from("/home/user/.local/share/applications/ubuntu-mimeapps.list"),
+                PathBuf::from("/home/user/.local/share/applications/gnome-mimeapps.list"),
+                PathBuf::from("/home/user/.local/share/applications/mimeapps.list"),
+                PathBuf::from("/usr/share/applications/ubuntu-mimeapps.list"),
+                PathBuf::from("/usr/share/applications/gnome-mimeapps.list"),
+                PathBuf::from("/usr/share/applications/mimeapps.list"),
+            ],
+            xdg.mimeapps_lists()?,
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn default_app_for() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_home.path().join("sys"));
+        env::set_var("XDG_DATA_DIRS", data_home.path().join("sys"));
+        env::set_var("XDG_CURRENT_DESKTOP", "KDE");
+
+        let applications = data_home.path().join("applications");
+        fs::create_dir_all(&applications)?;
+        fs::write(applications.join("org.example.Editor.desktop"), "")?;
+        fs::write(applications.join("org.example.Notes.desktop"), "")?;
+        fs::write(
+            applications.join("mimeapps.list"),
+            "[Default Applications]\ntext/markdown=org.example.Notes.desktop\n",
+        )?;
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(
+            Some(String::from("org.example.Notes.desktop")),
+            xdg.default_app_for("text/markdown")?,
+        );
+        assert_eq!(None, xdg.default_app_for("text/plain")?);
+
+        // Desktop-specific lists in the configuration home take precedence.
+        fs::write(
+            config_home.path().join("kde-mimeapps.list"),
+            "[Default Applications]\ntext/markdown=missing.desktop;org.example.Editor.desktop\n",
+        )?;
+        assert_eq!(
+            Some(String::from("org.example.Editor.desktop")),
+            xdg.default_app_for("text/markdown")?,
+        );
 
         Ok(())
     }
 
     #[test]
-    fn usr_base_dirs() -> Result<(), XdgError> {
+    #[cfg(feature = "desktop-entry")]
+    fn read_desktop_entry() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user1");
-        env::set_var("HOME", "/home/user1");
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_DATA_DIRS", data_home.path().join("sys"));
 
-        let xdg = Xdg::new()?;
+        fs::create_dir_all(data_home.path().join("applications"))?;
+        fs::write(
+            data_home.path().join("applications/org.example.App.desktop"),
+            "[Desktop Entry]\nType=Application\nName=App\nExec=app %U\n",
+        )?;
 
-        assert_eq!(Path::new("/home/user1"), xdg.home());
-        assert_eq!(Path::new("/home/user1/.cache"), xdg.cache()?);
-        assert_eq!(Path::new("/home/user1/.config"), xdg.config()?);
-        assert_eq!(Path::new("/home/user1/.local/share"), xdg.data()?);
-        assert_eq!(Path::new("/home/user1/.local/state"), xdg.state()?);
-        assert_eq!(Path::new("/home/user1/.local/bin"), xdg.bin()?);
-        assert_eq!(None, xdg.runtime()?);
+        let xdg = Xdg::with_home("/home/user");
+        let desktop_entry =
+            xdg.read_desktop_entry("org.example.App.desktop")?.ok_or("desktop entry not found")?;
+        assert_eq!(Some("App"), desktop_entry.name());
+        assert_eq!(Some("app %U"), desktop_entry.exec());
+        assert_eq!(None, xdg.read_desktop_entry("missing.desktop")?);
 
-        env::set_var("XDG_CACHE_HOME", "/home/user2/.cache");
-        env::set_var("XDG_CONFIG_HOME", "/home/user2/.config");
-        env::set_var("XDG_DATA_HOME", "/home/user2/.local/share");
-        env::set_var("XDG_STATE_HOME", "/home/user2/.local/state");
-        env::set_var("XDG_BIN_HOME", "/home/user2/.local/bin");
-        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
-        assert_eq!(Path::new("/home/user2/.cache"), xdg.cache()?);
-        assert_eq!(Path::new("/home/user2/.config"), xdg.config()?);
-        assert_eq!(Path::new("/home/user2/.local/share"), xdg.data()?);
-        assert_eq!(Path::new("/home/user2/.local/state"), xdg.state()?);
-        assert_eq!(Path::new("/home/user2/.local/bin"), xdg.bin()?);
-        assert_eq!(Some(PathBuf::from("/run/user/1000")), xdg.runtime()?);
+        Ok(())
+    }
 
-        env::set_var("XDG_CACHE_HOME", "");
-        env::set_var("XDG_CONFIG_HOME", "");
-        env::set_var("XDG_DATA_HOME", "");
-        env::set_var("XDG_STATE_HOME", "");
-        env::set_var("XDG_BIN_HOME", "");
-        env::set_var("XDG_RUNTIME_DIR", "");
-        assert_eq!(Path::new("/home/user1/.cache"), xdg.cache()?);
-        assert_eq!(Path::new("/home/user1/.config"), xdg.config()?);
-        assert_eq!(Path::new("/home/user1/.local/share"), xdg.data()?);
-        assert_eq!(Path::new("/home/user1/.local/state"), xdg.state()?);
-        assert_eq!(Path::new("/home/user1/.local/bin"), xdg.bin()?);
-        assert_eq!(None, xdg.runtime()?);
+    #[test]
+    fn all_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
 
-        env::set_var("XDG_CACHE_HOME", "./cache");
-        env::set_var("XDG_CONFIG_HOME", "./config");
-        env::set_var("XDG_DATA_HOME", "./data");
-        env::set_var("XDG_STATE_HOME", "./state");
-        env::set_var("XDG_BIN_HOME", "./bin");
-        env::set_var("XDG_RUNTIME_DIR", "./runtime");
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_CACHE_HOME",
-                path: PathBuf::from("./cache"),
-            }),
-            xdg.cache(),
+            vec![PathBuf::from("/home/user/.config"), PathBuf::from("/etc/xdg")],
+            xdg.config_dirs_all()?.collect::<Vec<_>>(),
         );
+
+        env::set_var("XDG_CONFIG_HOME", "/config/home");
+        env::set_var("XDG_CONFIG_DIRS", "/config/dir1:/config/dir2");
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_CONFIG_HOME",
-                path: PathBuf::from("./config"),
-            }),
-            xdg.config(),
+            vec![
+                PathBuf::from("/config/home"),
+                PathBuf::from("/config/dir1"),
+                PathBuf::from("/config/dir2"),
+            ],
+            xdg.config_dirs_all()?.collect::<Vec<_>>(),
         );
+
+        env::set_var("XDG_CONFIG_DIRS", "/config/dir1:config/dir2");
+        assert!(xdg.config_dirs_all().is_err());
+
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_DATA_HOME",
-                path: PathBuf::from("./data"),
-            }),
-            xdg.data(),
+            vec![
+                PathBuf::from("/home/user/.local/share"),
+                PathBuf::from("/usr/local/share"),
+                PathBuf::from("/usr/share"),
+            ],
+            xdg.data_dirs_all()?.collect::<Vec<_>>(),
         );
+
+        env::set_var("XDG_DATA_HOME", "data/home");
+        assert!(xdg.data_dirs_all().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn usr_file() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("USER", "user");
+        env::set_var("HOME", "/home/user");
+
+        let xdg = Xdg::new()?;
+
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_STATE_HOME",
-                path: PathBuf::from("./state"),
-            }),
-            xdg.state(),
+            Path::new("/home/user/.cache/file"),
+            xdg.cache_file("file")?,
         );
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_BIN_HOME",
-                path: PathBuf::from("./bin"),
-            }),
-            xdg.bin(),
+            Path::new("/home/user/.config/file"),
+            xdg.config_file("file")?,
         );
         assert_eq!(
-            Err(XdgError::RelativePath {
-                env_var_key: "XDG_RUNTIME_DIR",
-                path: PathBuf::from("./runtime"),
-            }),
-            xdg.runtime(),
+            Path::new("/home/user/.local/share/file"),
+            xdg.data_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.local/state/file"),
+            xdg.state_file("file")?,
+        );
+        assert_eq!(
+            Path::new("/home/user/.local/bin/file"),
+            xdg.bin_file("file")?,
         );
 
-        let invalid_unicode = OsStr::from_bytes(&INVALID_UNICODE_BYTES);
-        env::set_var("XDG_CACHE_HOME", invalid_unicode);
-        env::set_var("XDG_CONFIG_HOME", invalid_unicode);
-        env::set_var("XDG_DATA_HOME", invalid_unicode);
-        env::set_var("XDG_STATE_HOME", invalid_unicode);
-        env::set_var("XDG_BIN_HOME", invalid_unicode);
-        env::set_var("XDG_RUNTIME_DIR", invalid_unicode);
+        env::set_var("XDG_CACHE_HOME", "/home/user1/.cache");
+        env::set_var("XDG_CONFIG_HOME", "/home/user1/.config");
+        env::set_var("XDG_DATA_HOME", "/home/user1/.local/share");
+        env::set_var("XDG_STATE_HOME", "/home/user1/.local/state");
+        env::set_var("XDG_BIN_HOME", "/home/user1/.local/bin");
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_CACHE_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.cache(),
+            Path::new("/home/user1/.cache/file"),
+            xdg.cache_file("file")?,
         );
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_CONFIG_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.config(),
+            Path::new("/home/user1/.config/file"),
+            xdg.config_file("file")?,
         );
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_DATA_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.data(),
+            Path::new("/home/user1/.local/share/file"),
+            xdg.data_file("file")?,
         );
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_STATE_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.state(),
+            Path::new("/home/user1/.local/state/file"),
+            xdg.state_file("file")?,
         );
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_BIN_HOME",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.bin(),
+            Path::new("/home/user1/.local/bin/file"),
+            xdg.bin_file("file")?,
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_files() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+
+        assert!(xdg.search_config_files("microxdg")?.is_empty());
+        assert!(xdg.search_data_files("microxdg")?.is_empty());
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let config_home = tmp_dir_builder.tempdir()?;
+        let config_dir1 = tmp_dir_builder.tempdir()?;
+        let config_dir2 = tmp_dir_builder.tempdir()?;
+        let config_dir3 = tmp_dir_builder.tempdir()?;
+
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var(
+            "XDG_CONFIG_DIRS",
+            env::join_paths([config_dir1.path(), config_dir2.path(), config_dir3.path()])?,
+        );
+
+        let mut tmp_file_builder = tempfile::Builder::new();
+        tmp_file_builder.prefix("microxdg");
+        tmp_file_builder.rand_bytes(0);
+
+        let config_file1 = tmp_file_builder.tempfile_in(config_dir1.path())?;
+        let config_file3 = tmp_file_builder.tempfile_in(config_dir3.path())?;
+        assert_eq!(
+            vec![config_file1.path().to_path_buf(), config_file3.path().to_path_buf()],
+            xdg.search_config_files("microxdg")?,
         );
+
+        let config_file = tmp_file_builder.tempfile_in(config_home.path())?;
         assert_eq!(
-            Err(XdgError::InvalidUnicode {
-                env_var_key: "XDG_RUNTIME_DIR",
-                env_var_val: invalid_unicode.to_os_string(),
-            }),
-            xdg.runtime(),
+            vec![
+                config_file.path().to_path_buf(),
+                config_file1.path().to_path_buf(),
+                config_file3.path().to_path_buf(),
+            ],
+            xdg.search_config_files("microxdg")?,
+        );
+        assert_eq!(
+            vec![config_file.path().to_path_buf(), config_file1.path().to_path_buf()],
+            xdg.search_config_files_bounded("microxdg", 2)?,
+        );
+        assert_eq!(
+            vec![config_file.path().to_path_buf()],
+            xdg.search_config_files_bounded("microxdg", 1)?,
+        );
+        assert!(xdg.search_config_files_bounded("microxdg", 0)?.is_empty());
+        assert_eq!(3, xdg.search_config_files_bounded("microxdg", 8)?.len());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn checked_dirs() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(tmp_dir.path().join("file"), "")?;
+        env::set_var("XDG_CONFIG_HOME", tmp_dir.path());
+        env::set_var("XDG_DATA_HOME", tmp_dir.path().join("missing"));
+        env::set_var("XDG_STATE_HOME", tmp_dir.path().join("file"));
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(Some(tmp_dir.path().to_path_buf()), xdg.config_checked()?);
+        assert_eq!(None, xdg.data_checked()?);
+        assert_eq!(None, xdg.state_checked()?);
+        assert_eq!(None, xdg.runtime_checked()?);
+
+        env::set_var("XDG_RUNTIME_DIR", tmp_dir.path());
+        assert_eq!(Some(tmp_dir.path().to_path_buf()), xdg.runtime_checked()?);
+
+        env::set_var("XDG_CACHE_HOME", "cache");
+        assert!(xdg.cache_checked().is_err());
+
+        env::remove_var("XDG_CACHE_HOME");
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_STATE_HOME");
+        env::remove_var("XDG_RUNTIME_DIR");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_canonicalize() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let real_dir = tmp_dir.path().join("real");
+        fs::create_dir(&real_dir)?;
+        fs::write(real_dir.join("file"), "")?;
+        std::os::unix::fs::symlink(&real_dir, tmp_dir.path().join("link"))?;
+
+        env::set_var("XDG_CONFIG_HOME", tmp_dir.path().join("link"));
+        env::set_var("XDG_CONFIG_DIRS", tmp_dir.path().join("real/../link"));
+
+        let xdg = Xdg::with_home("/home/user");
+        assert!(!xdg.canonicalize());
+        assert_eq!(
+            Some(tmp_dir.path().join("link/file")),
+            xdg.search_config_file("file")?,
         );
 
+        let canonical_file = real_dir.canonicalize()?.join("file");
+        let xdg = xdg.with_canonicalize(true);
+        assert!(xdg.canonicalize());
+        assert_eq!(Some(canonical_file.clone()), xdg.search_config_file("file")?);
+        assert_eq!(
+            vec![canonical_file.clone(), canonical_file],
+            xdg.search_config_files("file")?,
+        );
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
-    #[rustfmt::skip]
-    fn sys_base_dirs() -> Result<(), XdgError> {
+    fn search_symlink_policy() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user");
-        env::set_var("HOME", "/home/user");
-        assert_eq!(
-            vec![PathBuf::from("/etc/xdg")],
-            Xdg::sys_config()?,
-        );
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(config_home.path().join("file"), "")?;
+        std::os::unix::fs::symlink("file", config_home.path().join("link"))?;
+        std::os::unix::fs::symlink("missing", config_home.path().join("dangling"))?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(SymlinkPolicy::Follow, xdg.symlink_policy());
+        assert_eq!(Some(config_home.path().join("link")), xdg.search_config_file("link")?);
+        assert_eq!(None, xdg.search_config_file("dangling")?);
+
+        let xdg = xdg.with_symlink_policy(SymlinkPolicy::DontFollow);
+        assert_eq!(Some(config_home.path().join("file")), xdg.search_config_file("file")?);
+        assert_eq!(None, xdg.search_config_file("link")?);
+        assert_eq!(None, xdg.search_config_file("dangling")?);
+
+        let xdg = xdg.with_symlink_policy(SymlinkPolicy::ErrorOnDangling);
+        assert_eq!(Some(config_home.path().join("link")), xdg.search_config_file("link")?);
+        assert_eq!(None, xdg.search_config_file("missing")?);
         assert_eq!(
-            vec![
-                PathBuf::from("/usr/local/share"),
-                PathBuf::from("/usr/share"),
-            ],
-            Xdg::sys_data()?,
+            XdgError::DanglingSymlink { path: config_home.path().join("dangling") },
+            xdg.search_config_file("dangling").unwrap_err(),
         );
 
-        env::set_var(
-            "XDG_CONFIG_DIRS",
-            "/config/dir1:/config/dir2:/config/dir3:/config/dir4",
-        );
-        env::set_var(
-            "XDG_DATA_DIRS",
-            "/data/dir1:/data/dir2:/data/dir3:/data/dir4",
+        env::remove_var("XDG_CONFIG_HOME");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_entry() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(data_home.path().join("file"), "")?;
+        fs::create_dir(data_dirs.path().join("dir"))?;
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_DATA_DIRS", data_dirs.path());
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(None, xdg.search_data_file("dir")?);
+        assert_eq!(
+            Some(data_dirs.path().join("dir")),
+            xdg.search_data_entry("dir", EntryType::Dir)?,
         );
+        assert_eq!(None, xdg.search_data_entry("file", EntryType::Dir)?);
         assert_eq!(
-            vec![
-                PathBuf::from("/config/dir1"),
-                PathBuf::from("/config/dir2"),
-                PathBuf::from("/config/dir3"),
-                PathBuf::from("/config/dir4"),
-            ],
-            Xdg::sys_config()?,
+            Some(data_home.path().join("file")),
+            xdg.search_data_entry("file", EntryType::File)?,
         );
         assert_eq!(
-            vec![
-                PathBuf::from("/data/dir1"),
-                PathBuf::from("/data/dir2"),
-                PathBuf::from("/data/dir3"),
-                PathBuf::from("/data/dir4"),
-            ],
-            Xdg::sys_data()?,
+            Some(data_home.path().join("file")),
+            xdg.search_data_entry("file", EntryType::Any)?,
         );
+        assert_eq!(None, xdg.search_data_entry("missing", EntryType::Any)?);
+        assert_eq!(Some(data_dirs.path().join("dir")), xdg.search_data_dir("dir")?);
+        assert_eq!(None, xdg.search_data_dir("file")?);
+
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_DATA_DIRS");
 
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
-    #[rustfmt::skip]
-    fn usr_file() -> Result<(), XdgError> {
+    fn search_order() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
 
-        env::set_var("USER", "user");
-        env::set_var("HOME", "/home/user");
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(config_home.path().join("file"), "")?;
+        fs::write(config_home.path().join("user_only"), "")?;
+        fs::write(config_dirs.path().join("file"), "")?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
 
-        let xdg = Xdg::new()?;
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(SearchOrder::UserFirst, xdg.search_order());
+        assert_eq!(Some(config_home.path().join("file")), xdg.search_config_file("file")?);
 
+        let xdg = xdg.with_search_order(SearchOrder::SystemFirst);
+        assert_eq!(SearchOrder::SystemFirst, xdg.search_order());
+        assert_eq!(Some(config_dirs.path().join("file")), xdg.search_config_file("file")?);
         assert_eq!(
-            Path::new("/home/user/.cache/file"),
-            xdg.cache_file("file")?,
-        );
-        assert_eq!(
-            Path::new("/home/user/.config/file"),
-            xdg.config_file("file")?,
+            Some(config_home.path().join("user_only")),
+            xdg.search_config_file("user_only")?,
         );
         assert_eq!(
-            Path::new("/home/user/.local/share/file"),
-            xdg.data_file("file")?,
+            vec![config_home.path().join("file"), config_dirs.path().join("file")],
+            xdg.search_config_files("file")?,
         );
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_file_with() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(config_home.path().join("file"), "")?;
+        fs::write(config_dirs.path().join("file"), "contents")?;
+        fs::create_dir_all(config_home.path().join("dir"))?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        let xdg = Xdg::with_home("/home/user");
         assert_eq!(
-            Path::new("/home/user/.local/state/file"),
-            xdg.state_file("file")?,
+            Some(config_home.path().join("file")),
+            xdg.search_config_file_with("file", |_, _| true)?,
         );
         assert_eq!(
-            Path::new("/home/user/.local/bin/file"),
-            xdg.bin_file("file")?,
+            Some(config_dirs.path().join("file")),
+            xdg.search_config_file_with("file", |_, metadata| metadata.len() > 0)?,
         );
+        assert_eq!(None, xdg.search_config_file_with("file", |_, metadata| metadata.len() > 8)?);
+        assert_eq!(None, xdg.search_config_file_with("dir", |_, _| true)?);
+
+        let mut probed = Vec::new();
+        xdg.search_config_file_with("file", |path, _| {
+            probed.push(path.to_path_buf());
+            false
+        })?;
+        assert_eq!(vec![config_home.path().join("file"), config_dirs.path().join("file")], probed);
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_file_newest() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(None, xdg.search_config_file_newest("file")?);
+
+        fs::write(config_home.path().join("file"), "")?;
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        fs::write(config_dirs.path().join("file"), "")?;
+        assert_eq!(Some(config_dirs.path().join("file")), xdg.search_config_file_newest("file")?);
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        fs::write(config_home.path().join("file"), "")?;
+        assert_eq!(Some(config_home.path().join("file")), xdg.search_config_file_newest("file")?);
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn find_executable() -> Result<(), Box<dyn Error>> {
+        use std::os::unix::fs::PermissionsExt;
+
+        remove_xdg_vars();
+
+        let bin_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let path_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        for (dir, file, mode) in [
+            (bin_home.path(), "tool", 0o755),
+            (bin_home.path(), "script", 0o644),
+            (path_dir.path(), "script", 0o700),
+            (path_dir.path(), "other", 0o755),
+        ] {
+            fs::write(dir.join(file), "")?;
+            fs::set_permissions(dir.join(file), fs::Permissions::from_mode(mode))?;
+        }
+        env::set_var("XDG_BIN_HOME", bin_home.path());
+        let path = env::var_os("PATH");
+        env::set_var("PATH", env::join_paths([Path::new("relative"), path_dir.path()])?);
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(Some(bin_home.path().join("tool")), xdg.find_executable("tool")?);
+        assert_eq!(None, xdg.find_executable("script")?);
+        assert_eq!(None, xdg.find_executable("other")?);
+        assert_eq!(Some(bin_home.path().join("tool")), xdg.find_executable_in_path("tool")?);
+        assert_eq!(Some(path_dir.path().join("script")), xdg.find_executable_in_path("script")?);
+        assert_eq!(Some(path_dir.path().join("other")), xdg.find_executable_in_path("other")?);
+        assert_eq!(None, xdg.find_executable_in_path("missing")?);
+
+        match path {
+            Some(path) => env::set_var("PATH", path),
+            None => env::remove_var("PATH"),
+        }
+        env::remove_var("XDG_BIN_HOME");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_sys_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let config_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let config_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(config_home.path().join("file"), "")?;
+        fs::write(config_dirs.path().join("file"), "")?;
+        fs::write(config_home.path().join("user_only"), "")?;
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+        env::set_var("XDG_DATA_DIRS", config_dirs.path());
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(Some(config_home.path().join("file")), xdg.search_config_file("file")?);
+        assert_eq!(Some(config_dirs.path().join("file")), xdg.search_sys_config_file("file")?);
+        assert_eq!(None, xdg.search_sys_config_file("user_only")?);
+        assert_eq!(Some(config_dirs.path().join("file")), xdg.search_sys_data_file("file")?);
+        assert_eq!(None, xdg.search_sys_data_file("missing")?);
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+        env::remove_var("XDG_DATA_DIRS");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn search_usr_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let data_home = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let data_dirs = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(data_home.path().join("file"), "")?;
+        fs::write(data_dirs.path().join("sys_only"), "")?;
+        env::set_var("XDG_CONFIG_HOME", data_home.path());
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_CONFIG_DIRS", data_dirs.path());
+        env::set_var("XDG_DATA_DIRS", data_dirs.path());
+
+        let xdg = Xdg::with_home("/home/user");
+        assert_eq!(Some(data_dirs.path().join("sys_only")), xdg.search_data_file("sys_only")?);
+        assert_eq!(None, xdg.search_usr_data_file("sys_only")?);
+        assert_eq!(None, xdg.search_usr_config_file("sys_only")?);
+        assert_eq!(Some(data_home.path().join("file")), xdg.search_usr_data_file("file")?);
+        assert_eq!(Some(data_home.path().join("file")), xdg.search_usr_config_file("file")?);
+
+        env::set_var("XDG_DATA_HOME", "relative/path");
+        assert!(xdg.search_usr_data_file("file").is_err());
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_CONFIG_DIRS");
+        env::remove_var("XDG_DATA_DIRS");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "glob")]
+    fn search_data_glob() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let data_home = tmp_dir_builder.tempdir()?;
+        let data_dirs = tmp_dir_builder.tempdir()?;
+
+        env::set_var("XDG_DATA_HOME", data_home.path());
+        env::set_var("XDG_DATA_DIRS", data_dirs.path());
+
+        assert!(xdg.search_data_glob("themes/*.toml")?.is_empty());
+
+        std::fs::create_dir(data_home.path().join("themes"))?;
+        std::fs::create_dir(data_dirs.path().join("themes"))?;
+        std::fs::write(data_home.path().join("themes/light.toml"), "")?;
+        std::fs::write(data_home.path().join("themes/dark.toml"), "")?;
+        std::fs::write(data_home.path().join("themes/dark.yml"), "")?;
+        std::fs::write(data_dirs.path().join("themes/dark.toml"), "")?;
 
-        env::set_var("XDG_CACHE_HOME", "/home/user1/.cache");
-        env::set_var("XDG_CONFIG_HOME", "/home/user1/.config");
-        env::set_var("XDG_DATA_HOME", "/home/user1/.local/share");
-        env::set_var("XDG_STATE_HOME", "/home/user1/.local/state");
-        env::set_var("XDG_BIN_HOME", "/home/user1/.local/bin");
-        assert_eq!(
-            Path::new("/home/user1/.cache/file"),
-            xdg.cache_file("file")?,
-        );
         assert_eq!(
-            Path::new("/home/user1/.config/file"),
-            xdg.config_file("file")?,
+            vec![
+                data_home.path().join("themes/dark.toml"),
+                data_home.path().join("themes/light.toml"),
+                data_dirs.path().join("themes/dark.toml"),
+            ],
+            xdg.search_data_glob("themes/*.toml")?,
         );
         assert_eq!(
-            Path::new("/home/user1/.local/share/file"),
-            xdg.data_file("file")?,
+            vec![data_home.path().join("themes/dark.yml")],
+            xdg.search_data_glob("th*/d?rk.yml")?,
         );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn read_file() -> Result<(), Box<dyn Error>> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+
+        let mut tmp_dir_builder = tempfile::Builder::new();
+        tmp_dir_builder.prefix("microxdg");
+        tmp_dir_builder.rand_bytes(4);
+
+        let config_home = tmp_dir_builder.tempdir()?;
+        let config_dirs = tmp_dir_builder.tempdir()?;
+
+        env::set_var("XDG_CONFIG_HOME", config_home.path());
+        env::set_var("XDG_CONFIG_DIRS", config_dirs.path());
+
         assert_eq!(
-            Path::new("/home/user1/.local/state/file"),
-            xdg.state_file("file")?,
+            Err(XdgError::FileNotFound {
+                file: PathBuf::from("file"),
+                searched: vec![config_home.path().to_path_buf(), config_dirs.path().to_path_buf()],
+            }),
+            xdg.read_config_file("file"),
         );
+
+        fs::write(config_dirs.path().join("file"), "system")?;
+        assert_eq!(b"system".as_slice(), xdg.read_config_file("file")?);
+
+        fs::write(config_home.path().join("file"), "user")?;
+        assert_eq!("user", xdg.read_config_file_to_string("file")?);
+
+        fs::write(config_home.path().join("file"), INVALID_UNICODE_BYTES)?;
         assert_eq!(
-            Path::new("/home/user1/.local/bin/file"),
-            xdg.bin_file("file")?,
+            Err(XdgError::Io {
+                path: config_home.path().join("file"),
+                source: io::ErrorKind::InvalidData.into(),
+            }),
+            xdg.read_config_file_to_string("file"),
         );
 
         Ok(())
     }
 
+    #[test]
+    fn preallocated_paths() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        let xdg = Xdg::with_home("/home/user");
+        let additional = Path::new("file").append_len();
+
+        // Appending the file reuses the buffer, without reallocating it.
+        let config_dir = xdg.get_dir_path_with_capacity(XdgDir::Config, additional)?;
+        assert!(config_dir.capacity() >= config_dir.as_os_str().len() + additional);
+        let ptr = config_dir.as_os_str().as_bytes().as_ptr();
+        let config_file = config_dir.append("file");
+        assert_eq!(Path::new("/home/user/.config/file"), config_file);
+        assert_eq!(ptr, config_file.as_os_str().as_bytes().as_ptr());
+
+        Ok(())
+    }
+
+    #[test]
+    fn borrowed_dirs() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("XDG_DATA_HOME", "/data");
+
+        let xdg = Xdg::with_home("/home/user");
+        assert!(matches!(xdg.data_cow()?, Cow::Owned(_)));
+        assert_eq!(Path::new("/data"), xdg.data_cow()?);
+
+        let xdg = xdg.with_caching(true);
+        assert!(matches!(xdg.data_cow()?, Cow::Borrowed(_)));
+        assert_eq!(Path::new("/data"), xdg.data_cow()?);
+        assert_eq!(Path::new("/home/user/.config"), xdg.config_cow()?);
+        assert_eq!(xdg.bin()?, xdg.bin_cow()?);
+
+        env::remove_var("XDG_DATA_HOME");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
     #[test]
     fn search_file() -> Result<(), Box<dyn Error>> {
         remove_xdg_vars();
@@ -1541,4 +8475,68 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn trace_events() -> Result<(), XdgError> {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Level, Metadata, Subscriber};
+
+        /// Subscriber collecting the messages of the debug events.
+        struct Collector(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for &Collector {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                if field.name() == "message" {
+                    self.0.lock().unwrap().push(format!("{value:?}"));
+                }
+            }
+        }
+
+        impl Subscriber for Collector {
+            fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+                *metadata.level() == Level::DEBUG
+            }
+
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                event.record(&mut &*self);
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        env::remove_var("XDG_CONFIG_HOME");
+        env::set_var("XDG_CACHE_HOME", "/tmp/cache");
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let collector = Collector(Arc::clone(&events));
+        tracing::subscriber::with_default(collector, || -> Result<(), XdgError> {
+            let xdg = Xdg::with_home("/home/user");
+            xdg.config()?;
+            xdg.cache()?;
+            Ok(())
+        })?;
+        env::remove_var("XDG_CACHE_HOME");
+
+        let events = events.lock().unwrap();
+        assert!(events.contains(&String::from(
+            "XDG_CONFIG_HOME not set, falling back to `/home/user/.config`"
+        )));
+        assert!(events.contains(&String::from("XDG_CACHE_HOME set to `/tmp/cache`")));
+
+        Ok(())
+    }
 }
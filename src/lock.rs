@@ -0,0 +1,186 @@
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Xdg, XdgError};
+
+/// Extension of the sidecar lock files.
+const LOCK_EXT: &str = "lock";
+
+/// Interval between two attempts to acquire a held lock, on platforms lacking
+/// blocking locks.
+#[cfg(windows)]
+const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// Advisory lock on a file, held through a sidecar `<file>.lock` file and
+/// released when dropped, returned by
+/// [`XdgApp::lock_app_config_file`](crate::XdgApp::lock_app_config_file).
+///
+/// The lock is an `flock` on the sidecar file on Unix, and an exclusive
+/// handle to it on Windows: either way, it is released by the operating
+/// system as soon as the file is closed, even if the process crashes. The
+/// sidecar file itself is never removed, as removing it would let two
+/// processes lock different files at the same path.
+///
+/// The lock is advisory: it only excludes other processes acquiring the same
+/// lock, not ones accessing the locked file directly.
+#[derive(Debug)]
+#[must_use = "the lock is released as soon as it is dropped"]
+pub struct FileLock {
+    path: PathBuf,
+    // Held only to keep the lock until dropped.
+    _file: File,
+}
+
+impl FileLock {
+    /// Returns the path of the sidecar lock file.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path of the sidecar lock file of `file`.
+    fn lock_path(file: &Path) -> PathBuf {
+        let mut lock_path = file.as_os_str().to_os_string();
+        lock_path.push(".");
+        lock_path.push(LOCK_EXT);
+        PathBuf::from(lock_path)
+    }
+
+    /// Acquires the lock on `file`, blocking until released by the process
+    /// holding it if `block` is `true`, or returning `None` otherwise.
+    fn lock(file: &Path, block: bool) -> Result<Option<FileLock>, XdgError> {
+        let path = FileLock::lock_path(file);
+        if let Some(parent) = path.parent() {
+            Xdg::create_dir_all(parent)?;
+        }
+
+        match lock_file(&path, block) {
+            Ok(Some(file)) => Ok(Some(FileLock { path, _file: file })),
+            Ok(None) => Ok(None),
+            Err(source) => Err(XdgError::Io { path, source }),
+        }
+    }
+
+    /// Attempts to acquire the lock on `file`, returning `None` if held by
+    /// another process.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an [`XdgError::Io`] error if the lock file or one
+    /// of its parent directories could not be created, or could not be
+    /// locked.
+    #[inline]
+    pub(crate) fn try_acquire(file: &Path) -> Result<Option<FileLock>, XdgError> {
+        FileLock::lock(file, false)
+    }
+
+    /// Acquires the lock on `file`, blocking until released by the process
+    /// holding it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an [`XdgError::Io`] error if the lock file or one
+    /// of its parent directories could not be created, or could not be
+    /// locked.
+    pub(crate) fn acquire(file: &Path) -> Result<FileLock, XdgError> {
+        loop {
+            // Blocking attempts only return once the lock is held.
+            if let Some(lock) = FileLock::lock(file, true)? {
+                return Ok(lock);
+            }
+        }
+    }
+}
+
+/// Opens the lock file at `path`, creating it if missing, and locks it,
+/// returning `None` if held elsewhere and `block` is `false`.
+#[cfg(unix)]
+fn lock_file(path: &Path, block: bool) -> io::Result<Option<File>> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .mode(0o600)
+        .open(path)?;
+    Ok(crate::sys::flock(&file, block)?.then_some(file))
+}
+
+/// Opens the lock file at `path`, creating it if missing, and locks it,
+/// returning `None` if held elsewhere and `block` is `false`.
+#[cfg(windows)]
+fn lock_file(path: &Path, block: bool) -> io::Result<Option<File>> {
+    use std::os::windows::fs::OpenOptionsExt;
+
+    /// Error code of a file opened by another process without sharing.
+    const ERROR_SHARING_VIOLATION: i32 = 32;
+
+    loop {
+        let mut open_options = OpenOptions::new();
+        open_options.read(true).write(true).create(true).truncate(false).share_mode(0);
+        match open_options.open(path) {
+            Ok(file) => return Ok(Some(file)),
+            Err(err) if err.raw_os_error() == Some(ERROR_SHARING_VIOLATION) => {
+                if !block {
+                    return Ok(None);
+                }
+
+                std::thread::sleep(RETRY_INTERVAL);
+            },
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Opens the lock file at `path` and locks it, unsupported on the target
+/// platform.
+#[cfg(not(any(unix, windows)))]
+fn lock_file(_path: &Path, _block: bool) -> io::Result<Option<File>> {
+    Err(io::ErrorKind::Unsupported.into())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn file_lock() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let file = tmp_dir.path().join("app_name/config");
+
+        let lock = FileLock::try_acquire(&file)?.expect("lock not held");
+        assert_eq!(tmp_dir.path().join("app_name/config.lock"), lock.path());
+        assert!(FileLock::try_acquire(&file)?.is_none());
+
+        // The lock file is kept, while the lock is released.
+        drop(lock);
+        assert!(tmp_dir.path().join("app_name/config.lock").exists());
+        assert!(FileLock::try_acquire(&file)?.is_some());
+
+        // Blocking acquisitions wait for the lock to be released.
+        let lock = FileLock::acquire(&file)?;
+        let (sender, receiver) = mpsc::channel();
+        let waiter = thread::spawn({
+            let file = file.clone();
+            move || {
+                let lock = FileLock::acquire(&file);
+                sender.send(()).ok();
+                lock.map(drop)
+            }
+        });
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+        drop(lock);
+        assert!(receiver.recv_timeout(Duration::from_secs(10)).is_ok());
+        waiter.join().expect("waiter panicked")?;
+
+        Ok(())
+    }
+}
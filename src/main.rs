@@ -0,0 +1,128 @@
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use microxdg::{Xdg, XdgError};
+
+/// Command line usage.
+const USAGE: &str = "\
+Usage: microxdg <DIR>
+
+Prints the resolved XDG base directory.
+
+Directories:
+  cache     User-specific cache directory ($XDG_CACHE_HOME)
+  config    User-specific configuration directory ($XDG_CONFIG_HOME)
+  data      User-specific data directory ($XDG_DATA_HOME)
+  state     User-specific state directory ($XDG_STATE_HOME)
+  exec      User-specific executables directory ($XDG_BIN_HOME), alias: bin
+  runtime   User-specific runtime directory ($XDG_RUNTIME_DIR)
+
+Options:
+  -h, --help     Print help
+  -V, --version  Print version
+
+Exit status:
+  0  the directory was resolved
+  1  the directory could not be resolved
+  2  invalid usage";
+
+/// Exit status returned when the directory could not be resolved.
+const EXIT_FAILURE: u8 = 1;
+/// Exit status returned on invalid usage.
+const EXIT_USAGE: u8 = 2;
+
+/// Resolves the XDG base directory named `dir`.
+///
+/// # Note
+///
+/// This function returns `None` if `dir` does not name an XDG base directory.
+fn resolve(xdg: &Xdg, dir: &str) -> Option<Result<PathBuf, XdgError>> {
+    Some(match dir {
+        "cache" => xdg.cache(),
+        "config" => xdg.config(),
+        "data" => xdg.data(),
+        "state" => xdg.state(),
+        "exec" | "bin" => xdg.bin(),
+        "runtime" => xdg.runtime().and_then(|runtime| runtime.ok_or(XdgError::RuntimeDirNotSet)),
+        _ => return None,
+    })
+}
+
+/// Writes `path` to the standard output, followed by a newline.
+///
+/// # Note
+///
+/// Non-unicode paths are written as they are on Unix.
+fn print_path(path: &Path) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        stdout.write_all(path.as_os_str().as_bytes())?;
+    }
+
+    #[cfg(not(unix))]
+    write!(stdout, "{}", path.display())?;
+
+    stdout.write_all(b"\n")
+}
+
+fn main() -> ExitCode {
+    // Arguments are parsed through `args_os`, as `args` panics on invalid
+    // unicode.
+    let args: Result<Vec<String>, OsString> =
+        env::args_os().skip(1).map(OsString::into_string).collect();
+    let args = match args {
+        Ok(args) => args,
+        Err(arg) => {
+            let arg = arg.to_string_lossy();
+            eprintln!("microxdg: invalid unicode argument `{arg}`\n\n{USAGE}");
+            return ExitCode::from(EXIT_USAGE);
+        },
+    };
+    let dir = match args.as_slice() {
+        [arg] if arg == "-h" || arg == "--help" => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        },
+        [arg] if arg == "-V" || arg == "--version" => {
+            println!("microxdg {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        },
+        [dir] => dir,
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(EXIT_USAGE);
+        },
+    };
+
+    let xdg = match Xdg::new() {
+        Ok(xdg) => xdg,
+        Err(err) => {
+            eprintln!("microxdg: {err}");
+            return ExitCode::from(EXIT_FAILURE);
+        },
+    };
+
+    match resolve(&xdg, dir) {
+        Some(Ok(path)) => match print_path(&path) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("microxdg: {err}");
+                ExitCode::from(EXIT_FAILURE)
+            },
+        },
+        Some(Err(err)) => {
+            eprintln!("microxdg: {err}");
+            ExitCode::from(EXIT_FAILURE)
+        },
+        None => {
+            eprintln!("microxdg: unknown directory `{dir}`\n\n{USAGE}");
+            ExitCode::from(EXIT_USAGE)
+        },
+    }
+}
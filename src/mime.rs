@@ -0,0 +1,154 @@
+use std::env;
+use std::path::PathBuf;
+
+/// Name of the MIME type associations files.
+const MIMEAPPS_LIST: &str = "mimeapps.list";
+
+/// Returns the lowercase names of the current desktop environments, as set to
+/// the colon separated `XDG_CURRENT_DESKTOP` environment variable.
+pub(crate) fn current_desktops() -> Vec<String> {
+    env::var("XDG_CURRENT_DESKTOP")
+        .unwrap_or_default()
+        .split(':')
+        .filter(|desktop| !desktop.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Returns the `mimeapps.list` files to look up inside each of `dirs`, in
+/// precedence order: for each directory, the desktop-specific
+/// `<desktop>-mimeapps.list` files come first, followed by `mimeapps.list`.
+pub(crate) fn mimeapps_lists<I>(dirs: I, desktops: &[String]) -> Vec<PathBuf>
+where
+    I: IntoIterator<Item = PathBuf>,
+{
+    let mut lists = Vec::new();
+    for dir in dirs {
+        for desktop in desktops {
+            lists.push(dir.join(format!("{desktop}-{MIMEAPPS_LIST}")));
+        }
+        lists.push(dir.join(MIMEAPPS_LIST));
+    }
+
+    lists
+}
+
+/// Returns the desktop file IDs associated to `mime_type` within `group` of
+/// the `mimeapps.list` file `content`, in order of preference.
+#[cfg(feature = "fs")]
+pub(crate) fn associations(content: &str, group: &str, mime_type: &str) -> Vec<String> {
+    let mut in_group = false;
+    let mut ids = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            in_group = name == group;
+            continue;
+        }
+
+        match line.split_once('=') {
+            Some((key, value)) if in_group && key.trim_end() == mime_type => {
+                ids.extend(
+                    value.trim_start().split(';').filter(|id| !id.is_empty()).map(str::to_owned),
+                );
+            },
+            _ => {},
+        }
+    }
+
+    ids
+}
+
+/// Returns the default application for `mime_type` out of the `mimeapps.list`
+/// file `lists`, sorted by precedence, i.e. the first installed desktop file
+/// ID listed in the `[Default Applications]` groups or, as a fallback, in the
+/// `[Added Associations]` groups not removed by the `[Removed Associations]`
+/// group of a file with higher precedence.
+#[cfg(feature = "fs")]
+pub(crate) fn default_app<F, E>(
+    lists: &[String],
+    mime_type: &str,
+    mut installed: F,
+) -> Result<Option<String>, E>
+where
+    F: FnMut(&str) -> Result<bool, E>,
+{
+    for content in lists {
+        for id in associations(content, "Default Applications", mime_type) {
+            if installed(&id)? {
+                return Ok(Some(id));
+            }
+        }
+    }
+
+    let mut removed = Vec::new();
+    for content in lists {
+        for id in associations(content, "Added Associations", mime_type) {
+            if !removed.contains(&id) && installed(&id)? {
+                return Ok(Some(id));
+            }
+        }
+        removed.extend(associations(content, "Removed Associations", mime_type));
+    }
+
+    Ok(None)
+}
+
+#[cfg(all(test, feature = "fs"))]
+mod test {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    #[test]
+    fn default_app_lookup() -> Result<(), Infallible> {
+        let user_list = "\
+[Default Applications]
+text/markdown=missing.desktop;org.example.Editor.desktop;
+
+[Added Associations]
+image/png=org.example.Viewer.desktop;
+
+[Removed Associations]
+image/png=org.example.Paint.desktop;
+";
+        let sys_list = "\
+[Default Applications]
+text/markdown=org.example.Notes.desktop
+
+[Added Associations]
+image/png=org.example.Paint.desktop;org.example.Gallery.desktop;
+text/plain=org.example.Notes.desktop;
+";
+        assert_eq!(
+            vec!["missing.desktop", "org.example.Editor.desktop"],
+            associations(user_list, "Default Applications", "text/markdown"),
+        );
+        assert!(associations(user_list, "Added Associations", "text/markdown").is_empty());
+
+        let lists = [String::from(user_list), String::from(sys_list)];
+        let installed = |id: &str| -> Result<bool, Infallible> {
+            Ok(id != "missing.desktop" && id != "org.example.Viewer.desktop")
+        };
+        assert_eq!(
+            Some(String::from("org.example.Editor.desktop")),
+            default_app(&lists, "text/markdown", installed)?,
+        );
+        assert_eq!(
+            Some(String::from("org.example.Notes.desktop")),
+            default_app(&lists, "text/plain", installed)?,
+        );
+        // Removed associations are skipped in lower precedence files.
+        assert_eq!(
+            Some(String::from("org.example.Gallery.desktop")),
+            default_app(&lists, "image/png", installed)?,
+        );
+        assert_eq!(None, default_app(&lists, "video/mp4", installed)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+use std::fs;
+use std::path::Path;
+
+use crate::glob;
+
+/// Default weight of a glob pattern, as defined by the specification.
+const DEFAULT_WEIGHT: u32 = 50;
+
+/// Glob pattern mapping file names to a MIME type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MimeGlob {
+    weight: u32,
+    mime_type: String,
+    pattern: String,
+    case_sensitive: bool,
+}
+
+impl MimeGlob {
+    /// Returns `true` if `file_name` matches the pattern.
+    fn matches(&self, file_name: &str) -> bool {
+        match self.case_sensitive {
+            true => glob::matches(&self.pattern, file_name),
+            false => glob::matches(&self.pattern.to_lowercase(), &file_name.to_lowercase()),
+        }
+    }
+}
+
+/// File name to MIME type mapping, loaded from the `globs2` files of the
+/// [_Shared MIME-info Database_](<https://specifications.freedesktop.org/shared-mime-info-spec/latest/>).
+///
+/// See [`Xdg::mime_globs`](crate::Xdg::mime_globs) to load the mapping from
+/// the MIME database directories.
+///
+/// # Note
+///
+/// Patterns support the `*` and `?` wildcards only: patterns relying on
+/// character classes (e.g. `*.[1-9]`) never match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::MimeGlobs;
+/// let globs = MimeGlobs::parse("50:text/markdown:*.md\n50:text/x-readme:README*\n");
+/// assert_eq!(Some("text/markdown"), globs.mime_type("notes.md"));
+/// assert_eq!(Some("text/x-readme"), globs.mime_type("README"));
+/// assert_eq!(None, globs.mime_type("picture.png"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MimeGlobs {
+    globs: Vec<MimeGlob>,
+}
+
+impl MimeGlobs {
+    /// Parses the content of a `globs2` file, made of
+    /// `weight:mime_type:pattern[:flags]` lines.
+    ///
+    /// Comments and malformed lines are skipped.
+    #[must_use]
+    pub fn parse(content: &str) -> MimeGlobs {
+        let mut globs = MimeGlobs::default();
+        globs.extend(content);
+        globs
+    }
+
+    /// Parses `content`, adding its patterns with higher precedence than the
+    /// current ones.
+    ///
+    /// A `__NOGLOBS__` pattern drops the current patterns of its MIME type,
+    /// as defined by lower precedence files.
+    fn extend(&mut self, content: &str) {
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(4, ':');
+            let (Some(weight), Some(mime_type), Some(pattern)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+
+            if pattern == "__NOGLOBS__" {
+                self.globs.retain(|glob| glob.mime_type != mime_type);
+                continue;
+            }
+
+            let case_sensitive =
+                fields.next().is_some_and(|flags| flags.split(',').any(|flag| flag == "cs"));
+            self.globs.push(MimeGlob {
+                weight: weight.parse().unwrap_or(DEFAULT_WEIGHT),
+                mime_type: mime_type.to_owned(),
+                pattern: pattern.to_owned(),
+                case_sensitive,
+            });
+        }
+    }
+
+    /// Returns the MIME type of `file_name`, i.e. the one of the matching
+    /// pattern with the highest weight, if any.
+    ///
+    /// Ties are broken in favor of case-sensitive patterns first, of the
+    /// longest pattern then, and of the pattern with the highest precedence
+    /// last.
+    #[must_use]
+    pub fn mime_type(&self, file_name: &str) -> Option<&str> {
+        // Patterns are stored from the lowest to the highest precedence, and
+        // `max_by_key` returns the last maximum.
+        self.globs
+            .iter()
+            .filter(|glob| glob.matches(file_name))
+            .max_by_key(|glob| (glob.weight, glob.case_sensitive, glob.pattern.len()))
+            .map(|glob| glob.mime_type.as_str())
+    }
+
+    /// Returns the MIME type of the file at `path` judging by its file name,
+    /// if any.
+    #[inline]
+    #[must_use]
+    pub fn mime_type_of<P>(&self, path: P) -> Option<&str>
+    where
+        P: AsRef<Path>,
+    {
+        path.as_ref().file_name()?.to_str().and_then(|file_name| self.mime_type(file_name))
+    }
+
+    /// Loads the `globs2` files inside `mime_dirs`, sorted by precedence.
+    /// Missing or unreadable files are skipped.
+    pub(crate) fn load<P>(mime_dirs: &[P]) -> MimeGlobs
+    where
+        P: AsRef<Path>,
+    {
+        let mut globs = MimeGlobs::default();
+        for mime_dir in mime_dirs.iter().rev() {
+            if let Ok(content) = fs::read_to_string(mime_dir.as_ref().join("globs2")) {
+                globs.extend(&content);
+            }
+        }
+
+        globs
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mime_type() {
+        let globs = MimeGlobs::parse(
+            "# Comment\n\
+             50:text/plain:*.txt\n\
+             50:text/x-readme:README*\n\
+             50:application/x-compressed-tar:*.tar.gz\n\
+             50:application/gzip:*.gz\n\
+             60:text/x-makefile:Makefile:cs\n\
+             50:text/x-c++src:*.C:cs\n\
+             50:text/x-csrc:*.c\n\
+             malformed\n",
+        );
+        assert_eq!(Some("text/plain"), globs.mime_type("notes.txt"));
+        assert_eq!(Some("text/plain"), globs.mime_type("NOTES.TXT"));
+        assert_eq!(Some("text/x-readme"), globs.mime_type("README.md"));
+        assert_eq!(Some("application/x-compressed-tar"), globs.mime_type("archive.tar.gz"));
+        assert_eq!(Some("application/gzip"), globs.mime_type("file.gz"));
+        assert_eq!(Some("text/x-makefile"), globs.mime_type("Makefile"));
+        assert_eq!(None, globs.mime_type("makefile"));
+        assert_eq!(Some("text/x-c++src"), globs.mime_type("main.C"));
+        assert_eq!(Some("text/x-csrc"), globs.mime_type("main.c"));
+        assert_eq!(Some("text/plain"), globs.mime_type_of("/home/user/notes.txt"));
+        assert_eq!(None, globs.mime_type("picture.png"));
+    }
+
+    #[test]
+    fn load_mime_globs() -> Result<(), std::io::Error> {
+        let user_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        let sys_dir = tempfile::Builder::new().prefix("microxdg").tempdir()?;
+        fs::write(
+            user_dir.path().join("globs2"),
+            "50:text/x-notes:*.txt\n50:text/markdown:__NOGLOBS__\n",
+        )?;
+        fs::write(sys_dir.path().join("globs2"), "50:text/plain:*.txt\n50:text/markdown:*.md\n")?;
+
+        let globs = MimeGlobs::load(&[user_dir.path(), sys_dir.path()]);
+        assert_eq!(Some("text/x-notes"), globs.mime_type("notes.txt"));
+        assert_eq!(None, globs.mime_type("notes.md"));
+
+        Ok(())
+    }
+}
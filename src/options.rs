@@ -0,0 +1,466 @@
+use std::time::Duration;
+
+/// Options for [`XdgApp::remove_app_dirs`](crate::XdgApp::remove_app_dirs),
+/// selecting which application subdirectories get removed.
+///
+/// By default, only the **cache** and **state** app subdirectories are
+/// selected, as they hold data that the application can regenerate; the
+/// **configuration** and **data** app subdirectories must be opted in
+/// explicitly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::RemoveOptions;
+/// // Remove every app subdirectory, e.g. on `app uninstall`.
+/// let options = RemoveOptions::new().config(true).data(true);
+///
+/// // Only list what would be removed, without touching the filesystem.
+/// let options = RemoveOptions::new().dry_run(true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoveOptions {
+    pub(crate) cache: bool,
+    pub(crate) config: bool,
+    pub(crate) data: bool,
+    pub(crate) state: bool,
+    pub(crate) dry_run: bool,
+}
+
+impl RemoveOptions {
+    /// Constructs new [`RemoveOptions`], selecting the **cache** and
+    /// **state** app subdirectories.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> RemoveOptions {
+        RemoveOptions { cache: true, config: false, data: false, state: true, dry_run: false }
+    }
+
+    /// Selects whether the **cache** app subdirectory gets removed.
+    #[inline]
+    #[must_use]
+    pub const fn cache(mut self, cache: bool) -> RemoveOptions {
+        self.cache = cache;
+        self
+    }
+
+    /// Selects whether the **configuration** app subdirectory gets removed.
+    #[inline]
+    #[must_use]
+    pub const fn config(mut self, config: bool) -> RemoveOptions {
+        self.config = config;
+        self
+    }
+
+    /// Selects whether the **data** app subdirectory gets removed.
+    #[inline]
+    #[must_use]
+    pub const fn data(mut self, data: bool) -> RemoveOptions {
+        self.data = data;
+        self
+    }
+
+    /// Selects whether the **state** app subdirectory gets removed.
+    #[inline]
+    #[must_use]
+    pub const fn state(mut self, state: bool) -> RemoveOptions {
+        self.state = state;
+        self
+    }
+
+    /// Only lists the app subdirectories that would be removed, leaving the
+    /// filesystem untouched.
+    #[inline]
+    #[must_use]
+    pub const fn dry_run(mut self, dry_run: bool) -> RemoveOptions {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+impl Default for RemoveOptions {
+    #[inline]
+    fn default() -> RemoveOptions {
+        RemoveOptions::new()
+    }
+}
+
+/// Options for [`XdgApp::prune_app_cache`](crate::XdgApp::prune_app_cache),
+/// bounding the age and size of the files inside the application cache.
+///
+/// By default, no bound is set and pruning leaves the cache untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use microxdg::PruneOptions;
+/// // Drop files older than a week and keep the cache below 100 MiB.
+/// let options = PruneOptions::new()
+///     .max_age(Duration::from_secs(7 * 24 * 60 * 60))
+///     .max_total_size(100 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneOptions {
+    pub(crate) max_age: Option<Duration>,
+    pub(crate) max_total_size: Option<u64>,
+}
+
+impl PruneOptions {
+    /// Constructs new [`PruneOptions`], with no age nor size bound.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> PruneOptions {
+        PruneOptions { max_age: None, max_total_size: None }
+    }
+
+    /// Sets the maximum age of cache files: files last modified longer than
+    /// `max_age` ago are removed.
+    #[inline]
+    #[must_use]
+    pub const fn max_age(mut self, max_age: Duration) -> PruneOptions {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Sets the maximum total size, in bytes, of cache files: the least
+    /// recently modified files are removed until the total size fits.
+    #[inline]
+    #[must_use]
+    pub const fn max_total_size(mut self, max_total_size: u64) -> PruneOptions {
+        self.max_total_size = Some(max_total_size);
+        self
+    }
+}
+
+/// Options for
+/// [`XdgApp::install_app_data_file`](crate::XdgApp::install_app_data_file),
+/// controlling whether already installed files get overwritten.
+///
+/// By default, the destination file is always overwritten.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::InstallOptions;
+/// // Keep destination files modified after the source, e.g. by the user.
+/// let options = InstallOptions::new().skip_newer(true);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InstallOptions {
+    pub(crate) skip_newer: bool,
+}
+
+impl InstallOptions {
+    /// Constructs new [`InstallOptions`], always overwriting the destination
+    /// file.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> InstallOptions {
+        InstallOptions { skip_newer: false }
+    }
+
+    /// Skips the installation if the destination file was last modified
+    /// after (or at the same time as) the source file.
+    #[inline]
+    #[must_use]
+    pub const fn skip_newer(mut self, skip_newer: bool) -> InstallOptions {
+        self.skip_newer = skip_newer;
+        self
+    }
+}
+
+/// Options for [`XdgApp::save_app_state`](crate::XdgApp::save_app_state),
+/// controlling whether the previous version of the file is kept.
+///
+/// By default, the previous version is discarded.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::SaveOptions;
+/// // Keep the previous version as `<file>.bak`.
+/// let options = SaveOptions::new().backup(true);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SaveOptions {
+    pub(crate) backup: bool,
+}
+
+impl SaveOptions {
+    /// Constructs new [`SaveOptions`], discarding the previous version of the
+    /// file.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> SaveOptions {
+        SaveOptions { backup: false }
+    }
+
+    /// Keeps the previous version of the file, if any, as `<file>.bak`.
+    #[inline]
+    #[must_use]
+    pub const fn backup(mut self, backup: bool) -> SaveOptions {
+        self.backup = backup;
+        self
+    }
+}
+
+/// Policy governing XDG environment variables set to relative paths.
+///
+/// The specification states that XDG environment variables must be set to
+/// absolute paths, and that relative ones should be ignored. By default,
+/// [`Xdg`](crate::Xdg) surfaces such values as
+/// [`XdgError::RelativePath`](crate::XdgError::RelativePath) errors.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::path::PathBuf;
+/// # use microxdg::{RelativePathPolicy, Xdg, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// std::env::set_var("XDG_CONFIG_HOME", ".config");
+///
+/// let xdg = Xdg::with_home("/home/user").with_relative_path_policy(RelativePathPolicy::Ignore);
+/// assert_eq!(PathBuf::from("/home/user/.config"), xdg.config()?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RelativePathPolicy {
+    /// Fails with [`XdgError::RelativePath`](crate::XdgError::RelativePath).
+    #[default]
+    Error,
+    /// Ignores the relative path, as mandated by the specification: _user_
+    /// directories fall back as if the variable was not set, while relative
+    /// entries of _system_ directory lists are dropped.
+    Ignore,
+    /// Resolves the relative path against the user's home directory.
+    ResolveAgainstHome,
+}
+
+/// Type of the directory entries matched by the `search_*_entry` methods.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{EntryType, Xdg, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// # #[cfg(feature = "fs")] {
+/// let xdg = Xdg::new()?;
+/// // Looks for a `themes` directory rather than a regular file.
+/// let themes_dir = xdg.search_data_entry("app_name/themes", EntryType::Dir)?;
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EntryType {
+    /// Regular files only.
+    #[default]
+    File,
+    /// Directories only.
+    Dir,
+    /// Any entry, including sockets, FIFOs and device files.
+    Any,
+}
+
+impl EntryType {
+    /// Returns `true` if `file_type` matches the entry type.
+    #[inline]
+    #[cfg(feature = "fs")]
+    pub(crate) fn matches(self, file_type: &std::fs::FileType) -> bool {
+        match self {
+            EntryType::File => file_type.is_file(),
+            EntryType::Dir => file_type.is_dir(),
+            EntryType::Any => true,
+        }
+    }
+}
+
+/// Policy governing symbolic links found by the `search_*` methods.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{SymlinkPolicy, Xdg, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// # #[cfg(feature = "fs")] {
+/// let xdg = Xdg::new()?.with_symlink_policy(SymlinkPolicy::ErrorOnDangling);
+/// match xdg.search_config_file("app_name/config.toml") {
+///     Ok(Some(config_file)) => { /* ... */ },
+///     Ok(None) => { /* ... */ },
+///     Err(XdgError::DanglingSymlink { path }) => { /* ... */ },
+///     Err(err) => return Err(err),
+/// }
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SymlinkPolicy {
+    /// Follows symbolic links, treating dangling ones as missing files.
+    #[default]
+    Follow,
+    /// Never matches symbolic links, even if pointing to regular files.
+    DontFollow,
+    /// Follows symbolic links, failing with
+    /// [`XdgError::DanglingSymlink`](crate::XdgError::DanglingSymlink) on
+    /// dangling ones.
+    ErrorOnDangling,
+}
+
+/// Order in which the _user-specific_ and _system-wide_ XDG directories are
+/// searched by the `search_*` methods returning the first match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{SearchOrder, Xdg, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// # #[cfg(feature = "fs")] {
+/// // Policy files installed by the administrator in `/etc/xdg` win over the
+/// // user's overrides.
+/// let xdg = Xdg::new()?.with_search_order(SearchOrder::SystemFirst);
+/// let policy_file = xdg.search_config_file("app_name/policy.toml")?;
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SearchOrder {
+    /// Searches the _user-specific_ XDG directory first, as mandated by the
+    /// specification.
+    #[default]
+    UserFirst,
+    /// Searches the _system-wide_ XDG directories first, falling back to the
+    /// _user-specific_ one.
+    SystemFirst,
+}
+
+/// Scope of the _user-specific_ XDG base directories, selecting the fallback
+/// directories used when the XDG environment variables are not set.
+///
+/// | Directory  | [`Scope::User`]      | [`Scope::System`]  |
+/// | ---------- | -------------------- | ------------------ |
+/// | **cache**  | `$HOME/.cache`       | `/var/cache`       |
+/// | **config** | `$HOME/.config`      | `/etc`             |
+/// | **data**   | `$HOME/.local/share` | `/usr/local/share` |
+/// | **state**  | `$HOME/.local/state` | `/var/lib`         |
+/// | **bin**    | `$HOME/.local/bin`   | `/usr/local/bin`   |
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::path::Path;
+/// # use microxdg::{Scope, XdgApp, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// std::env::remove_var("XDG_STATE_HOME");
+///
+/// let xdg = XdgApp::new("app_name")?.with_scope(Scope::System);
+/// assert_eq!(Path::new("/var/lib/app_name"), xdg.app_state()?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scope {
+    /// Directories of a user session, falling back to the home directory.
+    #[default]
+    User,
+    /// Directories of a system service (e.g. a daemon running as `root` or as
+    /// a dedicated service user), falling back to `/var/lib`, `/var/cache`
+    /// and `/etc`, as expected by systemd services, and to `/usr/local/share`
+    /// for data.
+    System,
+}
+
+/// Policy governing how the home directory is resolved when the process runs
+/// under `sudo`.
+///
+/// `sudo` commonly resets `HOME` to the target user's home directory (i.e.
+/// `/root`), while privileged helpers usually act on behalf of the invoking
+/// user, whose name `sudo` sets to the `SUDO_USER` environment variable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::path::Path;
+/// # use microxdg::{SudoPolicy, Xdg, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// std::env::set_var("HOME", "/root");
+/// std::env::set_var("SUDO_USER", "user");
+///
+/// let xdg = Xdg::new_with_sudo_policy(SudoPolicy::InvokingUser)?;
+/// assert_eq!(Path::new("/home/user"), xdg.home());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SudoPolicy {
+    /// Ignores `SUDO_USER`, resolving the home directory of the user owning
+    /// the process.
+    #[default]
+    Ignore,
+    /// Resolves the home directory of the user invoking `sudo`, if
+    /// `SUDO_USER` is set to a user other than `root`.
+    InvokingUser,
+}
+
+/// Shell syntax of the environment variable assignments returned by
+/// [`Xdg::to_env_exports`](crate::Xdg::to_env_exports).
+///
+/// # Examples
+///
+/// ```rust
+/// # use microxdg::{Shell, Xdg, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// std::env::remove_var("XDG_RUNTIME_DIR");
+/// std::env::remove_var("XDG_CONFIG_DIRS");
+/// std::env::remove_var("XDG_DATA_DIRS");
+/// std::env::remove_var("XDG_CACHE_HOME");
+/// std::env::remove_var("XDG_CONFIG_HOME");
+/// std::env::remove_var("XDG_DATA_HOME");
+/// std::env::remove_var("XDG_STATE_HOME");
+/// std::env::remove_var("XDG_BIN_HOME");
+///
+/// let exports = Xdg::with_home("/home/user").to_env_exports(Shell::Fish)?;
+/// assert!(exports.starts_with("set -gx XDG_CACHE_HOME '/home/user/.cache'\n"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shell {
+    /// POSIX shells (e.g. `sh`, `bash`, `zsh`), through `export` statements.
+    #[default]
+    Posix,
+    /// The `fish` shell, through `set -gx` commands, exporting global variables
+    /// even when evaluated inside a function.
+    Fish,
+}
+
+impl Shell {
+    /// Appends to `exports` the line assigning `value` to the `key`
+    /// environment variable and exporting it, quoting `value` so that it is
+    /// never expanded by the shell.
+    pub(crate) fn push_export(self, exports: &mut String, key: &str, value: &str) {
+        match self {
+            Shell::Posix => {
+                exports.push_str("export ");
+                exports.push_str(key);
+                exports.push_str("='");
+                exports.push_str(&value.replace('\'', r"'\''"));
+            },
+            Shell::Fish => {
+                exports.push_str("set -gx ");
+                exports.push_str(key);
+                exports.push_str(" '");
+                exports.push_str(&value.replace('\\', r"\\").replace('\'', r"\'"));
+            },
+        }
+        exports.push_str("'\n");
+    }
+}
@@ -0,0 +1,67 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::sys;
+
+/// Path of the local user database.
+const PASSWD: &str = "/etc/passwd";
+
+/// Returns the home directory of the user named `user` or, if not given, of
+/// the user matching `uid` out of the `/etc/passwd` file `content`.
+fn parse_home(content: &str, user: Option<&str>, uid: Option<u32>) -> Option<PathBuf> {
+    content.lines().find_map(|line| {
+        // name:password:uid:gid:gecos:home:shell
+        let fields: Vec<&str> = line.split(':').collect();
+        let [name, _, entry_uid, _, _, home, ..] = fields.as_slice() else {
+            return None;
+        };
+
+        let matches = match user {
+            Some(user) => *name == user,
+            None => uid.is_some() && entry_uid.parse().ok() == uid,
+        };
+        (matches && !home.is_empty()).then(|| PathBuf::from(home))
+    })
+}
+
+/// Resolves the home directory of the user owning the process out of the
+/// system user database, falling back to parsing the local `/etc/passwd`
+/// file.
+///
+/// The user is identified by `user`, i.e. the `USER` environment variable,
+/// or, if not set, by the real user ID of the process.
+pub(crate) fn home_dir(user: Option<&str>) -> Option<PathBuf> {
+    sys::user_home(user).or_else(|| {
+        let content = fs::read_to_string(PASSWD).ok()?;
+        let uid = user.is_none().then(sys::uid);
+        parse_home(&content, user, uid)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn passwd_home() {
+        let content = "\
+root:x:0:0:root:/root:/bin/bash
+daemon:x:1:1::/usr/sbin:/usr/sbin/nologin
+malformed line
+nohome:x:999:999:::/bin/false
+user:x:1000:1000:User:/var/home/user:/bin/zsh
+";
+        assert_eq!(Some(PathBuf::from("/root")), parse_home(content, None, Some(0)));
+        assert_eq!(Some(PathBuf::from("/var/home/user")), parse_home(content, Some("user"), None));
+        assert_eq!(Some(PathBuf::from("/usr/sbin")), parse_home(content, Some("daemon"), Some(0)));
+        assert_eq!(None, parse_home(content, Some("missing"), Some(0)));
+        assert_eq!(None, parse_home(content, None, Some(999)));
+        assert_eq!(None, parse_home(content, None, None));
+    }
+    #[test]
+    fn user_database_home() {
+        assert_eq!(Some(PathBuf::from("/root")), home_dir(Some("root")));
+        assert_eq!(None, home_dir(Some("microxdg-missing-user")));
+        assert_eq!(None, home_dir(Some("nul\0user")));
+    }
+}
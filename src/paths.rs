@@ -0,0 +1,167 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Xdg, XdgError, XdgSysDirs};
+
+/// Snapshot of every XDG base directory, resolved eagerly.
+///
+/// Unlike the [`Xdg`] methods, which read the environment on each call, an
+/// [`XdgPaths`] instance is immune to later changes of the environment: this
+/// makes it suitable for long-running processes that need to read the
+/// environment exactly once at startup.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::path::Path;
+/// # use microxdg::{Xdg, XdgError};
+/// # fn main() -> Result<(), XdgError> {
+/// std::env::set_var("XDG_CONFIG_HOME", "/home/user/.config");
+///
+/// let paths = Xdg::new()?.paths()?;
+/// std::env::set_var("XDG_CONFIG_HOME", "/home/user/.other");
+///
+/// assert_eq!(Path::new("/home/user/.config"), paths.config);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XdgPaths {
+    /// Home directory of the user owning the process.
+    pub home: PathBuf,
+    /// _User-specific_ XDG **cache** directory.
+    pub cache: PathBuf,
+    /// _User-specific_ XDG **configuration** directory.
+    pub config: PathBuf,
+    /// _User-specific_ XDG **data** directory.
+    pub data: PathBuf,
+    /// _User-specific_ XDG **state** directory.
+    pub state: PathBuf,
+    /// _User-specific_ XDG **executable** directory.
+    pub bin: PathBuf,
+    /// _User-specific_ XDG **runtime** directory, if set.
+    pub runtime: Option<PathBuf>,
+    /// _System-wide_, preference-ordered, XDG **configuration** directories.
+    pub sys_config: Vec<PathBuf>,
+    /// _System-wide_, preference-ordered, XDG **data** directories.
+    pub sys_data: Vec<PathBuf>,
+}
+
+impl XdgPaths {
+    /// Resolves every XDG base directory for the given [`Xdg`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any of the XDG environment variables
+    /// is set to a relative path or to invalid unicode.
+    pub(crate) fn resolve(xdg: &Xdg) -> Result<XdgPaths, XdgError> {
+        Ok(XdgPaths {
+            home: xdg.home().to_path_buf(),
+            cache: xdg.cache()?,
+            config: xdg.config()?,
+            data: xdg.data()?,
+            state: xdg.state()?,
+            bin: xdg.bin()?,
+            runtime: xdg.runtime()?,
+            sys_config: xdg.resolve_sys_dir_paths(XdgSysDirs::Config)?,
+            sys_data: xdg.resolve_sys_dir_paths(XdgSysDirs::Data)?,
+        })
+    }
+}
+
+/// _User-specific_ XDG subdirectories of an application, as created by
+/// [`XdgApp::ensure_app_dirs`](crate::XdgApp::ensure_app_dirs).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XdgAppDirs {
+    /// _User-specific_ XDG **cache** app subdirectory.
+    pub cache: PathBuf,
+    /// _User-specific_ XDG **configuration** app subdirectory.
+    pub config: PathBuf,
+    /// _User-specific_ XDG **data** app subdirectory.
+    pub data: PathBuf,
+    /// _User-specific_ XDG **state** app subdirectory.
+    pub state: PathBuf,
+    /// _User-specific_ XDG **runtime** app subdirectory, if
+    /// `XDG_RUNTIME_DIR` is set.
+    pub runtime: Option<PathBuf>,
+}
+
+impl XdgAppDirs {
+    /// Returns an iterator over the paths of the app subdirectories.
+    pub fn iter(&self) -> impl Iterator<Item = &Path> {
+        [&self.cache, &self.config, &self.data, &self.state]
+            .into_iter()
+            .map(PathBuf::as_path)
+            .chain(self.runtime.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    #[inline]
+    fn remove_xdg_vars() {
+        env::remove_var("USER");
+        env::remove_var("LOGNAME");
+        env::remove_var("HOME");
+
+        // User XDG environment variables
+        env::remove_var("XDG_CACHE_HOME");
+        env::remove_var("XDG_CONFIG_HOME");
+        env::remove_var("XDG_DATA_HOME");
+        env::remove_var("XDG_STATE_HOME");
+        env::remove_var("XDG_BIN_HOME");
+        env::remove_var("XDG_RUNTIME_DIR");
+
+        // System XDG environment variables
+        env::remove_var("XDG_CONFIG_DIRS");
+        env::remove_var("XDG_DATA_DIRS");
+    }
+
+    #[test]
+    fn snapshot() -> Result<(), XdgError> {
+        remove_xdg_vars();
+
+        env::set_var("HOME", "/home/user");
+        env::set_var("XDG_CONFIG_HOME", "/home/user/.myconfig");
+        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
+
+        let paths = Xdg::new()?.paths()?;
+
+        env::set_var("XDG_CONFIG_HOME", "/home/user/.other");
+        env::remove_var("XDG_RUNTIME_DIR");
+
+        assert_eq!(
+            XdgPaths {
+                home: PathBuf::from("/home/user"),
+                cache: PathBuf::from("/home/user/.cache"),
+                config: PathBuf::from("/home/user/.myconfig"),
+                data: PathBuf::from("/home/user/.local/share"),
+                state: PathBuf::from("/home/user/.local/state"),
+                bin: PathBuf::from("/home/user/.local/bin"),
+                runtime: Some(PathBuf::from("/run/user/1000")),
+                sys_config: vec![PathBuf::from("/etc/xdg")],
+                sys_data: vec![
+                    PathBuf::from("/usr/local/share"),
+                    PathBuf::from("/usr/share"),
+                ],
+            },
+            paths,
+        );
+
+        env::set_var("XDG_DATA_DIRS", "relative/path");
+        assert_eq!(
+            Err(XdgError::RelativePath {
+                env_var_key: "XDG_DATA_DIRS",
+                path: PathBuf::from("relative/path"),
+            }),
+            Xdg::new()?.paths(),
+        );
+
+        Ok(())
+    }
+}
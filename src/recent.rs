@@ -0,0 +1,213 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::{trash, Xdg, XdgError};
+
+/// Content of an empty `recently-used.xbel` file.
+const EMPTY_XBEL: &str = "\
+<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<xbel version=\"1.0\"
+      xmlns:bookmark=\"http://www.freedesktop.org/standards/desktop-bookmarks\"
+      xmlns:mime=\"http://www.freedesktop.org/standards/shared-mime-info\"
+>
+</xbel>
+";
+
+/// Entry of the desktop's recently used files list, as stored in
+/// `$XDG_DATA_HOME/recently-used.xbel` following the
+/// [_Desktop Bookmark Specification_](<https://www.freedesktop.org/wiki/Specifications/desktop-bookmark-spec/>).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentFile {
+    /// URI of the file (e.g. `file:///home/user/notes.txt`).
+    pub uri: String,
+    /// MIME type of the file, if recorded.
+    pub mime_type: Option<String>,
+    /// Date the entry was added, as an ISO 8601 UTC timestamp.
+    pub added: Option<String>,
+    /// Date the entry was last modified, as an ISO 8601 UTC timestamp.
+    pub modified: Option<String>,
+    /// Date the file was last visited, as an ISO 8601 UTC timestamp.
+    pub visited: Option<String>,
+}
+
+/// Escapes `value` for use inside a double-quoted XML attribute.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Unescapes the predefined XML entities inside `value`.
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Returns the unescaped value of the attribute `name` inside the `tag`
+/// content (i.e. the text between `<` and `>`), if any.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!(" {name}=\""))? + name.len() + 3;
+    let end = tag[start..].find('"')? + start;
+    Some(unescape(&tag[start..end]))
+}
+
+/// Parses the content of a `recently-used.xbel` file.
+///
+/// Parsing is lenient: bookmarks without an `href` attribute are skipped.
+pub(crate) fn parse(content: &str) -> Vec<RecentFile> {
+    let mut recent_files = Vec::new();
+    for bookmark in content.split("<bookmark ").skip(1) {
+        let Some(tag_end) = bookmark.find('>') else {
+            continue;
+        };
+        let tag = format!(" {}", &bookmark[..tag_end]);
+        let Some(uri) = attribute(&tag, "href") else {
+            continue;
+        };
+
+        let body = &bookmark[tag_end..bookmark.find("</bookmark>").unwrap_or(bookmark.len())];
+        let mime_type = body.split_once("<mime:mime-type ").and_then(|(_, mime_tag)| {
+            attribute(&format!(" {}", &mime_tag[..mime_tag.find('>')?]), "type")
+        });
+
+        recent_files.push(RecentFile {
+            uri,
+            mime_type,
+            added: attribute(&tag, "added"),
+            modified: attribute(&tag, "modified"),
+            visited: attribute(&tag, "visited"),
+        });
+    }
+
+    recent_files
+}
+
+/// Reads the recently used files list at `path`, treating a missing file as
+/// an empty list.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if the file exists but
+/// could not be read.
+pub(crate) fn list(path: &Path) -> Result<Vec<RecentFile>, XdgError> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(parse(&content)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(source) => Err(XdgError::Io { path: path.into(), source }),
+    }
+}
+
+/// Adds `uri` to the recently used files list at `path`, registering `app`
+/// as the application that used it, and returns whether it was added.
+///
+/// # Note
+///
+/// URIs already in the list are left untouched.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if the file could not be
+/// read or written.
+pub(crate) fn add(path: &Path, uri: &str, mime_type: &str, app: &str) -> Result<bool, XdgError> {
+    let io_err = |source| XdgError::Io { path: path.into(), source };
+
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::from(EMPTY_XBEL),
+        Err(source) => return Err(io_err(source)),
+    };
+    if parse(&content).iter().any(|recent_file| recent_file.uri == uri) {
+        return Ok(false);
+    }
+
+    let Some(end) = content.rfind("</xbel>") else {
+        return Err(io_err(io::Error::new(io::ErrorKind::InvalidData, "invalid XBEL file")));
+    };
+
+    let now = format!("{}Z", trash::format_date(SystemTime::now()));
+    let (uri, mime_type, app) = (escape(uri), escape(mime_type), escape(app));
+    let bookmark = format!(
+        "  <bookmark href=\"{uri}\" added=\"{now}\" modified=\"{now}\" visited=\"{now}\">
+    <info>
+      <metadata owner=\"http://freedesktop.org\">
+        <mime:mime-type type=\"{mime_type}\"/>
+        <bookmark:applications>
+          <bookmark:application name=\"{app}\" exec=\"&apos;{app} %u&apos;\" \
+modified=\"{now}\" count=\"1\"/>
+        </bookmark:applications>
+      </metadata>
+    </info>
+  </bookmark>
+"
+    );
+
+    let mut file = Xdg::create_file(path.to_path_buf())?;
+    file.write_all(format!("{}{bookmark}{}", &content[..end], &content[end..]).as_bytes())
+        .map_err(io_err)?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_xbel() {
+        let content = "\
+<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<xbel version=\"1.0\">
+  <bookmark href=\"file:///home/user/a%20b.txt\" added=\"2024-01-01T10:00:00Z\" \
+modified=\"2024-01-02T10:00:00Z\" visited=\"2024-01-03T10:00:00Z\">
+    <info>
+      <metadata owner=\"http://freedesktop.org\">
+        <mime:mime-type type=\"text/plain\"/>
+      </metadata>
+    </info>
+  </bookmark>
+  <bookmark href=\"file:///home/user/Tom&amp;Jerry.mkv\">
+  </bookmark>
+  <bookmark added=\"2024-01-01T10:00:00Z\">
+  </bookmark>
+</xbel>
+";
+        assert_eq!(
+            vec![
+                RecentFile {
+                    uri: String::from("file:///home/user/a%20b.txt"),
+                    mime_type: Some(String::from("text/plain")),
+                    added: Some(String::from("2024-01-01T10:00:00Z")),
+                    modified: Some(String::from("2024-01-02T10:00:00Z")),
+                    visited: Some(String::from("2024-01-03T10:00:00Z")),
+                },
+                RecentFile {
+                    uri: String::from("file:///home/user/Tom&Jerry.mkv"),
+                    mime_type: None,
+                    added: None,
+                    modified: None,
+                    visited: None,
+                },
+            ],
+            parse(content),
+        );
+        assert!(parse(EMPTY_XBEL).is_empty());
+        assert_eq!("&lt;a&gt; &amp; &quot;b&quot;", escape("<a> & \"b\""));
+        assert_eq!("<a> & \"b\"", unescape(&escape("<a> & \"b\"")));
+    }
+}
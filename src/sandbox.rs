@@ -0,0 +1,125 @@
+#[cfg(feature = "fs")]
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::session::var;
+
+/// Metadata file Flatpak places at the root of the sandbox filesystem.
+#[cfg(feature = "fs")]
+const FLATPAK_INFO: &str = "/.flatpak-info";
+
+/// Application sandboxing technology.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SandboxKind {
+    /// [Flatpak](<https://flatpak.org/>) sandbox.
+    Flatpak,
+    /// [Snap](<https://snapcraft.io/>) sandbox.
+    Snap,
+}
+
+/// Application sandbox the current process runs in, as detected by
+/// [`Xdg::sandbox`](crate::Xdg::sandbox).
+///
+/// Sandboxes redirect the XDG base directories to per-application
+/// directories, here referred to as the sandbox _roots_:
+/// - Flatpak sets `XDG_CONFIG_HOME`, `XDG_DATA_HOME`, `XDG_CACHE_HOME` and
+///   `XDG_STATE_HOME` to subdirectories of `$HOME/.var/app/<app_id>`;
+/// - Snap sets `HOME` to `$SNAP_USER_DATA` (i.e. `~/snap/<name>/<revision>`),
+///   alongside the revision independent `$SNAP_USER_COMMON` directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sandbox {
+    kind: SandboxKind,
+    app_id: Option<String>,
+    roots: Vec<PathBuf>,
+}
+
+impl Sandbox {
+    /// Returns the sandboxing technology.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> SandboxKind {
+        self.kind
+    }
+
+    /// Returns the identifier of the sandboxed application (the Flatpak
+    /// application ID or the snap name), if known.
+    #[inline]
+    #[must_use]
+    pub fn app_id(&self) -> Option<&str> {
+        self.app_id.as_deref()
+    }
+
+    /// Returns the directories the sandbox redirects the XDG base
+    /// directories into.
+    #[inline]
+    #[must_use]
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Returns `true` if `path` points inside one of the sandbox roots, i.e.
+    /// it would be found at a different location outside of the sandbox.
+    #[must_use]
+    pub fn contains<P>(&self, path: P) -> bool
+    where
+        P: AsRef<Path>,
+    {
+        self.roots.iter().any(|root| path.as_ref().starts_with(root))
+    }
+}
+
+/// Returns the Flatpak application ID out of the `/.flatpak-info` metadata
+/// file content, i.e. the `name` key of the `[Application]` group.
+fn flatpak_info_app_id(content: &str) -> Option<String> {
+    let mut in_group = false;
+    for line in content.lines().map(str::trim) {
+        if let Some(group) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            in_group = group == "Application";
+        } else if let (true, Some(("name", value))) = (in_group, line.split_once('=')) {
+            return Some(value.trim().to_owned());
+        }
+    }
+
+    None
+}
+
+/// Detects the sandbox the current process runs in, resolving Flatpak roots
+/// against `home`.
+pub(crate) fn detect(home: &Path) -> Option<Sandbox> {
+    #[cfg(feature = "fs")]
+    let flatpak_info = fs::read_to_string(FLATPAK_INFO).ok();
+    // Without filesystem access, Flatpak is detected through `FLATPAK_ID` only.
+    #[cfg(not(feature = "fs"))]
+    let flatpak_info: Option<String> = None;
+    if flatpak_info.is_some() || var("FLATPAK_ID").is_some() {
+        let app_id = var("FLATPAK_ID").or_else(|| flatpak_info_app_id(flatpak_info.as_deref()?));
+        let roots = app_id.iter().map(|app_id| home.join(".var/app").join(app_id)).collect();
+        return Some(Sandbox { kind: SandboxKind::Flatpak, app_id, roots });
+    }
+
+    if var("SNAP").is_some() {
+        let roots = ["SNAP_USER_DATA", "SNAP_USER_COMMON"]
+            .into_iter()
+            .filter_map(var)
+            .map(PathBuf::from)
+            .collect();
+        return Some(Sandbox { kind: SandboxKind::Snap, app_id: var("SNAP_NAME"), roots });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn flatpak_app_id() {
+        assert_eq!(
+            Some(String::from("org.example.App")),
+            flatpak_info_app_id("[Application]\nname=org.example.App\nruntime=runtime/x\n"),
+        );
+        assert_eq!(None, flatpak_info_app_id("[Instance]\nname=org.example.App\n"));
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use crate::{EntryType, XdgDir};
+
+/// Key of a cached search: the searched XDG directory, entry type and file.
+type SearchKey = (XdgDir, EntryType, PathBuf);
+
+/// Paths where files were last found, for each live [`SearchCache`].
+///
+/// The paths are kept outside of the cache itself, so that [`XdgApp`]
+/// instances hold no interior mutability and can be used as keys of hashed
+/// collections.
+///
+/// [`XdgApp`]: crate::XdgApp
+static FOUND: Mutex<Option<HashMap<u64, HashMap<SearchKey, PathBuf>>>> = Mutex::new(None);
+
+/// Identifier of the next [`SearchCache`].
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Cache of the paths where the `search_app_*` methods last found a file,
+/// enabled through
+/// [`XdgApp::with_search_cache`](crate::XdgApp::with_search_cache).
+///
+/// A cached path is probed again on each lookup, so that a file removed since
+/// is searched for through the whole precedence walk. Clones of the cache are
+/// always empty, since the cloned instance may be configured differently,
+/// while the cached paths are forgotten as soon as the cache is dropped.
+pub(crate) struct SearchCache {
+    id: u64,
+}
+
+impl SearchCache {
+    /// Runs `f` on the cached paths.
+    fn with_found<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce(&mut HashMap<SearchKey, PathBuf>) -> T,
+    {
+        let mut found = FOUND.lock().unwrap_or_else(PoisonError::into_inner);
+        f(found.get_or_insert_with(HashMap::new).entry(self.id).or_default())
+    }
+
+    /// Returns the path where `file` was last found inside the `dir` XDG
+    /// directories, if any.
+    #[cfg(feature = "fs")]
+    pub(crate) fn get(&self, dir: XdgDir, entry_type: EntryType, file: &Path) -> Option<PathBuf> {
+        self.with_found(|found| found.get(&(dir, entry_type, file.to_path_buf())).cloned())
+    }
+
+    /// Records the `path` where `file` was found inside the `dir` XDG
+    /// directories, or forgets it if `file` was not found.
+    #[cfg(feature = "fs")]
+    pub(crate) fn set(&self, dir: XdgDir, entry_type: EntryType, file: &Path, path: Option<&Path>) {
+        let key = (dir, entry_type, file.to_path_buf());
+        self.with_found(|found| match path {
+            Some(path) => {
+                found.insert(key, path.to_path_buf());
+            },
+            None => {
+                found.remove(&key);
+            },
+        });
+    }
+
+    /// Forgets every cached path.
+    #[inline]
+    pub(crate) fn clear(&self) {
+        self.with_found(HashMap::clear);
+    }
+}
+
+impl Default for SearchCache {
+    #[inline]
+    fn default() -> SearchCache {
+        SearchCache { id: NEXT_ID.fetch_add(1, Ordering::Relaxed) }
+    }
+}
+
+impl Drop for SearchCache {
+    fn drop(&mut self) {
+        let mut found = FOUND.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(found) = found.as_mut() {
+            found.remove(&self.id);
+        }
+    }
+}
+
+impl Clone for SearchCache {
+    #[inline]
+    fn clone(&self) -> SearchCache {
+        SearchCache::default()
+    }
+}
+
+impl fmt::Debug for SearchCache {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_struct("SearchCache").finish_non_exhaustive()
+    }
+}
+
+// The cached paths are derived from the filesystem: two caches are always
+// considered equal, so that caching does not affect the identity of an
+// `XdgApp` instance.
+impl PartialEq for SearchCache {
+    #[inline]
+    fn eq(&self, _other: &SearchCache) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchCache {}
+
+impl Hash for SearchCache {
+    #[inline]
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
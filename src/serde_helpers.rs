@@ -0,0 +1,101 @@
+use std::io;
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+/// Environment variables whose key an [`XdgError`](crate::XdgError) can
+/// carry.
+const ENV_VAR_KEYS: [&str; 10] = [
+    "HOME",
+    "USER",
+    "XDG_BIN_HOME",
+    "XDG_CACHE_HOME",
+    "XDG_CONFIG_DIRS",
+    "XDG_CONFIG_HOME",
+    "XDG_DATA_DIRS",
+    "XDG_DATA_HOME",
+    "XDG_RUNTIME_DIR",
+    "XDG_STATE_HOME",
+];
+
+/// Maps an environment variable `key` back onto the known `&'static str` key,
+/// rejecting unknown ones.
+pub(crate) fn env_var_key<E>(key: &str) -> Result<&'static str, E>
+where
+    E: de::Error,
+{
+    match ENV_VAR_KEYS.iter().find(|&&known| known == key) {
+        Some(&known) => Ok(known),
+        None => {
+            Err(E::invalid_value(de::Unexpected::Str(key), &"an XDG environment variable key"))
+        },
+    }
+}
+
+/// Sources of the host name an [`XdgError`](crate::XdgError) can carry.
+const HOST_NAME_ORIGINS: [&str; 2] = ["COMPUTERNAME", "gethostname"];
+
+/// Maps a host name `origin` back onto the known `&'static str` origin,
+/// rejecting unknown ones.
+pub(crate) fn host_name_origin<E>(origin: &str) -> Result<&'static str, E>
+where
+    E: de::Error,
+{
+    match HOST_NAME_ORIGINS.iter().find(|&&known| known == origin) {
+        Some(&known) => Ok(known),
+        None => Err(E::invalid_value(de::Unexpected::Str(origin), &"a host name source")),
+    }
+}
+
+/// Serializes an [`io::Error`] as its message.
+pub(crate) fn serialize_io_error<S>(err: &io::Error, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(err)
+}
+
+/// Deserializes an [`io::Error`] of kind [`io::ErrorKind::Other`] from its
+/// message.
+pub(crate) fn deserialize_io_error<'de, D>(deserializer: D) -> Result<io::Error, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer).map(|message| io::Error::new(io::ErrorKind::Other, message))
+}
+
+#[cfg(test)]
+mod test {
+    use serde::de::value::{Error, StrDeserializer};
+    use serde::de::{DeserializeOwned, IntoDeserializer};
+    use serde::Serialize;
+
+    use super::*;
+    use crate::{RelativePathPolicy, Xdg, XdgApp, XdgAppDirs, XdgError, XdgPaths};
+
+    fn assert_serde<T: Serialize + DeserializeOwned>() {}
+
+    #[test]
+    fn serde_impls() {
+        assert_serde::<Xdg>();
+        assert_serde::<XdgApp>();
+        assert_serde::<XdgPaths>();
+        assert_serde::<XdgAppDirs>();
+        assert_serde::<XdgError>();
+        assert_serde::<RelativePathPolicy>();
+    }
+
+    #[test]
+    fn deserialize_helpers() -> Result<(), Error> {
+        let deserializer: StrDeserializer<Error> = "permission denied".into_deserializer();
+        let err = deserialize_io_error(deserializer)?;
+        assert_eq!(io::ErrorKind::Other, err.kind());
+        assert_eq!("permission denied", err.to_string());
+
+        assert_eq!("XDG_CONFIG_HOME", env_var_key::<Error>("XDG_CONFIG_HOME")?);
+        assert!(env_var_key::<Error>("PATH").is_err());
+        assert_eq!("gethostname", host_name_origin::<Error>("gethostname")?);
+        assert!(host_name_origin::<Error>("/etc/hostname").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::env;
+
+/// Type of the current login session, as set to the `XDG_SESSION_TYPE`
+/// environment variable by `systemd-logind`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SessionType {
+    /// Wayland session (`wayland`).
+    Wayland,
+    /// X11 session (`x11`).
+    X11,
+    /// Text console session (`tty`).
+    Tty,
+    /// Mir session (`mir`).
+    Mir,
+    /// Session of unspecified type, e.g. an SSH login (`unspecified`).
+    Unspecified,
+    /// Any other session type, holding its value as is.
+    Other(String),
+}
+
+impl SessionType {
+    /// Parses a session type.
+    #[must_use]
+    pub fn parse(value: &str) -> SessionType {
+        match value {
+            "wayland" => SessionType::Wayland,
+            "x11" => SessionType::X11,
+            "tty" => SessionType::Tty,
+            "mir" => SessionType::Mir,
+            "unspecified" => SessionType::Unspecified,
+            _ => SessionType::Other(value.to_owned()),
+        }
+    }
+}
+
+/// Class of the current login session, as set to the `XDG_SESSION_CLASS`
+/// environment variable by `systemd-logind`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SessionClass {
+    /// Regular user session (`user`).
+    User,
+    /// Display manager greeter session (`greeter`).
+    Greeter,
+    /// Lock screen session (`lock-screen`).
+    LockScreen,
+    /// Background session, without a seat (`background`).
+    Background,
+    /// Any other session class, holding its value as is.
+    Other(String),
+}
+
+impl SessionClass {
+    /// Parses a session class.
+    #[must_use]
+    pub fn parse(value: &str) -> SessionClass {
+        match value {
+            "user" => SessionClass::User,
+            "greeter" => SessionClass::Greeter,
+            "lock-screen" => SessionClass::LockScreen,
+            "background" => SessionClass::Background,
+            _ => SessionClass::Other(value.to_owned()),
+        }
+    }
+}
+
+/// Returns the value of the environment variable `key`, if set to a
+/// non-empty, valid unicode value.
+pub(crate) fn var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_session() {
+        assert_eq!(SessionType::Wayland, SessionType::parse("wayland"));
+        assert_eq!(SessionType::Tty, SessionType::parse("tty"));
+        assert_eq!(SessionType::Other(String::from("Wayland")), SessionType::parse("Wayland"));
+        assert_eq!(SessionClass::LockScreen, SessionClass::parse("lock-screen"));
+        assert_eq!(SessionClass::Other(String::from("manager")), SessionClass::parse("manager"));
+    }
+}
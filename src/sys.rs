@@ -0,0 +1,152 @@
+// Safe wrappers around the system calls lacking a standard library
+// counterpart: this is the only module allowed to use `unsafe` code.
+#![allow(unsafe_code)]
+
+use std::ffi::OsString;
+use std::fs::File;
+use std::io;
+use std::os::unix::ffi::OsStringExt;
+use std::os::unix::io::AsRawFd;
+use std::mem::MaybeUninit;
+#[cfg(feature = "passwd")]
+use std::{
+    ffi::{CStr, CString, OsStr},
+    os::unix::ffi::OsStrExt,
+    path::PathBuf,
+    ptr,
+};
+
+/// Maximum size of the buffer holding a user database entry, in bytes.
+#[cfg(feature = "passwd")]
+const MAX_PASSWD_BUF_LEN: usize = 1 << 20;
+
+/// Returns the effective user ID of the process.
+#[inline]
+pub(crate) fn euid() -> u32 {
+    // SAFETY: `geteuid` takes no arguments, is always successful and has no
+    // side effects.
+    unsafe { libc::geteuid() }
+}
+
+/// Returns the real user ID of the process.
+#[inline]
+#[cfg(feature = "passwd")]
+pub(crate) fn uid() -> u32 {
+    // SAFETY: `getuid` takes no arguments, is always successful and has no
+    // side effects.
+    unsafe { libc::getuid() }
+}
+
+/// Returns the home directory of the user named `user` or, if not given, of
+/// the user owning the process, looked up in the system user database
+/// through the name service switch (e.g. `/etc/passwd`, LDAP or
+/// `systemd-homed`).
+#[cfg(feature = "passwd")]
+pub(crate) fn user_home(user: Option<&str>) -> Option<PathBuf> {
+    let name = match user {
+        Some(user) => Some(CString::new(user).ok()?),
+        None => None,
+    };
+
+    let mut buf: Vec<libc::c_char> = vec![0; 1024];
+    let mut passwd = MaybeUninit::<libc::passwd>::uninit();
+    let mut result = ptr::null_mut();
+    loop {
+        // SAFETY: `passwd`, `buf` and `result` are valid for writes, and
+        // `buf.len()` is the actual length of `buf`.
+        let code = unsafe {
+            match &name {
+                Some(name) => libc::getpwnam_r(
+                    name.as_ptr(),
+                    passwd.as_mut_ptr(),
+                    buf.as_mut_ptr(),
+                    buf.len(),
+                    &mut result,
+                ),
+                None => libc::getpwuid_r(
+                    libc::getuid(),
+                    passwd.as_mut_ptr(),
+                    buf.as_mut_ptr(),
+                    buf.len(),
+                    &mut result,
+                ),
+            }
+        };
+
+        match code {
+            0 => break,
+            libc::EINTR => {},
+            libc::ERANGE if buf.len() < MAX_PASSWD_BUF_LEN => buf.resize(buf.len() * 2, 0),
+            _ => return None,
+        }
+    }
+
+    if result.is_null() {
+        return None;
+    }
+
+    // SAFETY: on success, `result` points to `passwd`, whose strings point
+    // into `buf`, both still alive.
+    let home = unsafe { (*result).pw_dir };
+    if home.is_null() {
+        return None;
+    }
+
+    // SAFETY: `home` is a NUL-terminated string inside `buf`.
+    let home = unsafe { CStr::from_ptr(home) }.to_bytes();
+    (!home.is_empty()).then(|| PathBuf::from(OsStr::from_bytes(home)))
+}
+
+/// Returns the name of the host, as set in the kernel.
+pub(crate) fn hostname() -> io::Result<OsString> {
+    // Large enough for any host name, `HOST_NAME_MAX` being 255 bytes at most
+    // on the supported platforms.
+    let mut buf = vec![0_u8; 256];
+    // SAFETY: `buf` is valid for writes of `buf.len()` bytes.
+    if unsafe { libc::gethostname(buf.as_mut_ptr().cast(), buf.len()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // Truncated host names are not guaranteed to be NUL-terminated.
+    if let Some(len) = buf.iter().position(|&byte| byte == 0) {
+        buf.truncate(len);
+    }
+
+    Ok(OsString::from_vec(buf))
+}
+
+/// Returns the local date and time of `secs` seconds since the Unix epoch, as
+/// `(year, month, day, hours, minutes, seconds)`, according to the time zone
+/// of the system.
+pub(crate) fn local_time(secs: i64) -> Option<(i32, i32, i32, i32, i32, i32)> {
+    let time = libc::time_t::try_from(secs).ok()?;
+    let mut tm = MaybeUninit::<libc::tm>::uninit();
+    // SAFETY: `time` and `tm` are valid for reads and writes, respectively.
+    if unsafe { libc::localtime_r(&time, tm.as_mut_ptr()) }.is_null() {
+        return None;
+    }
+
+    // SAFETY: on success, `localtime_r` initializes `tm`.
+    let tm = unsafe { tm.assume_init() };
+    Some((tm.tm_year + 1900, tm.tm_mon + 1, tm.tm_mday, tm.tm_hour, tm.tm_min, tm.tm_sec))
+}
+
+/// Places an exclusive `flock` on `file`, blocking until released elsewhere
+/// if `block` is `true`, or returning `false` otherwise.
+pub(crate) fn flock(file: &File, block: bool) -> io::Result<bool> {
+    let operation = if block { libc::LOCK_EX } else { libc::LOCK_EX | libc::LOCK_NB };
+    loop {
+        // SAFETY: the file descriptor is owned by `file`, hence open for the
+        // duration of the call.
+        if unsafe { libc::flock(file.as_raw_fd(), operation) } == 0 {
+            return Ok(true);
+        }
+
+        let err = io::Error::last_os_error();
+        match err.raw_os_error() {
+            Some(libc::EINTR) => continue,
+            Some(libc::EWOULDBLOCK) => return Ok(false),
+            _ => return Err(err),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+/// Size classes defined by the [_Thumbnail Managing Standard_](<https://specifications.freedesktop.org/thumbnail-spec/latest/>),
+/// each one stored in a dedicated subdirectory of `$XDG_CACHE_HOME/thumbnails`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThumbnailSize {
+    /// Thumbnails up to 128x128 pixels, stored in `normal`.
+    Normal,
+    /// Thumbnails up to 256x256 pixels, stored in `large`.
+    Large,
+    /// Thumbnails up to 512x512 pixels, stored in `x-large`.
+    XLarge,
+    /// Thumbnails up to 1024x1024 pixels, stored in `xx-large`.
+    XXLarge,
+}
+
+impl ThumbnailSize {
+    /// Every size class, from the smallest to the largest.
+    pub const ALL: [ThumbnailSize; 4] = [
+        ThumbnailSize::Normal,
+        ThumbnailSize::Large,
+        ThumbnailSize::XLarge,
+        ThumbnailSize::XXLarge,
+    ];
+
+    /// Returns the name of the subdirectory holding thumbnails of this size.
+    #[inline]
+    #[must_use]
+    pub const fn dir_name(self) -> &'static str {
+        match self {
+            ThumbnailSize::Normal => "normal",
+            ThumbnailSize::Large => "large",
+            ThumbnailSize::XLarge => "x-large",
+            ThumbnailSize::XXLarge => "xx-large",
+        }
+    }
+
+    /// Returns the maximum width and height, in pixels, of thumbnails of this
+    /// size.
+    #[inline]
+    #[must_use]
+    pub const fn pixels(self) -> u32 {
+        match self {
+            ThumbnailSize::Normal => 128,
+            ThumbnailSize::Large => 256,
+            ThumbnailSize::XLarge => 512,
+            ThumbnailSize::XXLarge => 1024,
+        }
+    }
+}
+
+/// Per-round shift amounts of the MD5 algorithm.
+const MD5_SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// Computes the MD5 digest of `data`, as required by the thumbnail naming
+/// scheme.
+fn md5(data: &[u8]) -> [u8; 16] {
+    // Per-round constants: `floor(abs(sin(i + 1)) * 2^32)`.
+    let constants: [u32; 64] =
+        std::array::from_fn(|i| ((i as f64 + 1.0).sin().abs() * 4_294_967_296.0) as u32);
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+    message.resize(message.len() + (64 + 56 - message.len() % 64) % 64, 0);
+    message.extend_from_slice(&((data.len() as u64).wrapping_mul(8)).to_le_bytes());
+
+    let mut state: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+    for chunk in message.chunks_exact(64) {
+        let words: [u32; 16] = std::array::from_fn(|i| {
+            u32::from_le_bytes([chunk[i * 4], chunk[i * 4 + 1], chunk[i * 4 + 2], chunk[i * 4 + 3]])
+        });
+
+        let [mut a, mut b, mut c, mut d] = state;
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+
+            let rotated = a
+                .wrapping_add(f)
+                .wrapping_add(constants[i])
+                .wrapping_add(words[g])
+                .rotate_left(MD5_SHIFTS[i]);
+            (a, b, c, d) = (d, b.wrapping_add(rotated), b, c);
+        }
+
+        for (word, value) in state.iter_mut().zip([a, b, c, d]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+
+    let mut digest = [0; 16];
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+
+    digest
+}
+
+/// Returns the thumbnail file name for `uri`, i.e. the lowercase hexadecimal
+/// MD5 digest of the URI followed by the `.png` extension.
+pub(crate) fn file_name(uri: &str) -> String {
+    let mut file_name = String::with_capacity(36);
+    for byte in md5(uri.as_bytes()) {
+        file_name.push(char::from_digit(u32::from(byte >> 4), 16).unwrap_or('0'));
+        file_name.push(char::from_digit(u32::from(byte & 0xf), 16).unwrap_or('0'));
+    }
+
+    file_name.push_str(".png");
+    file_name
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn thumbnail_file_name() {
+        assert_eq!("d41d8cd98f00b204e9800998ecf8427e.png", file_name(""));
+        assert_eq!(
+            "9e107d9d372bb6826bd81d3542a419d6.png",
+            file_name("The quick brown fox jumps over the lazy dog"),
+        );
+        // Example from the Thumbnail Managing Standard.
+        assert_eq!(
+            "c6ee772d9e49320e97ec29a7eb5b1697.png",
+            file_name("file:///home/jens/photos/me.png"),
+        );
+        // Inputs spanning multiple blocks.
+        assert_eq!("7707d6ae4e027c70eea2a935c2296f21.png", file_name(&"a".repeat(1_000_000)));
+    }
+}
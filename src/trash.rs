@@ -0,0 +1,355 @@
+use std::env;
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{Xdg, XdgError};
+
+/// Extension of the trash metadata files.
+const INFO_EXT: &str = "trashinfo";
+
+/// Entry of the _home trash_, as defined by the [_FreeDesktop.org Trash specification_](<https://specifications.freedesktop.org/trash-spec/latest/>).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashEntry {
+    /// Name of the trashed file inside the trash `files` directory.
+    pub name: OsString,
+    /// Path the file was trashed from.
+    pub original_path: PathBuf,
+    /// Deletion date, as recorded in the metadata file (`YYYY-MM-DDThh:mm:ss`).
+    pub deletion_date: String,
+    /// Path of the trashed file, inside the trash `files` directory.
+    pub path: PathBuf,
+    /// Path of the metadata file, inside the trash `info` directory.
+    pub info_path: PathBuf,
+}
+
+/// Returns `true` if `byte` may appear unescaped in a trashed path.
+#[inline]
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte)
+}
+
+/// Returns the raw bytes of `path`.
+#[cfg(unix)]
+fn path_to_bytes(path: &Path) -> Vec<u8> {
+    std::os::unix::ffi::OsStrExt::as_bytes(path.as_os_str()).to_vec()
+}
+
+/// Returns the raw bytes of `path`.
+#[cfg(not(unix))]
+fn path_to_bytes(path: &Path) -> Vec<u8> {
+    path.to_string_lossy().into_owned().into_bytes()
+}
+
+/// Builds a path out of its raw bytes.
+#[cfg(unix)]
+fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    PathBuf::from(<OsString as std::os::unix::ffi::OsStringExt>::from_vec(bytes))
+}
+
+/// Builds a path out of its raw bytes.
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    String::from_utf8_lossy(&bytes).into_owned().into()
+}
+
+/// Percent-encodes `path`, as required for the `Path` key of metadata files.
+fn encode_path(path: &Path) -> String {
+    let mut encoded = String::new();
+    for byte in path_to_bytes(path) {
+        if is_unreserved(byte) {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+/// Decodes a percent-encoded path, returning `None` if malformed.
+fn decode_path(encoded: &str) -> Option<PathBuf> {
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut encoded_bytes = encoded.bytes();
+    while let Some(byte) = encoded_bytes.next() {
+        if byte != b'%' {
+            bytes.push(byte);
+            continue;
+        }
+
+        let high = char::from(encoded_bytes.next()?).to_digit(16)?;
+        let low = char::from(encoded_bytes.next()?).to_digit(16)?;
+        bytes.push((high * 16 + low) as u8);
+    }
+
+    Some(path_from_bytes(bytes))
+}
+
+/// Formats `time` as `YYYY-MM-DDThh:mm:ss`, in UTC.
+pub(crate) fn format_date(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since the Unix epoch (proleptic Gregorian calendar).
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60
+    )
+}
+
+/// Formats `time` as `YYYY-MM-DDThh:mm:ss`, in the local time zone as required
+/// by the trash specification for the deletion date.
+///
+/// # Note
+///
+/// The local time zone is only available on Unix: on other platforms, `time`
+/// is formatted in UTC instead.
+fn format_local_date(time: SystemTime) -> String {
+    #[cfg(unix)]
+    if let Some((year, month, day, hours, minutes, seconds)) = time
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|duration| i64::try_from(duration.as_secs()).ok())
+        .and_then(crate::sys::local_time)
+    {
+        return format!("{year:04}-{month:02}-{day:02}T{hours:02}:{minutes:02}:{seconds:02}");
+    }
+
+    format_date(time)
+}
+
+/// Parses the content of a metadata file, returning the original path and
+/// the deletion date.
+fn parse_info(content: &str) -> Option<(PathBuf, String)> {
+    let mut lines = content.lines().map(str::trim).filter(|line| !line.is_empty());
+    if lines.next()? != "[Trash Info]" {
+        return None;
+    }
+
+    let (mut original_path, mut deletion_date) = (None, None);
+    for line in lines.take_while(|line| !line.starts_with('[')) {
+        match line.split_once('=') {
+            Some(("Path", value)) => original_path = decode_path(value),
+            Some(("DeletionDate", value)) => deletion_date = Some(value.to_owned()),
+            _ => {},
+        }
+    }
+
+    Some((original_path?, deletion_date.unwrap_or_default()))
+}
+
+/// Returns the candidate trashed file name for `file_name`, disambiguated by
+/// `attempt` if greater than zero (e.g. `file.2.txt`).
+fn candidate_name(file_name: &Path, attempt: usize) -> OsString {
+    if attempt == 0 {
+        return file_name.as_os_str().to_os_string();
+    }
+
+    let mut name = file_name.file_stem().unwrap_or(file_name.as_os_str()).to_os_string();
+    name.push(format!(".{attempt}"));
+    if let Some(ext) = file_name.extension() {
+        name.push(".");
+        name.push(ext);
+    }
+
+    name
+}
+
+/// Returns the path of the metadata file of the trashed file `name`.
+#[inline]
+fn info_path(info_dir: &Path, name: &OsString) -> PathBuf {
+    let mut info_name = name.clone();
+    info_name.push(".");
+    info_name.push(INFO_EXT);
+    info_dir.join(info_name)
+}
+
+/// Moves `path` into the `trash` directory, writing its metadata file.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if `path` does not exist,
+/// the trash directories or the metadata file could not be created, or
+/// `path` could not be moved (e.g. because it lives on another filesystem).
+pub(crate) fn put(trash: &Path, path: &Path) -> Result<TrashEntry, XdgError> {
+    let io_err = |path: &Path, source| XdgError::Io { path: path.into(), source };
+
+    let original_path = match path.is_absolute() {
+        true => path.to_path_buf(),
+        false => env::current_dir().map_err(|source| io_err(path, source))?.join(path),
+    };
+    fs::symlink_metadata(&original_path).map_err(|source| io_err(&original_path, source))?;
+    let file_name = original_path.file_name().map(Path::new).ok_or_else(|| {
+        io_err(&original_path, io::Error::new(io::ErrorKind::InvalidInput, "no file name"))
+    })?;
+
+    let (files_dir, info_dir) = (trash.join("files"), trash.join("info"));
+    Xdg::create_dir_all(&files_dir)?;
+    Xdg::create_dir_all(&info_dir)?;
+
+    let deletion_date = format_local_date(SystemTime::now());
+    let info = format!(
+        "[Trash Info]\nPath={}\nDeletionDate={deletion_date}\n",
+        encode_path(&original_path)
+    );
+
+    // Reserve a name by atomically creating its metadata file.
+    let mut attempt = 0;
+    let (name, info_path, mut info_file) = loop {
+        let name = candidate_name(file_name, attempt);
+        let info_path = info_path(&info_dir, &name);
+        attempt += 1;
+
+        if files_dir.join(&name).exists() {
+            continue;
+        }
+
+        match OpenOptions::new().write(true).create_new(true).open(&info_path) {
+            Ok(info_file) => break (name, info_path, info_file),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(source) => return Err(io_err(&info_path, source)),
+        }
+    };
+
+    let trashed_path = files_dir.join(&name);
+    let result = info_file
+        .write_all(info.as_bytes())
+        .map_err(|source| io_err(&info_path, source))
+        .and_then(|()| {
+            fs::rename(&original_path, &trashed_path)
+                .map_err(|source| io_err(&original_path, source))
+        });
+
+    if let Err(err) = result {
+        // Release the reserved name, the file was not trashed.
+        let _ = fs::remove_file(&info_path);
+        return Err(err);
+    }
+
+    Ok(TrashEntry { name, original_path, deletion_date, path: trashed_path, info_path })
+}
+
+/// Lists the entries of the `trash` directory, sorted by name.
+///
+/// # Note
+///
+/// Malformed metadata files and metadata files without a matching trashed
+/// file are skipped.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if the `info` directory
+/// exists but could not be read.
+pub(crate) fn list(trash: &Path) -> Result<Vec<TrashEntry>, XdgError> {
+    let (files_dir, info_dir) = (trash.join("files"), trash.join("info"));
+    let entries = match fs::read_dir(&info_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => return Err(XdgError::Io { path: info_dir, source }),
+    };
+
+    let mut trash_entries = Vec::new();
+    for entry in entries.filter_map(Result::ok) {
+        let info_path = entry.path();
+        if info_path.extension().map_or(true, |ext| ext != INFO_EXT) {
+            continue;
+        }
+
+        let Some(name) = info_path.file_stem().map(OsString::from) else {
+            continue;
+        };
+        let Some((original_path, deletion_date)) =
+            fs::read_to_string(&info_path).ok().as_deref().and_then(parse_info)
+        else {
+            continue;
+        };
+
+        let path = files_dir.join(&name);
+        if fs::symlink_metadata(&path).is_ok() {
+            trash_entries.push(TrashEntry { name, original_path, deletion_date, path, info_path });
+        }
+    }
+
+    trash_entries.sort_unstable_by(|entry, other| entry.name.cmp(&other.name));
+    Ok(trash_entries)
+}
+
+/// Moves the trashed file of `entry` back to its original path, removing its
+/// metadata file.
+///
+/// # Errors
+///
+/// This function returns an [`XdgError::Io`] error if the original path is
+/// already taken or the trashed file could not be moved back.
+pub(crate) fn restore(entry: &TrashEntry) -> Result<PathBuf, XdgError> {
+    let original_path = &entry.original_path;
+    if fs::symlink_metadata(original_path).is_ok() {
+        return Err(XdgError::Io {
+            path: original_path.clone(),
+            source: io::Error::new(io::ErrorKind::AlreadyExists, "original path already exists"),
+        });
+    }
+
+    fs::rename(&entry.path, original_path)
+        .map_err(|source| XdgError::Io { path: entry.path.clone(), source })?;
+    fs::remove_file(&entry.info_path)
+        .map_err(|source| XdgError::Io { path: entry.info_path.clone(), source })?;
+
+    Ok(original_path.clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn trash_info() {
+        let path = Path::new("/home/user/my file%.txt");
+        assert_eq!("/home/user/my%20file%25.txt", encode_path(path));
+        assert_eq!(Some(path.to_path_buf()), decode_path(&encode_path(path)));
+        assert_eq!(None, decode_path("/home/user/%2"));
+        assert_eq!(None, decode_path("/home/user/%zz"));
+
+        assert_eq!("1970-01-01T00:00:00", format_date(UNIX_EPOCH));
+        assert_eq!(
+            "2004-08-31T22:32:08",
+            format_date(UNIX_EPOCH + std::time::Duration::from_secs(1_093_991_528)),
+        );
+        assert_eq!(
+            "2024-02-29T12:00:00",
+            format_date(UNIX_EPOCH + std::time::Duration::from_secs(1_709_208_000)),
+        );
+        let date = format_local_date(SystemTime::now());
+        assert_eq!(19, date.len());
+        assert_eq!(Some('T'), date.chars().nth(10));
+
+        assert_eq!(
+            Some((PathBuf::from("/home/user/my file"), String::from("2004-08-31T22:32:08"))),
+            parse_info(
+                "[Trash Info]\nPath=/home/user/my%20file\nDeletionDate=2004-08-31T22:32:08\n"
+            ),
+        );
+        assert_eq!(None, parse_info("[Desktop Entry]\nPath=/home/user/file\n"));
+        assert_eq!(None, parse_info("[Trash Info]\nDeletionDate=2004-08-31T22:32:08\n"));
+
+        assert_eq!(OsString::from("file.txt"), candidate_name(Path::new("file.txt"), 0));
+        assert_eq!(OsString::from("file.2.txt"), candidate_name(Path::new("file.txt"), 2));
+        assert_eq!(OsString::from("file.1"), candidate_name(Path::new("file"), 1));
+    }
+}
@@ -0,0 +1,74 @@
+use std::future::Future;
+use std::panic;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::task::JoinHandle;
+
+/// Future resolving to the output of a blocking closure run on the Tokio
+/// blocking thread pool, returned by [`Xdg::unblock`](crate::Xdg::unblock) and
+/// [`XdgApp::unblock`](crate::XdgApp::unblock).
+///
+/// The blocking pool reuses its threads and bounds their number, queueing
+/// closures once the bound is reached. Dropping the future does not cancel a
+/// closure already running, which runs to completion in the background. If
+/// the closure panics, the panic is propagated when the future is polled.
+#[must_use = "futures do nothing unless polled"]
+pub struct Unblock<T> {
+    handle: JoinHandle<T>,
+}
+
+impl<T> Future for Unblock<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        match Pin::new(&mut self.get_mut().handle).poll(cx) {
+            Poll::Ready(Ok(output)) => Poll::Ready(output),
+            Poll::Ready(Err(err)) if err.is_panic() => panic::resume_unwind(err.into_panic()),
+            // Blocking closures are only cancelled when the runtime shuts down
+            // before they start.
+            Poll::Ready(Err(err)) => panic!("blocking closure not run: {err}"),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Runs `f` on the Tokio blocking thread pool, returning a future resolving
+/// to its output.
+///
+/// # Panics
+///
+/// This function panics if called outside the context of a Tokio runtime.
+pub(crate) fn unblock<F, T>(f: F) -> Unblock<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Unblock { handle: tokio::task::spawn_blocking(f) }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    /// Runs `future` to completion on a single-threaded Tokio runtime.
+    pub(crate) fn block_on<F>(future: F) -> F::Output
+    where
+        F: Future,
+    {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("unable to build the runtime")
+            .block_on(future)
+    }
+
+    #[test]
+    fn unblock_future() {
+        assert_eq!(42, block_on(async { unblock(|| 6 * 7).await }));
+
+        let result = panic::catch_unwind(|| {
+            block_on(async { unblock(|| panic!("unblock")).await });
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::thread::{self, JoinHandle};
+
+use notify::event::EventKind;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::app::collect_files_recursive;
+
+/// Shared handle to the underlying `notify` watcher.
+type SharedWatcher = Arc<Mutex<RecommendedWatcher>>;
+
+/// Handle to a background watcher started by
+/// [`XdgApp::watch_app_config`](crate::XdgApp::watch_app_config).
+///
+/// The watcher stops when [`ConfigWatcher::stop`] is called or, at the
+/// latest, when the handle is dropped.
+#[derive(Debug)]
+#[must_use = "the watcher stops as soon as it is dropped"]
+pub struct ConfigWatcher {
+    watcher: Option<SharedWatcher>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ConfigWatcher {
+    /// Stops the watcher, waiting for its background thread to exit, so that
+    /// the callback is never invoked once this method returns.
+    #[inline]
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    /// Drops the `notify` watcher, disconnecting the event channel, and joins
+    /// the background thread.
+    fn shutdown(&mut self) {
+        // The background thread only holds a weak reference to the watcher:
+        // dropping this one drops the watcher along with its event sender.
+        self.watcher = None;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Directory of the precedence chain being watched.
+struct WatchedDir {
+    dir: PathBuf,
+    /// Whether `dir` is missing, its nearest existing ancestor being watched
+    /// non-recursively in its place until it gets created.
+    pending: bool,
+}
+
+/// Converts a `notify` error into an [`io::Error`].
+fn io_error(err: notify::Error) -> io::Error {
+    match err.kind {
+        notify::ErrorKind::Io(err) => err,
+        _ => io::Error::new(io::ErrorKind::Other, err),
+    }
+}
+
+/// Starts watching `dir`: recursively if it exists, or else its nearest
+/// existing ancestor, non-recursively, until `dir` gets created.
+fn watch_dir(watcher: &mut RecommendedWatcher, dir: PathBuf) -> io::Result<WatchedDir> {
+    if dir.is_dir() {
+        watcher.watch(&dir, RecursiveMode::Recursive).map_err(io_error)?;
+        return Ok(WatchedDir { dir, pending: false });
+    }
+
+    if let Some(ancestor) = dir.ancestors().skip(1).find(|ancestor| ancestor.is_dir()) {
+        watcher.watch(ancestor, RecursiveMode::NonRecursive).map_err(io_error)?;
+    }
+
+    Ok(WatchedDir { dir, pending: true })
+}
+
+/// Handles the `event`, invoking `callback` with each changed path inside the
+/// watched directories, and moving the watches of the directories created or
+/// removed.
+fn handle_event<F>(
+    event: &Event,
+    dirs: &mut [WatchedDir],
+    watcher: &Weak<Mutex<RecommendedWatcher>>,
+    callback: &mut F,
+) where
+    F: FnMut(&Path),
+{
+    if matches!(event.kind, EventKind::Access(_)) {
+        return;
+    }
+
+    for path in &event.paths {
+        if dirs.iter().any(|watched| path.starts_with(&watched.dir)) {
+            callback(path);
+        }
+    }
+
+    for watched in dirs.iter_mut() {
+        let created =
+            watched.pending && event.paths.iter().any(|path| watched.dir.starts_with(path));
+        let removed = !watched.pending
+            && matches!(event.kind, EventKind::Remove(_))
+            && event.paths.iter().any(|path| path == &watched.dir);
+        if !created && !removed {
+            continue;
+        }
+
+        let Some(watcher) = watcher.upgrade() else {
+            return;
+        };
+        let mut watcher = watcher.lock().unwrap_or_else(PoisonError::into_inner);
+        let Ok(rewatched) = watch_dir(&mut watcher, watched.dir.clone()) else {
+            continue;
+        };
+        drop(watcher);
+
+        // Files written before the directory was watched are reported as
+        // well, as no event was emitted for them.
+        if created && !rewatched.pending {
+            let mut files = Vec::new();
+            collect_files_recursive(&rewatched.dir, &mut files);
+            files.sort();
+            for (path, ..) in files {
+                callback(&path);
+            }
+        }
+        *watched = rewatched;
+    }
+}
+
+/// Spawns a background thread watching `dirs` through the platform
+/// notification API, invoking `callback` with the path of each file created,
+/// modified or removed.
+pub(crate) fn spawn<F>(dirs: Vec<PathBuf>, mut callback: F) -> io::Result<ConfigWatcher>
+where
+    F: FnMut(&Path) + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(io_error)?;
+    let mut watched = Vec::with_capacity(dirs.len());
+    for dir in dirs {
+        watched.push(watch_dir(&mut watcher, dir)?);
+    }
+
+    let watcher = Arc::new(Mutex::new(watcher));
+    let weak_watcher = Arc::downgrade(&watcher);
+    let handle = thread::Builder::new().name(String::from("microxdg-watch")).spawn(move || {
+        run(&receiver, &mut watched, &weak_watcher, &mut callback);
+    })?;
+
+    Ok(ConfigWatcher { watcher: Some(watcher), handle: Some(handle) })
+}
+
+/// Handles the events received through `receiver`, until the watcher is
+/// dropped.
+fn run<F>(
+    receiver: &Receiver<notify::Result<Event>>,
+    dirs: &mut [WatchedDir],
+    watcher: &Weak<Mutex<RecommendedWatcher>>,
+    callback: &mut F,
+) where
+    F: FnMut(&Path),
+{
+    // Errors (e.g. an overflowing event queue) are not actionable.
+    for event in receiver.iter().flatten() {
+        handle_event(&event, dirs, watcher, callback);
+    }
+}